@@ -169,3 +169,32 @@ fn _4d_u64_single_point_read_location_1_100000_1_1(b: &mut Bencher) {
         ).unwrap();
     });
 }
+
+#[bench]
+fn _4d_u64_single_point_read_location_1_1_1_1_handle(b: &mut Bencher) {
+    let axis = [
+        2,
+        2,
+        2,
+        2,
+    ];
+
+    let point1 = [1, 1, 1, 1];
+    let value1 = 5;
+
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &axis
+    ).unwrap();
+
+    let mut database = cql_db::open::<U64>(DATABASE_LOCATION).unwrap();
+
+    database.link_dimensions_unchecked(&point1[0..3]).unwrap();
+    database.write_value_unchecked(&point1, value1).unwrap();
+
+    b.iter(|| {
+        database.read_value(
+            &point1
+        ).unwrap();
+    });
+}
@@ -0,0 +1,203 @@
+/*!
+Aggregations that can be computed over a range of values as they are read from a database, using [aggregate](../fn.aggregate.html).
+
+Each aggregation works with any storage type implementing [CqlAggregatable](https://docs.rs/cql_model/0.3/cql_model/trait.CqlAggregatable.html), such as
+[U64](https://docs.rs/cql_u64/0.3/cql_u64/struct.U64.html).  Values that do not hold a number, such as the nulls of a
+[NullableF64](https://docs.rs/cql_nullable_f64/0.3/cql_nullable_f64/struct.NullableF64.html), are skipped by every aggregation - including [Count](struct.Count.html).
+Values that have never been written are aggregated as the default value of their type, in the same way as they would be read.
+
+# Examples
+```
+use cql_u64::U64;
+use cql_db::aggregation::{ Sum, Max, Mean };
+
+# use std::error::Error;
+# use std::fs::remove_file;
+# fn main() -> Result<(), Box<dyn Error>> {
+#
+# const DATABASE_LOCATION: &str = "./.test_db";
+# let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/db"));
+# let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/ax"));
+cql_db::create_db::<U64>(
+    DATABASE_LOCATION,
+    &[4]
+)?;
+
+cql_db::write_values::<U64>(DATABASE_LOCATION, &[(&[1], 2), (&[2], 4), (&[3], 9)])?;
+
+assert_eq!(cql_db::aggregate::<U64, Sum>(DATABASE_LOCATION, &[1], 4)?, 15);
+assert_eq!(cql_db::aggregate::<U64, Max>(DATABASE_LOCATION, &[1], 4)?, Some(9));
+assert_eq!(cql_db::aggregate::<U64, Mean>(DATABASE_LOCATION, &[1], 3)?, Some(5.0));
+# Ok(())
+# }
+```
+*/
+use cql_model::CqlAggregatable;
+
+/// An aggregation of the numbers held by a range of values.
+///
+/// Numbers are added to the aggregation's [State](#associatedtype.State) one by one as they are read, before the result is taken from it once every
+/// value has been read.
+pub trait Aggregation<TStore: CqlAggregatable> {
+    /// The running state of the aggregation, the default of which is the state before any number has been added.
+    type State: Default;
+    /// The result of the aggregation.
+    type Output;
+
+    /// Adds the given number to the given state.
+    fn add(state: &mut Self::State, number: TStore::Number);
+
+    /// Adds every number summarised by the given summary to the given state, as if they had been added one by one.
+    ///
+    /// Returns `false`, leaving the state unchanged, should the aggregation not be able to be computed from summaries - in which case
+    /// [rollups](../fn.rollup.html) will read the values themselves rather than the [rollup levels](../fn.create_db_with_rollups.html) of the database.
+    /// Unless overridden, always returns `false`.
+    fn add_summary(_state: &mut Self::State, _summary: &Summary<TStore>) -> bool {
+        false
+    }
+
+    /// Returns the result of the aggregation from the given state.
+    fn result(state: Self::State) -> Self::Output;
+}
+
+/// A summary of a range of numbers, as kept by the [rollup levels](../fn.create_db_with_rollups.html) of a database.
+pub struct Summary<TStore: CqlAggregatable> {
+    /// The number of values holding a number.
+    pub count: u64,
+    /// The sum of the numbers.
+    pub sum: TStore::Sum,
+    /// The smallest of the numbers, or `None` should there be no numbers.
+    pub min: Option<TStore::Number>,
+    /// The largest of the numbers, or `None` should there be no numbers.
+    pub max: Option<TStore::Number>,
+}
+
+/// The sum of the numbers, as the storage type's [Sum](https://docs.rs/cql_model/0.3/cql_model/trait.CqlAggregatable.html#associatedtype.Sum) type.
+/// The sum of no numbers is zero.
+pub struct Sum;
+
+impl<TStore: CqlAggregatable> Aggregation<TStore> for Sum {
+    type State = TStore::Sum;
+    type Output = TStore::Sum;
+
+    fn add(state: &mut TStore::Sum, number: TStore::Number) {
+        *state = *state + TStore::Sum::from(number);
+    }
+
+    fn add_summary(state: &mut TStore::Sum, summary: &Summary<TStore>) -> bool {
+        *state = *state + summary.sum;
+        true
+    }
+
+    fn result(state: TStore::Sum) -> TStore::Sum {
+        state
+    }
+}
+
+/// The smallest of the numbers, or `None` should there be no numbers.
+pub struct Min;
+
+impl<TStore: CqlAggregatable> Aggregation<TStore> for Min {
+    type State = Option<TStore::Number>;
+    type Output = Option<TStore::Number>;
+
+    fn add(state: &mut Option<TStore::Number>, number: TStore::Number) {
+        let is_new_min = match state {
+            Some(min) => number < *min,
+            None => true,
+        };
+
+        if is_new_min {
+            *state = Some(number);
+        }
+    }
+
+    fn add_summary(state: &mut Option<TStore::Number>, summary: &Summary<TStore>) -> bool {
+        if let Some(number) = summary.min {
+            <Min as Aggregation<TStore>>::add(state, number);
+        }
+        true
+    }
+
+    fn result(state: Option<TStore::Number>) -> Option<TStore::Number> {
+        state
+    }
+}
+
+/// The largest of the numbers, or `None` should there be no numbers.
+pub struct Max;
+
+impl<TStore: CqlAggregatable> Aggregation<TStore> for Max {
+    type State = Option<TStore::Number>;
+    type Output = Option<TStore::Number>;
+
+    fn add(state: &mut Option<TStore::Number>, number: TStore::Number) {
+        let is_new_max = match state {
+            Some(max) => number > *max,
+            None => true,
+        };
+
+        if is_new_max {
+            *state = Some(number);
+        }
+    }
+
+    fn add_summary(state: &mut Option<TStore::Number>, summary: &Summary<TStore>) -> bool {
+        if let Some(number) = summary.max {
+            <Max as Aggregation<TStore>>::add(state, number);
+        }
+        true
+    }
+
+    fn result(state: Option<TStore::Number>) -> Option<TStore::Number> {
+        state
+    }
+}
+
+/// The mean of the numbers, or `None` should there be no numbers.
+pub struct Mean;
+
+impl<TStore: CqlAggregatable> Aggregation<TStore> for Mean {
+    type State = (TStore::Sum, u64);
+    type Output = Option<f64>;
+
+    fn add(state: &mut (TStore::Sum, u64), number: TStore::Number) {
+        state.0 = state.0 + TStore::Sum::from(number);
+        state.1 += 1;
+    }
+
+    fn add_summary(state: &mut (TStore::Sum, u64), summary: &Summary<TStore>) -> bool {
+        state.0 = state.0 + summary.sum;
+        state.1 += summary.count;
+        true
+    }
+
+    fn result((sum, count): (TStore::Sum, u64)) -> Option<f64> {
+        if count == 0 {
+            return None
+        }
+
+        Some(TStore::sum_to_f64(sum) / count as f64)
+    }
+}
+
+/// The number of values holding a number.
+pub struct Count;
+
+impl<TStore: CqlAggregatable> Aggregation<TStore> for Count {
+    type State = u64;
+    type Output = u64;
+
+    fn add(state: &mut u64, _: TStore::Number) {
+        *state += 1;
+    }
+
+    fn add_summary(state: &mut u64, summary: &Summary<TStore>) -> bool {
+        *state += summary.count;
+        true
+    }
+
+    fn result(state: u64) -> u64 {
+        state
+    }
+}
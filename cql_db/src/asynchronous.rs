@@ -0,0 +1,408 @@
+/*!
+Async versions of the core functions of this crate, for use within a [tokio](https://docs.rs/tokio) runtime.  Requires the `async` feature.
+
+A database may be opened as an async [handle](struct.Database.html), which holds its files open between calls in the same way as the synchronous
+[handle](../struct.Database.html) it wraps.  Each call runs the matching method of the synchronous handle on the runtime's blocking thread pool, so they
+validate the given parameters, lock the database and return errors in exactly the same way - without blocking the thread that awaits them.  Free functions
+opening the database for each call, as their synchronous counterparts (e.g. [read_value](../fn.read_value.html)) do, are also provided.
+
+Values read to a stream are written to the given [AsyncWrite](https://docs.rs/tokio/1/tokio/io/trait.AsyncWrite.html) stream in blocks of 64 KiB as they
+are read, with each block written before the one after the next is read, so that a slow stream holds back the read rather than it being buffered in
+memory.
+
+# Panics
+
+Functions will panic if called outside of a tokio runtime, and will resume any panic raised by their synchronous counterparts.
+
+# Examples
+```
+use cql_u64::U64;
+
+# use std::error::Error;
+# use std::fs::remove_file;
+# fn main() -> Result<(), Box<dyn Error>> {
+#
+# const DATABASE_LOCATION: &str = "./.test_db";
+# let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/db"));
+# let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/ax"));
+# let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/key1_2"));
+let runtime = tokio::runtime::Builder::new_current_thread().build()?;
+
+runtime.block_on(async {
+    cql_db::create_db::<U64>(
+        DATABASE_LOCATION,
+        &[2, 5, 3]
+    )?;
+
+    let mut database = cql_db::asynchronous::open::<U64>(DATABASE_LOCATION).await?;
+
+    database.link_dimensions(&[2, 4]).await?;
+    database.write_value(&[2, 4, 3], 5).await?;
+
+    let mut stream = Vec::new();
+    database.read_to_stream(&mut stream, &[2, 4, 2], 2).await?;
+
+    assert_eq!(cql_db::asynchronous::read_value::<U64>(DATABASE_LOCATION, &[2, 4, 3]).await?, 5);
+    assert_eq!(stream, [0, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0]);
+    Ok(())
+})
+# }
+```
+*/
+use std::io;
+use std::io::Write;
+use std::mem;
+use std::panic;
+use std::sync::{ Arc, Mutex, MutexGuard, PoisonError };
+
+use tokio::io::{ AsyncWrite, AsyncWriteExt };
+use tokio::sync::mpsc;
+use tokio::task;
+use tokio::task::JoinHandle;
+
+use cql_model::{
+    CqlType,
+    CqlRangeReadable,
+    CqlBufferWritable,
+    CqlBufferStreamReadable,
+};
+
+use crate::database::BUFFER_SIZE;
+use crate::storage::FileStorage;
+use crate::result;
+
+/// An async handle to an open CQL database, holding its files open between calls.
+///
+/// Opened with [open](fn.open.html).  See the synchronous [handle](../struct.Database.html) that it wraps for more information.
+///
+/// # Examples
+/// ```
+/// use cql_u64::U64;
+///
+/// # use std::error::Error;
+/// # use std::fs::remove_file;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// #
+/// # const DATABASE_LOCATION: &str = "./.test_db";
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/db"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/ax"));
+/// let runtime = tokio::runtime::Builder::new_current_thread().build()?;
+///
+/// runtime.block_on(async {
+///     cql_db::create_db::<U64>(
+///         DATABASE_LOCATION,
+///         &[10]
+///     )?;
+///
+///     let mut database = cql_db::asynchronous::open::<U64>(DATABASE_LOCATION).await?;
+///
+///     for i in 1..4 {
+///         database.write_value(&[i], i * 10).await?;
+///     }
+///
+///     assert_eq!(database.read_value(&[2]).await?, 20);
+///     Ok(())
+/// })
+/// # }
+/// ```
+pub struct Database<TStore: CqlType> {
+    // shared with the blocking task running each call, which runs to completion even should the future awaiting it be dropped
+    database: Arc<Mutex<crate::Database<TStore>>>,
+}
+
+impl<TStore: CqlType + 'static> Database<TStore> {
+    fn new(database: crate::Database<TStore>) -> Database<TStore> {
+        Database {
+            database: Arc::new(Mutex::new(database)),
+        }
+    }
+
+    /// Links dimension indexes together if they are not already linked.  Does not validate given parameters.
+    ///
+    /// See [link_dimensions_unchecked](../fn.link_dimensions_unchecked.html) for more information.
+    pub async fn link_dimensions_unchecked(&mut self, location: &[u64]) -> io::Result<()> {
+        let location = location.to_vec();
+
+        self.run(move |database| database.link_dimensions_unchecked(&location)).await
+    }
+
+    /// Links dimension indexes together if they are not already linked.
+    ///
+    /// See [link_dimensions](../fn.link_dimensions.html) for more information.
+    pub async fn link_dimensions(&mut self, location: &[u64]) -> result::Result<()> {
+        let location = location.to_vec();
+
+        self.run(move |database| database.link_dimensions(&location)).await
+    }
+
+    /// Writes a value to the given location in the database.  Does not validate given parameters.
+    ///
+    /// See [write_value_unchecked](../fn.write_value_unchecked.html) for more information.
+    pub async fn write_value_unchecked(&mut self, location: &[u64], value: TStore::ValueType) -> io::Result<()>
+        where TStore: CqlBufferWritable, TStore::ValueType: Send {
+        let location = location.to_vec();
+
+        self.run(move |database| database.write_value_unchecked(&location, value)).await
+    }
+
+    /// Writes a value to the given location in the database.
+    ///
+    /// See [write_value](../fn.write_value.html) for more information.
+    pub async fn write_value(&mut self, location: &[u64], value: TStore::ValueType) -> result::Result<()>
+        where TStore: CqlBufferWritable, TStore::ValueType: Send {
+        let location = location.to_vec();
+
+        self.run(move |database| database.write_value(&location, value)).await
+    }
+
+    /// Reads a value from the given location in the database.  Does not validate given parameters.
+    ///
+    /// See [read_value_unchecked](../fn.read_value_unchecked.html) for more information.
+    pub async fn read_value_unchecked(&mut self, location: &[u64]) -> io::Result<TStore::ValueType>
+        where TStore: CqlRangeReadable, TStore::ValueType: Send {
+        let location = location.to_vec();
+
+        self.run(move |database| database.read_value_unchecked(&location)).await
+    }
+
+    /// Reads a value from the given location in the database.
+    ///
+    /// See [read_value](../fn.read_value.html) for more information.
+    pub async fn read_value(&mut self, location: &[u64]) -> result::Result<TStore::ValueType>
+        where TStore: CqlRangeReadable, TStore::ValueType: Send {
+        let location = location.to_vec();
+
+        self.run(move |database| database.read_value(&location)).await
+    }
+
+    /// Reads `n_values` from the given location onward into the given stream.  Does not validate given parameters.
+    ///
+    /// See [read_to_stream_unchecked](../fn.read_to_stream_unchecked.html) for more information.  The values are written to the stream in blocks as
+    /// they are read, so should the read fail part way through, those read before it will already have been written.
+    pub async fn read_to_stream_unchecked(
+        &mut self,
+        stream: &mut (impl AsyncWrite + Unpin + ?Sized),
+        location: &[u64],
+        n_values: u64
+    ) -> io::Result<()> where TStore: CqlBufferStreamReadable {
+        let location = location.to_vec();
+
+        self.run_to_stream(stream, move |database, blocks| database.read_to_stream_unchecked(blocks, &location, n_values)).await
+    }
+
+    /// Reads `n_values` from the given location onward into the given stream.
+    ///
+    /// See [read_to_stream](../fn.read_to_stream.html) for more information.  Nothing is written to the stream should the given parameters be invalid,
+    /// but as the values are written in blocks as they are read, should the read fail part way through, those read before it will already have been
+    /// written.
+    pub async fn read_to_stream(
+        &mut self,
+        stream: &mut (impl AsyncWrite + Unpin + ?Sized),
+        location: &[u64],
+        n_values: u64
+    ) -> result::Result<()> where TStore: CqlBufferStreamReadable {
+        let location = location.to_vec();
+
+        self.run_to_stream(stream, move |database, blocks| database.read_to_stream(blocks, &location, n_values)).await
+    }
+
+    // Runs the given function against the synchronous handle on the blocking thread pool
+    async fn run<T, E>(&mut self, function: impl FnOnce(&mut crate::Database<TStore>) -> Result<T, E> + Send + 'static) -> Result<T, E>
+        where T: Send + 'static, E: From<io::Error> + Send + 'static {
+        let database = Arc::clone(&self.database);
+
+        blocking(move || function(&mut lock(&database))).await
+    }
+
+    // Runs the given function against the synchronous handle on the blocking thread pool, writing each block that it reads to the given stream before
+    // the function is allowed to read past the next.  Should writing to the stream fail, the function's writes will fail in turn, ending the read.
+    async fn run_to_stream<E>(
+        &mut self,
+        stream: &mut (impl AsyncWrite + Unpin + ?Sized),
+        function: impl FnOnce(&mut crate::Database<TStore>, &mut BlockWriter) -> Result<(), E> + Send + 'static
+    ) -> Result<(), E> where E: From<io::Error> + Send + 'static {
+        let database = Arc::clone(&self.database);
+        let (sender, mut receiver) = mpsc::channel(1);
+
+        let reading = task::spawn_blocking(move || {
+            let mut blocks = BlockWriter::new(sender);
+            function(&mut lock(&database), &mut blocks)?;
+            blocks.flush()?;
+            Ok::<_, E>(())
+        });
+
+        while let Some(block) = receiver.recv().await {
+            stream.write_all(&block).await?;
+        }
+
+        join(reading).await?;
+        stream.flush().await?;
+        Ok(())
+    }
+}
+
+/// Opens an async [handle](struct.Database.html) to an existing CQL database at the given location.
+///
+/// See [open](../fn.open.html) for more information.
+pub async fn open<TStore: CqlType + 'static>(db_location: &str) -> result::Result<Database<TStore>> {
+    let db_location = db_location.to_string();
+
+    let database = blocking(move || crate::open::<TStore>(&db_location)).await?;
+    Ok(Database::new(database))
+}
+
+/// Links dimension indexes together if they are not already linked.  Does not validate given parameters.
+///
+/// See [link_dimensions_unchecked](../fn.link_dimensions_unchecked.html) for more information.
+pub async fn link_dimensions_unchecked<TStore: CqlType + 'static>(db_location: &str, location: &[u64]) -> io::Result<()> {
+    open_unchecked::<TStore>(db_location).await?.link_dimensions_unchecked(location).await
+}
+
+/// Links dimension indexes together if they are not already linked.
+///
+/// See [link_dimensions](../fn.link_dimensions.html) for more information.
+pub async fn link_dimensions<TStore: CqlType + 'static>(db_location: &str, location: &[u64]) -> result::Result<()> {
+    open::<TStore>(db_location).await?.link_dimensions(location).await
+}
+
+/// Writes a value to the given location in the database.  Does not validate given parameters.
+///
+/// See [write_value_unchecked](../fn.write_value_unchecked.html) for more information.
+pub async fn write_value_unchecked<TStore: CqlBufferWritable + 'static>(db_location: &str, location: &[u64], value: TStore::ValueType)
+    -> io::Result<()> where TStore::ValueType: Send {
+    open_unchecked::<TStore>(db_location).await?.write_value_unchecked(location, value).await
+}
+
+/// Writes a value to the given location in the database.
+///
+/// See [write_value](../fn.write_value.html) for more information.
+pub async fn write_value<TStore: CqlBufferWritable + 'static>(db_location: &str, location: &[u64], value: TStore::ValueType)
+    -> result::Result<()> where TStore::ValueType: Send {
+    open::<TStore>(db_location).await?.write_value(location, value).await
+}
+
+/// Reads a value from the given location in the database.  Does not validate given parameters.
+///
+/// See [read_value_unchecked](../fn.read_value_unchecked.html) for more information.
+pub async fn read_value_unchecked<TStore: CqlRangeReadable + 'static>(db_location: &str, location: &[u64]) -> io::Result<TStore::ValueType>
+    where TStore::ValueType: Send {
+    open_read_only_unchecked::<TStore>(db_location).await?.read_value_unchecked(location).await
+}
+
+/// Reads a value from the given location in the database.
+///
+/// See [read_value](../fn.read_value.html) for more information.
+pub async fn read_value<TStore: CqlRangeReadable + 'static>(db_location: &str, location: &[u64]) -> result::Result<TStore::ValueType>
+    where TStore::ValueType: Send {
+    open_read_only::<TStore>(db_location).await?.read_value(location).await
+}
+
+/// Reads `n_values` from the given location onward into the given stream.  Does not validate given parameters.
+///
+/// See [Database::read_to_stream_unchecked](struct.Database.html#method.read_to_stream_unchecked) for more information.
+pub async fn read_to_stream_unchecked<TStore: CqlBufferStreamReadable + 'static>(
+    db_location: &str,
+    stream: &mut (impl AsyncWrite + Unpin + ?Sized),
+    location: &[u64],
+    n_values: u64
+) -> io::Result<()> {
+    open_read_only_unchecked::<TStore>(db_location).await?.read_to_stream_unchecked(stream, location, n_values).await
+}
+
+/// Reads `n_values` from the given location onward into the given stream.
+///
+/// See [Database::read_to_stream](struct.Database.html#method.read_to_stream) for more information.
+pub async fn read_to_stream<TStore: CqlBufferStreamReadable + 'static>(
+    db_location: &str,
+    stream: &mut (impl AsyncWrite + Unpin + ?Sized),
+    location: &[u64],
+    n_values: u64
+) -> result::Result<()> {
+    open_read_only::<TStore>(db_location).await?.read_to_stream(stream, location, n_values).await
+}
+
+// Opens a handle for the free functions that do not validate their parameters, without checking the type of the database
+async fn open_unchecked<TStore: CqlType + 'static>(db_location: &str) -> io::Result<Database<TStore>> {
+    let db_location = db_location.to_string();
+
+    let database = blocking(move || crate::Database::<TStore>::open(FileStorage::new(&db_location))).await?;
+    Ok(Database::new(database))
+}
+
+// Opens a handle for the free functions that only read from the database, which need not be able to write to its files
+async fn open_read_only<TStore: CqlType + 'static>(db_location: &str) -> result::Result<Database<TStore>> {
+    let db_location = db_location.to_string();
+
+    let database = blocking(move || crate::open_read_only::<TStore>(&db_location)).await?;
+    Ok(Database::new(database))
+}
+
+async fn open_read_only_unchecked<TStore: CqlType + 'static>(db_location: &str) -> io::Result<Database<TStore>> {
+    let db_location = db_location.to_string();
+
+    let database = blocking(move || crate::Database::<TStore>::open_read_only(FileStorage::new(&db_location))).await?;
+    Ok(Database::new(database))
+}
+
+// Passes the bytes written to it on to the task awaiting them in blocks of BUFFER_SIZE, waiting for the block before to be taken before sending each
+struct BlockWriter {
+    sender: mpsc::Sender<Vec<u8>>,
+    block: Vec<u8>,
+}
+
+impl BlockWriter {
+    fn new(sender: mpsc::Sender<Vec<u8>>) -> BlockWriter {
+        BlockWriter {
+            sender,
+            block: Vec::with_capacity(BUFFER_SIZE),
+        }
+    }
+
+    fn send(&mut self) -> io::Result<()> {
+        let block = mem::replace(&mut self.block, Vec::with_capacity(BUFFER_SIZE));
+
+        match self.sender.blocking_send(block) {
+            Ok(()) => Ok(()),
+            // the awaiting task only stops receiving should it have failed to write to its stream
+            Err(_) => Err(io::Error::new(io::ErrorKind::BrokenPipe, "the stream being read to is no longer being written to")),
+        }
+    }
+}
+
+impl Write for BlockWriter {
+    fn write(&mut self, buffer: &[u8]) -> io::Result<usize> {
+        let n_bytes = buffer.len().min(BUFFER_SIZE - self.block.len());
+        self.block.extend_from_slice(&buffer[..n_bytes]);
+
+        if self.block.len() == BUFFER_SIZE {
+            self.send()?;
+        }
+        Ok(n_bytes)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        if !self.block.is_empty() {
+            self.send()?;
+        }
+        Ok(())
+    }
+}
+
+// Should a call panic whilst holding the handle, the panic is resumed by the task awaiting it, the handle is left to be used by any later calls
+fn lock<TStore: CqlType>(database: &Mutex<crate::Database<TStore>>) -> MutexGuard<'_, crate::Database<TStore>> {
+    database.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+// Runs the given function on the blocking thread pool, should the runtime be shut down before it completes an Interrupted error is returned
+async fn blocking<T, E>(function: impl FnOnce() -> Result<T, E> + Send + 'static) -> Result<T, E>
+    where T: Send + 'static, E: From<io::Error> + Send + 'static {
+    join(task::spawn_blocking(function)).await
+}
+
+async fn join<T, E>(task: JoinHandle<Result<T, E>>) -> Result<T, E> where E: From<io::Error> {
+    match task.await {
+        Ok(result) => result,
+        Err(join_error) if join_error.is_panic() => panic::resume_unwind(join_error.into_panic()),
+        Err(join_error) => Err(io::Error::new(io::ErrorKind::Interrupted, join_error).into()),
+    }
+}
@@ -1,13 +1,16 @@
-use std::fs::OpenOptions;
 use std::io;
 
 use cql_u64::U64;
 use cql_model::{
     CqlType,
-    CqlWritable,
-    CqlReadable,
+    CqlRangeReadable,
+    CqlBufferWritable,
 };
 
+use crate::journal::{ Batch, Operation };
+use crate::storage::{ Storage, StorageFile };
+use crate::FORMAT_VERSION;
+
 const AXIS_FILE_NAME: &str = "/ax";
 
 pub struct AxisDefinition {
@@ -16,42 +19,83 @@ pub struct AxisDefinition {
 }
 
 // The axis definitions are stored in the axis library.  The first block contains how many dimensions exist.
-// The subsequent blocks contain the max size of each dimension.
-pub fn create(db_location: &str, axis_definitions: &[AxisDefinition], create_new: bool) -> io::Result<()> {
-    let library_axis_location = format!("{}{}", db_location, AXIS_FILE_NAME);
-    OpenOptions::new()
-        .write(true)
-        .create(true)
-        .create_new(create_new)
-        .truncate(true)
-        .open(&library_axis_location)
-        ?
-        .set_len(
-            (1 + axis_definitions.len() as u64) * U64::VALUE_SIZE as u64
-        )?;
-
-    // unwrap should never fail
-    U64::write_to_db(&library_axis_location, 0, axis_definitions.len() as u64).unwrap();
+// The subsequent blocks contain the max size of each dimension, followed by a final block containing the format version of the database.
+pub fn create<TStorage: Storage>(storage: &TStorage, axis_definitions: &[AxisDefinition], create_new: bool) -> io::Result<()> {
+    let library = storage.create(AXIS_FILE_NAME, create_new)?;
+    library.set_size((2 + axis_definitions.len() as u64) * U64::VALUE_SIZE as u64)?;
+
+    write_block(&library, 0, axis_definitions.len() as u64)?;
 
 	for axis_definition in axis_definitions {
-        // unwrap should never fail
-		U64::write_to_db(&library_axis_location, axis_definition.id, axis_definition.max).unwrap();
+		write_block(&library, axis_definition.id, axis_definition.max)?;
     }
 
+    set_format_version(storage, FORMAT_VERSION)
+}
+
+// Adds the operation needed to set the capacity of the given axis to the given batch
+pub fn set_max_batched(axis_definition: &AxisDefinition, batch: &mut Batch) -> io::Result<()> {
+    let mut buffer = [0; U64::VALUE_SIZE];
+    U64::write_to_buffer(axis_definition.max, &mut buffer)?;
+
+    batch.push(
+        Operation::Write { file_name: AXIS_FILE_NAME.to_string(), position: axis_definition.id * U64::VALUE_SIZE as u64, bytes: buffer.to_vec() }
+    );
     Ok(())
 }
 
-pub fn count(db_location: &str) -> Result<u64, io::Error> {
-    let library_axis_location = format!("{}{}", db_location, AXIS_FILE_NAME);
+pub fn set_format_version<TStorage: Storage>(storage: &TStorage, format_version: u64) -> io::Result<()> {
+    let library = storage.open(AXIS_FILE_NAME)?;
+    let count = get_blocks(&library)?.next().unwrap_or(0);
+
+    write_block(&library, 1 + count, format_version)
+}
+
+// Returns None for databases created before the format version was recorded
+pub fn get_format_version<TStorage: Storage>(storage: &TStorage) -> io::Result<Option<u64>> {
+    let mut blocks = get_blocks(&storage.open_read_only(AXIS_FILE_NAME)?)?;
 
-    U64::read_from_db(&library_axis_location, 0)
+    let count = blocks.next().unwrap_or(0);
+    Ok(blocks.nth(count as usize))
 }
 
-pub fn get_by_id(db_location: &str, axis_id: u64) -> Result<AxisDefinition, io::Error> {
-    let library_axis_location = format!("{}{}", db_location, AXIS_FILE_NAME);
+pub fn get_all<TStorage: Storage>(storage: &TStorage) -> io::Result<Vec<AxisDefinition>> {
+    Ok(get_all_with_format_version(storage)?.0)
+}
+
+// Returns the axis definitions alongside the format version, from a single read of the axis library
+pub fn get_all_with_format_version<TStorage: Storage>(storage: &TStorage) -> io::Result<(Vec<AxisDefinition>, Option<u64>)> {
+    let mut blocks = get_blocks(&storage.open_read_only(AXIS_FILE_NAME)?)?;
+    let count = blocks.next().unwrap_or(0);
+    let axis_definitions: Vec<AxisDefinition> = blocks
+        .by_ref()
+        .take(count as usize)
+        .enumerate()
+        .map(|(index, max)| AxisDefinition { id: index as u64 + 1, max })
+        .collect();
 
-    match U64::read_from_db(&library_axis_location, axis_id) {
-        Ok(max_value) => Ok(AxisDefinition { id: axis_id, max: max_value }),
-        Err(e) => Err(e),
+    if axis_definitions.len() as u64 != count {
+        return Err(io::Error::new(io::ErrorKind::UnexpectedEof, "axis library is shorter than the number of dimensions it declares"))
     }
+
+    Ok((axis_definitions, blocks.next()))
+}
+
+fn write_block(library: &impl StorageFile, block_index: u64, value: u64) -> io::Result<()> {
+    let mut buffer = [0; U64::VALUE_SIZE];
+    U64::write_to_buffer(value, &mut buffer)?;
+    library.write_at(block_index * U64::VALUE_SIZE as u64, &buffer)
+}
+
+fn get_blocks(library: &impl StorageFile) -> io::Result<impl Iterator<Item = u64>> {
+    let mut buffer = vec![0; library.size()? as usize];
+    library.read_at(0, &mut buffer)?;
+
+    Ok(
+        buffer
+            .chunks_exact(U64::VALUE_SIZE)
+            .map(U64::read_from_buffer)
+            .collect::<io::Result<Vec<u64>>>()?
+            .into_iter()
+    )
 }
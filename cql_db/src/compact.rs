@@ -0,0 +1,78 @@
+use std::io;
+
+use cql_model::CqlType;
+
+use crate::database;
+use crate::result;
+use crate::written;
+use crate::rollup_library;
+use crate::handle::Database;
+use crate::storage::FileStorage;
+use crate::create_or_replace_db;
+
+// Copies every linked row from the source database into a new database, linking them in ascending order so that the keys and rows of the
+// new database are dense, before reading each row back from a freshly opened copy to verify it against the source.
+pub fn compact<TStore: CqlType>(source_db_location: &str, target_db_location: &str) -> result::Result<()> {
+    let source_storage = FileStorage::new(source_db_location);
+    let target_storage = FileStorage::new(target_db_location);
+
+    let mut source = Database::<TStore>::open_read_only(source_storage)?;
+    source.validate_type()?;
+    let _lock = source.lock_shared()?;
+
+    let capacities = source.capacities();
+    let row_size = capacities[capacities.len() - 1];
+
+    create_or_replace_db::<TStore, _>(&target_storage, &capacities, true)?;
+    // the levels are carried over without their summaries, which are rebuilt from the copied rows
+    rollup_library::create(&target_storage, &rollup_library::get(source.storage())?)?;
+
+    let rows = source.linked_rows()?;
+    let source_size = database::size(source.db_file())?;
+
+    let mut target = Database::<TStore>::open(target_storage.clone())?;
+    for row in rows.iter() {
+        let source_position = source.row_position(row)?;
+
+        // rows of databases with fewer than three dimensions always exist, there is nothing to copy for those beyond the end of the file
+        if capacities.len() < 3 && source_position * TStore::VALUE_SIZE as u64 >= source_size {
+            break
+        }
+
+        if capacities.len() > 2 {
+            target.link_dimensions_unchecked(row)?;
+        }
+
+        let values = database::read_bytes::<TStore>(source.db_file(), source_position, row_size)?;
+        let target_position = target.row_position(row)?;
+        database::write_bytes::<TStore>(target.db_file(), target_position, &values)?;
+
+        let written_values = written::get(source.written_file(), source_position, row_size)?;
+        written::set(target.written_file(), target_position, &written_values)?;
+    }
+    database::sync(target.db_file())?;
+    written::sync(target.written_file())?;
+
+    let n_target_rows = database::size(target.db_file())?.div_ceil(row_size * TStore::VALUE_SIZE as u64);
+    rollup_library::rebuild(&target_storage, target.db_file(), target.rollups(), row_size, n_target_rows, true)?;
+
+    let mut target = Database::<TStore>::open(target_storage)?;
+    for row in rows.iter() {
+        let source_position = source.row_position(row)?;
+        let target_position = target.row_position(row)?;
+
+        let source_values = database::read_bytes::<TStore>(source.db_file(), source_position, row_size)?;
+        let target_values = database::read_bytes::<TStore>(target.db_file(), target_position, row_size)?;
+
+        let source_written = written::get(source.written_file(), source_position, row_size)?;
+        let target_written = written::get(target.written_file(), target_position, row_size)?;
+
+        if source_values != target_values || source_written != target_written {
+            return Err(
+                io::Error::new(io::ErrorKind::InvalidData, format!("compacted row {:?} does not match the source database", row)).into()
+            )
+        }
+    }
+
+    Ok(())
+}
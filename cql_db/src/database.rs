@@ -1,48 +1,258 @@
 use std::io;
-use std::io::Write;
-use std::fs::OpenOptions;
+use std::io::{ Read, Write };
+use std::sync::Arc;
 use cql_model::{
     CqlType,
-    CqlWritable,
-    CqlReadable,
-    CqlStreamReadable,
+    CqlAggregatable,
+    CqlRangeReadable,
+    CqlBufferWritable,
+    CqlBufferStreamReadable,
+    CqlBufferStreamWritable,
 };
 
-const DB_FILE_NAME: &str = "/db";
+use crate::journal::{ Batch, Operation };
+use crate::storage;
+use crate::storage::{ Storage, StorageFile };
+use crate::Direction;
+use crate::aggregation::Aggregation;
 
-pub fn create<TStore: CqlType>(db_location: &str, create_new: bool) -> io::Result<()> {
-    let db_key_location = format!("{}{}", db_location, DB_FILE_NAME);
-    let result = OpenOptions::new()
-        .write(true)
-        .create(true)
-        .create_new(create_new)
-        .truncate(true)
-        .open(db_key_location);
+pub const DB_FILE_NAME: &str = "/db";
+// the maximum number of bytes read or written at once when working through large runs of values
+pub const BUFFER_SIZE: usize = 64 * 1024;
+// the fewest strided values worth picking out of a single read of BUFFER_SIZE bytes, should fewer fit they are each read on their own instead
+const MIN_STRIDED_VALUES_PER_BUFFER: u64 = 16;
 
-    match result {
-        Ok(_) => Ok(()),
-        Err(e) => Err(e),
+pub fn create<TStorage: Storage>(storage: &TStorage, create_new: bool) -> io::Result<()> {
+    storage.create(DB_FILE_NAME, create_new)?;
+    Ok(())
+}
+
+pub fn open<TStorage: Storage>(storage: &TStorage, read_only: bool) -> io::Result<TStorage::File> {
+    storage::open_file(storage, DB_FILE_NAME, read_only)
+}
+
+// Adds the operation needed to grow the database file so that it can hold at least `n_values` to the given batch, it will not be shrunk if it is
+// already larger
+pub fn allocate<TStore: CqlType>(db_file: &impl StorageFile, n_values: u64, batch: &mut Batch) -> io::Result<()> {
+    let required_size = n_values * TStore::VALUE_SIZE as u64;
+    if batch.length(DB_FILE_NAME, db_file)? < required_size {
+        batch.push(Operation::SetLength { file_name: DB_FILE_NAME.to_string(), length: required_size });
+    }
+
+    Ok(())
+}
+
+// Adds the operation needed to write the given (encoded) values from the start location onward to the given batch
+pub fn write_bytes_batched<TStore: CqlType>(start_location: u64, bytes: &[u8], batch: &mut Batch) {
+    batch.push(
+        Operation::Write { file_name: DB_FILE_NAME.to_string(), position: start_location * TStore::VALUE_SIZE as u64, bytes: bytes.to_vec() }
+    );
+}
+
+// Reads the raw bytes of `n_values` from the database file, values beyond the end of the file are returned as zeroed bytes
+pub fn read_bytes<TStore: CqlType>(db_file: &impl StorageFile, start_location: u64, n_values: u64) -> io::Result<Vec<u8>> {
+    let mut buffer = vec![0; n_values as usize * TStore::VALUE_SIZE];
+    db_file.read_at(start_location * TStore::VALUE_SIZE as u64, &mut buffer)?;
+
+    Ok(buffer)
+}
+
+pub fn write_bytes<TStore: CqlType>(db_file: &impl StorageFile, start_location: u64, bytes: &[u8]) -> io::Result<()> {
+    db_file.write_at(start_location * TStore::VALUE_SIZE as u64, bytes)
+}
+
+// Returns the bytes to be stored for each of the given values, in order
+pub fn encode_values<TStore: CqlBufferWritable>(values: Vec<TStore::ValueType>) -> io::Result<Vec<u8>> {
+    let mut encoded_values = vec![0; values.len() * TStore::VALUE_SIZE];
+    for (value, buffer) in values.into_iter().zip(encoded_values.chunks_mut(TStore::VALUE_SIZE)) {
+        TStore::write_to_buffer(value, buffer)?;
+    }
+
+    Ok(encoded_values)
+}
+
+pub fn read_value<TStore: CqlRangeReadable>(db_file: &impl StorageFile, value_location: u64) -> io::Result<TStore::ValueType> {
+    let buffer = read_bytes::<TStore>(db_file, value_location, 1)?;
+    TStore::read_from_buffer(&buffer)
+}
+
+// Reads `n_values` from the start location onward to the given stream, a block at a time
+pub fn read_to_stream<TStore: CqlBufferStreamReadable>(db_file: &impl StorageFile, stream: &mut dyn Write, start_location: u64, n_values: u64)
+    -> io::Result<()> {
+    let values_per_block = (BUFFER_SIZE / TStore::VALUE_SIZE).max(1) as u64;
+
+    let mut index = 0;
+    while index < n_values {
+        let n_block_values = values_per_block.min(n_values - index);
+        let buffer = read_bytes::<TStore>(db_file, start_location + index, n_block_values)?;
+        TStore::read_buffer_to_stream(&buffer, stream)?;
+        index += n_block_values;
+    }
+
+    stream.flush()
+}
+
+// Reads `n_values` to the given stream, every `stride`th value from the start location onward, in the given direction.  Values close enough together
+// are picked out of blocks of the file read in one go, whilst values further apart are read on their own so that the values between them are not read.
+pub fn read_strided_to_stream<TStore: CqlBufferStreamReadable>(
+    db_file: &impl StorageFile,
+    stream: &mut dyn Write,
+    start_location: u64,
+    n_values: u64,
+    stride: u64,
+    direction: Direction
+) -> io::Result<()> {
+    let value_size = TStore::VALUE_SIZE;
+    let values_per_block = (BUFFER_SIZE / value_size).max(1) as u64;
+    let values_per_buffer = BUFFER_SIZE as u64 / (stride.max(1) * value_size as u64);
+    let reads_blocks = values_per_buffer >= MIN_STRIDED_VALUES_PER_BUFFER;
+
+    let location_of = |index: u64| match direction {
+        Direction::Forward => start_location + index * stride,
+        Direction::Reverse => start_location + (n_values - 1 - index) * stride,
+    };
+
+    let mut buffer = Vec::new();
+    let mut index = 0;
+    while index < n_values {
+        let n_block_values = if reads_blocks { values_per_buffer.min(values_per_block) } else { values_per_block }.min(n_values - index);
+        buffer.resize(n_block_values as usize * value_size, 0);
+
+        if reads_blocks {
+            let first_location = location_of(index).min(location_of(index + n_block_values - 1));
+            let last_location = location_of(index).max(location_of(index + n_block_values - 1));
+            let block = read_bytes::<TStore>(db_file, first_location, last_location - first_location + 1)?;
+
+            for (value_index, value) in (index..).zip(buffer.chunks_mut(value_size)) {
+                let offset = (location_of(value_index) - first_location) as usize * value_size;
+                value.copy_from_slice(&block[offset..offset + value_size]);
+            }
+        } else {
+            for (value_index, value) in (index..).zip(buffer.chunks_mut(value_size)) {
+                db_file.read_at(location_of(value_index) * value_size as u64, value)?;
+            }
+        }
+
+        TStore::read_buffer_to_stream(&buffer, stream)?;
+        index += n_block_values;
+    }
+
+    stream.flush()
+}
+
+// Aggregates `n_values` from the start location onward, decoding them a block at a time
+pub fn aggregate<TStore: CqlAggregatable, TAggregation: Aggregation<TStore>>(db_file: &impl StorageFile, start_location: u64, n_values: u64)
+    -> io::Result<TAggregation::Output> {
+    let mut state = TAggregation::State::default();
+    read_numbers::<TStore>(db_file, start_location, n_values, |_, number| TAggregation::add(&mut state, number))?;
+
+    Ok(TAggregation::result(state))
+}
+
+// Aggregates each `bucket_size` values from the start location onward separately, the last bucket holding any values left over
+pub fn rollup<TStore: CqlAggregatable, TAggregation: Aggregation<TStore>>(
+    db_file: &impl StorageFile,
+    start_location: u64,
+    n_values: u64,
+    bucket_size: u64
+) -> io::Result<Vec<TAggregation::Output>> {
+    let mut states = Vec::new();
+    states.resize_with(n_values.div_ceil(bucket_size) as usize, TAggregation::State::default);
+    read_numbers::<TStore>(db_file, start_location, n_values, |index, number| {
+        TAggregation::add(&mut states[(index / bucket_size) as usize], number)
+    })?;
+
+    Ok(states.into_iter().map(TAggregation::result).collect())
+}
+
+// Decodes `n_values` from the start location onward a block at a time, handing the index and number of each value holding a number to the given function
+fn read_numbers<TStore: CqlAggregatable>(
+    db_file: &impl StorageFile,
+    start_location: u64,
+    n_values: u64,
+    mut add: impl FnMut(u64, TStore::Number)
+) -> io::Result<()> {
+    let values_per_block = (BUFFER_SIZE / TStore::VALUE_SIZE).max(1) as u64;
+
+    let mut index = 0;
+    while index < n_values {
+        let n_block_values = values_per_block.min(n_values - index);
+        let buffer = read_bytes::<TStore>(db_file, start_location + index, n_block_values)?;
+
+        for (value_index, value) in (index..).zip(buffer.chunks_exact(TStore::VALUE_SIZE)) {
+            if let Some(number) = TStore::to_number(TStore::read_from_buffer(value)?) {
+                add(value_index, number);
+            }
+        }
+        index += n_block_values;
     }
+
+    Ok(())
 }
 
-pub fn grow<TStore: CqlType>(db_location: &str, size_to_grow: u64) -> io::Result<()> {
-    let db_key_location = format!("{}{}", db_location, DB_FILE_NAME);
-    let file = OpenOptions::new().write(true).open(db_key_location)?;
+// Decodes `n_values` from the given stream a block at a time, handing the start location and (encoded) values of each block to the given function to
+// be written
+pub fn read_from_stream<TStore: CqlBufferStreamWritable>(
+    stream: &mut dyn Read,
+    start_location: u64,
+    n_values: u64,
+    mut write: impl FnMut(u64, &[u8]) -> io::Result<()>
+) -> io::Result<()> {
+    let values_per_block = (BUFFER_SIZE / TStore::VALUE_SIZE).max(1) as u64;
+    let mut buffer = Vec::new();
+
+    let mut index = 0;
+    while index < n_values {
+        let n_block_values = values_per_block.min(n_values - index);
+        buffer.resize(n_block_values as usize * TStore::VALUE_SIZE, 0);
+        TStore::write_stream_to_buffer(stream, &mut buffer)?;
+        write(start_location + index, &buffer)?;
+        index += n_block_values;
+    }
 
-    file.set_len(file.metadata()?.len() + size_to_grow * TStore::VALUE_SIZE as u64)
+    Ok(())
 }
 
-pub fn write_value<TStore: CqlWritable>(db_location: &str, value_location: u64, value: TStore::ValueType) -> io::Result<()> {
-	let db_key_location = format!("{}{}", db_location, DB_FILE_NAME);
-    TStore::write_to_db(&db_key_location, value_location, value)
+pub fn sync(db_file: &impl StorageFile) -> io::Result<()> {
+    db_file.sync()
 }
 
-pub fn read_value<TStore: CqlReadable>(db_location: &str, value_location: u64) -> Result<TStore::ValueType, io::Error> {
-	let db_key_location = format!("{}{}", db_location, DB_FILE_NAME);
-	TStore::read_from_db(&db_key_location, value_location)
+pub fn size(db_file: &impl StorageFile) -> io::Result<u64> {
+    db_file.size()
+}
+
+// Returns an iterator over `n_values` from the start location onward, reading them from the given database file in blocks as it is advanced.  The
+// iterator ends once it has returned an error, as the rest of the block being read can no longer be trusted.
+pub fn read_range<TStore: CqlRangeReadable, TFile: StorageFile>(db_file: Arc<TFile>, start_location: u64, n_values: u64)
+    -> io::Result<impl Iterator<Item = io::Result<TStore::ValueType>>> {
+    let values_per_block = (BUFFER_SIZE / TStore::VALUE_SIZE).max(1) as u64;
+    let mut buffer = Vec::new();
+    let mut failed = false;
+
+    let mut read_value = move |index: u64| {
+        let block_index = (index % values_per_block) as usize;
+        if block_index == 0 {
+            let n_block_values = values_per_block.min(n_values - index);
+            buffer.resize(n_block_values as usize * TStore::VALUE_SIZE, 0);
+            db_file.read_at((start_location + index) * TStore::VALUE_SIZE as u64, &mut buffer)?;
+        }
+
+        TStore::read_from_buffer(&buffer[block_index * TStore::VALUE_SIZE..(block_index + 1) * TStore::VALUE_SIZE])
+    };
+
+    Ok(
+        (0..n_values).map_while(move |index| {
+            if failed {
+                return None
+            }
+
+            let value = read_value(index);
+            failed = value.is_err();
+            Some(value)
+        })
+    )
 }
 
-pub fn read_to_stream<TStore: CqlStreamReadable>(db_location: &str, stream: &mut dyn Write, start_location: u64, n_values: u64) -> io::Result<()> {
-	let db_key_location = format!("{}{}", db_location, DB_FILE_NAME);
-	TStore::read_to_stream(&db_key_location, stream, start_location, n_values)
+pub fn location(db_location: &str) -> String {
+    format!("{}{}", db_location, DB_FILE_NAME)
 }
@@ -0,0 +1,47 @@
+/// The order in which values are read by a [strided read](fn.read_strided_to_stream.html).
+///
+/// Either direction reads the same values, the values read in [Reverse](#variant.Reverse) are those that would be read [Forward](#variant.Forward),
+/// latest first.
+///
+/// # Examples
+/// ```
+/// # use std::io::{ Cursor, SeekFrom, Seek };
+/// use cql_u64::{ U64, unpack_stream };
+/// use cql_db::Direction;
+///
+/// # use std::error::Error;
+/// # use std::fs::remove_file;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// #
+/// # const DATABASE_LOCATION: &str = "./.test_db";
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/db"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/ax"));
+/// cql_db::create_db::<U64>(
+///     DATABASE_LOCATION,
+///     &[5]
+/// )?;
+///
+/// for value in 1..=5 {
+///     cql_db::write_value::<U64>(DATABASE_LOCATION, &[value], value * 10)?;
+/// }
+///
+/// let mut stream = Cursor::new(Vec::new());
+/// cql_db::read_strided_to_stream::<U64>(DATABASE_LOCATION, &mut stream, &[1], 3, 2, Direction::Reverse)?;
+///
+/// let mut result = Vec::new();
+/// stream.seek(SeekFrom::Start(0));
+/// unpack_stream(&mut stream, 3, |_, value| {
+///     result.push(value)
+/// })?;
+///
+/// assert_eq!(result, vec![50, 30, 10]);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Hash)]
+pub enum Direction {
+    /// Values are read from the given location onward, in ascending order.
+    Forward,
+    /// The values that would be read [Forward](#variant.Forward) are read in descending order, ending at the given location.
+    Reverse,
+}
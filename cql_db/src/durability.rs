@@ -0,0 +1,50 @@
+/// How eagerly changes made through a [Database](struct.Database.html) handle are synced to disk.
+///
+/// Changes that have not been synced are held by the operating system and are safe should the process crash, but may be lost should the machine lose
+/// power.  Each mode syncs everything synced by the modes before it, and changes can be synced at any time using [flush](struct.Database.html#method.flush),
+/// whatever the mode.  The free functions in this crate use [DEFAULT_DURABILITY](constant.DEFAULT_DURABILITY.html).
+///
+/// # Examples
+/// ```
+/// use cql_u64::U64;
+/// use cql_db::Durability;
+///
+/// # use std::error::Error;
+/// # use std::fs::remove_file;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// #
+/// # const DATABASE_LOCATION: &str = "./.test_db";
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/db"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/ax"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/key1_2"));
+/// cql_db::create_db::<U64>(
+///     DATABASE_LOCATION,
+///     &[2, 5, 3]
+/// )?;
+///
+/// let mut database = cql_db::open::<U64>(DATABASE_LOCATION)?;
+/// database.set_durability(Durability::None);
+///
+/// database.link_dimensions(&[2, 4])?;
+/// for value in 1..=3 {
+///     database.write_value(&[2, 4, value], value)?;
+/// }
+///
+/// database.flush()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
+pub enum Durability {
+    /// Nothing is synced unless [flush](struct.Database.html#method.flush) is called, including the journal used to make links
+    /// [crash safe](index.html#crash-safety) - links interrupted by a loss of power may leave the database corrupted.
+    None,
+    /// Links and [transactions](struct.Transaction.html) are synced as they are committed, whilst written values are only synced by
+    /// [flush](struct.Database.html#method.flush).
+    Explicit,
+    /// Additionally syncs calls that write many values at once, such as [write_values](struct.Database.html#method.write_values) and
+    /// [write_from_stream](struct.Database.html#method.write_from_stream), before they return.
+    PerBatch,
+    /// Additionally syncs every call that changes the database before it returns, including single value writes and unlinks.
+    PerCall,
+}
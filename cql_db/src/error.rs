@@ -32,6 +32,14 @@ use std::{ io, error, fmt };
 ///             error::cql::Error::IndexOutOfRangeError { dimension_index, requested, min, max } => { },
 ///             error::cql::Error::DimensionsOutOfRangeError { requested, min, max } => { },
 ///             error::cql::Error::ElementsNotLinkedError{ x_dimension, x, y_dimension, y } => { },
+///             error::cql::Error::TypeMismatchError { requested, requested_value_size, stored, stored_value_size } => { },
+///             error::cql::Error::FormatVersionError { stored, supported } => { },
+///             error::cql::Error::LockTimeoutError { timeout } => { },
+///             error::cql::Error::LockUpgradeError => { },
+///             error::cql::Error::BucketTooSmallError => { },
+///             error::cql::Error::StrideTooSmallError => { },
+///             error::cql::Error::ResizeShrinkError { current, requested } => { },
+///             error::cql::Error::RollupTypeError { requested } => { },
 ///         },
 ///         error::Error::Io(io_error) => match io_error.kind() {
 ///             io::ErrorKind::AlreadyExists => { },
@@ -65,6 +73,11 @@ impl fmt::Display for Error {
 
 impl From<io::Error> for Error {
     fn from(err: io::Error) -> Error {
+        // Cql errors raised where only I/O errors may be returned are carried within them, and are unwrapped here so that they can be matched on
+        if let Some(cql_error) = err.get_ref().and_then(|inner| inner.downcast_ref::<cql::Error>()) {
+            return Error::Cql(cql_error.clone())
+        }
+
         Error::Io(err)
     }
 }
@@ -78,6 +91,7 @@ impl From<cql::Error> for Error {
 /// Cql db specific errors returned when provided with invalid parameters
 pub mod cql {
     use std::{ error, fmt };
+    use std::time::Duration;
 
     #[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Hash)]
     pub enum Error {
@@ -85,13 +99,21 @@ pub mod cql {
         IndexOutOfRangeError { dimension_index: usize, requested: u64, min: u64, max: u64 },
         DimensionsOutOfRangeError { requested: usize, min: usize, max: usize },
         ElementsNotLinkedError { x_dimension: usize, x: u64, y_dimension: usize, y: u64 },
+        TypeMismatchError { requested: String, requested_value_size: usize, stored: String, stored_value_size: usize },
+        FormatVersionError { stored: u64, supported: u64 },
+        LockTimeoutError { timeout: Duration },
+        LockUpgradeError,
+        BucketTooSmallError,
+        StrideTooSmallError,
+        ResizeShrinkError { current: u64, requested: u64 },
+        RollupTypeError { requested: String },
     }
 
     impl error::Error for Error { }
 
     impl fmt::Display for Error {
         fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-            match *self {
+            match self {
                 Error::DimensionTooSmallError => write!(f, "Dimensions must have a capacity of 1 or higher"),
                 Error::IndexOutOfRangeError { dimension_index, requested, min, max } =>
                     write!(
@@ -103,6 +125,28 @@ pub mod cql {
                     write!(f, "Requested dimension '{}' was out of range, .len() must be between {} and {}", requested, min, max),
                 Error::ElementsNotLinkedError { x_dimension, x, y_dimension, y } => write!(f, "Requested dimension '{}', element '{}' was not linked to dimension '{}', element'{}",
                     x_dimension, x, y_dimension, y),
+                Error::TypeMismatchError { requested, requested_value_size, stored, stored_value_size } =>
+                    write!(
+                        f,
+                        "Requested type '{}' ({} bytes) does not match the type '{}' ({} bytes) that the database was created with",
+                        requested, requested_value_size, stored, stored_value_size
+                    ),
+                Error::FormatVersionError { stored, supported } =>
+                    write!(f, "Database format version '{}' is not supported, this version of cql_db supports format version {}", stored, supported),
+                Error::LockTimeoutError { timeout } => write!(f, "Could not lock the database within the timeout of {:?}", timeout),
+                Error::LockUpgradeError =>
+                    write!(f, "Could not lock the database exclusively whilst the handle holds a shared lock on it, such as for an unfinished range read"),
+                Error::BucketTooSmallError => write!(f, "Buckets must hold 1 or more values"),
+                Error::StrideTooSmallError => write!(f, "Strides must be 1 or more values apart"),
+                Error::ResizeShrinkError { current, requested } =>
+                    write!(f, "Requested capacity '{}' is less than the current capacity of {}, dimensions can only be grown", requested, current),
+                Error::RollupTypeError { requested } =>
+                    write!(
+                        f,
+                        "Requested type '{}' cannot keep the summaries of the database's rollup levels up to date, it must be the aggregatable type the \
+                        database was created with and override CqlType::visit_aggregatable",
+                        requested
+                    ),
             }
         }
     }
@@ -0,0 +1,1367 @@
+use std::io;
+use std::io::{ Read, Write };
+use std::marker::PhantomData;
+use std::ops::Range;
+use std::sync::Arc;
+use std::time::Duration;
+
+use cql_model::{
+    CqlType,
+    CqlAggregatable,
+    CqlRangeReadable,
+    CqlBufferWritable,
+    CqlBufferStreamReadable,
+    CqlBufferStreamWritable,
+};
+
+use crate::database;
+use crate::axis_library;
+use crate::axis_library::AxisDefinition;
+use crate::key_library;
+use crate::journal;
+use crate::journal::Batch;
+use crate::transaction::Transaction;
+use crate::key_library::KeyLibrary;
+use crate::type_library;
+use crate::type_library::TypeDefinition;
+use crate::error;
+use crate::result;
+use crate::vectors::calculate_index;
+use crate::lock;
+use crate::lock::{ DatabaseLock, LockGuard };
+use crate::written;
+use crate::layout;
+use crate::rollup_library;
+use crate::rollup_library::Rollups;
+use crate::resize;
+use crate::storage::{ Storage, FileStorage, OptionalFile };
+use crate::FORMAT_VERSION;
+use crate::DEFAULT_LOCK_TIMEOUT;
+use crate::DEFAULT_DURABILITY;
+use crate::Durability;
+use crate::Direction;
+use crate::aggregation::Aggregation;
+
+/// An open handle to a CQL database.
+///
+/// Holds the key libraries and database file open for the lifetime of the handle, so repeated calls do not pay the cost of re-opening them.  The capacity
+/// of each dimension is re-read as each call locks the database, so dimensions [resized](../fn.resize_dimension.html) elsewhere are seen by the next call.
+/// The free functions in this crate (e.g. [read_value](../fn.read_value.html)) are thin wrappers that open a handle for the duration of a single call.
+///
+/// Each call made through the handle locks the database for its duration, see [concurrent access](../index.html#concurrent-access) for more information.
+///
+/// Handles to databases kept in files use [FileStorage](../storage/struct.FileStorage.html), handles to databases kept in other
+/// [storage backends](../storage/index.html) are opened using [open_in](../fn.open_in.html).
+///
+/// # Examples
+/// ```
+/// use cql_u64::U64;
+///
+/// # use std::error::Error;
+/// # use std::fs::remove_file;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// #
+/// # const DATABASE_LOCATION: &str = "./.test_db";
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/db"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/ax"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/key1_2"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/key2_3"));
+/// cql_db::create_db::<U64>(
+///     DATABASE_LOCATION,
+///     &[2, 5, 3, 2]
+/// )?;
+///
+/// let mut database = cql_db::open::<U64>(DATABASE_LOCATION)?;
+///
+/// database.link_dimensions(&[2, 4, 3])?;
+/// database.write_value(&[2, 4, 3, 1], 5)?;
+///
+/// assert_eq!(database.read_value(&[2, 4, 3, 1])?, 5);
+/// # Ok(())
+/// # }
+/// ```
+pub struct Database<TStore: CqlType, TStorage: Storage = FileStorage> {
+    storage: TStorage,
+    axis_definitions: Vec<AxisDefinition>,
+    // key_libraries[i] maps axis (i + 1) to axis (i + 2)
+    key_libraries: Vec<KeyLibrary<TStorage::File>>,
+    // shared with the iterators returned by range reads
+    db_file: Arc<TStorage::File>,
+    written_file: OptionalFile<TStorage::File>,
+    // databases created before their format version was recorded also predate the written library, so values written to them may not be marked, unless
+    // the version has since been recorded by a handle able to write to them
+    predates_written_library: bool,
+    rollups: Rollups<OptionalFile<TStorage::File>>,
+    // held open so that pending updates can be found each time the database is locked
+    journal: OptionalFile<TStorage::File>,
+    resize_progress: OptionalFile<TStorage::File>,
+    lock: DatabaseLock,
+    // the generation of the database that the axis definitions were read at
+    generation: u64,
+    lock_timeout: Duration,
+    durability: Durability,
+    // the handle holds no values of TStore, so may be sent between threads whatever the type
+    store: PhantomData<fn() -> TStore>,
+}
+
+impl<TStore: CqlType, TStorage: Storage> Database<TStore, TStorage> {
+    pub(crate) fn open(storage: TStorage) -> io::Result<Database<TStore, TStorage>> {
+        Database::open_with(storage, false)
+    }
+
+    // Opens a handle that only reads from the database, which does not need to be able to write to its files, and will not create any that databases
+    // created by earlier versions of this crate do not have.  Writes made through the handle will fail.
+    pub(crate) fn open_read_only(storage: TStorage) -> io::Result<Database<TStore, TStorage>> {
+        Database::open_with(storage, true)
+    }
+
+    fn open_with(storage: TStorage, read_only: bool) -> io::Result<Database<TStore, TStorage>> {
+        let lock = lock::open(&storage)?;
+        // read before the axis definitions, so that should they be changed whilst being read they are re-read once the database is locked
+        let generation = lock::generation(&lock)?;
+
+        let (axis_definitions, recorded_format_version) = axis_library::get_all_with_format_version(&storage)?;
+        let format_version = match recorded_format_version {
+            Some(format_version) => format_version,
+            None => layout::detect_format_version::<TStore, _>(&storage, &axis_definitions)?,
+        };
+        if let Err(cql_error) = check_format_version(format_version) {
+            return Err(io::Error::new(io::ErrorKind::InvalidData, cql_error))
+        }
+
+        let mut predates_written_library = recorded_format_version.is_none();
+        if predates_written_library && !read_only {
+            let _lock = lock::exclusive(&lock, DEFAULT_LOCK_TIMEOUT)?;
+
+            // the version may have been recorded by another handle whilst this one waited for the lock
+            if axis_library::get_format_version(&storage)?.is_none() {
+                layout::set_format_version::<TStore, _>(&storage)?;
+            }
+            predates_written_library = false;
+        }
+
+        let mut key_libraries = Vec::with_capacity(axis_definitions.len().saturating_sub(2));
+        for y_axis_index in 1..axis_definitions.len().saturating_sub(1) {
+            key_libraries.push(
+                key_library::open(&storage, axis_definitions[y_axis_index - 1].id, axis_definitions[y_axis_index].id, read_only)?
+            );
+        }
+
+        Ok(Database {
+            db_file: Arc::new(database::open(&storage, read_only)?),
+            written_file: written::open(&storage, read_only)?,
+            predates_written_library,
+            rollups: rollup_library::open::<TStore, _>(&storage, read_only)?,
+            journal: journal::open(&storage, read_only)?,
+            resize_progress: resize::open(&storage, read_only)?,
+            lock,
+            generation,
+            storage,
+            axis_definitions,
+            key_libraries,
+            lock_timeout: DEFAULT_LOCK_TIMEOUT,
+            durability: DEFAULT_DURABILITY,
+            store: PhantomData,
+        })
+    }
+
+    // Checks that the database was created with TStore, databases created before types were recorded are assumed to match.
+    pub(crate) fn validate_type(&self) -> result::Result<()> {
+        let requested = TypeDefinition::of::<TStore>();
+
+        match type_library::get(&self.storage)? {
+            Some(stored) if stored != requested => Err(
+                error::Error::Cql(
+                    error::cql::Error::TypeMismatchError {
+                        requested: requested.name,
+                        requested_value_size: requested.value_size,
+                        stored: stored.name,
+                        stored_value_size: stored.value_size,
+                    }
+                )
+            ),
+            _ => Ok(()),
+        }
+    }
+
+    /// Returns the number of dimensions in the database.
+    pub fn dimensions(&self) -> usize {
+        self.axis_definitions.len()
+    }
+
+    /// Returns the capacity of each dimension in the database.
+    pub fn capacities(&self) -> Vec<u64> {
+        self.axis_definitions.iter().map(|axis_definition| axis_definition.max).collect()
+    }
+
+    /// Returns the number of values summarised by each summary of each of the database's [rollup levels](../fn.create_db_with_rollups.html), in
+    /// ascending order.  Databases created without rollup levels have none.
+    pub fn rollup_levels(&self) -> Vec<u64> {
+        self.rollups.sizes()
+    }
+
+    /// Returns how long calls made through the handle will wait to lock the database before giving up.
+    pub fn lock_timeout(&self) -> Duration {
+        self.lock_timeout
+    }
+
+    /// Sets how long calls made through the handle will wait to lock the database before giving up, defaults to
+    /// [DEFAULT_LOCK_TIMEOUT](../constant.DEFAULT_LOCK_TIMEOUT.html).
+    pub fn set_lock_timeout(&mut self, timeout: Duration) {
+        self.lock_timeout = timeout;
+    }
+
+    /// Returns how eagerly changes made through the handle are synced to disk.
+    pub fn durability(&self) -> Durability {
+        self.durability
+    }
+
+    /// Sets how eagerly changes made through the handle are synced to disk, defaults to [DEFAULT_DURABILITY](../constant.DEFAULT_DURABILITY.html).
+    pub fn set_durability(&mut self, durability: Durability) {
+        self.durability = durability;
+    }
+
+    /// Syncs all changes made to the database to disk, whatever the handle's [durability](../enum.Durability.html).
+    ///
+    /// # Errors
+    ///
+    /// Will return any [I/O errors](https://doc.rust-lang.org/nightly/std/io/enum.ErrorKind.html) encountered during the execution of the function.
+    pub fn flush(&mut self) -> io::Result<()> {
+        let _lock = self.lock_shared()?;
+        self.sync_all()
+    }
+
+    /// Links dimension indexs together if they are not already linked.  Does not validate given parameters.
+    ///
+    /// See [link_dimensions_unchecked](../fn.link_dimensions_unchecked.html) for more information.
+    pub fn link_dimensions_unchecked(&mut self, location: &[u64]) -> io::Result<()> {
+        let _lock = self.lock_exclusive()?;
+
+        let mut batch = Batch::new();
+        self.link_dimensions_batched(location, &mut batch)?;
+        batch.commit(&self.storage, self.syncs_journal())
+    }
+
+    /// Links dimension indexs together if they are not already linked.
+    ///
+    /// See [link_dimensions](../fn.link_dimensions.html) for more information.
+    pub fn link_dimensions(&mut self, location: &[u64]) -> result::Result<()> {
+        let _lock = self.lock_exclusive()?;
+        self.validate_link_dimensions_params(location)?;
+        self.link_dimensions_unchecked(location)?;
+        Ok(())
+    }
+
+    /// Unlinks the given dimension indexes, releasing everything linked beneath them.  Does not validate given parameters.
+    ///
+    /// See [unlink_dimensions_unchecked](../fn.unlink_dimensions_unchecked.html) for more information.
+    pub fn unlink_dimensions_unchecked(&mut self, location: &[u64]) -> io::Result<()> {
+        let _lock = self.lock_exclusive()?;
+        let y_axis_index = location.len() - 1;
+
+        let mut x_position = location[0];
+        for (x_axis_index, &x) in location.iter().enumerate().take(y_axis_index).skip(1) {
+            x_position = key_library::get(&mut self.key_libraries[x_axis_index - 1], x_position, x, &self.axis_definitions[x_axis_index])?;
+
+            if x_position == 0 {
+                return Ok(())
+            }
+        }
+
+        let y_axis_definition = &self.axis_definitions[y_axis_index];
+        let key_library = &mut self.key_libraries[y_axis_index - 1];
+
+        let key = key_library::get(key_library, x_position, location[y_axis_index], y_axis_definition)?;
+        if key == 0 {
+            return Ok(())
+        }
+
+        let mut batch = Batch::new();
+        key_library::remove_batched(key_library, x_position, location[y_axis_index], y_axis_definition, &mut batch);
+        self.release_key_batched(y_axis_index, key, &mut batch)?;
+        batch.commit(&self.storage, self.syncs_journal())
+    }
+
+    /// Unlinks the given dimension indexes, releasing everything linked beneath them.
+    ///
+    /// See [unlink_dimensions](../fn.unlink_dimensions.html) for more information.
+    pub fn unlink_dimensions(&mut self, location: &[u64]) -> result::Result<()> {
+        let _lock = self.lock_exclusive()?;
+        self.validate_link_dimensions_params(location)?;
+        self.validate_elements_linked(location)?;
+        self.unlink_dimensions_unchecked(location)?;
+        Ok(())
+    }
+
+    /// Returns true if the given dimension indexes are linked.  Does not validate given parameters.
+    ///
+    /// See [is_linked_unchecked](../fn.is_linked_unchecked.html) for more information.
+    pub fn is_linked_unchecked(&mut self, location: &[u64]) -> io::Result<bool> {
+        let _lock = self.lock_shared()?;
+        Ok(self.find_unlinked_element(location)?.is_none())
+    }
+
+    /// Returns true if the given dimension indexes are linked.
+    ///
+    /// See [is_linked](../fn.is_linked.html) for more information.
+    pub fn is_linked(&mut self, location: &[u64]) -> result::Result<bool> {
+        let _lock = self.lock_shared()?;
+        self.validate_link_dimensions_params(location)?;
+        let is_linked = self.is_linked_unchecked(location)?;
+        Ok(is_linked)
+    }
+
+    /// Returns the indexes of the next dimension that are linked beneath the given dimension indexes.  Does not validate given parameters.
+    ///
+    /// See [linked_children_unchecked](../fn.linked_children_unchecked.html) for more information.
+    pub fn linked_children_unchecked(&mut self, location: &[u64]) -> io::Result<impl Iterator<Item = u64>> {
+        let _lock = self.lock_shared()?;
+
+        let x_position = self.resolve_key(location)?;
+        let children = if x_position == 0 {
+            Vec::new()
+        } else {
+            self.linked_keys(location.len(), x_position)?.into_iter().map(|(y, _)| y).collect()
+        };
+
+        Ok(children.into_iter())
+    }
+
+    /// Returns the indexes of the next dimension that are linked beneath the given dimension indexes.
+    ///
+    /// See [linked_children](../fn.linked_children.html) for more information.
+    pub fn linked_children(&mut self, location: &[u64]) -> result::Result<impl Iterator<Item = u64>> {
+        let _lock = self.lock_shared()?;
+        self.validate_linked_children_params(location)?;
+        self.validate_elements_linked(location)?;
+        let children = self.linked_children_unchecked(location)?;
+        Ok(children)
+    }
+
+    /// Returns the location of every row linked beneath the given dimension indexes.  Does not validate given parameters.
+    ///
+    /// See [walk_links_unchecked](../fn.walk_links_unchecked.html) for more information.
+    pub fn walk_links_unchecked(&mut self, location: &[u64]) -> io::Result<impl Iterator<Item = Vec<u64>>> {
+        let _lock = self.lock_shared()?;
+        let rows = self.collect_linked_rows(location)?;
+        Ok(rows.into_iter())
+    }
+
+    /// Returns the location of every row linked beneath the given dimension indexes.
+    ///
+    /// See [walk_links](../fn.walk_links.html) for more information.
+    pub fn walk_links(&mut self, location: &[u64]) -> result::Result<impl Iterator<Item = Vec<u64>>> {
+        let _lock = self.lock_shared()?;
+        self.validate_walk_links_params(location)?;
+        self.validate_elements_linked(location)?;
+        let rows = self.walk_links_unchecked(location)?;
+        Ok(rows)
+    }
+
+    /// Returns true if a value has been written to the given location.  Does not validate given parameters.
+    ///
+    /// See [is_written_unchecked](../fn.is_written_unchecked.html) for more information.
+    pub fn is_written_unchecked(&mut self, location: &[u64]) -> io::Result<bool> {
+        let _lock = self.lock_shared()?;
+        let position = self.calculate_position(location)?;
+
+        if written::is_marked(&self.written_file, position)? {
+            return Ok(true)
+        }
+
+        // values written before writes were recorded are assumed to have been written should they not be zeroed
+        Ok(self.predates_written_library && database::read_bytes::<TStore>(self.db_file(), position, 1)?.iter().any(|&byte| byte != 0))
+    }
+
+    /// Returns true if a value has been written to the given location.
+    ///
+    /// See [is_written](../fn.is_written.html) for more information.
+    pub fn is_written(&mut self, location: &[u64]) -> result::Result<bool> {
+        let _lock = self.lock_shared()?;
+        self.validate_location_shape(location)?;
+
+        if self.find_unlinked_element(&location[0..location.len() - 1])?.is_some() {
+            return Ok(false)
+        }
+
+        let is_written = self.is_written_unchecked(location)?;
+        Ok(is_written)
+    }
+
+    /// Writes the given value to the given location in the database.  Does not validate given parameters.
+    ///
+    /// See [write_value_unchecked](../fn.write_value_unchecked.html) for more information.
+    pub fn write_value_unchecked(&mut self, location: &[u64], value: TStore::ValueType) -> io::Result<()> where TStore: CqlBufferWritable {
+        let _lock = self.lock_exclusive()?;
+        let position = self.calculate_position(location)?;
+        self.write_bytes(position, &database::encode_values::<TStore>(vec![value])?)?;
+        written::mark(&self.written_file, position, 1)?;
+        self.sync(Durability::PerCall)
+    }
+
+    /// Writes the given value to the given location in the database.
+    ///
+    /// See [write_value](../fn.write_value.html) for more information.
+    pub fn write_value(&mut self, location: &[u64], value: TStore::ValueType) -> result::Result<()> where TStore: CqlBufferWritable {
+        let _lock = self.lock_exclusive()?;
+        self.validate_read_write_location(location)?;
+        self.write_value_unchecked(location, value)?;
+        Ok(())
+    }
+
+    /// Writes the given values to the given locations in the database.  Does not validate given parameters.
+    ///
+    /// See [write_values_unchecked](../fn.write_values_unchecked.html) for more information.
+    pub fn write_values_unchecked(&mut self, values: &[(&[u64], TStore::ValueType)]) -> io::Result<()>
+        where TStore: CqlBufferWritable, TStore::ValueType: Clone {
+        let _lock = self.lock_exclusive()?;
+        let locations: Vec<&[u64]> = values.iter().map(|(location, _)| *location).collect();
+        let rows = self.resolve_rows(&locations)?;
+        self.write_values_to_rows(values, &rows)
+    }
+
+    /// Writes the given values to the given locations in the database.
+    ///
+    /// See [write_values](../fn.write_values.html) for more information.
+    pub fn write_values(&mut self, values: &[(&[u64], TStore::ValueType)]) -> result::Result<()>
+        where TStore: CqlBufferWritable, TStore::ValueType: Clone {
+        let _lock = self.lock_exclusive()?;
+        for (location, _) in values {
+            self.validate_location_shape(location)?;
+        }
+
+        let locations: Vec<&[u64]> = values.iter().map(|(location, _)| *location).collect();
+        let rows = self.resolve_rows(&locations)?;
+
+        for (location, &row) in locations.iter().zip(&rows) {
+            if row == 0 {
+                // the full check is only made on failure, to find and report the unlinked elements
+                self.validate_read_write_location(location)?;
+            }
+        }
+
+        self.write_values_to_rows(values, &rows)?;
+        Ok(())
+    }
+
+    /// Reads the value at the given location from the database.  Does not validate given parameters.
+    ///
+    /// See [read_value_unchecked](../fn.read_value_unchecked.html) for more information.
+    pub fn read_value_unchecked(&mut self, location: &[u64]) -> io::Result<TStore::ValueType> where TStore: CqlRangeReadable {
+        let _lock = self.lock_shared()?;
+        let position = self.calculate_position(location)?;
+        database::read_value::<TStore>(self.db_file(), position)
+    }
+
+    /// Reads the value at the given location from the database.
+    ///
+    /// See [read_value](../fn.read_value.html) for more information.
+    pub fn read_value(&mut self, location: &[u64]) -> result::Result<TStore::ValueType> where TStore: CqlRangeReadable {
+        let _lock = self.lock_shared()?;
+        self.validate_read_write_location(location)?;
+        let value = self.read_value_unchecked(location)?;
+        Ok(value)
+    }
+
+    /// Reads `n_values` from the given location onward into the given stream.  Does not validate given parameters.
+    ///
+    /// See [read_to_stream_unchecked](../fn.read_to_stream_unchecked.html) for more information.
+    pub fn read_to_stream_unchecked(&mut self, stream: &mut dyn Write, location: &[u64], n_values: u64) -> io::Result<()>
+        where TStore: CqlBufferStreamReadable {
+        let _lock = self.lock_shared()?;
+        let position = self.calculate_position(location)?;
+        database::read_to_stream::<TStore>(self.db_file(), stream, position, n_values)
+    }
+
+    /// Reads `n_values` from the given location onward into the given stream.
+    ///
+    /// See [read_to_stream](../fn.read_to_stream.html) for more information.
+    pub fn read_to_stream(&mut self, stream: &mut dyn Write, location: &[u64], n_values: u64) -> result::Result<()>
+        where TStore: CqlBufferStreamReadable {
+        let _lock = self.lock_shared()?;
+        self.validate_stream_range(location, n_values)?;
+        self.read_to_stream_unchecked(stream, location, n_values)?;
+        Ok(())
+    }
+
+    /// Reads `n_values` from the given location onward into the given stream, `stride` values apart, in the given direction.  Does not validate given
+    /// parameters.
+    ///
+    /// See [read_strided_to_stream_unchecked](../fn.read_strided_to_stream_unchecked.html) for more information.
+    pub fn read_strided_to_stream_unchecked(
+        &mut self,
+        stream: &mut dyn Write,
+        location: &[u64],
+        n_values: u64,
+        stride: u64,
+        direction: Direction
+    ) -> io::Result<()> where TStore: CqlBufferStreamReadable {
+        let _lock = self.lock_shared()?;
+        let position = self.calculate_position(location)?;
+        database::read_strided_to_stream::<TStore>(self.db_file(), stream, position, n_values, stride, direction)
+    }
+
+    /// Reads `n_values` from the given location onward into the given stream, `stride` values apart, in the given direction.
+    ///
+    /// See [read_strided_to_stream](../fn.read_strided_to_stream.html) for more information.
+    pub fn read_strided_to_stream(
+        &mut self,
+        stream: &mut dyn Write,
+        location: &[u64],
+        n_values: u64,
+        stride: u64,
+        direction: Direction
+    ) -> result::Result<()> where TStore: CqlBufferStreamReadable {
+        let _lock = self.lock_shared()?;
+        self.validate_read_write_location(location)?;
+        self.validate_strided_length(location, n_values, stride)?;
+        self.read_strided_to_stream_unchecked(stream, location, n_values, stride, direction)?;
+        Ok(())
+    }
+
+    /// Aggregates `n_values` from the given location onward.  Does not validate given parameters.
+    ///
+    /// See [aggregate_unchecked](../fn.aggregate_unchecked.html) for more information.
+    pub fn aggregate_unchecked<TAggregation: Aggregation<TStore>>(&mut self, location: &[u64], n_values: u64) -> io::Result<TAggregation::Output>
+        where TStore: CqlAggregatable {
+        let _lock = self.lock_shared()?;
+        let position = self.calculate_position(location)?;
+        database::aggregate::<TStore, TAggregation>(self.db_file(), position, n_values)
+    }
+
+    /// Aggregates `n_values` from the given location onward.
+    ///
+    /// See [aggregate](../fn.aggregate.html) for more information.
+    pub fn aggregate<TAggregation: Aggregation<TStore>>(&mut self, location: &[u64], n_values: u64) -> result::Result<TAggregation::Output>
+        where TStore: CqlAggregatable {
+        let _lock = self.lock_shared()?;
+        self.validate_stream_range(location, n_values)?;
+        let output = self.aggregate_unchecked::<TAggregation>(location, n_values)?;
+        Ok(output)
+    }
+
+    /// Aggregates each `bucket_size` values of the `n_values` from the given location onward separately.  Does not validate given parameters.
+    ///
+    /// See [rollup_unchecked](../fn.rollup_unchecked.html) for more information.
+    pub fn rollup_unchecked<TAggregation: Aggregation<TStore>>(&mut self, location: &[u64], n_values: u64, bucket_size: u64)
+        -> io::Result<Vec<TAggregation::Output>> where TStore: CqlAggregatable {
+        let _lock = self.lock_shared()?;
+        let position = self.calculate_position(location)?;
+        let row_size = self.row_size();
+
+        match rollup_library::rollup::<TStore, TAggregation>(self.db_file(), &self.rollups, row_size, position, n_values, bucket_size)? {
+            Some(outputs) => Ok(outputs),
+            None => database::rollup::<TStore, TAggregation>(self.db_file(), position, n_values, bucket_size),
+        }
+    }
+
+    /// Aggregates each `bucket_size` values of the `n_values` from the given location onward separately.
+    ///
+    /// See [rollup](../fn.rollup.html) for more information.
+    pub fn rollup<TAggregation: Aggregation<TStore>>(&mut self, location: &[u64], n_values: u64, bucket_size: u64)
+        -> result::Result<Vec<TAggregation::Output>> where TStore: CqlAggregatable {
+        let _lock = self.lock_shared()?;
+        self.validate_stream_range(location, n_values)?;
+        if bucket_size == 0 {
+            return Err(error::Error::Cql(error::cql::Error::BucketTooSmallError))
+        }
+
+        let outputs = self.rollup_unchecked::<TAggregation>(location, n_values, bucket_size)?;
+        Ok(outputs)
+    }
+
+    /// Reads the values within the given range of each dimension into the given stream, one row at a time.  Does not validate given parameters.
+    ///
+    /// See [read_slice_unchecked](../fn.read_slice_unchecked.html) for more information.
+    pub fn read_slice_unchecked(&mut self, stream: &mut dyn Write, ranges: &[Range<u64>]) -> io::Result<Vec<Vec<u64>>>
+        where TStore: CqlBufferStreamReadable {
+        let _lock = self.lock_shared()?;
+        let last_index = ranges.len() - 1;
+        let last_range = &ranges[last_index];
+        let rows = self.collect_rows_within(&ranges[0..last_index])?;
+
+        if last_range.start < last_range.end {
+            for (_, key) in rows.iter() {
+                let position = calculate_index(*key, last_range.start, self.axis_definitions[last_index].max);
+                database::read_to_stream::<TStore>(self.db_file(), stream, position, last_range.end - last_range.start)?;
+            }
+        }
+
+        Ok(rows.into_iter().map(|(row, _)| row).collect())
+    }
+
+    /// Reads the values within the given range of each dimension into the given stream, one row at a time.
+    ///
+    /// See [read_slice](../fn.read_slice.html) for more information.
+    pub fn read_slice(&mut self, stream: &mut dyn Write, ranges: &[Range<u64>]) -> result::Result<Vec<Vec<u64>>>
+        where TStore: CqlBufferStreamReadable {
+        let _lock = self.lock_shared()?;
+        self.validate_slice_ranges(ranges)?;
+        let rows = self.read_slice_unchecked(stream, ranges)?;
+        Ok(rows)
+    }
+
+    /// Returns an iterator over `n_values` from the given location onward.  Does not validate given parameters.
+    ///
+    /// See [read_range_unchecked](../fn.read_range_unchecked.html) for more information.
+    pub fn read_range_unchecked(&mut self, location: &[u64], n_values: u64) -> io::Result<impl Iterator<Item = io::Result<TStore::ValueType>>>
+        where TStore: CqlRangeReadable {
+        // the values are read after this call returns, so the range holds the lock until it is dropped
+        let range_lock = self.lock_shared()?;
+        let position = self.calculate_position(location)?;
+        let range = database::read_range::<TStore, _>(Arc::clone(&self.db_file), position, n_values)?;
+
+        Ok(
+            range.inspect(move |_| {
+                let _ = &range_lock;
+            })
+        )
+    }
+
+    /// Returns an iterator over `n_values` from the given location onward.
+    ///
+    /// See [read_range](../fn.read_range.html) for more information.
+    pub fn read_range(&mut self, location: &[u64], n_values: u64) -> result::Result<impl Iterator<Item = io::Result<TStore::ValueType>>>
+        where TStore: CqlRangeReadable {
+        let _lock = self.lock_shared()?;
+        self.validate_stream_range(location, n_values)?;
+        let range = self.read_range_unchecked(location, n_values)?;
+        Ok(range)
+    }
+
+    /// Writes `n_values` from the given stream to the given location onward.  Does not validate given parameters.
+    ///
+    /// See [write_from_stream_unchecked](../fn.write_from_stream_unchecked.html) for more information.
+    pub fn write_from_stream_unchecked(&mut self, stream: &mut dyn Read, location: &[u64], n_values: u64) -> io::Result<()>
+        where TStore: CqlBufferStreamWritable {
+        let _lock = self.lock_exclusive()?;
+        let position = self.calculate_position(location)?;
+        database::read_from_stream::<TStore>(stream, position, n_values, |start_position, bytes| self.write_bytes(start_position, bytes))?;
+        written::mark(&self.written_file, position, n_values)?;
+        self.sync(Durability::PerBatch)
+    }
+
+    /// Writes `n_values` from the given stream to the given location onward.
+    ///
+    /// See [write_from_stream](../fn.write_from_stream.html) for more information.
+    pub fn write_from_stream(&mut self, stream: &mut dyn Read, location: &[u64], n_values: u64) -> result::Result<()>
+        where TStore: CqlBufferStreamWritable {
+        let _lock = self.lock_exclusive()?;
+        self.validate_stream_range(location, n_values)?;
+        self.write_from_stream_unchecked(stream, location, n_values)?;
+        Ok(())
+    }
+
+    /// Begins a [transaction](./struct.Transaction.html) against the database.
+    ///
+    /// Links and writes made through the transaction are buffered until it is committed, and are then applied all at once - dropping the
+    /// transaction without committing it discards them.
+    pub fn transaction(&mut self) -> Transaction<'_, TStore, TStorage> where TStore: CqlBufferWritable {
+        Transaction::new(self)
+    }
+
+    // Adds the changes needed to link the given location to the batch, on top of any changes already in it
+    pub(crate) fn link_dimensions_batched(&mut self, location: &[u64], batch: &mut Batch) -> io::Result<()> {
+        let mut x_position = location[0];
+
+        for (y_axis_index, &y_position) in location.iter().enumerate().skip(1) {
+            let y_axis_definition = &self.axis_definitions[y_axis_index];
+            let key_library = &self.key_libraries[y_axis_index - 1];
+
+            let mut key = key_library::get_batched(key_library, x_position, y_position, y_axis_definition, batch)?;
+
+            if key == 0 {
+                key = key_library::add(key_library, x_position, y_position, y_axis_definition, batch)?;
+
+                if y_axis_index == self.axis_definitions.len() - 2 {
+                    // the key may be a released row, in which case the database will already be large enough
+                    let last_axis_max = self.axis_definitions[y_axis_index + 1].max;
+                    database::allocate::<TStore>(self.db_file(), key * last_axis_max, batch)?;
+                }
+            };
+            x_position = key;
+        }
+
+        Ok(())
+    }
+
+    // Returns the position of the given location as it will be once the batch has been applied, validating that its elements will be linked
+    pub(crate) fn calculate_batched_position(&self, location: &[u64], batch: &Batch) -> result::Result<u64> {
+        let last_index = location.len() - 1;
+        if last_index == 0 {
+            return Ok(location[0] - 1)
+        }
+
+        let mut x_position = location[0];
+        for (y_axis_index, &y_position) in location.iter().enumerate().take(last_index).skip(1) {
+            x_position = key_library::get_batched(
+                &self.key_libraries[y_axis_index - 1],
+                x_position,
+                y_position,
+                &self.axis_definitions[y_axis_index],
+                batch
+            )?;
+
+            if x_position == 0 {
+                return Err(
+                    error::Error::Cql(
+                        error::cql::Error::ElementsNotLinkedError {
+                            x_dimension: y_axis_index - 1,
+                            x: location[y_axis_index - 1],
+                            y_dimension: y_axis_index,
+                            y: y_position,
+                        }
+                    )
+                )
+            }
+        }
+
+        Ok(
+            calculate_index(x_position, location[last_index], self.axis_definitions[last_index].max)
+        )
+    }
+
+    // Writes a value at the given location through the given function, which is passed its position and writes it to the database file itself, as
+    // types predating CqlBufferWritable do.  The value is marked as written and the summaries covering it updated once it has been written, but unlike
+    // values written by the handle it is not written through the journal.
+    pub(crate) fn write_value_with_unchecked(&mut self, location: &[u64], write: impl FnOnce(u64) -> io::Result<()>) -> io::Result<()> {
+        let _lock = self.lock_exclusive()?;
+        let position = self.calculate_position(location)?;
+        write(position)?;
+
+        if !self.rollups.is_empty() {
+            let mut batch = Batch::new();
+            rollup_library::update_batched(self.db_file(), &self.rollups, self.row_size(), &[position], &mut batch)?;
+            batch.commit(&self.storage, self.syncs_journal())?;
+        }
+
+        written::mark(&self.written_file, position, 1)?;
+        self.sync(Durability::PerCall)
+    }
+
+    pub(crate) fn write_value_with(&mut self, location: &[u64], write: impl FnOnce(u64) -> io::Result<()>) -> result::Result<()> {
+        let _lock = self.lock_exclusive()?;
+        self.validate_read_write_location(location)?;
+        self.write_value_with_unchecked(location, write)?;
+        Ok(())
+    }
+
+    // Reads from the given location through the given function, which is passed its position and reads the database file itself, as types predating
+    // CqlRangeReadable and CqlBufferStreamReadable do
+    pub(crate) fn read_with_unchecked<T>(&mut self, location: &[u64], read: impl FnOnce(u64) -> io::Result<T>) -> io::Result<T> {
+        let _lock = self.lock_shared()?;
+        let position = self.calculate_position(location)?;
+        read(position)
+    }
+
+    pub(crate) fn read_with<T>(&mut self, location: &[u64], n_values: u64, read: impl FnOnce(u64) -> io::Result<T>) -> result::Result<T> {
+        let _lock = self.lock_shared()?;
+        self.validate_stream_range(location, n_values)?;
+        let value = self.read_with_unchecked(location, read)?;
+        Ok(value)
+    }
+
+    // Journaled changes are only synced should the handle sync anything at all, as the journal is what keeps multi-file changes consistent
+    pub(crate) fn syncs_journal(&self) -> bool {
+        self.durability >= Durability::Explicit
+    }
+
+    // Syncs everything should the handle's durability be at least that given
+    fn sync(&self, durability: Durability) -> io::Result<()> {
+        if self.durability >= durability {
+            return self.sync_all()
+        }
+
+        Ok(())
+    }
+
+    fn sync_all(&self) -> io::Result<()> {
+        database::sync(self.db_file())?;
+        written::sync(&self.written_file)?;
+        rollup_library::sync(&self.rollups)?;
+        for key_library in self.key_libraries.iter() {
+            key_library::sync(key_library)?;
+        }
+
+        Ok(())
+    }
+
+    #[cfg(feature = "mmap")]
+    pub(crate) fn axis_definitions(&self) -> &[AxisDefinition] {
+        &self.axis_definitions
+    }
+
+    pub(crate) fn storage(&self) -> &TStorage {
+        &self.storage
+    }
+
+    // A pending journal or resize can only be seen once its writer has released its lock, meaning that it was interrupted, so it is completed before
+    // continuing.  The database may have been resized since the handle last held the lock, in which case the axis definitions are re-read.
+    pub(crate) fn lock_shared(&mut self) -> io::Result<LockGuard> {
+        let guard = lock::shared(&self.lock, self.lock_timeout)?;
+        if !guard.is_outermost() {
+            return Ok(guard)
+        }
+
+        if self.is_recovery_pending()? {
+            drop(guard);
+            drop(self.lock_exclusive()?);
+            return self.lock_shared()
+        }
+
+        self.refresh_axis_definitions()?;
+        Ok(guard)
+    }
+
+    pub(crate) fn lock_exclusive(&mut self) -> io::Result<LockGuard> {
+        let guard = lock::exclusive(&self.lock, self.lock_timeout)?;
+        if !guard.is_outermost() {
+            return Ok(guard)
+        }
+
+        if self.is_recovery_pending()? {
+            journal::recover(&self.storage)?;
+            resize::recover(&self.storage)?;
+            // the interrupted update may have changed the capacities of the database
+            lock::advance_generation(&self.lock)?;
+        }
+
+        self.refresh_axis_definitions()?;
+        Ok(guard)
+    }
+
+    fn is_recovery_pending(&self) -> io::Result<bool> {
+        Ok(journal::is_pending(&self.storage, &self.journal)? || resize::is_pending(&self.storage, &self.resize_progress)?)
+    }
+
+    // Re-reads the axis definitions should the database have been resized since they were last read, the caller must hold the lock
+    fn refresh_axis_definitions(&mut self) -> io::Result<()> {
+        let generation = lock::generation(&self.lock)?;
+        if generation != self.generation {
+            self.axis_definitions = axis_library::get_all(&self.storage)?;
+            self.generation = generation;
+        }
+
+        Ok(())
+    }
+
+    // Returns the location of every row (the first N - 1 dimensions) that can currently hold values, in ascending order.
+    pub(crate) fn linked_rows(&mut self) -> io::Result<Vec<Vec<u64>>> {
+        self.collect_linked_rows(&[])
+    }
+
+    // Returns the position of the first value in the given row, which must be linked.
+    pub(crate) fn row_position(&mut self, row: &[u64]) -> io::Result<u64> {
+        let mut location = row.to_vec();
+        location.push(1);
+        self.calculate_position(&location)
+    }
+
+    pub(crate) fn db_file(&self) -> &TStorage::File {
+        &self.db_file
+    }
+
+    pub(crate) fn written_file(&self) -> &OptionalFile<TStorage::File> {
+        &self.written_file
+    }
+
+    pub(crate) fn rollups(&self) -> &Rollups<OptionalFile<TStorage::File>> {
+        &self.rollups
+    }
+
+    // The number of values in each row, i.e. the capacity of the last dimension
+    pub(crate) fn row_size(&self) -> u64 {
+        self.axis_definitions[self.axis_definitions.len() - 1].max
+    }
+
+    // Returns the location of every row beginning with the given location that can currently hold values, in ascending order
+    fn collect_linked_rows(&mut self, location: &[u64]) -> io::Result<Vec<Vec<u64>>> {
+        let ranges: Vec<Range<u64>> = self.axis_definitions.iter().map(|axis_definition| 1..axis_definition.max + 1).collect();
+        let ranges = &ranges[0..ranges.len() - 1];
+
+        let rows = if location.is_empty() {
+            self.collect_rows_within(ranges)?
+        } else {
+            let mut rows = Vec::new();
+            let x_position = self.resolve_key(location)?;
+            if x_position != 0 {
+                self.collect_rows_beneath(&mut location.to_vec(), x_position, ranges, &mut rows)?;
+            }
+            rows
+        };
+
+        Ok(rows.into_iter().map(|(row, _)| row).collect())
+    }
+
+    // Returns the location and key of every row that can currently hold values with each index within the range given for its dimension, in
+    // ascending order
+    fn collect_rows_within(&mut self, ranges: &[Range<u64>]) -> io::Result<Vec<(Vec<u64>, u64)>> {
+        if ranges.is_empty() {
+            // the only row of a 1 dimensional database has no location, and the single key the position calculations treat it as having
+            return Ok(vec![(Vec::new(), 1)])
+        }
+
+        let mut rows = Vec::new();
+        for x in ranges[0].clone() {
+            self.collect_rows_beneath(&mut vec![x], x, ranges, &mut rows)?;
+        }
+
+        Ok(rows)
+    }
+
+    fn collect_rows_beneath(&mut self, location: &mut Vec<u64>, x_position: u64, ranges: &[Range<u64>], rows: &mut Vec<(Vec<u64>, u64)>)
+        -> io::Result<()> {
+        if location.len() == self.axis_definitions.len() - 1 {
+            rows.push((location.clone(), x_position));
+            return Ok(())
+        }
+
+        let y_range = ranges[location.len()].clone();
+        for (y, key) in self.linked_keys(location.len(), x_position)? {
+            if !y_range.contains(&y) {
+                continue
+            }
+
+            location.push(y);
+            self.collect_rows_beneath(location, key, ranges, rows)?;
+            location.pop();
+        }
+
+        Ok(())
+    }
+
+    // Returns each index of the given axis linked to the given position in the axis before it, alongside the key it is linked with
+    fn linked_keys(&mut self, y_axis_index: usize, x_position: u64) -> io::Result<Vec<(u64, u64)>> {
+        let keys = key_library::get_all(&mut self.key_libraries[y_axis_index - 1], x_position, &self.axis_definitions[y_axis_index])?;
+
+        Ok(
+            (1..).zip(keys).filter(|&(_, key)| key != 0).collect()
+        )
+    }
+
+    // Walks the key chain of the given (non-empty) location, returning the key of its last element, or 0 should any of its elements not be linked.
+    // The first element is its own key.
+    fn resolve_key(&mut self, location: &[u64]) -> io::Result<u64> {
+        let mut x_position = location[0];
+
+        for (y_axis_index, &y_position) in location.iter().enumerate().skip(1) {
+            x_position = key_library::get(
+                &mut self.key_libraries[y_axis_index - 1],
+                x_position,
+                y_position,
+                &self.axis_definitions[y_axis_index]
+            )?;
+
+            if x_position == 0 {
+                return Ok(0)
+            }
+        }
+
+        Ok(x_position)
+    }
+
+    pub(crate) fn validate_link_dimensions_params(&self, location: &[u64]) -> result::Result<()> {
+        let number_of_dimensions = self.axis_definitions.len();
+
+        if location.len() < 2 || location.len() > (number_of_dimensions - 1) {
+            return Err(
+                error::Error::Cql(
+                    error::cql::Error::DimensionsOutOfRangeError {
+                        requested: location.len(),
+                        min: 2,
+                        max: number_of_dimensions - 1,
+                    }
+                )
+            )
+        }
+
+        self.validate_element_within_range(location)
+    }
+
+    fn validate_linked_children_params(&self, location: &[u64]) -> result::Result<()> {
+        let max = self.axis_definitions.len().saturating_sub(2);
+
+        if location.is_empty() || location.len() > max {
+            return Err(
+                error::Error::Cql(
+                    error::cql::Error::DimensionsOutOfRangeError {
+                        requested: location.len(),
+                        min: 1,
+                        max,
+                    }
+                )
+            )
+        }
+
+        self.validate_element_within_range(location)
+    }
+
+    fn validate_walk_links_params(&self, location: &[u64]) -> result::Result<()> {
+        let max = self.axis_definitions.len() - 1;
+
+        if location.len() > max {
+            return Err(
+                error::Error::Cql(
+                    error::cql::Error::DimensionsOutOfRangeError {
+                        requested: location.len(),
+                        min: 0,
+                        max,
+                    }
+                )
+            )
+        }
+
+        self.validate_element_within_range(location)
+    }
+
+    fn validate_slice_ranges(&self, ranges: &[Range<u64>]) -> result::Result<()> {
+        let number_of_dimensions = self.axis_definitions.len();
+        if ranges.len() != number_of_dimensions {
+            return Err(
+                error::Error::Cql(
+                    error::cql::Error::DimensionsOutOfRangeError {
+                        requested: ranges.len(),
+                        min: number_of_dimensions,
+                        max: number_of_dimensions,
+                    }
+                )
+            )
+        }
+
+        for (i, (range, axis_definition)) in ranges.iter().zip(&self.axis_definitions).enumerate() {
+            let requested = if range.start < 1 {
+                range.start
+            } else if range.end > axis_definition.max + 1 {
+                range.end - 1
+            } else {
+                continue
+            };
+
+            return Err(
+                error::Error::Cql(
+                    error::cql::Error::IndexOutOfRangeError {
+                        dimension_index: i,
+                        requested,
+                        min: 1,
+                        max: axis_definition.max
+                    }
+                )
+            )
+        }
+
+        Ok(())
+    }
+
+    fn validate_stream_range(&mut self, location: &[u64], n_values: u64) -> result::Result<()> {
+        self.validate_read_write_location(location)?;
+        self.validate_stream_length(location, n_values)
+    }
+
+    // Checks that the `n_values` from the given (valid) location onward are within the final dimension
+    pub(crate) fn validate_stream_length(&self, location: &[u64], n_values: u64) -> result::Result<()> {
+        let axis_index = location.len() - 1;
+        let axis_definition = &self.axis_definitions[axis_index];
+        let last_index = location[axis_index] + n_values - 1;
+
+        if last_index > axis_definition.max {
+            return Err(
+                error::Error::Cql(
+                    error::cql::Error::IndexOutOfRangeError {
+                        dimension_index: axis_index,
+                        requested: last_index,
+                        min: 1,
+                        max: axis_definition.max
+                    }
+                )
+            )
+        }
+
+        Ok(())
+    }
+
+    // Checks that the `n_values`, `stride` values apart, from the given (valid) location onward are within the final dimension
+    fn validate_strided_length(&self, location: &[u64], n_values: u64, stride: u64) -> result::Result<()> {
+        if stride == 0 {
+            return Err(error::Error::Cql(error::cql::Error::StrideTooSmallError))
+        }
+
+        let axis_index = location.len() - 1;
+        let axis_definition = &self.axis_definitions[axis_index];
+        let last_index = location[axis_index].saturating_add(n_values.saturating_sub(1).saturating_mul(stride));
+
+        if last_index > axis_definition.max {
+            return Err(
+                error::Error::Cql(
+                    error::cql::Error::IndexOutOfRangeError {
+                        dimension_index: axis_index,
+                        requested: last_index,
+                        min: 1,
+                        max: axis_definition.max
+                    }
+                )
+            )
+        }
+
+        Ok(())
+    }
+
+    fn validate_element_within_range(&self, location: &[u64]) -> result::Result<()> {
+        for (i, (&position, axis_definition)) in location.iter().zip(&self.axis_definitions).enumerate() {
+            if position < 1 || position > axis_definition.max {
+                return Err(
+                    error::Error::Cql(
+                        error::cql::Error::IndexOutOfRangeError {
+                            dimension_index: i,
+                            requested: position,
+                            min: 1,
+                            max: axis_definition.max
+                        }
+                    )
+                )
+            }
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn validate_location_shape(&self, location: &[u64]) -> result::Result<()> {
+        let number_of_dimensions = self.axis_definitions.len();
+        if location.len() != number_of_dimensions {
+            return Err(
+                error::Error::Cql(
+                    error::cql::Error::DimensionsOutOfRangeError {
+                        requested: location.len(),
+                        min: number_of_dimensions,
+                        max: number_of_dimensions,
+                    }
+                )
+            )
+        }
+
+        self.validate_element_within_range(location)
+    }
+
+    fn validate_read_write_location(&mut self, location: &[u64]) -> result::Result<()> {
+        self.validate_location_shape(location)?;
+        self.validate_elements_linked(&location[0..location.len() - 1])
+    }
+
+    fn validate_elements_linked(&mut self, location: &[u64]) -> result::Result<()> {
+        match self.find_unlinked_element(location)? {
+            Some(y_axis_index) => Err(
+                error::Error::Cql(
+                    error::cql::Error::ElementsNotLinkedError {
+                        x_dimension: y_axis_index - 1,
+                        x: location[y_axis_index - 1],
+                        y_dimension: y_axis_index,
+                        y: location[y_axis_index],
+                    }
+                )
+            ),
+            None => Ok(()),
+        }
+    }
+
+    // Walks the key chain of the given location, returning the index of the first element that is not linked to the element before it
+    fn find_unlinked_element(&mut self, location: &[u64]) -> io::Result<Option<usize>> {
+        let mut x_position = match location.first() {
+            Some(&x) => x,
+            None => return Ok(None),
+        };
+
+        for (y_axis_index, &y_position) in location.iter().enumerate().skip(1) {
+            let key = key_library::get(
+                &mut self.key_libraries[y_axis_index - 1],
+                x_position,
+                y_position,
+                &self.axis_definitions[y_axis_index]
+            )?;
+
+            if key == 0 {
+                return Ok(Some(y_axis_index))
+            }
+
+            x_position = key;
+        }
+
+        Ok(None)
+    }
+
+    // Adds the operations clearing everything linked beneath the given (already unlinked) key before releasing it for reuse to the given batch, so that
+    // nothing reappears should the key be handed out again.  Keys beneath the given key are not changed by the batch until they have been read, so
+    // they are read directly from the key libraries.
+    fn release_key_batched(&mut self, y_axis_index: usize, key: u64, batch: &mut Batch) -> io::Result<()> {
+        if y_axis_index == self.axis_definitions.len() - 2 {
+            let last_axis_max = self.axis_definitions[y_axis_index + 1].max;
+            let start_location = calculate_index(key, 1, last_axis_max);
+
+            database::write_bytes_batched::<TStore>(start_location, &vec![0; last_axis_max as usize * TStore::VALUE_SIZE], batch);
+            written::clear_batched(&self.written_file, start_location, last_axis_max, batch)?;
+
+            if !self.rollups.is_empty() {
+                let locations: Vec<u64> = (start_location..start_location + last_axis_max).collect();
+                rollup_library::update_batched(self.db_file(), &self.rollups, self.row_size(), &locations, batch)?;
+            }
+        } else {
+            let child_axis_index = y_axis_index + 1;
+            let child_keys = key_library::get_all(
+                &mut self.key_libraries[child_axis_index - 1], key, &self.axis_definitions[child_axis_index]
+            )?;
+
+            for (child_y, child_key) in (1..).zip(child_keys) {
+                if child_key != 0 {
+                    let child_axis_definition = &self.axis_definitions[child_axis_index];
+                    key_library::remove_batched(&self.key_libraries[child_axis_index - 1], key, child_y, child_axis_definition, batch);
+                    self.release_key_batched(child_axis_index, child_key, batch)?;
+                }
+            }
+        }
+
+        key_library::release_batched(&self.key_libraries[y_axis_index - 1], key, batch)
+    }
+
+    fn calculate_position(&mut self, location: &[u64]) -> io::Result<u64> {
+        if location.len() == 1 {
+            // minus one to handle the one-indexing
+            return Ok(
+                location[0] - 1
+            )
+        }
+
+        let last_index = location.len() - 1;
+
+        let mut x_position = location[0];
+        for (y_axis_index, &y_position) in location.iter().enumerate().take(last_index).skip(1) {
+            x_position = key_library::get(
+                &mut self.key_libraries[y_axis_index - 1],
+                x_position,
+                y_position,
+                &self.axis_definitions[y_axis_index]
+            )?;
+        }
+
+        Ok(
+            calculate_index(x_position, location[last_index], self.axis_definitions[last_index].max)
+        )
+    }
+
+    // Writes each of the given values to the given (resolved) row, the caller must hold an exclusive lock.  Values at adjacent locations within the same
+    // row are written as a single run, which is marked as written and has its summaries updated at once.
+    fn write_values_to_rows(&mut self, values: &[(&[u64], TStore::ValueType)], rows: &[u64]) -> io::Result<()>
+        where TStore: CqlBufferWritable, TStore::ValueType: Clone {
+        let row_size = self.row_size();
+        let mut positions: Vec<(u64, usize)> = values
+            .iter()
+            .zip(rows)
+            .enumerate()
+            .map(|(index, ((location, _), &row))| (calculate_index(row, location[location.len() - 1], row_size), index))
+            .collect();
+
+        // stable sort, so that only the last of any duplicate locations is kept
+        positions.sort_by_key(|&(position, _)| position);
+        positions.dedup_by(|next, previous| {
+            let duplicate = next.0 == previous.0;
+            if duplicate {
+                *previous = *next;
+            }
+            duplicate
+        });
+
+        let mut run_start = 0;
+        while run_start < positions.len() {
+            let start_position = positions[run_start].0;
+            let mut run_end = run_start + 1;
+            while run_end < positions.len()
+                && positions[run_end].0 == start_position + (run_end - run_start) as u64
+                && !positions[run_end].0.is_multiple_of(row_size) {
+                run_end += 1;
+            }
+
+            let run = &positions[run_start..run_end];
+            let bytes = database::encode_values::<TStore>(run.iter().map(|&(_, index)| values[index].1.clone()).collect())?;
+
+            self.write_bytes(start_position, &bytes)?;
+            written::mark(&self.written_file, start_position, run.len() as u64)?;
+
+            run_start = run_end;
+        }
+
+        self.sync(Durability::PerBatch)
+    }
+
+    // Writes the given (encoded) values from the start position onward, which must all be within the same row.  Should the database have rollup levels,
+    // the values are written through the journal in the same batch as the summaries covering them, so that the two are never seen out of step.
+    fn write_bytes(&self, start_position: u64, bytes: &[u8]) -> io::Result<()> {
+        if self.rollups.is_empty() {
+            return database::write_bytes::<TStore>(self.db_file(), start_position, bytes)
+        }
+
+        let locations: Vec<u64> = (start_position..start_position + (bytes.len() / TStore::VALUE_SIZE) as u64).collect();
+
+        let mut batch = Batch::new();
+        database::write_bytes_batched::<TStore>(start_position, bytes, &mut batch);
+        rollup_library::update_batched(self.db_file(), &self.rollups, self.row_size(), &locations, &mut batch)?;
+        batch.commit(&self.storage, self.syncs_journal())
+    }
+
+    // Resolves the row (penultimate dimension key) of each of the given full locations, returning 0 for any location that has not been linked.
+    // Locations are resolved in sorted order so that the key chain of each shared prefix is only walked once.
+    fn resolve_rows(&mut self, locations: &[&[u64]]) -> io::Result<Vec<u64>> {
+        let mut rows = vec![1; locations.len()];
+        if self.axis_definitions.len() < 2 {
+            return Ok(rows)
+        }
+
+        let mut order: Vec<usize> = (0..locations.len()).collect();
+        order.sort_by(|&a, &b| locations[a].cmp(locations[b]));
+
+        let mut previous_prefix: &[u64] = &[];
+        let mut chain: Vec<u64> = Vec::with_capacity(self.axis_definitions.len() - 1);
+
+        for index in order {
+            let location = locations[index];
+            let prefix = &location[0..location.len() - 1];
+
+            let shared_length = previous_prefix.iter().zip(prefix).take_while(|(a, b)| a == b).count();
+            chain.truncate(shared_length);
+            if chain.is_empty() {
+                chain.push(prefix[0]);
+            }
+
+            while chain.len() < prefix.len() {
+                let y_axis_index = chain.len();
+                let x_position = chain[y_axis_index - 1];
+
+                let key = if x_position == 0 {
+                    0
+                } else {
+                    key_library::get(
+                        &mut self.key_libraries[y_axis_index - 1],
+                        x_position,
+                        prefix[y_axis_index],
+                        &self.axis_definitions[y_axis_index]
+                    )?
+                };
+                chain.push(key);
+            }
+
+            rows[index] = chain[chain.len() - 1];
+            previous_prefix = prefix;
+        }
+
+        Ok(rows)
+    }
+}
+
+fn check_format_version(format_version: u64) -> result::cql::Result<()> {
+    if format_version != FORMAT_VERSION {
+        return Err(
+            error::cql::Error::FormatVersionError {
+                stored: format_version,
+                supported: FORMAT_VERSION,
+            }
+        )
+    }
+
+    Ok(())
+}
@@ -0,0 +1,276 @@
+use std::cmp::{ min, max };
+use std::collections::BTreeSet;
+use std::io;
+
+use crate::storage;
+use crate::storage::{ Storage, StorageFile, OptionalFile };
+
+const JOURNAL_FILE_NAME: &str = "/jnl";
+const WRITE_OPERATION: u8 = 1;
+const SET_LENGTH_OPERATION: u8 = 2;
+const BLOCK_SIZE: usize = 8;
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0100_0000_01b3;
+
+// A change to one of the files of the database, identified by its name within the database directory (e.g. "/key1_2").  Every operation sets
+// the file to an absolute state, so that operations may safely be applied more than once.
+pub enum Operation {
+    Write { file_name: String, position: u64, bytes: Vec<u8> },
+    SetLength { file_name: String, length: u64 },
+}
+
+// The operations of a single update spanning multiple files, collected before any of them are applied.  Reads made through the batch see the files as
+// they will be once its operations have been applied, so later operations can be planned on top of earlier ones.
+#[derive(Default)]
+pub struct Batch {
+    operations: Vec<Operation>,
+}
+
+impl Batch {
+    pub fn new() -> Batch {
+        Batch { operations: Vec::new() }
+    }
+
+    pub fn push(&mut self, operation: Operation) {
+        self.operations.push(operation);
+    }
+
+    // Fills the buffer from the given position in the file, bytes beyond the end of the file are zeroed
+    pub fn read(&self, file_name: &str, file: &impl StorageFile, position: u64, buffer: &mut [u8]) -> io::Result<()> {
+        file.read_at(position, buffer)?;
+
+        let end = position + buffer.len() as u64;
+        for operation in self.operations.iter() {
+            match operation {
+                Operation::Write { file_name: name, position: write_position, bytes }
+                    if name == file_name && *write_position < end && write_position + bytes.len() as u64 > position => {
+                    let start = max(*write_position, position);
+                    let stop = min(write_position + bytes.len() as u64, end);
+                    buffer[(start - position) as usize..(stop - position) as usize]
+                        .copy_from_slice(&bytes[(start - write_position) as usize..(stop - write_position) as usize]);
+                },
+                // anything truncated reads as zeroes should the file later be extended again
+                Operation::SetLength { file_name: name, length } if name == file_name && *length < end => {
+                    let start = max(*length, position);
+                    for byte in buffer[(start - position) as usize..].iter_mut() {
+                        *byte = 0;
+                    }
+                },
+                _ => { },
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn length(&self, file_name: &str, file: &impl StorageFile) -> io::Result<u64> {
+        let mut length = file.size()?;
+
+        for operation in self.operations.iter() {
+            match operation {
+                Operation::Write { file_name: name, position, bytes } if name == file_name => {
+                    length = max(length, position + bytes.len() as u64);
+                },
+                Operation::SetLength { file_name: name, length: new_length } if name == file_name => {
+                    length = *new_length;
+                },
+                _ => { },
+            }
+        }
+
+        Ok(length)
+    }
+
+    // Applies the batch through the journal, if `sync` is false nothing is synced to disk, which still protects against the process being interrupted
+    // but not against power loss
+    pub fn commit<TStorage: Storage>(&self, storage: &TStorage, sync: bool) -> io::Result<()> {
+        commit(storage, &self.operations, sync)
+    }
+}
+
+// The journal holds the operations of a single update spanning multiple files.  The operations are written and synced to the journal before any
+// of them are applied, and the journal is emptied once they all have been, so a non-empty journal belongs to an update that was interrupted part way
+// through, and is completed by re-applying its operations.  A journal that was itself only partially written will fail its checksum, in which case
+// none of its operations were applied and it is discarded.
+//
+// Each operation is stored as [kind: u8][file name length: u64][file name][position or length: u64][bytes length: u64][bytes], and is followed by
+// [number of operations: u64][checksum: u64] once all operations have been written.
+fn commit<TStorage: Storage>(storage: &TStorage, operations: &[Operation], sync: bool) -> io::Result<()> {
+    if operations.is_empty() {
+        return Ok(())
+    }
+
+    let journal = storage.create(JOURNAL_FILE_NAME, false)?;
+
+    journal.write_at(0, &encode(operations))?;
+    if sync {
+        journal.sync()?;
+    }
+
+    apply(storage, operations, sync)?;
+
+    journal.set_size(0)?;
+    if sync {
+        journal.sync()?;
+    }
+
+    Ok(())
+}
+
+// Any journal left by a database being replaced is always discarded
+pub fn create<TStorage: Storage>(storage: &TStorage) -> io::Result<()> {
+    storage.create(JOURNAL_FILE_NAME, false)?;
+    Ok(())
+}
+
+// Opens the journal so that it can be checked for a pending update each time the database is locked, without being reopened
+pub fn open<TStorage: Storage>(storage: &TStorage, read_only: bool) -> io::Result<OptionalFile<TStorage::File>> {
+    storage::open_optional_file(storage, JOURNAL_FILE_NAME, read_only)
+}
+
+pub fn is_pending<TStorage: Storage>(storage: &TStorage, journal: &OptionalFile<TStorage::File>) -> io::Result<bool> {
+    match journal {
+        OptionalFile::Present(journal) => Ok(journal.size()? > 0),
+        // the journal of a database created by an earlier version of this crate may have been created since it was opened
+        OptionalFile::Missing => find_pending(storage),
+    }
+}
+
+fn find_pending<TStorage: Storage>(storage: &TStorage) -> io::Result<bool> {
+    match storage.open_read_only(JOURNAL_FILE_NAME).and_then(|journal| journal.size()) {
+        Ok(length) => Ok(length > 0),
+        Err(e) => {
+            if e.kind() == io::ErrorKind::NotFound {
+                return Ok(false)
+            }
+            Err(e)
+        }
+    }
+}
+
+// Completes any interrupted update, the caller must hold an exclusive lock on the database
+pub fn recover<TStorage: Storage>(storage: &TStorage) -> io::Result<()> {
+    if !find_pending(storage)? {
+        return Ok(())
+    }
+
+    let journal = storage.open(JOURNAL_FILE_NAME)?;
+    let mut buffer = vec![0; journal.size()? as usize];
+    journal.read_at(0, &mut buffer)?;
+
+    if let Some(operations) = decode(&buffer) {
+        apply(storage, &operations, true)?;
+    }
+
+    journal.set_size(0)?;
+    journal.sync()
+}
+
+fn apply<TStorage: Storage>(storage: &TStorage, operations: &[Operation], sync: bool) -> io::Result<()> {
+    let mut file_names = BTreeSet::new();
+
+    for operation in operations {
+        match operation {
+            Operation::Write { file_name, position, bytes } => {
+                storage.open_or_create(file_name)?.write_at(*position, bytes)?;
+                file_names.insert(file_name);
+            },
+            Operation::SetLength { file_name, length } => {
+                storage.open_or_create(file_name)?.set_size(*length)?;
+                file_names.insert(file_name);
+            },
+        }
+    }
+
+    if sync {
+        for file_name in file_names {
+            storage.open_or_create(file_name)?.sync()?;
+        }
+    }
+
+    Ok(())
+}
+
+fn encode(operations: &[Operation]) -> Vec<u8> {
+    let mut buffer = Vec::new();
+
+    for operation in operations {
+        let (kind, file_name, value, bytes) = match operation {
+            Operation::Write { file_name, position, bytes } => (WRITE_OPERATION, file_name, *position, &bytes[..]),
+            Operation::SetLength { file_name, length } => (SET_LENGTH_OPERATION, file_name, *length, &[][..]),
+        };
+
+        buffer.push(kind);
+        buffer.extend_from_slice(&(file_name.len() as u64).to_le_bytes());
+        buffer.extend_from_slice(file_name.as_bytes());
+        buffer.extend_from_slice(&value.to_le_bytes());
+        buffer.extend_from_slice(&(bytes.len() as u64).to_le_bytes());
+        buffer.extend_from_slice(bytes);
+    }
+
+    buffer.extend_from_slice(&(operations.len() as u64).to_le_bytes());
+    let checksum = checksum(&buffer);
+    buffer.extend_from_slice(&checksum.to_le_bytes());
+
+    buffer
+}
+
+// Returns None if the journal is incomplete or otherwise invalid
+fn decode(journal: &[u8]) -> Option<Vec<Operation>> {
+    if journal.len() < 2 * BLOCK_SIZE {
+        return None
+    }
+
+    let checksum_position = journal.len() - BLOCK_SIZE;
+    if read_block(journal, checksum_position)? != checksum(&journal[..checksum_position]) {
+        return None
+    }
+
+    let count_position = checksum_position - BLOCK_SIZE;
+    let n_operations = read_block(journal, count_position)?;
+
+    let mut operations = Vec::new();
+    let mut position = 0;
+    while position < count_position {
+        let kind = journal[position];
+        position += 1;
+
+        let file_name_length = read_block(journal, position)? as usize;
+        position += BLOCK_SIZE;
+        let file_name = String::from_utf8(journal.get(position..position.checked_add(file_name_length)?)?.to_vec()).ok()?;
+        position += file_name_length;
+
+        let value = read_block(journal, position)?;
+        position += BLOCK_SIZE;
+
+        let bytes_length = read_block(journal, position)? as usize;
+        position += BLOCK_SIZE;
+        let bytes = journal.get(position..position.checked_add(bytes_length)?)?.to_vec();
+        position += bytes_length;
+
+        operations.push(
+            match kind {
+                WRITE_OPERATION => Operation::Write { file_name, position: value, bytes },
+                SET_LENGTH_OPERATION => Operation::SetLength { file_name, length: value },
+                _ => return None,
+            }
+        );
+    }
+
+    if position != count_position || operations.len() as u64 != n_operations {
+        return None
+    }
+
+    Some(operations)
+}
+
+fn read_block(buffer: &[u8], position: usize) -> Option<u64> {
+    let mut block = [0; BLOCK_SIZE];
+    block.copy_from_slice(buffer.get(position..position + BLOCK_SIZE)?);
+    Some(u64::from_le_bytes(block))
+}
+
+// FNV-1a, used to detect a partially written journal, or a partially written summary of a rollup level
+pub fn checksum(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(FNV_OFFSET_BASIS, |hash, &byte| (hash ^ byte as u64).wrapping_mul(FNV_PRIME))
+}
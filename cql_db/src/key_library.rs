@@ -1,57 +1,184 @@
+use std::convert::TryInto;
 use std::io;
-use std::fs::OpenOptions;
-
-use cql_u64::U64;
-use cql_model::{
-    CqlType,
-    CqlWritable,
-    CqlReadable,
-};
 
 use crate::axis_library::AxisDefinition;
+use crate::journal::{ Batch, Operation };
+use crate::storage;
+use crate::storage::{ Storage, StorageFile, OptionalFile };
 use crate::vectors::calculate_index;
 
 const KEY_FILE_NAME: &str = "/key";
+const FREE_KEYS_FILE_NAME: &str = "/freekey";
+pub const KEY_SIZE: u64 = 8;
 
-pub struct AxisPoint {
-	pub axis_id: u64,
-	pub position: u64
+pub struct KeyLibrary<TFile: StorageFile> {
+    keys: TFile,
+    free_keys: OptionalFile<TFile>,
+    keys_file_name: String,
+    free_keys_file_name: String,
 }
 
 // The dimensions between 0..(N - 1) are mapped in the key library, allowing each 'row' in the last dimension to be added on demand
 // reducing the storage space required.  Each key library contains the id of the last key added in the first block, and then acts like an 1D array
 // for every point thereafter, with each entry pointing at the location of it's data in the next key library, or the start of the actual data if
 // it is the penultimate dimension (N - 1).
-pub fn create(db_location: &str, axis_definitions: &[AxisDefinition], create_new: bool) -> io::Result<()> {
+//
+// Keys released by unlinking are pushed onto a stack held in a separate free key file, and are handed out again by `add` before any new keys are.
+// Adding or releasing a key changes several blocks across both files, so `add` and `release_batched` add the changes to a journal batch instead of
+// making them themselves.
+pub fn create<TStorage: Storage>(storage: &TStorage, axis_definitions: &[AxisDefinition], create_new: bool) -> io::Result<()> {
     for index in 1..axis_definitions.len() - 1 {
-        let library_key_location = format!("{}{}{}_{}", db_location, KEY_FILE_NAME, axis_definitions[index - 1].id, axis_definitions[index].id);
-        OpenOptions::new()
-            .write(true)
-            .create(true)
-            .create_new(create_new)
-            .truncate(true)
-            .open(library_key_location)?;
+        let x_axis_id = axis_definitions[index - 1].id;
+        let y_axis_id = axis_definitions[index].id;
+
+        storage.create(&library_file_name(x_axis_id, y_axis_id), create_new)?;
+
+        // the free keys belong to the key library created above, so any existing file is always replaced
+        storage.create(&free_keys_file_name(x_axis_id, y_axis_id), false)?;
     }
 
     Ok(())
 }
 
-pub fn add<TStore: CqlType>(db_location: &str, x: u64, y: u64, x_axis: &AxisDefinition, y_axis: &AxisDefinition) -> Result<u64, io::Error> {
-	let library_key_location = format!("{}{}{}_{}", db_location, KEY_FILE_NAME, x_axis.id, y_axis.id);
-	let last_key = U64::read_from_db(&library_key_location, 0)?;
+pub fn open<TStorage: Storage>(
+    storage: &TStorage,
+    x_axis_id: u64,
+    y_axis_id: u64,
+    read_only: bool
+) -> io::Result<KeyLibrary<TStorage::File>> {
+    let keys = storage::open_file(storage, &library_file_name(x_axis_id, y_axis_id), read_only)?;
+
+    // databases created before keys could be released will not have a free key file
+    let free_keys = storage::open_optional_file(storage, &free_keys_file_name(x_axis_id, y_axis_id), read_only)?;
+
+    Ok(
+        KeyLibrary {
+            keys,
+            free_keys,
+            keys_file_name: library_file_name(x_axis_id, y_axis_id),
+            free_keys_file_name: free_keys_file_name(x_axis_id, y_axis_id),
+        }
+    )
+}
 
-	let new_key = last_key + 1 as u64;
-	let key_index = calculate_index(x, y, y_axis.max);
+// Returns the key that linking `x` to `y` will use, adding the changes to the library needed to do so to the given batch for the caller to apply.
+// The library is not modified.
+pub fn add<TFile: StorageFile>(library: &KeyLibrary<TFile>, x: u64, y: u64, y_axis: &AxisDefinition, batch: &mut Batch) -> io::Result<u64> {
+    let new_key = match peek_free_key(library, batch)? {
+        Some((free_key, key_location)) => {
+            batch.push(
+                Operation::SetLength { file_name: library.free_keys_file_name.clone(), length: key_location * KEY_SIZE }
+            );
+            free_key
+        },
+        None => {
+            let new_key = read_batched_key(&library.keys, &library.keys_file_name, 0, batch)? + 1;
+            batch.push(key_operation(&library.keys_file_name, 0, new_key));
+            new_key
+        }
+    };
 
-    U64::write_to_db(&library_key_location, 0, new_key)?;
-	U64::write_to_db(&library_key_location, 1 + key_index, new_key)?;
+    let key_index = calculate_index(x, y, y_axis.max);
+    batch.push(key_operation(&library.keys_file_name, 1 + key_index, new_key));
 
     Ok(new_key)
 }
 
-pub fn get(db_location: &str, x: &AxisPoint, y: &AxisPoint, y_axis: &AxisDefinition) -> Result<u64, io::Error> {
-	let library_key_location = format!("{}{}{}_{}", db_location, KEY_FILE_NAME, x.axis_id, y.axis_id);
-	let key_location = calculate_index(x.position, y.position, y_axis.max);
+pub fn get<TFile: StorageFile>(library: &mut KeyLibrary<TFile>, x: u64, y: u64, y_axis: &AxisDefinition) -> io::Result<u64> {
+    let key_index = calculate_index(x, y, y_axis.max);
+
+    read_key(&library.keys, 1 + key_index)
+}
+
+// Returns the keys linking `x` to each `y` in 1..=y_axis.max, read as a single block as they are stored next to each other
+pub fn get_all<TFile: StorageFile>(library: &mut KeyLibrary<TFile>, x: u64, y_axis: &AxisDefinition) -> io::Result<Vec<u64>> {
+    let key_index = calculate_index(x, 1, y_axis.max);
+
+    let mut buffer = vec![0; (y_axis.max * KEY_SIZE) as usize];
+    library.keys.read_at((1 + key_index) * KEY_SIZE, &mut buffer)?;
+
+    Ok(
+        buffer
+            .chunks_exact(KEY_SIZE as usize)
+            .map(|key| u64::from_le_bytes(key.try_into().unwrap()))
+            .collect()
+    )
+}
+
+// Returns the position within the key library file of the key linking `x` to `y`, for callers reading the library by other means
+#[cfg(feature = "mmap")]
+pub fn key_position(x: u64, y: u64, y_axis: &AxisDefinition) -> u64 {
+    (1 + calculate_index(x, y, y_axis.max)) * KEY_SIZE
+}
+
+// Returns the key as it will be once the given batch has been applied
+pub fn get_batched<TFile: StorageFile>(library: &KeyLibrary<TFile>, x: u64, y: u64, y_axis: &AxisDefinition, batch: &Batch) -> io::Result<u64> {
+    let key_index = calculate_index(x, y, y_axis.max);
+
+    read_batched_key(&library.keys, &library.keys_file_name, 1 + key_index, batch)
+}
+
+// Adds the operation unlinking `x` from `y` to the given batch, the caller is responsible for releasing the key once everything that depends on it has
+// been cleared.
+pub fn remove_batched<TFile: StorageFile>(library: &KeyLibrary<TFile>, x: u64, y: u64, y_axis: &AxisDefinition, batch: &mut Batch) {
+    let key_index = calculate_index(x, y, y_axis.max);
+
+    batch.push(key_operation(&library.keys_file_name, 1 + key_index, 0));
+}
+
+// Adds the operation pushing the key onto the free key stack to the given batch, on top of any keys already released by it
+pub fn release_batched<TFile: StorageFile>(library: &KeyLibrary<TFile>, key: u64, batch: &mut Batch) -> io::Result<()> {
+    let key_location = batch.length(&library.free_keys_file_name, &library.free_keys)? / KEY_SIZE;
+
+    batch.push(key_operation(&library.free_keys_file_name, key_location, key));
+    Ok(())
+}
+
+pub fn sync<TFile: StorageFile>(library: &KeyLibrary<TFile>) -> io::Result<()> {
+    library.keys.sync()?;
+    library.free_keys.sync()
+}
+
+// Returns the key on top of the free key stack, and its location within the stack
+fn peek_free_key<TFile: StorageFile>(library: &KeyLibrary<TFile>, batch: &Batch) -> io::Result<Option<(u64, u64)>> {
+    let length = batch.length(&library.free_keys_file_name, &library.free_keys)?;
+    if length < KEY_SIZE {
+        return Ok(None)
+    }
+
+    let key_location = length / KEY_SIZE - 1;
+    let key = read_batched_key(&library.free_keys, &library.free_keys_file_name, key_location, batch)?;
+
+    Ok(Some((key, key_location)))
+}
+
+fn read_batched_key(library: &impl StorageFile, file_name: &str, key_location: u64, batch: &Batch) -> io::Result<u64> {
+    let mut buffer = [0; KEY_SIZE as usize];
+    batch.read(file_name, library, key_location * KEY_SIZE, &mut buffer)?;
+
+    Ok(u64::from_le_bytes(buffer))
+}
+
+fn key_operation(file_name: &str, key_location: u64, key: u64) -> Operation {
+    Operation::Write { file_name: file_name.to_string(), position: key_location * KEY_SIZE, bytes: key.to_le_bytes().to_vec() }
+}
+
+pub fn library_location(db_location: &str, x_axis_id: u64, y_axis_id: u64) -> String {
+    format!("{}{}", db_location, library_file_name(x_axis_id, y_axis_id))
+}
+
+pub fn library_file_name(x_axis_id: u64, y_axis_id: u64) -> String {
+    format!("{}{}_{}", KEY_FILE_NAME, x_axis_id, y_axis_id)
+}
+
+fn free_keys_file_name(x_axis_id: u64, y_axis_id: u64) -> String {
+    format!("{}{}_{}", FREE_KEYS_FILE_NAME, x_axis_id, y_axis_id)
+}
+
+fn read_key(library: &impl StorageFile, key_location: u64) -> io::Result<u64> {
+    // key libraries are not pre-allocated, unwritten keys beyond the end of the file read as unlinked (0)
+    let mut buffer = [0; KEY_SIZE as usize];
+    library.read_at(key_location * KEY_SIZE, &mut buffer)?;
 
-    U64::read_from_db(&library_key_location, 1 + key_location)
+    Ok(u64::from_le_bytes(buffer))
 }
@@ -0,0 +1,123 @@
+use std::io;
+
+use cql_model::CqlType;
+
+use crate::axis_library;
+use crate::axis_library::AxisDefinition;
+use crate::database;
+use crate::key_library;
+use crate::key_library::KEY_SIZE;
+use crate::written;
+use crate::storage::{ Storage, StorageFile };
+use crate::FORMAT_VERSION;
+
+// the format version of databases created by cql_db 0.1
+pub const FORMAT_VERSION_0_1: u64 = 1;
+
+// Databases created by cql_db 0.1 and 0.2 do not record their format version, so the version that created them has to be told from the layout of
+// their files.
+pub enum Layout {
+    Version0_1,
+    Version0_2,
+    // the files are valid for both versions, such as those of a database with fewer than three dimensions whose first value is zeroed, and whose last
+    // was never written
+    Either,
+}
+
+// Version 0.1 reserved a zeroed block at the start of the database file, and another after the first block of each key library, which were created at
+// their full size.  Databases with fewer than three dimensions have no key libraries, and the values of those created by either version are written
+// without being allocated, so only one written to the last location will make the database file larger than 0.2 would allow.
+pub fn detect<TStore: CqlType, TStorage: Storage>(storage: &TStorage, axis_definitions: &[AxisDefinition]) -> io::Result<Layout> {
+    let value_size = TStore::VALUE_SIZE as u64;
+    let db_file = database::open(storage, true)?;
+    let db_size = database::size(&db_file)?;
+
+    // version 0.1 never writes to the first block of the database file
+    if db_size >= value_size && !is_zeroed(&db_file, 0, value_size)? {
+        return Ok(Layout::Version0_2)
+    }
+
+    if axis_definitions.len() >= 3 {
+        return detect_key_libraries(storage, axis_definitions)
+    }
+
+    if db_size == 0 {
+        // nothing has been written for the reserved block to have moved
+        return Ok(Layout::Version0_2)
+    }
+
+    let capacity = axis_definitions.iter().fold(1u64, |capacity, axis_definition| capacity.saturating_mul(axis_definition.max));
+    if db_size > capacity.saturating_mul(value_size) {
+        return Ok(Layout::Version0_1)
+    }
+
+    Ok(Layout::Either)
+}
+
+// Returns the format version of a database that does not record it, as told from its layout, databases that could be in either the 0.1 or 0.2 format
+// are treated as 0.1 so that they are not read from the wrong locations
+pub fn detect_format_version<TStore: CqlType, TStorage: Storage>(storage: &TStorage, axis_definitions: &[AxisDefinition]) -> io::Result<u64> {
+    match detect::<TStore, _>(storage, axis_definitions)? {
+        Layout::Version0_2 => Ok(FORMAT_VERSION),
+        _ => Ok(FORMAT_VERSION_0_1),
+    }
+}
+
+// Records the current format version against a database laid out as version 0.2 would, marking each value that is not zeroed as written as databases that
+// predate the written library assume of them.  The caller must hold an exclusive lock.
+pub fn set_format_version<TStore: CqlType, TStorage: Storage>(storage: &TStorage) -> io::Result<()> {
+    mark_non_zero_values::<TStore, _>(storage)?;
+    axis_library::set_format_version(storage, FORMAT_VERSION)
+}
+
+fn mark_non_zero_values<TStore: CqlType, TStorage: Storage>(storage: &TStorage) -> io::Result<()> {
+    let db_file = database::open(storage, true)?;
+    let written_file = written::open(storage, false)?;
+
+    let n_values = database::size(&db_file)? / TStore::VALUE_SIZE as u64;
+    let values_per_block = (database::BUFFER_SIZE / TStore::VALUE_SIZE).max(1) as u64;
+
+    let mut start_location = 0;
+    while start_location < n_values {
+        let n_block_values = values_per_block.min(n_values - start_location);
+        let bytes = database::read_bytes::<TStore>(&db_file, start_location, n_block_values)?;
+        let marked = written::get(&written_file, start_location, n_block_values)?;
+
+        let is_written: Vec<bool> = bytes
+            .chunks_exact(TStore::VALUE_SIZE)
+            .zip(marked)
+            .map(|(value, is_marked)| is_marked || value.iter().any(|&byte| byte != 0))
+            .collect();
+        written::set(&written_file, start_location, &is_written)?;
+
+        start_location += n_block_values;
+    }
+
+    Ok(())
+}
+
+// Version 0.1 creates each key library at its full size, with the block after the first never written to, whereas version 0.2 creates them empty and
+// has them grow as dimensions are linked.  Only a 0.2 database with the last element of every key library linked, and the first of each not, could be
+// mistaken for a 0.1 one.
+fn detect_key_libraries<TStorage: Storage>(storage: &TStorage, axis_definitions: &[AxisDefinition]) -> io::Result<Layout> {
+    for y_axis_index in 1..axis_definitions.len() - 1 {
+        let x_axis = &axis_definitions[y_axis_index - 1];
+        let y_axis = &axis_definitions[y_axis_index];
+
+        let library = storage.open_read_only(&key_library::library_file_name(x_axis.id, y_axis.id))?;
+        let full_size = x_axis.max.saturating_mul(y_axis.max).saturating_add(1).saturating_mul(KEY_SIZE);
+
+        if library.size()? < full_size || !is_zeroed(&library, KEY_SIZE, KEY_SIZE)? {
+            return Ok(Layout::Version0_2)
+        }
+    }
+
+    Ok(Layout::Version0_1)
+}
+
+fn is_zeroed(file: &impl StorageFile, position: u64, n_bytes: u64) -> io::Result<bool> {
+    let mut buffer = vec![0; n_bytes as usize];
+    file.read_at(position, &mut buffer)?;
+
+    Ok(buffer.iter().all(|&byte| byte == 0))
+}
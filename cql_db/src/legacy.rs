@@ -0,0 +1,149 @@
+/*!
+Functions for types that only implement the deprecated file based traits of `cql_model`, such as
+[CqlWritable](https://docs.rs/cql_model/0.3/cql_model/trait.CqlWritable.html), for use whilst they are migrated to the buffer based traits.
+
+Each function locks the database, validates the given parameters and finds the position of the given location in the same way as its counterpart in
+the crate root (e.g. [write_value](../fn.write_value.html)), before passing the path of the database file and that position on to the type to read or
+write itself.  Only databases kept on the file system can be used, and values written through these functions are not written through the journal,
+although they are marked as [written](../fn.is_written.html) and the summaries of any [rollup levels](../fn.create_db_with_rollups.html) covering them
+are updated.
+
+These functions will be removed along with the file based traits.
+
+# Examples
+```
+# #![allow(deprecated)]
+use std::fs::{ File, OpenOptions };
+use std::io;
+use std::io::{ Read, Write, Seek, SeekFrom };
+use cql_model::{ CqlType, CqlWritable, CqlReadable };
+
+struct FileU32;
+
+impl CqlType for FileU32 {
+    type ValueType = u32;
+    const VALUE_SIZE: usize = 4;
+    const TYPE_NAME: &'static str = "my_crate::FileU32";
+}
+
+impl CqlWritable for FileU32 {
+    fn write_to_db(db_location: &str, value_location: u64, input_value: u32) -> io::Result<()> {
+        let mut file = OpenOptions::new().write(true).open(db_location)?;
+        file.seek(SeekFrom::Start(value_location * 4))?;
+        file.write_all(&input_value.to_le_bytes())
+    }
+}
+
+impl CqlReadable for FileU32 {
+    fn read_from_db(db_location: &str, value_location: u64) -> io::Result<u32> {
+        let mut file = File::open(db_location)?;
+        file.seek(SeekFrom::Start(value_location * 4))?;
+
+        let mut buffer = [0; 4];
+        match file.read_exact(&mut buffer) {
+            Err(e) if e.kind() != io::ErrorKind::UnexpectedEof => Err(e),
+            _ => Ok(u32::from_le_bytes(buffer)),
+        }
+    }
+}
+
+# use std::error::Error;
+# use std::fs::remove_file;
+# fn main() -> Result<(), Box<dyn Error>> {
+# const DATABASE_LOCATION: &str = "./.test_db";
+# let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/db"));
+# let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/ax"));
+# let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/key1_2"));
+cql_db::create_db::<FileU32>(
+    DATABASE_LOCATION,
+    &[2, 5, 3]
+)?;
+
+cql_db::link_dimensions::<FileU32>(DATABASE_LOCATION, &[2, 4])?;
+cql_db::legacy::write_value::<FileU32>(DATABASE_LOCATION, &[2, 4, 3], 5)?;
+
+assert_eq!(cql_db::legacy::read_value::<FileU32>(DATABASE_LOCATION, &[2, 4, 3])?, 5);
+# Ok(())
+# }
+```
+*/
+#![allow(deprecated)]
+
+use std::io;
+use std::io::Write;
+
+use cql_model::{ CqlWritable, CqlReadable, CqlStreamReadable };
+
+use crate::database::DB_FILE_NAME;
+use crate::handle::Database;
+use crate::result;
+use crate::storage::FileStorage;
+
+/// Writes a value to the given location in the database, through [CqlWritable::write_to_db](https://docs.rs/cql_model/0.3/cql_model/trait.CqlWritable.html).
+/// Does not validate given parameters.
+///
+/// See [write_value_unchecked](../fn.write_value_unchecked.html) for more information.
+pub fn write_value_unchecked<TStore: CqlWritable>(db_location: &str, location: &[u64], value: TStore::ValueType) -> io::Result<()> {
+    let db_file_location = db_file_location(db_location);
+
+    Database::<TStore>::open(FileStorage::new(db_location))?
+        .write_value_with_unchecked(location, |position| TStore::write_to_db(&db_file_location, position, value))
+}
+
+/// Writes a value to the given location in the database, through [CqlWritable::write_to_db](https://docs.rs/cql_model/0.3/cql_model/trait.CqlWritable.html).
+///
+/// See [write_value](../fn.write_value.html) for more information.
+pub fn write_value<TStore: CqlWritable>(db_location: &str, location: &[u64], value: TStore::ValueType) -> result::Result<()> {
+    let db_file_location = db_file_location(db_location);
+
+    crate::open::<TStore>(db_location)?
+        .write_value_with(location, |position| TStore::write_to_db(&db_file_location, position, value))
+}
+
+/// Reads a value from the given location in the database, through [CqlReadable::read_from_db](https://docs.rs/cql_model/0.3/cql_model/trait.CqlReadable.html).
+/// Does not validate given parameters.
+///
+/// See [read_value_unchecked](../fn.read_value_unchecked.html) for more information.
+pub fn read_value_unchecked<TStore: CqlReadable>(db_location: &str, location: &[u64]) -> io::Result<TStore::ValueType> {
+    let db_file_location = db_file_location(db_location);
+
+    Database::<TStore>::open_read_only(FileStorage::new(db_location))?
+        .read_with_unchecked(location, |position| TStore::read_from_db(&db_file_location, position))
+}
+
+/// Reads a value from the given location in the database, through [CqlReadable::read_from_db](https://docs.rs/cql_model/0.3/cql_model/trait.CqlReadable.html).
+///
+/// See [read_value](../fn.read_value.html) for more information.
+pub fn read_value<TStore: CqlReadable>(db_location: &str, location: &[u64]) -> result::Result<TStore::ValueType> {
+    let db_file_location = db_file_location(db_location);
+
+    crate::open_read_only::<TStore>(db_location)?
+        .read_with(location, 1, |position| TStore::read_from_db(&db_file_location, position))
+}
+
+/// Reads `n_values` from the given location onward into the given stream, through
+/// [CqlStreamReadable::read_to_stream](https://docs.rs/cql_model/0.3/cql_model/trait.CqlStreamReadable.html).  Does not validate given parameters.
+///
+/// See [read_to_stream_unchecked](../fn.read_to_stream_unchecked.html) for more information.
+pub fn read_to_stream_unchecked<TStore: CqlStreamReadable>(db_location: &str, stream: &mut dyn Write, location: &[u64], n_values: u64)
+    -> io::Result<()> {
+    let db_file_location = db_file_location(db_location);
+
+    Database::<TStore>::open_read_only(FileStorage::new(db_location))?
+        .read_with_unchecked(location, |position| TStore::read_to_stream(&db_file_location, stream, position, n_values))
+}
+
+/// Reads `n_values` from the given location onward into the given stream, through
+/// [CqlStreamReadable::read_to_stream](https://docs.rs/cql_model/0.3/cql_model/trait.CqlStreamReadable.html).
+///
+/// See [read_to_stream](../fn.read_to_stream.html) for more information.
+pub fn read_to_stream<TStore: CqlStreamReadable>(db_location: &str, stream: &mut dyn Write, location: &[u64], n_values: u64) -> result::Result<()> {
+    let db_file_location = db_file_location(db_location);
+
+    crate::open_read_only::<TStore>(db_location)?
+        .read_with(location, n_values, |position| TStore::read_to_stream(&db_file_location, stream, position, n_values))
+}
+
+fn db_file_location(db_location: &str) -> String {
+    format!("{}{}", db_location, DB_FILE_NAME)
+}
@@ -1,20 +1,70 @@
 /*!
-This crate contains the core CQL Database functionality, orchestrating implementors of the [CqlType](https://docs.rs/cql_model/0.2/cql_model/trait.CqlType.html)
+This crate contains the core CQL Database functionality, orchestrating implementors of the [CqlType](https://docs.rs/cql_model/0.3/cql_model/trait.CqlType.html)
 trait allowing the system to act as an array-based database.
 
 The library allows the consumers to provide a path to a local directory which will be used to store array based data as defined by the user.
 The number of dimensions in the array, and their maximum sizes must be stated on create of the database, however it will only allocate storage
-space for elements in the final (Nth) dimension upon [linking](fn.link_dimensions.html) of higher level dimensions.
-
-Elements in the array can be writen to [one by one](fn.write_value.html), and read either as [single points](fn.read_value.html) or to a
-[stream](fn.read_to_stream.html).
+space for elements in the final (Nth) dimension upon [linking](fn.link_dimensions.html) of higher level dimensions.  The capacity of the final
+dimension, or of any [other dimension](fn.resize_dimension.html), may be [increased](fn.resize_last_dimension.html) after the database has been created.
+
+Elements in the array can be writen to [one by one](fn.write_value.html), [in batches](fn.write_values.html) or from a [stream](fn.write_from_stream.html), and read
+either as [single points](fn.read_value.html), as a typed [range](fn.read_range.html) or to a [stream](fn.read_to_stream.html).  Ranges of several
+dimensions can be read to a stream at once as a [slice](fn.read_slice.html), and every `k`th value along the final dimension can be read to a stream
+with a [stride](fn.read_strided_to_stream.html), either forwards or in reverse.
+The elements that have been linked can be [listed](fn.linked_children.html) one dimension at a time or [walked](fn.walk_links.html) in full.  Whether
+given elements have been [linked](fn.is_linked.html), and whether a value has been [written](fn.is_written.html) to a location rather than it holding
+the default value of its type, can be checked without reading or writing anything.  Ranges of numeric values can be [aggregated](fn.aggregate.html), for
+example [summed](aggregation/struct.Sum.html) or [averaged](aggregation/struct.Mean.html), as they are read without reading them out of the database.
+A range can also be [rolled up](fn.rollup.html) into fixed size buckets, each aggregated separately, such as an hourly series from per-second values.
+Databases [created with rollup levels](fn.create_db_with_rollups.html) keep summaries of the final dimension at coarser resolutions, maintained as
+values are written, from which such rollups are answered without reading every value.
+
+Databases may instead be kept in memory, or in any other [storage backend](storage/index.html), by creating and opening them with
+[create_db_in](fn.create_db_in.html) and [open_in](fn.open_in.html).
+
+Async versions of [linking](asynchronous/fn.link_dimensions.html), [writing](asynchronous/fn.write_value.html) and [reading](asynchronous/fn.read_value.html)
+values, and of reading them to an async [stream](asynchronous/fn.read_to_stream.html), along with an async [handle](asynchronous/struct.Database.html) holding the
+database open between them, are provided by the [asynchronous](asynchronous/index.html) module when the `async` feature is enabled.
+
+Types that only implement the deprecated file based traits of `cql_model` can still be read and written, on the file system, through the
+[legacy](legacy/index.html) module whilst they are migrated.
+
+# Concurrent access
+
+Databases may be used by many threads and processes at once.  Calls that only read from the database take an advisory lock on it that is shared with
+other readers, whilst calls that link, unlink or write take an exclusive lock, waiting up to [DEFAULT_LOCK_TIMEOUT](constant.DEFAULT_LOCK_TIMEOUT.html)
+(or the [timeout](struct.Database.html#method.set_lock_timeout) set on a [handle](struct.Database.html)) for any conflicting locks to be released.  Checked
+functions will return a [LockTimeoutError](./error/cql/enum.Error.html#variant.LockTimeoutError) should the timeout expire, whilst unchecked functions will
+return a [TimedOut](https://doc.rust-lang.org/nightly/std/io/enum.ErrorKind.html#variant.TimedOut) I/O error.  The iterator returned by a [range
+read](fn.read_range.html) holds its lock until it is dropped, sharing the lock of the [handle](struct.Database.html#method.read_range) it was read
+from, so calls made through that handle that need an exclusive lock will return a [LockUpgradeError](./error/cql/enum.Error.html#variant.LockUpgradeError)
+until the iterator has been dropped.
+
+The locks are advisory, and do not protect a database from other programs, or from calls to [create_db](fn.create_db.html) replacing it.
+
+# Crash safety
+
+Linking dimensions changes several files at once, so the changes are recorded in a journal within the database directory and synced to disk before any of
+them are made.  Should a link be interrupted part way through, for example by a crash or power loss, it will be completed the next time the database is
+used.  [Resizing](fn.resize_dimension.html) a dimension moves its rows through the journal a chunk at a time, recording how far it has got alongside each
+chunk, so an interrupted resize is also completed the next time the database is used.  Values written to a database with [rollup
+levels](fn.create_db_with_rollups.html) are journaled alongside the summaries covering them, so that the summaries never disagree with the values.
+
+Groups of links and writes that must be applied together, such as all the values for a single point in time, can be buffered in a
+[Transaction](struct.Transaction.html) obtained from [Database::transaction](struct.Database.html#method.transaction).  A committed transaction is
+journaled in the same way, so readers will see either all of its changes or none of them, and a transaction dropped without being committed changes nothing.
+
+Values written outside of a transaction are left for the operating system to write to disk, unless a [handle](struct.Database.html) is
+[flushed](struct.Database.html#method.flush) or configured with a stricter [durability](enum.Durability.html) mode.  Should the machine lose power, any
+values not yet written to disk will be lost.
 
 # Storage space consumption
 
 This crate will allocate file space upon linking of dimensions, as well as a small amount on create of a database, so before starting you
 should be aware of the disk space requirements.
 
-Given a database with `N` dimensions, calling [create_db](fn.create_db.html) will allocate `(1 + N) * 8` bytes. Thereafter,
+Given a database with `N` dimensions, calling [create_db](fn.create_db.html) will allocate `(2 + N) * 8` bytes, plus `8` bytes and the length of
+the storage type's name used to record the type the database was created with. Thereafter,
 [linking](fn.link_dimensions.html) a set of dimensions, will then expand the maximum file sizes according to the function below:
 ```
 # const DATABASE_LOCATION: &str = "./.test_db";
@@ -59,10 +109,16 @@ assert_eq!(
 # Ok(())
 # }
 ```
-Should additional elements be linked, the key libraries will expand accordingly.
+Should additional elements be linked, the key libraries will expand accordingly.  Space released by [unlinking](fn.unlink_dimensions.html) elements
+is reused by subsequent links before the files are expanded any further, and can be reclaimed entirely by [compacting](fn.compact.html) the database.
 
 Additional space will be allocated for each penultimate dimenion `(Nn-1)` linked using the [link_dimensions](fn.link_dimensions.html) function, this is
-equal to the maximum size of the final dimension multiplied by the [VALUE_SIZE](https://docs.rs/cql_model/0.2/cql_model/trait.CqlType.html#associatedconstant.VALUE_SIZE) of the stored struct.
+equal to the maximum size of the final dimension multiplied by the [VALUE_SIZE](https://docs.rs/cql_model/0.3/cql_model/trait.CqlType.html#associatedconstant.VALUE_SIZE) of the stored struct.
+A further bit is allocated for each of these values when they are first written, recording that they have been [written](fn.is_written.html).
+
+Databases [created with rollup levels](fn.create_db_with_rollups.html) allocate a further `16 + SUM_SIZE + 2 * VALUE_SIZE` bytes for every `level`
+values of each row, rounding up, in each level as the values they summarise are first written - where
+[SUM_SIZE](https://docs.rs/cql_model/0.3/cql_model/trait.CqlAggregatable.html#associatedconstant.SUM_SIZE) is that of the stored struct.
 
 # Benchmarks
 
@@ -80,6 +136,19 @@ Stream read 1 point | 1 | 2 500 (+/- 300) | 10 000 (+/- 850)
 Stream read 1 point | 4 | 14 900 (+/- 600) | 42 500 (+/- 6 500)
 Stream read 50 000 points | 1 | 27 650 000 (+/- 31 000) | 27 630 000 (+/- 180 000)
 Stream read 50 000 points | 4 | 27 660 000 (+/- 1 200 000) | 27 620 000 (+/- 480 000)
+
+Reads made through a memory mapped handle, available with the `mmap` feature and opened with [open_mapped](fn.open_mapped.html), avoid re-reading the database files
+on each call:
+
+Operation | Database dimensions | Mean time (ns)
+--- | --- | ---
+Single point read | 1 | 1 750 (+/- 650)
+Single point read | 4 | 2 050 (+/- 450)
+Stream read 1 point | 1 | 2 500 (+/- 1 200)
+Stream read 1 point | 4 | 2 200 (+/- 3 000)
+Stream read 50 000 points | 1 | 136 000 (+/- 62 000)
+Stream read 50 000 points | 4 | 104 000 (+/- 34 000)
+
 # Examples
 
 The following example creates a 4 dimensional database of unsigned 64 bit integers, links a chain of elements, writes a value, and then reads it:
@@ -130,28 +199,76 @@ assert_eq!(result, value);
 # }
 ```
 */
-#![doc(html_root_url = "https://docs.rs/cql_db/0.2.4")]
+#![doc(html_root_url = "https://docs.rs/cql_db/0.3.0")]
 use std::io;
-use std::io::Write;
+use std::io::{ Read, Write };
+use std::ops::Range;
+use std::time::Duration;
 
 use cql_model::{
     CqlType,
-    CqlWritable,
-    CqlReadable,
-    CqlStreamReadable
+    CqlAggregatable,
+    CqlRangeReadable,
+    CqlBufferWritable,
+    CqlBufferStreamReadable,
+    CqlBufferStreamWritable,
 };
 
 mod database;
 mod axis_library;
 mod key_library;
+mod type_library;
+mod handle;
+mod compact;
+mod lock;
+mod journal;
+mod transaction;
+mod durability;
+mod direction;
+mod written;
+mod layout;
+mod rollup_library;
+#[cfg(feature = "mmap")]
+mod mapped;
+mod resize;
 mod result;
 mod vectors;
 
 /// Error types returned by cql_db
 pub mod error;
+pub mod aggregation;
+pub mod migrate;
+pub mod legacy;
+pub mod storage;
+#[cfg(feature = "async")]
+pub mod asynchronous;
+
+pub use handle::Database;
+pub use transaction::Transaction;
+pub use durability::Durability;
+pub use direction::Direction;
+#[cfg(feature = "mmap")]
+pub use mapped::MappedDatabase;
+
+/// The version of the on-disk format written by this version of cql_db.
+///
+/// The format version is recorded on [create](fn.create_db.html) of a database and checked whenever it is opened.  Databases created by cql_db 0.1 and
+/// 0.2 do not record their format version, which is instead told from the layout of their files.  Those created by 0.2 have the current version recorded
+/// the first time they are opened by a handle able to write to them, whilst those created by 0.1, or whose files could have been created by either, must
+/// first be [migrated](migrate/index.html).  Checked functions will return a [FormatVersionError](./error/cql/enum.Error.html#variant.FormatVersionError) when given a database in any other format, whilst
+/// unchecked functions will return an [InvalidData](https://doc.rust-lang.org/nightly/std/io/enum.ErrorKind.html#variant.InvalidData) I/O error.
+pub const FORMAT_VERSION: u64 = 2;
+
+/// How long calls will wait to lock the database before giving up, unless [configured](struct.Database.html#method.set_lock_timeout) otherwise.
+pub const DEFAULT_LOCK_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// How eagerly changes are synced to disk, unless [configured](struct.Database.html#method.set_durability) otherwise.
+pub const DEFAULT_DURABILITY: Durability = Durability::Explicit;
 
 use axis_library::AxisDefinition;
-use vectors::calculate_index;
+use rollup_library::RollupDefinition;
+use aggregation::Aggregation;
+use storage::{ Storage, FileStorage };
 
 /// Creates an CQL database in the provided directory, overwriting existing files.  Does not validate given parameters.
 ///
@@ -230,11 +347,14 @@ use vectors::calculate_index;
 /// ```
 /// But see the type(s) that you are interested in for performance benchmarks, and the [index page](./index.html) to see how to calcuate file size requirements.
 pub fn create_db_unchecked<TStore: CqlType>(db_location: &str, array_size: &[u64]) -> io::Result<()> {
-    create_or_replace_db::<TStore>(db_location, array_size, false)
+    create_or_replace_db::<TStore, _>(&FileStorage::new(db_location), array_size, false)
 }
 
 /// Creates an CQL database in the provided directory, if a database doesn't exist already.
 ///
+/// The name and [VALUE_SIZE](https://docs.rs/cql_model/0.3/cql_model/trait.CqlType.html#associatedconstant.VALUE_SIZE) of `TStore` are recorded
+/// alongside the database, and the checked functions in this crate will refuse to operate on it with any other storage type.
+///
 /// There is an [unchecked](fn.create_db_unchecked.html) version of this function, allowing you to replace existing databases if needed.
 ///
 /// # Errors
@@ -385,55 +505,290 @@ pub fn create_db_unchecked<TStore: CqlType>(db_location: &str, array_size: &[u64
 /// ```
 /// But see the type(s) that you are interested in for performance benchmarks, and the [index page](./index.html) to see how to calcuate file size requirements.
 pub fn create_db<TStore: CqlType>(db_location: &str, array_size: &[u64]) -> result::Result<()> {
-    validate_create_db_params::<TStore>(array_size)?;
-    create_or_replace_db::<TStore>(db_location, array_size, true)?;
+    validate_create_db_params(array_size)?;
+    create_or_replace_db::<TStore, _>(&FileStorage::new(db_location), array_size, true)?;
+    Ok(())
+}
+
+/// Creates an CQL database in the provided [storage backend](./storage/index.html), overwriting any existing database.  Does not validate given
+/// parameters.
+///
+/// See [create_db_unchecked](fn.create_db_unchecked.html) for more information.
+///
+/// # Examples
+/// ```
+/// use cql_u64::U64;
+/// use cql_db::storage::MemoryStorage;
+///
+/// # use std::error::Error;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// let storage = MemoryStorage::new();
+///
+/// cql_db::create_db_in_unchecked::<U64, _>(
+///     &storage,
+///     &[2, 3]
+/// )?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn create_db_in_unchecked<TStore: CqlType, TStorage: Storage>(storage: &TStorage, array_size: &[u64]) -> io::Result<()> {
+    create_or_replace_db::<TStore, _>(storage, array_size, false)
+}
+
+/// Creates an CQL database in the provided [storage backend](./storage/index.html).
+///
+/// See [create_db](fn.create_db.html) for more information.
+///
+/// # Errors
+///
+/// Will return any [I/O errors](https://doc.rust-lang.org/nightly/std/io/enum.ErrorKind.html) encountered during the execution of the function, including
+/// should a database [already exist](https://doc.rust-lang.org/nightly/std/io/enum.ErrorKind.html#variant.AlreadyExists) in the backend.
+///
+/// Additionally, the following [Cql errors](./error/cql/enum.Error.html) may be returned:
+/// - A [DimensionsOutOfRangeError](./error/cql/enum.Error.html#variant.DimensionsOutOfRangeError) will be returned if the provided `array_size.len()` is less
+///   than 1, or greater than `u64::max_value() - 1`.
+/// - A [DimensionTooSmallError](./error/cql/enum.Error.html#variant.DimensionTooSmallError) will be returned if any of the provided capacities in `array_size`
+///   equal zero.
+///
+/// # Examples
+/// ```
+/// use cql_u64::U64;
+/// use cql_db::storage::MemoryStorage;
+///
+/// # use std::error::Error;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// let storage = MemoryStorage::new();
+///
+/// cql_db::create_db_in::<U64, _>(
+///     &storage,
+///     &[2, 3]
+/// )?;
+///
+/// // the database already exists
+/// assert!(cql_db::create_db_in::<U64, _>(&storage, &[2, 3]).is_err());
+/// # Ok(())
+/// # }
+/// ```
+pub fn create_db_in<TStore: CqlType, TStorage: Storage>(storage: &TStorage, array_size: &[u64]) -> result::Result<()> {
+    validate_create_db_params(array_size)?;
+    create_or_replace_db::<TStore, _>(storage, array_size, true)?;
+    Ok(())
+}
+
+/// Creates an CQL database in the provided directory, along with the given rollup levels, overwriting any existing database.  Does not validate given
+/// parameters.
+///
+/// See [create_db_with_rollups](fn.create_db_with_rollups.html) for more information.
+///
+/// # Errors
+///
+/// Will return any [I/O errors](https://doc.rust-lang.org/nightly/std/io/enum.ErrorKind.html) encountered during the execution of the function.  Function may
+/// partially succeed resulting in changes to the file system if an I/O error has occured.
+///
+/// An [InvalidInput](https://doc.rust-lang.org/nightly/std/io/enum.ErrorKind.html#variant.InvalidInput) error will be returned, and nothing created, if
+/// `rollup_levels` is not empty and `TStore` does not override
+/// [visit_aggregatable](https://docs.rs/cql_model/0.3/cql_model/trait.CqlType.html#method.visit_aggregatable).
+///
+/// # Panics
+///
+/// Function does not check the validity of the given parameters, and may panic should they be invalid.  For example, if any of the given levels are zero.
+///
+/// # Examples
+/// ```
+/// use cql_u64::U64;
+///
+/// # use std::error::Error;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # const DATABASE_LOCATION: &str = "./.test_db";
+/// cql_db::create_db_with_rollups_unchecked::<U64>(
+///     DATABASE_LOCATION,
+///     &[2, 3600],
+///     &[60, 3600]
+/// )?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn create_db_with_rollups_unchecked<TStore: CqlAggregatable>(db_location: &str, array_size: &[u64], rollup_levels: &[u64]) -> io::Result<()> {
+    create_or_replace_db_with_rollups::<TStore, _>(&FileStorage::new(db_location), array_size, rollup_levels, false)
+}
+
+/// Creates an CQL database in the provided directory, along with the given rollup levels, if a database doesn't exist already.
+///
+/// Each rollup level holds a summary (the count, sum, smallest and largest of the numbers) of every `level` values along the final dimension, the last
+/// summary of each row covering whatever is left of it.  [Rollups](fn.rollup.html) with buckets that line up with the summaries of a level are answered
+/// from the coarsest such level, rather than by reading every value in the range, for any of the [aggregations](./aggregation/index.html) in this crate
+/// apart from those that [cannot be computed](./aggregation/trait.Aggregation.html#method.add_summary) from summaries.  A rollup lines up with a level
+/// should both its start and its bucket size be multiples of the level, and it either cover a multiple of the level or run to the end of the row.
+///
+/// Writes update the summaries covering the values they write, from the next finer level they are made up of (or from the values themselves), and
+/// record both through the journal at once - at a cost to each write that grows with the size of the levels.  Storage types must
+/// [expose](https://docs.rs/cql_model/0.3/cql_model/trait.CqlType.html#method.visit_aggregatable) their aggregatable implementation for the summaries to
+/// be kept up to date, as all of this crate's numeric types do, and databases with rollup levels cannot be created or opened with those that do not.
+/// Rollups only read the levels, and so may run alongside other readers.
+///
+/// Sums of floating point numbers answered from rollup levels are summed in a different order to those read from the values themselves, and so may
+/// differ from them by rounding.
+///
+/// The levels are recorded alongside the database, and may be listed with [Database::rollup_levels](./struct.Database.html#method.rollup_levels).
+///
+/// There is an [unchecked](fn.create_db_with_rollups_unchecked.html) version of this function, allowing you to replace existing databases if needed.
+///
+/// # Errors
+///
+/// Will return any [I/O errors](https://doc.rust-lang.org/nightly/std/io/enum.ErrorKind.html) encountered during the execution of the function, including if
+/// a database [already exists](https://doc.rust-lang.org/nightly/std/io/enum.ErrorKind.html#variant.AlreadyExists).  Function may partially succeed resulting
+/// in changes to the file system if an I/O error has occured.
+///
+/// Additionally, the following [Cql errors](./error/cql/enum.Error.html) may be returned:
+/// - A [DimensionsOutOfRangeError](./error/cql/enum.Error.html#variant.DimensionsOutOfRangeError) will be returned if the provided `array_size.len()` is less
+///   than 1, or greater than `u64::max_value() - 1`.
+/// - A [DimensionTooSmallError](./error/cql/enum.Error.html#variant.DimensionTooSmallError) will be returned if any of the provided capacities in `array_size`
+///   equal zero.
+/// - A [BucketTooSmallError](./error/cql/enum.Error.html#variant.BucketTooSmallError) will be returned if any of the provided `rollup_levels` equal zero.
+/// - A [RollupTypeError](./error/cql/enum.Error.html#variant.RollupTypeError) will be returned if `rollup_levels` is not empty and `TStore` does not
+///   override [visit_aggregatable](https://docs.rs/cql_model/0.3/cql_model/trait.CqlType.html#method.visit_aggregatable).
+///
+/// # Panics
+///
+/// Function should not panic.  If you get it to panic, please raise an issue in [github](https://github.com/AndrewSisley/CQLDb/issues).
+///
+/// # Examples
+///
+/// The below code creates a database of per-second readings summarised by minute and by hour, and then rolls an hour of readings up into minutes
+/// from the per-minute summaries:
+/// ```
+/// use cql_u64::U64;
+/// use cql_db::aggregation::Sum;
+///
+/// # use std::error::Error;
+/// # use std::fs::remove_file;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # const DATABASE_LOCATION: &str = "./.test_db";
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/db"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/ax"));
+/// cql_db::create_db_with_rollups::<U64>(
+///     DATABASE_LOCATION,
+///     &[3600],
+///     &[60, 3600]
+/// )?;
+///
+/// cql_db::write_value::<U64>(DATABASE_LOCATION, &[61], 3)?;
+/// cql_db::write_value::<U64>(DATABASE_LOCATION, &[62], 5)?;
+///
+/// let sums = cql_db::rollup::<U64, Sum>(DATABASE_LOCATION, &[1], 3600, 60)?;
+///
+/// assert_eq!(sums.len(), 60);
+/// assert_eq!(sums[1], 8);
+/// assert_eq!(cql_db::open::<U64>(DATABASE_LOCATION)?.rollup_levels(), vec![60, 3600]);
+/// # Ok(())
+/// # }
+/// ```
+pub fn create_db_with_rollups<TStore: CqlAggregatable>(db_location: &str, array_size: &[u64], rollup_levels: &[u64]) -> result::Result<()> {
+    validate_create_db_params(array_size)?;
+    validate_rollup_levels(rollup_levels)?;
+    create_or_replace_db_with_rollups::<TStore, _>(&FileStorage::new(db_location), array_size, rollup_levels, true)?;
+    Ok(())
+}
+
+fn create_or_replace_db_with_rollups<TStore: CqlAggregatable, TStorage: Storage>(
+    storage: &TStorage,
+    array_size: &[u64],
+    rollup_levels: &[u64],
+    create_new: bool
+) -> io::Result<()> {
+    let rollup_definition = RollupDefinition::of::<TStore>(rollup_levels);
+    // checked before any files are created, so that a database that cannot be opened with TStore is not left behind
+    rollup_library::validate::<TStore>(&rollup_definition)?;
+
+    create_or_replace_db::<TStore, _>(storage, array_size, create_new)?;
+    rollup_library::create(storage, &rollup_definition)
+}
+
+fn create_or_replace_db<TStore: CqlType, TStorage: Storage>(storage: &TStorage, array_size: &[u64], create_new: bool) -> io::Result<()> {
+    let axis_definitions: Vec<AxisDefinition> = array_size
+        .iter()
+        .enumerate()
+        .map(|(index, &max)| AxisDefinition { id: index as u64 + 1, max })
+        .collect();
+
+    database::create(storage, create_new)?;
+    axis_library::create(storage, &axis_definitions, create_new)?;
+    key_library::create(storage, &axis_definitions, create_new)?;
+    type_library::create::<TStore, _>(storage)?;
+    written::create(storage)?;
+    rollup_library::create(storage, &RollupDefinition::none())?;
+    journal::create(storage)?;
+
+    // any handle still open on a replaced database must re-read its capacities
+    lock::advance_generation(&lock::open(storage)?)
+}
+
+fn validate_resize_dimension_params(capacities: &[u64], dimension_index: usize, new_max: u64) -> result::cql::Result<()> {
+    if dimension_index >= capacities.len() {
+        return Err(error::cql::Error::DimensionsOutOfRangeError {
+            requested: dimension_index,
+            min: 0,
+            max: capacities.len() - 1,
+        })
+    }
+
+    if new_max < capacities[dimension_index] {
+        return Err(error::cql::Error::ResizeShrinkError {
+            current: capacities[dimension_index],
+            requested: new_max,
+        })
+    }
+
     Ok(())
 }
 
-fn create_or_replace_db<TStore: CqlType>(db_location: &str, array_size: &[u64], create_new: bool) -> io::Result<()> {
-    let mut axis_definitions = Vec::with_capacity(array_size.len());
-    for index in 0..array_size.len() {
-        axis_definitions.push(AxisDefinition {
-            id: index as u64 + 1,
-            max: array_size[index] as u64,
-        });
+fn validate_rollup_levels(rollup_levels: &[u64]) -> result::cql::Result<()> {
+    if rollup_levels.contains(&0) {
+        return Err(error::cql::Error::BucketTooSmallError)
     }
 
-    database::create::<TStore>(&db_location, create_new)?;
-    axis_library::create(db_location, &axis_definitions, create_new)?;
-    key_library::create(db_location, &axis_definitions, create_new)
+    Ok(())
 }
 
-fn validate_create_db_params<TStore: CqlType>(array_size: &[u64]) -> result::cql::Result<()> {
-    if array_size.len() == 0 {
+fn validate_create_db_params(array_size: &[u64]) -> result::cql::Result<()> {
+    if array_size.is_empty() {
         return Err(error::cql::Error::DimensionsOutOfRangeError {
             requested: array_size.len(),
             min: 1,
-            max: u64::max_value() as usize - 1,
+            max: u64::MAX as usize - 1,
         })
     }
 
-    if array_size.iter().any(|&dimension_capacity| dimension_capacity == 0) {
+    if array_size.contains(&0) {
         return Err(error::cql::Error::DimensionTooSmallError)
     }
 
     Ok(())
 }
 
-/// Links dimension indexs together if they are not already linked.  Does not validate given parameters.
+/// Opens a [handle](./struct.Database.html) to an existing CQL database in the provided directory.
 ///
-/// This is required before read-writing to a location, and allocates the file space required to store the Nth dimension data.
-/// The last (Nth) dimension should not be linked.
+/// The handle caches the database's axis definitions and holds the underlying files open, making it considerably cheaper than the free
+/// functions in this crate when making many calls against the same database.
 ///
 /// # Errors
 ///
-/// Will return any [I/O errors](https://doc.rust-lang.org/nightly/std/io/enum.ErrorKind.html) encountered during the execution of the function.  Function may
-/// partially succeed resulting in changes to the file system.
+/// Will return any [I/O errors](https://doc.rust-lang.org/nightly/std/io/enum.ErrorKind.html) encountered during the execution of the function, including
+/// if a database [does not exist](https://doc.rust-lang.org/nightly/std/io/enum.ErrorKind.html#variant.NotFound) in the provided directory.
+///
+/// Additionally, the following [Cql errors](./error/cql/enum.Error.html) may be returned:
+/// - A [FormatVersionError](./error/cql/enum.Error.html#variant.FormatVersionError) will be returned if the database was created by a version of cql_db
+///   using a different [format version](constant.FORMAT_VERSION.html).
+/// - A [TypeMismatchError](./error/cql/enum.Error.html#variant.TypeMismatchError) will be returned if the database was created with a different
+///   storage type to `TStore`.  Databases created before the storage type was recorded are not checked.
+/// - A [RollupTypeError](./error/cql/enum.Error.html#variant.RollupTypeError) will be returned if the database has rollup levels and `TStore` does not
+///   override [visit_aggregatable](https://docs.rs/cql_model/0.3/cql_model/trait.CqlType.html#method.visit_aggregatable).
+/// - A [RollupTypeError](./error/cql/enum.Error.html#variant.RollupTypeError) will be returned if the database has rollup levels and `TStore` does not
+///   override [visit_aggregatable](https://docs.rs/cql_model/0.3/cql_model/trait.CqlType.html#method.visit_aggregatable).
 ///
 /// # Panics
 ///
-/// Function does not actively defend against panics, and may do so if given invalid parameters.  Function may partially succeed resulting in changes
-/// to the file system.
+/// Function should not panic.  If you get it to panic, please raise an issue in [github](https://github.com/AndrewSisley/CQLDb/issues).
 ///
 /// # Examples
 /// ```
@@ -447,136 +802,95 @@ fn validate_create_db_params<TStore: CqlType>(array_size: &[u64]) -> result::cql
 /// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/ax"));
 /// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/key1_2"));
 /// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/key2_3"));
-/// // Create a database with a maximum capacity of `[2, 5, 3, 2]`
 /// cql_db::create_db::<U64>(
 ///     DATABASE_LOCATION,
 ///     &[2, 5, 3, 2]
 /// )?;
 ///
-/// // Link the 2nd element of the 1st dimension with the 4th element of the 2nd dimension, and
-/// // the 4th of the 2nd with the 3rd of the 3rd - for example:
-/// // Turbine 2 has data for Signal 4 for Year 3
-/// cql_db::link_dimensions_unchecked::<U64>(
-///     DATABASE_LOCATION,
-///     &[2, 4, 3], // don't link the Nth dimension
-/// )?;
+/// let mut database = cql_db::open::<U64>(DATABASE_LOCATION)?;
+///
+/// database.link_dimensions(&[2, 4, 3])?;
+///
+/// for i in 1..3 {
+///     database.write_value(&[2, 4, 3, i], i * 10)?;
+/// }
+///
+/// assert_eq!(database.read_value(&[2, 4, 3, 2])?, 20);
 /// # Ok(())
 /// # }
 /// ```
-pub fn link_dimensions_unchecked<TStore: CqlType>(db_location: &str, location: &[u64]) -> io::Result<()> {
-    let mut x_position = location[0];
-
-    for x_axis_id in 1..location.len() {
-        let y_axis_id = x_axis_id as u64 + 1;
-        let y_position = location[x_axis_id];
-        let y_axis_definition = axis_library::get_by_id(db_location, y_axis_id)?;
-
-        let mut key = key_library::get(
-            db_location,
-            &key_library::AxisPoint { axis_id: x_axis_id as u64, position: x_position },
-            &key_library::AxisPoint { axis_id: y_axis_id, position: y_position },
-            &y_axis_definition
-        )?;
-
-        if key == 0 {
-            key = key_library::add::<TStore>(
-                db_location,
-                x_position,
-                y_position,
-                &axis_library::get_by_id(db_location, x_axis_id as u64)?,
-                &y_axis_definition
-            )?;
-
-            let last_axis_id = axis_library::count(db_location)?;
-            if y_axis_id == last_axis_id - 1 {
-                database::grow::<TStore>(&db_location, y_axis_definition.max)?;
-            }
-        };
-        x_position = key;
-    }
-
-    Ok(())
+pub fn open<TStore: CqlType>(db_location: &str) -> result::Result<Database<TStore>> {
+    open_in(FileStorage::new(db_location))
 }
 
-/// Links dimension indexs together if they are not already linked.
+/// Opens a [handle](./struct.Database.html) to an existing CQL database in the provided [storage backend](./storage/index.html).
 ///
-/// This is required before read-writing to a location, and allocates the file space required to store the Nth dimension data.
-/// The last (Nth) dimension should not be linked.  There is an [unchecked](fn.link_dimensions_unchecked.html) version of this function if required.
+/// See [open](fn.open.html) for more information.
 ///
 /// # Errors
 ///
-/// Will return any [I/O errors](https://doc.rust-lang.org/nightly/std/io/enum.ErrorKind.html) encountered during the execution of the function.  Function may
-/// partially succeed resulting in changes to the file system if such an error is returned.
+/// Will return any [I/O errors](https://doc.rust-lang.org/nightly/std/io/enum.ErrorKind.html) encountered during the execution of the function, including
+/// if a database [does not exist](https://doc.rust-lang.org/nightly/std/io/enum.ErrorKind.html#variant.NotFound) in the provided backend.
 ///
 /// Additionally, the following [Cql errors](./error/cql/enum.Error.html) may be returned:
-/// - A [DimensionsOutOfRangeError](./error/cql/enum.Error.html#variant.DimensionsOutOfRangeError) will be returned if the provided `location.len()` is less than 2,
-/// or greater than the number of dimensions in the database - 1.
-/// - An [IndexOutOfRangeError](./error/cql/enum.Error.html#variant.IndexOutOfRangeError) will be returned if any of the provided indexes in `location` are less than 1,
-/// or greater than that dimension's capacity.
+/// - A [FormatVersionError](./error/cql/enum.Error.html#variant.FormatVersionError) will be returned if the database was created by a version of cql_db
+///   using a different [format version](constant.FORMAT_VERSION.html).
+/// - A [TypeMismatchError](./error/cql/enum.Error.html#variant.TypeMismatchError) will be returned if the database was created with a different
+///   storage type to `TStore`.
+///
+/// # Examples
 /// ```
-/// # use cql_u64::U64;
-/// # use cql_db::error;
-/// # use cql_db::error::cql::Error;
-/// # const DATABASE_LOCATION: &str = "./.test_db";
-/// #
-/// # use std::error::Error as StdError;
-/// # use std::fs::remove_file;
-/// # fn main() -> Result<(), Box<dyn StdError>> {
-/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/db"));
-/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/ax"));
-/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/key1_2"));
-/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/key2_3"));
-/// // Create a database with a maximum capacity of `[2, 5, 3, 2]`
-/// cql_db::create_db::<U64>(
-///     DATABASE_LOCATION,
+/// use cql_u64::U64;
+/// use cql_db::storage::MemoryStorage;
+///
+/// # use std::error::Error;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// let storage = MemoryStorage::new();
+/// cql_db::create_db_in::<U64, _>(
+///     &storage,
 ///     &[2, 5, 3, 2]
 /// )?;
 ///
-/// let result = match cql_db::link_dimensions::<U64>(
-///     DATABASE_LOCATION,
-///     // not enough dimensions
-///     &[1]
-/// ) {
-///     Err(error) => match error {
-///         error::Error::Cql(cql_error) => Some(cql_error),
-///         _ => None,
-///     }
-///     _ => None,
-/// };
-///
-/// assert_eq!(
-///     result.unwrap(),
-///     Error::DimensionsOutOfRangeError {
-///         requested: 1,
-///         min: 2,
-///         max: 3, // 4 - 1
-///     }
-/// );
+/// // handles opened on clones of the backend share the same database
+/// let mut writer = cql_db::open_in::<U64, _>(storage.clone())?;
+/// let mut reader = cql_db::open_in::<U64, _>(storage)?;
 ///
-/// let result2 = match cql_db::link_dimensions::<U64>(
-///     DATABASE_LOCATION,
-///     // dimension[1] index is too large
-///     &[1, 6]
-/// ) {
-///     Err(error) => match error {
-///         error::Error::Cql(cql_error) => Some(cql_error),
-///         _ => None,
-///     }
-///     _ => None,
-/// };
+/// writer.link_dimensions(&[2, 4, 3])?;
+/// writer.write_value(&[2, 4, 3, 2], 20)?;
 ///
-/// assert_eq!(
-///     result2.unwrap(),
-///     Error::IndexOutOfRangeError {
-///         dimension_index: 1,
-///         requested: 6,
-///         min: 1,
-///         max: 5,
-///     }
-/// );
+/// assert_eq!(reader.read_value(&[2, 4, 3, 2])?, 20);
 /// # Ok(())
 /// # }
 /// ```
+pub fn open_in<TStore: CqlType, TStorage: Storage>(storage: TStorage) -> result::Result<Database<TStore, TStorage>> {
+    let database = Database::open(storage)?;
+    database.validate_type()?;
+    Ok(database)
+}
+
+// Opens a handle for the free functions that only read from the database, which need not be able to write to its files
+fn open_read_only<TStore: CqlType>(db_location: &str) -> result::Result<Database<TStore>> {
+    let storage = FileStorage::new(db_location);
+    let database = Database::open_read_only(storage)?;
+    database.validate_type()?;
+    Ok(database)
+}
+
+/// Opens a read only, [memory mapped](./struct.MappedDatabase.html) handle to an existing CQL database in the provided directory.
+///
+/// Reads made through the handle are served as copies from memory maps of the database's files, making them considerably cheaper than those made through a
+/// [Database](./struct.Database.html) handle for read heavy workloads.  Only available with the `mmap` feature.
+///
+/// # Errors
+///
+/// Will return any [I/O errors](https://doc.rust-lang.org/nightly/std/io/enum.ErrorKind.html) encountered during the execution of the function, including
+/// if a database [does not exist](https://doc.rust-lang.org/nightly/std/io/enum.ErrorKind.html#variant.NotFound) in the provided directory.
+///
+/// Additionally, the following [Cql errors](./error/cql/enum.Error.html) may be returned:
+/// - A [FormatVersionError](./error/cql/enum.Error.html#variant.FormatVersionError) will be returned if the database was created by a version of cql_db
+///   using a different [format version](constant.FORMAT_VERSION.html).
+/// - A [TypeMismatchError](./error/cql/enum.Error.html#variant.TypeMismatchError) will be returned if the database was created with a different
+///   storage type to `TStore`.  Databases created before the storage type was recorded are not checked.
 ///
 /// # Panics
 ///
@@ -589,147 +903,192 @@ pub fn link_dimensions_unchecked<TStore: CqlType>(db_location: &str, location: &
 /// #
 /// # use std::error::Error;
 /// # use std::fs::remove_file;
+/// # use std::io::Cursor;
 /// # fn main() -> Result<(), Box<dyn Error>> {
 /// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/db"));
 /// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/ax"));
 /// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/key1_2"));
 /// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/key2_3"));
-/// // Create a database with a maximum capacity of `[2, 5, 3, 2]`
 /// cql_db::create_db::<U64>(
 ///     DATABASE_LOCATION,
 ///     &[2, 5, 3, 2]
 /// )?;
 ///
-/// // Link the 2nd element of the 1st dimension with the 4th element of the 2nd dimension, and
-/// // the 4th of the 2nd with the 3rd of the 3rd - for example:
-/// // Turbine 2 has data for Signal 4 for Year 3
-/// cql_db::link_dimensions::<U64>(
-///     DATABASE_LOCATION,
-///     &[2, 4, 3], // don't link the Nth dimension
-/// )?;
+/// let mut database = cql_db::open_mapped::<U64>(DATABASE_LOCATION)?;
+///
+/// cql_db::link_dimensions::<U64>(DATABASE_LOCATION, &[2, 4, 3])?;
+/// for i in 1..3 {
+///     cql_db::write_value::<U64>(DATABASE_LOCATION, &[2, 4, 3, i], i * 10)?;
+/// }
+///
+/// let mut stream = Cursor::new(Vec::new());
+/// database.read_to_stream(&mut stream, &[2, 4, 3, 1], 2)?;
+///
+/// assert_eq!(database.read_value(&[2, 4, 3, 2])?, 20);
+/// assert_eq!(stream.into_inner().len(), 16);
 /// # Ok(())
 /// # }
 /// ```
-pub fn link_dimensions<TStore: CqlType>(db_location: &str, location: &[u64]) -> result::Result<()> {
-    validate_link_dimensions_params::<TStore>(db_location, location)?;
-    link_dimensions_unchecked::<TStore>(db_location, location)?;
-    Ok(())
-}
-
-fn validate_link_dimensions_params<TStore: CqlType>(db_location: &str, location: &[u64]) -> result::Result<()> {
-    let number_of_dimensions = axis_library::count(db_location)?;
-
-    if location.len() < 2 || location.len() as u64 > (number_of_dimensions - 1) {
-        return Err(
-            error::Error::Cql(
-                error::cql::Error::DimensionsOutOfRangeError {
-                    requested: location.len(),
-                    min: 2,
-                    max: number_of_dimensions as usize - 1,
-                }
-            )
-        )
-    }
-
-    validate_element_within_range(db_location, location)
+#[cfg(feature = "mmap")]
+pub fn open_mapped<TStore: CqlType>(db_location: &str) -> result::Result<MappedDatabase<TStore>> {
+    let database = open_read_only::<TStore>(db_location)?;
+    let database = MappedDatabase::open(database)?;
+    Ok(database)
 }
 
-/// Writes the given value to the given location in the database.  Does not validate given parameters.
+/// Rewrites the database at the source location into a new database at the target location, omitting any space that is no longer linked.
 ///
-/// Can result in writing to an 'alternative' location if provided with an invalid location in the final dimension, other invalid dimensions will likely
-/// result in a panic.
+/// Every linked row of the source database is copied to the target, with the links between dimensions recreated in ascending order so that the keys
+/// and rows of the new database are densely packed.  Once copied, every row is read back from the target database and compared against the source.
+/// The target directory must already exist, but must not contain a database.  The source database is not modified.
 ///
 /// # Errors
 ///
-/// Will return any [I/O errors](https://doc.rust-lang.org/nightly/std/io/enum.ErrorKind.html) encountered during the execution of the function.
-/// If an error is returned it is not guaranteed that no bytes have been written to the requested location.
+/// Will return any [I/O errors](https://doc.rust-lang.org/nightly/std/io/enum.ErrorKind.html) encountered during the execution of the function, including
+/// [io::ErrorKind::AlreadyExists](https://doc.rust-lang.org/nightly/std/io/enum.ErrorKind.html#variant.AlreadyExists) if a database already exists in the
+/// target location, and [io::ErrorKind::InvalidData](https://doc.rust-lang.org/nightly/std/io/enum.ErrorKind.html#variant.InvalidData) if a row read back from
+/// the target does not match the source.  Function may partially succeed resulting in changes to the target location if an error is returned.
+///
+/// Additionally, the following [Cql errors](./error/cql/enum.Error.html) may be returned, in which case the target location will not have been modified:
+/// - A [FormatVersionError](./error/cql/enum.Error.html#variant.FormatVersionError) will be returned if the database was created by a version of cql_db
+///   using a different [format version](constant.FORMAT_VERSION.html).
+/// - A [TypeMismatchError](./error/cql/enum.Error.html#variant.TypeMismatchError) will be returned if the source database was created with a different
+///   storage type to `TStore`.
+/// - A [LockTimeoutError](./error/cql/enum.Error.html#variant.LockTimeoutError) will be returned if the database could not be locked within the
+///   [DEFAULT_LOCK_TIMEOUT](constant.DEFAULT_LOCK_TIMEOUT.html).
 ///
 /// # Panics
 ///
-/// Function does not actively defend against panics, and may do so if given invalid parameters.  If the function panics, no changes will have been made to the
-/// file system and the previous value should still be present.
+/// Function should not panic.  If you get it to panic, please raise an issue in [github](https://github.com/AndrewSisley/CQLDb/issues).
 ///
 /// # Examples
 /// ```
 /// # use cql_u64::U64;
 /// # const DATABASE_LOCATION: &str = "./.test_db";
+/// # const COMPACTED_DATABASE_LOCATION: &str = "./.test_db/compacted";
 /// #
 /// # use std::error::Error;
-/// # use std::fs::remove_file;
+/// # use std::fs::{ create_dir_all, remove_dir_all, remove_file };
 /// # fn main() -> Result<(), Box<dyn Error>> {
 /// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/db"));
 /// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/ax"));
 /// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/key1_2"));
 /// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/key2_3"));
+/// # let _ = remove_dir_all(COMPACTED_DATABASE_LOCATION);
+/// # create_dir_all(COMPACTED_DATABASE_LOCATION)?;
 /// cql_db::create_db::<U64>(
 ///     DATABASE_LOCATION,
 ///     &[2, 5, 3, 2]
 /// )?;
 ///
-/// // higher order elements must be linked before they can be writen to
-/// cql_db::link_dimensions::<U64>(
-///     DATABASE_LOCATION,
-///     &[2, 4, 3],
-/// )?;
+/// cql_db::link_dimensions::<U64>(DATABASE_LOCATION, &[1, 1, 1])?;
+/// cql_db::link_dimensions::<U64>(DATABASE_LOCATION, &[2, 4, 3])?;
+/// cql_db::write_value::<U64>(DATABASE_LOCATION, &[2, 4, 3, 1], 5)?;
+/// cql_db::unlink_dimensions::<U64>(DATABASE_LOCATION, &[1, 1])?;
 ///
-/// // Write `5` to location `[2, 4, 3, 1]`
-/// cql_db::write_value_unchecked::<U64>(
+/// cql_db::compact::<U64>(
 ///     DATABASE_LOCATION,
-///     &[2, 4, 3, 1],
-///     5
+///     COMPACTED_DATABASE_LOCATION
 /// )?;
+///
+/// assert_eq!(cql_db::read_value::<U64>(COMPACTED_DATABASE_LOCATION, &[2, 4, 3, 1])?, 5);
 /// # Ok(())
 /// # }
 /// ```
-pub fn write_value_unchecked<TStore: CqlWritable>(db_location: &str, location: &[u64], value: TStore::ValueType) -> io::Result<()> {
-	let position = calculate_position(db_location, location)?;
-	database::write_value::<TStore>(&db_location, position, value)
+pub fn compact<TStore: CqlType>(source_db_location: &str, target_db_location: &str) -> result::Result<()> {
+    compact::compact::<TStore>(source_db_location, target_db_location)
 }
 
-/// Writes the given value to the given location in the database.
+/// Increases the capacity of the last dimension of the database to `new_max`.  Does not validate given parameters.
 ///
-/// There is an [unchecked](fn.write_value_unchecked.html) version of this function if required.
+/// Every row of the last dimension is moved to its new position within the database file, with the new elements at the end of each row reading as the
+/// default value for the stored type.  Rows are moved a chunk at a time, each chunk recorded in the database's journal, so an interrupted resize
+/// will be completed the next time the database is used.  Handles already open see the new capacity from their next call.
+///
+/// There is a [checked](fn.resize_last_dimension.html) version of this function.
 ///
 /// # Errors
 ///
-/// Will return any [I/O errors](https://doc.rust-lang.org/nightly/std/io/enum.ErrorKind.html) encountered during the execution of the function.
-/// If an error is returned it is not guaranteed that no bytes have been written to the requested location.
+/// Will return any [I/O errors](https://doc.rust-lang.org/nightly/std/io/enum.ErrorKind.html) encountered during the execution of the function.  If an error is
+/// returned once rows have begun to be moved, the resize will be completed the next time the database is used.
 ///
-/// Additionally, the following [Cql errors](./error/cql/enum.Error.html) may be returned:
-/// - A [DimensionsOutOfRangeError](./error/cql/enum.Error.html#variant.DimensionsOutOfRangeError) will be returned if the provided `location.len()` is not equal
-/// to the number of dimensions in the database.
-/// - An [IndexOutOfRangeError](./error/cql/enum.Error.html#variant.IndexOutOfRangeError) will be returned if any of the provided indexes in `location` are less than 1,
-/// or greater than that dimension's capacity.
-/// - An [ElementsNotLinkedError](./error/cql/enum.Error.html#variant.ElementsNotLinkedError) will be returned if the provided elements have not been linked.
+/// # Panics
+///
+/// Function does not actively defend against panics, and will likely do so if given invalid parameters.  Function may partially succeed resulting in changes
+/// to the file system.  Giving a `new_max` smaller than the current capacity will corrupt the database.
+///
+/// # Examples
 /// ```
 /// # use cql_u64::U64;
-/// # use cql_db::error;
-/// # use cql_db::error::cql::Error;
 /// # const DATABASE_LOCATION: &str = "./.test_db";
 /// #
-/// # use std::error::Error as StdError;
+/// # use std::error::Error;
 /// # use std::fs::remove_file;
-/// # fn main() -> Result<(), Box<dyn StdError>> {
+/// # fn main() -> Result<(), Box<dyn Error>> {
 /// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/db"));
 /// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/ax"));
 /// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/key1_2"));
-/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/key2_3"));
-/// // Create a database with a maximum capacity of `[2, 5, 3, 2]`
 /// cql_db::create_db::<U64>(
 ///     DATABASE_LOCATION,
-///     &[2, 5, 6]
+///     &[2, 3, 2]
 /// )?;
 ///
-/// cql_db::link_dimensions::<U64>(
-///     DATABASE_LOCATION,
-///     &[2, 4],
-/// )?;
+/// cql_db::link_dimensions::<U64>(DATABASE_LOCATION, &[2, 3])?;
+/// cql_db::write_value::<U64>(DATABASE_LOCATION, &[2, 3, 2], 5)?;
 ///
-/// let result = match cql_db::write_value::<U64>(
+/// cql_db::resize_last_dimension_unchecked::<U64>(DATABASE_LOCATION, 4)?;
+///
+/// cql_db::write_value::<U64>(DATABASE_LOCATION, &[2, 3, 4], 6)?;
+///
+/// assert_eq!(cql_db::read_value::<U64>(DATABASE_LOCATION, &[2, 3, 2])?, 5);
+/// assert_eq!(cql_db::read_value::<U64>(DATABASE_LOCATION, &[2, 3, 4])?, 6);
+/// # Ok(())
+/// # }
+/// ```
+pub fn resize_last_dimension_unchecked<TStore: CqlType>(db_location: &str, new_max: u64) -> io::Result<()> {
+    let last_index = axis_library::get_all(&FileStorage::new(db_location))?.len() - 1;
+    resize::resize_dimension::<TStore>(db_location, last_index, new_max)
+}
+
+/// Increases the capacity of the last dimension of the database to `new_max`.
+///
+/// Every row of the last dimension is moved to its new position within the database file, with the new elements at the end of each row reading as the
+/// default value for the stored type.  Rows are moved a chunk at a time, each chunk recorded in the database's journal, so an interrupted resize
+/// will be completed the next time the database is used.  Handles already open see the new capacity from their next call.
+///
+/// There is an [unchecked](fn.resize_last_dimension_unchecked.html) version of this function.
+///
+/// # Errors
+///
+/// Will return any [I/O errors](https://doc.rust-lang.org/nightly/std/io/enum.ErrorKind.html) encountered during the execution of the function.  If an error is
+/// returned once rows have begun to be moved, the resize will be completed the next time the database is used.
+///
+/// Additionally, the following [Cql errors](./error/cql/enum.Error.html) may be returned, in which case the database will not have been modified:
+/// - A [ResizeShrinkError](./error/cql/enum.Error.html#variant.ResizeShrinkError) will be returned if the provided `new_max` is less than the
+///   current capacity of the last dimension.
+/// - A [FormatVersionError](./error/cql/enum.Error.html#variant.FormatVersionError) will be returned if the database was created by a version of cql_db
+///   using a different [format version](constant.FORMAT_VERSION.html).
+/// - A [TypeMismatchError](./error/cql/enum.Error.html#variant.TypeMismatchError) will be returned if the database was created with a different
+///   storage type to `TStore`.
+/// - A [LockTimeoutError](./error/cql/enum.Error.html#variant.LockTimeoutError) will be returned if the database could not be locked within the
+///   [DEFAULT_LOCK_TIMEOUT](constant.DEFAULT_LOCK_TIMEOUT.html).
+/// ```
+/// # use cql_u64::U64;
+/// # use cql_db::error;
+/// # use cql_db::error::cql::Error;
+/// # const DATABASE_LOCATION: &str = "./.test_db";
+/// #
+/// # use std::fs::remove_file;
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/db"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/ax"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/key1_2"));
+/// # cql_db::create_db::<U64>(
+/// #     DATABASE_LOCATION,
+/// #     &[2, 3, 2]
+/// # );
+/// let result = match cql_db::resize_last_dimension::<U64>(
 ///     DATABASE_LOCATION,
-///     // not enough dimensions
-///     &[2, 4],
-///     10
+///     1
 /// ) {
 ///     Err(error) => match error {
 ///         error::Error::Cql(cql_error) => Some(cql_error),
@@ -740,41 +1099,149 @@ pub fn write_value_unchecked<TStore: CqlWritable>(db_location: &str, location: &
 ///
 /// assert_eq!(
 ///     result.unwrap(),
-///     Error::DimensionsOutOfRangeError {
-///         requested: 2,
-///         min: 3, // 4 - 1
-///         max: 3, // 4 - 1
+///     Error::ResizeShrinkError {
+///         current: 2,
+///         requested: 1,
 ///     }
 /// );
+/// ```
 ///
-/// let result2 = match cql_db::write_value::<U64>(
+/// # Panics
+///
+/// Function should not panic.  If you get it to panic, please raise an issue in [github](https://github.com/AndrewSisley/CQLDb/issues).
+///
+/// # Examples
+/// ```
+/// # use cql_u64::U64;
+/// # const DATABASE_LOCATION: &str = "./.test_db";
+/// #
+/// # use std::error::Error;
+/// # use std::fs::remove_file;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/db"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/ax"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/key1_2"));
+/// cql_db::create_db::<U64>(
 ///     DATABASE_LOCATION,
-///     // dimension[2] index is too large
-///     &[2, 4, 7],
-///     10
-/// ) {
-///     Err(error) => match error {
-///         error::Error::Cql(cql_error) => Some(cql_error),
-///         _ => None,
-///     }
-///     _ => None,
-/// };
+///     &[2, 3, 2]
+/// )?;
 ///
-/// assert_eq!(
-///     result2.unwrap(),
-///     Error::IndexOutOfRangeError {
-///         dimension_index: 2,
-///         requested: 7,
-///         min: 1,
-///         max: 6,
-///     }
-/// );
+/// cql_db::link_dimensions::<U64>(DATABASE_LOCATION, &[2, 3])?;
+/// cql_db::write_value::<U64>(DATABASE_LOCATION, &[2, 3, 2], 5)?;
 ///
-/// let result3 = match cql_db::write_value::<U64>(
+/// cql_db::resize_last_dimension::<U64>(DATABASE_LOCATION, 4)?;
+///
+/// cql_db::write_value::<U64>(DATABASE_LOCATION, &[2, 3, 4], 6)?;
+///
+/// assert_eq!(cql_db::read_value::<U64>(DATABASE_LOCATION, &[2, 3, 2])?, 5);
+/// assert_eq!(cql_db::read_value::<U64>(DATABASE_LOCATION, &[2, 3, 4])?, 6);
+/// # Ok(())
+/// # }
+/// ```
+pub fn resize_last_dimension<TStore: CqlType>(db_location: &str, new_max: u64) -> result::Result<()> {
+    let capacities = open::<TStore>(db_location)?.capacities();
+    let last_index = capacities.len() - 1;
+    validate_resize_dimension_params(&capacities, last_index, new_max)?;
+
+    resize::resize_dimension::<TStore>(db_location, last_index, new_max)?;
+    Ok(())
+}
+
+/// Increases the capacity of the dimension at `dimension_index` (zero-based) to `new_max`.  Does not validate given parameters.
+///
+/// The key library indexed by the dimension is rewritten using the new capacity, or every row of the database file is moved if it is the last
+/// dimension.  Existing links and values are preserved, and the new elements are unlinked, or read as the default value for the stored type if in the last
+/// dimension.  Rows are moved a chunk at a time, each chunk recorded in the database's journal, so an interrupted resize will be completed the next time
+/// the database is used.  Handles already open see the new capacity from their next call.
+///
+/// There is a [checked](fn.resize_dimension.html) version of this function.
+///
+/// # Errors
+///
+/// Will return any [I/O errors](https://doc.rust-lang.org/nightly/std/io/enum.ErrorKind.html) encountered during the execution of the function.  If an error is
+/// returned once rows have begun to be moved, the resize will be completed the next time the database is used.
+///
+/// # Panics
+///
+/// Function does not actively defend against panics, and will likely do so if given invalid parameters.  Function may partially succeed resulting in changes
+/// to the file system.  Giving a `new_max` smaller than the current capacity will corrupt the database.
+///
+/// # Examples
+/// ```
+/// # use cql_u64::U64;
+/// # const DATABASE_LOCATION: &str = "./.test_db";
+/// #
+/// # use std::error::Error;
+/// # use std::fs::remove_file;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/db"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/ax"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/key1_2"));
+/// cql_db::create_db::<U64>(
 ///     DATABASE_LOCATION,
-///     // location[2, 3] has not been linked
-///     &[2, 3, 3],
-///     10
+///     &[2, 3, 2]
+/// )?;
+///
+/// cql_db::link_dimensions::<U64>(DATABASE_LOCATION, &[2, 3])?;
+/// cql_db::write_value::<U64>(DATABASE_LOCATION, &[2, 3, 2], 5)?;
+///
+/// cql_db::resize_dimension_unchecked::<U64>(DATABASE_LOCATION, 1, 5)?;
+///
+/// cql_db::link_dimensions::<U64>(DATABASE_LOCATION, &[2, 5])?;
+/// cql_db::write_value::<U64>(DATABASE_LOCATION, &[2, 5, 1], 6)?;
+///
+/// assert_eq!(cql_db::read_value::<U64>(DATABASE_LOCATION, &[2, 3, 2])?, 5);
+/// assert_eq!(cql_db::read_value::<U64>(DATABASE_LOCATION, &[2, 5, 1])?, 6);
+/// # Ok(())
+/// # }
+/// ```
+pub fn resize_dimension_unchecked<TStore: CqlType>(db_location: &str, dimension_index: usize, new_max: u64) -> io::Result<()> {
+    resize::resize_dimension::<TStore>(db_location, dimension_index, new_max)
+}
+
+/// Increases the capacity of the dimension at `dimension_index` (zero-based) to `new_max`.
+///
+/// The key library indexed by the dimension is rewritten using the new capacity, or every row of the database file is moved if it is the last
+/// dimension.  Existing links and values are preserved, and the new elements are unlinked, or read as the default value for the stored type if in the last
+/// dimension.  Rows are moved a chunk at a time, each chunk recorded in the database's journal, so an interrupted resize will be completed the next time
+/// the database is used.  Handles already open see the new capacity from their next call.
+///
+/// There is an [unchecked](fn.resize_dimension_unchecked.html) version of this function.
+///
+/// # Errors
+///
+/// Will return any [I/O errors](https://doc.rust-lang.org/nightly/std/io/enum.ErrorKind.html) encountered during the execution of the function.  If an error is
+/// returned once rows have begun to be moved, the resize will be completed the next time the database is used.
+///
+/// Additionally, the following [Cql errors](./error/cql/enum.Error.html) may be returned, in which case the database will not have been modified:
+/// - A [DimensionsOutOfRangeError](./error/cql/enum.Error.html#variant.DimensionsOutOfRangeError) will be returned if the provided `dimension_index` is
+///   not less than the number of dimensions in the database.
+/// - A [ResizeShrinkError](./error/cql/enum.Error.html#variant.ResizeShrinkError) will be returned if the provided `new_max` is less than the
+///   current capacity of the dimension.
+/// - A [FormatVersionError](./error/cql/enum.Error.html#variant.FormatVersionError) will be returned if the database was created by a version of cql_db
+///   using a different [format version](constant.FORMAT_VERSION.html).
+/// - A [TypeMismatchError](./error/cql/enum.Error.html#variant.TypeMismatchError) will be returned if the database was created with a different
+///   storage type to `TStore`.
+/// - A [LockTimeoutError](./error/cql/enum.Error.html#variant.LockTimeoutError) will be returned if the database could not be locked within the
+///   [DEFAULT_LOCK_TIMEOUT](constant.DEFAULT_LOCK_TIMEOUT.html).
+/// ```
+/// # use cql_u64::U64;
+/// # use cql_db::error;
+/// # use cql_db::error::cql::Error;
+/// # const DATABASE_LOCATION: &str = "./.test_db";
+/// #
+/// # use std::fs::remove_file;
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/db"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/ax"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/key1_2"));
+/// # cql_db::create_db::<U64>(
+/// #     DATABASE_LOCATION,
+/// #     &[2, 3, 2]
+/// # );
+/// let result = match cql_db::resize_dimension::<U64>(
+///     DATABASE_LOCATION,
+///     3,
+///     4
 /// ) {
 ///     Err(error) => match error {
 ///         error::Error::Cql(cql_error) => Some(cql_error),
@@ -784,16 +1251,13 @@ pub fn write_value_unchecked<TStore: CqlWritable>(db_location: &str, location: &
 /// };
 ///
 /// assert_eq!(
-///     result3.unwrap(),
-///     Error::ElementsNotLinkedError {
-///         x_dimension: 0,
-///         x: 2,
-///         y_dimension: 1,
-///         y: 3,
+///     result.unwrap(),
+///     Error::DimensionsOutOfRangeError {
+///         requested: 3,
+///         min: 0,
+///         max: 2,
 ///     }
 /// );
-/// # Ok(())
-/// # }
 /// ```
 ///
 /// # Panics
@@ -811,45 +1275,46 @@ pub fn write_value_unchecked<TStore: CqlWritable>(db_location: &str, location: &
 /// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/db"));
 /// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/ax"));
 /// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/key1_2"));
-/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/key2_3"));
 /// cql_db::create_db::<U64>(
 ///     DATABASE_LOCATION,
-///     &[2, 5, 3, 2]
+///     &[2, 3, 2]
 /// )?;
 ///
-/// // higher order elements must be linked before they can be writen to
-/// cql_db::link_dimensions::<U64>(
-///     DATABASE_LOCATION,
-///     &[2, 4, 3],
-/// )?;
+/// cql_db::link_dimensions::<U64>(DATABASE_LOCATION, &[2, 3])?;
+/// cql_db::write_value::<U64>(DATABASE_LOCATION, &[2, 3, 2], 5)?;
 ///
-/// // Write `5` to location `[2, 4, 3, 1]`
-/// cql_db::write_value::<U64>(
-///     DATABASE_LOCATION,
-///     &[2, 4, 3, 1],
-///     5
-/// )?;
+/// cql_db::resize_dimension::<U64>(DATABASE_LOCATION, 1, 5)?;
+///
+/// cql_db::link_dimensions::<U64>(DATABASE_LOCATION, &[2, 5])?;
+/// cql_db::write_value::<U64>(DATABASE_LOCATION, &[2, 5, 1], 6)?;
+///
+/// assert_eq!(cql_db::read_value::<U64>(DATABASE_LOCATION, &[2, 3, 2])?, 5);
+/// assert_eq!(cql_db::read_value::<U64>(DATABASE_LOCATION, &[2, 5, 1])?, 6);
 /// # Ok(())
 /// # }
 /// ```
-pub fn write_value<TStore: CqlWritable>(db_location: &str, location: &[u64], value: TStore::ValueType) -> result::Result<()> {
-	validate_read_write_location(db_location, location)?;
-    write_value_unchecked::<TStore>(db_location, location, value)?;
+pub fn resize_dimension<TStore: CqlType>(db_location: &str, dimension_index: usize, new_max: u64) -> result::Result<()> {
+    let capacities = open::<TStore>(db_location)?.capacities();
+    validate_resize_dimension_params(&capacities, dimension_index, new_max)?;
+
+    resize::resize_dimension::<TStore>(db_location, dimension_index, new_max)?;
     Ok(())
 }
 
-/// Reads the value at the given location from the database.  Does not validate given parameters.
+/// Links dimension indexs together if they are not already linked.  Does not validate given parameters.
 ///
-/// Can result in reading from an 'alternative' location if provided with an invalid location in the final dimension, other invalid dimensions will likely
-/// result in a panic.
+/// This is required before read-writing to a location, and allocates the file space required to store the Nth dimension data.
+/// The last (Nth) dimension should not be linked.
 ///
 /// # Errors
 ///
-/// Will return any [I/O errors](https://doc.rust-lang.org/nightly/std/io/enum.ErrorKind.html) encountered during the execution of the function.
+/// Will return any [I/O errors](https://doc.rust-lang.org/nightly/std/io/enum.ErrorKind.html) encountered during the execution of the function.  Function may
+/// partially succeed resulting in changes to the file system.
 ///
 /// # Panics
 ///
-/// Function does not actively defend against panics, and may do so if given invalid parameters.
+/// Function does not actively defend against panics, and may do so if given invalid parameters.  Function may partially succeed resulting in changes
+/// to the file system.
 ///
 /// # Examples
 /// ```
@@ -863,64 +1328,2317 @@ pub fn write_value<TStore: CqlWritable>(db_location: &str, location: &[u64], val
 /// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/ax"));
 /// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/key1_2"));
 /// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/key2_3"));
-/// let point = [2, 4, 3, 1];
-/// let value = 5;
-///
+/// // Create a database with a maximum capacity of `[2, 5, 3, 2]`
 /// cql_db::create_db::<U64>(
 ///     DATABASE_LOCATION,
 ///     &[2, 5, 3, 2]
 /// )?;
 ///
-/// // higher order elements must be linked before they can be read from
-/// cql_db::link_dimensions::<U64>(
+/// // Link the 2nd element of the 1st dimension with the 4th element of the 2nd dimension, and
+/// // the 4th of the 2nd with the 3rd of the 3rd - for example:
+/// // Turbine 2 has data for Signal 4 for Year 3
+/// cql_db::link_dimensions_unchecked::<U64>(
 ///     DATABASE_LOCATION,
-///     &point[0..3],
+///     &[2, 4, 3], // don't link the Nth dimension
 /// )?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn link_dimensions_unchecked<TStore: CqlType>(db_location: &str, location: &[u64]) -> io::Result<()> {
+    Database::<TStore>::open(FileStorage::new(db_location))?.link_dimensions_unchecked(location)
+}
+
+/// Links dimension indexs together if they are not already linked.
 ///
-/// // Read the default value from point `point`
-/// let result1 = cql_db::read_value_unchecked::<U64>(
+/// This is required before read-writing to a location, and allocates the file space required to store the Nth dimension data.
+/// The last (Nth) dimension should not be linked.  There is an [unchecked](fn.link_dimensions_unchecked.html) version of this function if required.
+///
+/// # Errors
+///
+/// Will return any [I/O errors](https://doc.rust-lang.org/nightly/std/io/enum.ErrorKind.html) encountered during the execution of the function.  Function may
+/// partially succeed resulting in changes to the file system if such an error is returned.
+///
+/// Additionally, the following [Cql errors](./error/cql/enum.Error.html) may be returned:
+/// - A [DimensionsOutOfRangeError](./error/cql/enum.Error.html#variant.DimensionsOutOfRangeError) will be returned if the provided `location.len()` is less than 2,
+///   or greater than the number of dimensions in the database - 1.
+/// - An [IndexOutOfRangeError](./error/cql/enum.Error.html#variant.IndexOutOfRangeError) will be returned if any of the provided indexes in `location` are less than 1,
+///   or greater than that dimension's capacity.
+/// - A [FormatVersionError](./error/cql/enum.Error.html#variant.FormatVersionError) will be returned if the database was created by a version of cql_db
+///   using a different [format version](constant.FORMAT_VERSION.html).
+/// - A [TypeMismatchError](./error/cql/enum.Error.html#variant.TypeMismatchError) will be returned if the database was created with a different
+///   storage type to `TStore`.
+/// - A [LockTimeoutError](./error/cql/enum.Error.html#variant.LockTimeoutError) will be returned if the database could not be locked within the
+///   [DEFAULT_LOCK_TIMEOUT](constant.DEFAULT_LOCK_TIMEOUT.html).
+/// ```
+/// # use cql_u64::U64;
+/// # use cql_db::error;
+/// # use cql_db::error::cql::Error;
+/// # const DATABASE_LOCATION: &str = "./.test_db";
+/// #
+/// # use std::error::Error as StdError;
+/// # use std::fs::remove_file;
+/// # fn main() -> Result<(), Box<dyn StdError>> {
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/db"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/ax"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/key1_2"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/key2_3"));
+/// // Create a database with a maximum capacity of `[2, 5, 3, 2]`
+/// cql_db::create_db::<U64>(
 ///     DATABASE_LOCATION,
-///     &point
+///     &[2, 5, 3, 2]
+/// )?;
+///
+/// let result = match cql_db::link_dimensions::<U64>(
+///     DATABASE_LOCATION,
+///     // not enough dimensions
+///     &[1]
+/// ) {
+///     Err(error) => match error {
+///         error::Error::Cql(cql_error) => Some(cql_error),
+///         _ => None,
+///     }
+///     _ => None,
+/// };
+///
+/// assert_eq!(
+///     result.unwrap(),
+///     Error::DimensionsOutOfRangeError {
+///         requested: 1,
+///         min: 2,
+///         max: 3, // 4 - 1
+///     }
+/// );
+///
+/// let result2 = match cql_db::link_dimensions::<U64>(
+///     DATABASE_LOCATION,
+///     // dimension[1] index is too large
+///     &[1, 6]
+/// ) {
+///     Err(error) => match error {
+///         error::Error::Cql(cql_error) => Some(cql_error),
+///         _ => None,
+///     }
+///     _ => None,
+/// };
+///
+/// assert_eq!(
+///     result2.unwrap(),
+///     Error::IndexOutOfRangeError {
+///         dimension_index: 1,
+///         requested: 6,
+///         min: 1,
+///         max: 5,
+///     }
+/// );
+/// # Ok(())
+/// # }
+/// ```
+///
+/// # Panics
+///
+/// Function should not panic.  If you get it to panic, please raise an issue in [github](https://github.com/AndrewSisley/CQLDb/issues).
+///
+/// # Examples
+/// ```
+/// # use cql_u64::U64;
+/// # const DATABASE_LOCATION: &str = "./.test_db";
+/// #
+/// # use std::error::Error;
+/// # use std::fs::remove_file;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/db"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/ax"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/key1_2"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/key2_3"));
+/// // Create a database with a maximum capacity of `[2, 5, 3, 2]`
+/// cql_db::create_db::<U64>(
+///     DATABASE_LOCATION,
+///     &[2, 5, 3, 2]
+/// )?;
+///
+/// // Link the 2nd element of the 1st dimension with the 4th element of the 2nd dimension, and
+/// // the 4th of the 2nd with the 3rd of the 3rd - for example:
+/// // Turbine 2 has data for Signal 4 for Year 3
+/// cql_db::link_dimensions::<U64>(
+///     DATABASE_LOCATION,
+///     &[2, 4, 3], // don't link the Nth dimension
+/// )?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn link_dimensions<TStore: CqlType>(db_location: &str, location: &[u64]) -> result::Result<()> {
+    open::<TStore>(db_location)?.link_dimensions(location)
+}
+
+/// Unlinks the last of the given dimension indexes from the one before it, releasing everything linked beneath them.  Does not validate given parameters.
+///
+/// This is the inverse of [link_dimensions](fn.link_dimensions_unchecked.html).  Any values stored beneath the unlinked location are cleared, and the
+/// file space used to store them is reused by subsequent links instead of growing the database.  Does nothing if the given location is not linked.
+///
+/// # Errors
+///
+/// Will return any [I/O errors](https://doc.rust-lang.org/nightly/std/io/enum.ErrorKind.html) encountered during the execution of the function.  Function may
+/// partially succeed resulting in changes to the file system.
+///
+/// # Panics
+///
+/// Function does not actively defend against panics, and may do so if given invalid parameters.  Function may partially succeed resulting in changes
+/// to the file system.
+///
+/// # Examples
+/// ```
+/// # use cql_u64::U64;
+/// # const DATABASE_LOCATION: &str = "./.test_db";
+/// #
+/// # use std::error::Error;
+/// # use std::fs::remove_file;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/db"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/ax"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/key1_2"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/key2_3"));
+/// cql_db::create_db::<U64>(
+///     DATABASE_LOCATION,
+///     &[2, 5, 3, 2]
+/// )?;
+///
+/// cql_db::link_dimensions::<U64>(
+///     DATABASE_LOCATION,
+///     &[2, 4, 3],
+/// )?;
+///
+/// cql_db::write_value::<U64>(
+///     DATABASE_LOCATION,
+///     &[2, 4, 3, 1],
+///     5
+/// )?;
+///
+/// // Turbine 2 has been decommissioned, so it no longer has data for Signal 4 for any Year
+/// cql_db::unlink_dimensions_unchecked::<U64>(
+///     DATABASE_LOCATION,
+///     &[2, 4],
+/// )?;
+///
+/// // the space released is reused by the next link, and starts out empty
+/// cql_db::link_dimensions::<U64>(
+///     DATABASE_LOCATION,
+///     &[1, 1, 1],
+/// )?;
+///
+/// assert_eq!(cql_db::read_value::<U64>(DATABASE_LOCATION, &[1, 1, 1, 1])?, 0);
+/// # Ok(())
+/// # }
+/// ```
+pub fn unlink_dimensions_unchecked<TStore: CqlType>(db_location: &str, location: &[u64]) -> io::Result<()> {
+    Database::<TStore>::open(FileStorage::new(db_location))?.unlink_dimensions_unchecked(location)
+}
+
+/// Unlinks the last of the given dimension indexes from the one before it, releasing everything linked beneath them.
+///
+/// This is the inverse of [link_dimensions](fn.link_dimensions.html).  Any values stored beneath the unlinked location are cleared, and the
+/// file space used to store them is reused by subsequent links instead of growing the database.  There is an
+/// [unchecked](fn.unlink_dimensions_unchecked.html) version of this function if required.
+///
+/// # Errors
+///
+/// Will return any [I/O errors](https://doc.rust-lang.org/nightly/std/io/enum.ErrorKind.html) encountered during the execution of the function.  Function may
+/// partially succeed resulting in changes to the file system if such an error is returned.
+///
+/// Additionally, the following [Cql errors](./error/cql/enum.Error.html) may be returned:
+/// - A [DimensionsOutOfRangeError](./error/cql/enum.Error.html#variant.DimensionsOutOfRangeError) will be returned if the provided `location.len()` is less than 2,
+///   or greater than the number of dimensions in the database - 1.
+/// - An [IndexOutOfRangeError](./error/cql/enum.Error.html#variant.IndexOutOfRangeError) will be returned if any of the provided indexes in `location` are less than 1,
+///   or greater than that dimension's capacity.
+/// - An [ElementsNotLinkedError](./error/cql/enum.Error.html#variant.ElementsNotLinkedError) will be returned if the provided elements are not linked.
+/// - A [FormatVersionError](./error/cql/enum.Error.html#variant.FormatVersionError) will be returned if the database was created by a version of cql_db
+///   using a different [format version](constant.FORMAT_VERSION.html).
+/// - A [TypeMismatchError](./error/cql/enum.Error.html#variant.TypeMismatchError) will be returned if the database was created with a different
+///   storage type to `TStore`.
+/// - A [LockTimeoutError](./error/cql/enum.Error.html#variant.LockTimeoutError) will be returned if the database could not be locked within the
+///   [DEFAULT_LOCK_TIMEOUT](constant.DEFAULT_LOCK_TIMEOUT.html).
+/// ```
+/// # use cql_u64::U64;
+/// # use cql_db::error;
+/// # use cql_db::error::cql::Error;
+/// # const DATABASE_LOCATION: &str = "./.test_db";
+/// #
+/// # use std::error::Error as StdError;
+/// # use std::fs::remove_file;
+/// # fn main() -> Result<(), Box<dyn StdError>> {
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/db"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/ax"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/key1_2"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/key2_3"));
+/// cql_db::create_db::<U64>(
+///     DATABASE_LOCATION,
+///     &[2, 5, 3, 2]
+/// )?;
+///
+/// cql_db::link_dimensions::<U64>(
+///     DATABASE_LOCATION,
+///     &[2, 4, 3],
+/// )?;
+///
+/// let result = match cql_db::unlink_dimensions::<U64>(
+///     DATABASE_LOCATION,
+///     // location[4, 2] has not been linked
+///     &[2, 4, 2]
+/// ) {
+///     Err(error::Error::Cql(cql_error)) => Some(cql_error),
+///     _ => None,
+/// };
+///
+/// assert_eq!(
+///     result.unwrap(),
+///     Error::ElementsNotLinkedError {
+///         x_dimension: 1,
+///         x: 4,
+///         y_dimension: 2,
+///         y: 2,
+///     }
+/// );
+/// # Ok(())
+/// # }
+/// ```
+///
+/// # Panics
+///
+/// Function should not panic.  If you get it to panic, please raise an issue in [github](https://github.com/AndrewSisley/CQLDb/issues).
+///
+/// # Examples
+/// ```
+/// # use cql_u64::U64;
+/// # const DATABASE_LOCATION: &str = "./.test_db";
+/// #
+/// # use std::error::Error;
+/// # use std::fs::remove_file;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/db"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/ax"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/key1_2"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/key2_3"));
+/// cql_db::create_db::<U64>(
+///     DATABASE_LOCATION,
+///     &[2, 5, 3, 2]
+/// )?;
+///
+/// cql_db::link_dimensions::<U64>(
+///     DATABASE_LOCATION,
+///     &[2, 4, 3],
+/// )?;
+///
+/// cql_db::write_value::<U64>(
+///     DATABASE_LOCATION,
+///     &[2, 4, 3, 1],
+///     5
+/// )?;
+///
+/// // Turbine 2 has been decommissioned, so it no longer has data for Signal 4 for any Year
+/// cql_db::unlink_dimensions::<U64>(
+///     DATABASE_LOCATION,
+///     &[2, 4],
+/// )?;
+///
+/// // the space released is reused by the next link, and starts out empty
+/// cql_db::link_dimensions::<U64>(
+///     DATABASE_LOCATION,
+///     &[1, 1, 1],
+/// )?;
+///
+/// assert_eq!(cql_db::read_value::<U64>(DATABASE_LOCATION, &[1, 1, 1, 1])?, 0);
+/// # Ok(())
+/// # }
+/// ```
+pub fn unlink_dimensions<TStore: CqlType>(db_location: &str, location: &[u64]) -> result::Result<()> {
+    open::<TStore>(db_location)?.unlink_dimensions(location)
+}
+
+/// Returns true if the given dimension indexes are linked.  Does not validate given parameters.
+///
+/// Walks the links between the given dimension indexes in the same way as the checks made by [read_value](fn.read_value.html), returning false instead
+/// of an error should any of them not be linked.  The location may be partial, for example `[2, 4]` checks that the 2nd element of the 1st dimension
+/// is linked to the 4th element of the 2nd, regardless of whether anything has been linked beneath it.
+///
+/// # Errors
+///
+/// Will return any [I/O errors](https://doc.rust-lang.org/nightly/std/io/enum.ErrorKind.html) encountered during the execution of the function.
+///
+/// # Panics
+///
+/// Function does not actively defend against panics, and may do so if given invalid parameters.
+///
+/// # Examples
+/// ```
+/// # use cql_u64::U64;
+/// # const DATABASE_LOCATION: &str = "./.test_db";
+/// #
+/// # use std::error::Error;
+/// # use std::fs::remove_file;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/db"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/ax"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/key1_2"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/key2_3"));
+/// cql_db::create_db::<U64>(
+///     DATABASE_LOCATION,
+///     &[2, 5, 3, 2]
+/// )?;
+///
+/// cql_db::link_dimensions::<U64>(
+///     DATABASE_LOCATION,
+///     &[2, 4, 3],
+/// )?;
+///
+/// assert!(cql_db::is_linked_unchecked::<U64>(DATABASE_LOCATION, &[2, 4])?);
+/// assert!(cql_db::is_linked_unchecked::<U64>(DATABASE_LOCATION, &[2, 4, 3])?);
+/// assert!(!cql_db::is_linked_unchecked::<U64>(DATABASE_LOCATION, &[2, 4, 2])?);
+/// # Ok(())
+/// # }
+/// ```
+pub fn is_linked_unchecked<TStore: CqlType>(db_location: &str, location: &[u64]) -> io::Result<bool> {
+    Database::<TStore>::open_read_only(FileStorage::new(db_location))?.is_linked_unchecked(location)
+}
+
+/// Returns true if the given dimension indexes are linked.
+///
+/// Walks the links between the given dimension indexes in the same way as the checks made by [read_value](fn.read_value.html), returning false instead
+/// of an [ElementsNotLinkedError](./error/cql/enum.Error.html#variant.ElementsNotLinkedError) should any of them not be linked.  The location may be
+/// partial, for example `[2, 4]` checks that the 2nd element of the 1st dimension is linked to the 4th element of the 2nd, regardless of whether anything
+/// has been linked beneath it.  There is an [unchecked](fn.is_linked_unchecked.html) version of this function if required.
+///
+/// # Errors
+///
+/// Will return any [I/O errors](https://doc.rust-lang.org/nightly/std/io/enum.ErrorKind.html) encountered during the execution of the function.
+///
+/// Additionally, the following [Cql errors](./error/cql/enum.Error.html) may be returned:
+/// - A [DimensionsOutOfRangeError](./error/cql/enum.Error.html#variant.DimensionsOutOfRangeError) will be returned if the provided `location.len()` is less than 2,
+///   or greater than the number of dimensions in the database - 1.
+/// - An [IndexOutOfRangeError](./error/cql/enum.Error.html#variant.IndexOutOfRangeError) will be returned if any of the provided indexes in `location` are less than 1,
+///   or greater than that dimension's capacity.
+/// - A [FormatVersionError](./error/cql/enum.Error.html#variant.FormatVersionError) will be returned if the database was created by a version of cql_db
+///   using a different [format version](constant.FORMAT_VERSION.html).
+/// - A [TypeMismatchError](./error/cql/enum.Error.html#variant.TypeMismatchError) will be returned if the database was created with a different
+///   storage type to `TStore`.
+/// - A [LockTimeoutError](./error/cql/enum.Error.html#variant.LockTimeoutError) will be returned if the database could not be locked within the
+///   [DEFAULT_LOCK_TIMEOUT](constant.DEFAULT_LOCK_TIMEOUT.html).
+///
+/// # Panics
+///
+/// Function should not panic.  If you get it to panic, please raise an issue in [github](https://github.com/AndrewSisley/CQLDb/issues).
+///
+/// # Examples
+/// ```
+/// # use cql_u64::U64;
+/// # const DATABASE_LOCATION: &str = "./.test_db";
+/// #
+/// # use std::error::Error;
+/// # use std::fs::remove_file;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/db"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/ax"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/key1_2"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/key2_3"));
+/// cql_db::create_db::<U64>(
+///     DATABASE_LOCATION,
+///     &[2, 5, 3, 2]
+/// )?;
+///
+/// cql_db::link_dimensions::<U64>(
+///     DATABASE_LOCATION,
+///     &[2, 4, 3],
+/// )?;
+///
+/// // Turbine 2 has data for Signal 4, but Turbine 1 has none for any Signal
+/// assert!(cql_db::is_linked::<U64>(DATABASE_LOCATION, &[2, 4])?);
+/// assert!(!cql_db::is_linked::<U64>(DATABASE_LOCATION, &[1, 4])?);
+///
+/// cql_db::unlink_dimensions::<U64>(
+///     DATABASE_LOCATION,
+///     &[2, 4],
+/// )?;
+///
+/// assert!(!cql_db::is_linked::<U64>(DATABASE_LOCATION, &[2, 4, 3])?);
+/// # Ok(())
+/// # }
+/// ```
+pub fn is_linked<TStore: CqlType>(db_location: &str, location: &[u64]) -> result::Result<bool> {
+    open_read_only::<TStore>(db_location)?.is_linked(location)
+}
+
+/// Returns the indexes of the next dimension that are linked beneath the given dimension indexes, in ascending order.  Does not validate given parameters.
+///
+/// For example, given `[2]` this will return each index of the 2nd dimension that has been [linked](fn.link_dimensions.html) to the 2nd element of the
+/// 1st.  The keys of each element are read from the key libraries in a single block, instead of each index being checked one by one.  Should any of
+/// the given dimension indexes not be linked nothing will be returned.
+///
+/// # Errors
+///
+/// Will return any [I/O errors](https://doc.rust-lang.org/nightly/std/io/enum.ErrorKind.html) encountered during the execution of the function.
+///
+/// # Panics
+///
+/// Function does not actively defend against panics, and may do so if given invalid parameters.
+///
+/// # Examples
+/// ```
+/// # use cql_u64::U64;
+/// # const DATABASE_LOCATION: &str = "./.test_db";
+/// #
+/// # use std::error::Error;
+/// # use std::fs::remove_file;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/db"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/ax"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/key1_2"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/key2_3"));
+/// cql_db::create_db::<U64>(
+///     DATABASE_LOCATION,
+///     &[2, 5, 3, 2]
+/// )?;
+///
+/// cql_db::link_dimensions::<U64>(DATABASE_LOCATION, &[2, 4, 3])?;
+/// cql_db::link_dimensions::<U64>(DATABASE_LOCATION, &[2, 1, 3])?;
+///
+/// let children: Vec<u64> = cql_db::linked_children_unchecked::<U64>(DATABASE_LOCATION, &[2])?.collect();
+///
+/// assert_eq!(children, vec![1, 4]);
+/// # Ok(())
+/// # }
+/// ```
+pub fn linked_children_unchecked<TStore: CqlType>(db_location: &str, location: &[u64]) -> io::Result<impl Iterator<Item = u64>> {
+    Database::<TStore>::open_read_only(FileStorage::new(db_location))?.linked_children_unchecked(location)
+}
+
+/// Returns the indexes of the next dimension that are linked beneath the given dimension indexes, in ascending order.
+///
+/// For example, given `[2]` this will return each index of the 2nd dimension that has been [linked](fn.link_dimensions.html) to the 2nd element of the
+/// 1st - such as the signals for which data exists for the 2nd turbine.  The keys of each element are read from the key libraries in a single block,
+/// instead of each index being checked one by one.  There is an [unchecked](fn.linked_children_unchecked.html) version of this function if required.
+///
+/// # Errors
+///
+/// Will return any [I/O errors](https://doc.rust-lang.org/nightly/std/io/enum.ErrorKind.html) encountered during the execution of the function.
+///
+/// Additionally, the following [Cql errors](./error/cql/enum.Error.html) may be returned:
+/// - A [DimensionsOutOfRangeError](./error/cql/enum.Error.html#variant.DimensionsOutOfRangeError) will be returned if the provided `location.len()` is less than 1,
+///   or greater than the number of dimensions in the database - 2.
+/// - An [IndexOutOfRangeError](./error/cql/enum.Error.html#variant.IndexOutOfRangeError) will be returned if any of the provided indexes in `location` are less than 1,
+///   or greater than that dimension's capacity.
+/// - An [ElementsNotLinkedError](./error/cql/enum.Error.html#variant.ElementsNotLinkedError) will be returned if the provided indexes in `location` have not
+///   been linked.
+/// - A [FormatVersionError](./error/cql/enum.Error.html#variant.FormatVersionError) will be returned if the database was created by a version of cql_db
+///   using a different [format version](constant.FORMAT_VERSION.html).
+/// - A [TypeMismatchError](./error/cql/enum.Error.html#variant.TypeMismatchError) will be returned if the database was created with a different
+///   storage type to `TStore`.
+/// - A [LockTimeoutError](./error/cql/enum.Error.html#variant.LockTimeoutError) will be returned if the database could not be locked within the
+///   [DEFAULT_LOCK_TIMEOUT](constant.DEFAULT_LOCK_TIMEOUT.html).
+///
+/// # Panics
+///
+/// Function should not panic.  If you get it to panic, please raise an issue in [github](https://github.com/AndrewSisley/CQLDb/issues).
+///
+/// # Examples
+/// ```
+/// # use cql_u64::U64;
+/// # const DATABASE_LOCATION: &str = "./.test_db";
+/// #
+/// # use std::error::Error;
+/// # use std::fs::remove_file;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/db"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/ax"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/key1_2"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/key2_3"));
+/// cql_db::create_db::<U64>(
+///     DATABASE_LOCATION,
+///     &[2, 5, 3, 2]
+/// )?;
+///
+/// cql_db::link_dimensions::<U64>(DATABASE_LOCATION, &[2, 4, 3])?;
+/// cql_db::link_dimensions::<U64>(DATABASE_LOCATION, &[2, 4, 1])?;
+/// cql_db::link_dimensions::<U64>(DATABASE_LOCATION, &[2, 1, 3])?;
+///
+/// let signals: Vec<u64> = cql_db::linked_children::<U64>(DATABASE_LOCATION, &[2])?.collect();
+/// let days: Vec<u64> = cql_db::linked_children::<U64>(DATABASE_LOCATION, &[2, 4])?.collect();
+///
+/// assert_eq!(signals, vec![1, 4]);
+/// assert_eq!(days, vec![1, 3]);
+/// # Ok(())
+/// # }
+/// ```
+pub fn linked_children<TStore: CqlType>(db_location: &str, location: &[u64]) -> result::Result<impl Iterator<Item = u64>> {
+    open_read_only::<TStore>(db_location)?.linked_children(location)
+}
+
+/// Returns the location of every row linked beneath the given dimension indexes, in ascending order.  Does not validate given parameters.
+///
+/// A row is the location of the first `N - 1` dimensions of an `N` dimensional database, the values of which may be read and written once it has been
+/// [linked](fn.link_dimensions.html).  The key libraries are traversed from the given location downwards, and an empty location will return every
+/// row in the database.  Should any of the given dimension indexes not be linked nothing will be returned.
+///
+/// # Errors
+///
+/// Will return any [I/O errors](https://doc.rust-lang.org/nightly/std/io/enum.ErrorKind.html) encountered during the execution of the function.
+///
+/// # Panics
+///
+/// Function does not actively defend against panics, and may do so if given invalid parameters.
+///
+/// # Examples
+/// ```
+/// # use cql_u64::U64;
+/// # const DATABASE_LOCATION: &str = "./.test_db";
+/// #
+/// # use std::error::Error;
+/// # use std::fs::remove_file;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/db"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/ax"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/key1_2"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/key2_3"));
+/// cql_db::create_db::<U64>(
+///     DATABASE_LOCATION,
+///     &[2, 5, 3, 2]
+/// )?;
+///
+/// cql_db::link_dimensions::<U64>(DATABASE_LOCATION, &[2, 4, 3])?;
+/// cql_db::link_dimensions::<U64>(DATABASE_LOCATION, &[1, 1, 2])?;
+///
+/// let rows: Vec<Vec<u64>> = cql_db::walk_links_unchecked::<U64>(DATABASE_LOCATION, &[])?.collect();
+///
+/// assert_eq!(rows, vec![vec![1, 1, 2], vec![2, 4, 3]]);
+/// # Ok(())
+/// # }
+/// ```
+pub fn walk_links_unchecked<TStore: CqlType>(db_location: &str, location: &[u64]) -> io::Result<impl Iterator<Item = Vec<u64>>> {
+    Database::<TStore>::open_read_only(FileStorage::new(db_location))?.walk_links_unchecked(location)
+}
+
+/// Returns the location of every row linked beneath the given dimension indexes, in ascending order.
+///
+/// A row is the location of the first `N - 1` dimensions of an `N` dimensional database, the values of which may be read and written once it has been
+/// [linked](fn.link_dimensions.html).  The key libraries are traversed from the given location downwards, and an empty location will return every
+/// row in the database.  To list only the indexes of the next dimension, use [linked_children](fn.linked_children.html).  There is an
+/// [unchecked](fn.walk_links_unchecked.html) version of this function if required.
+///
+/// # Errors
+///
+/// Will return any [I/O errors](https://doc.rust-lang.org/nightly/std/io/enum.ErrorKind.html) encountered during the execution of the function.
+///
+/// Additionally, the following [Cql errors](./error/cql/enum.Error.html) may be returned:
+/// - A [DimensionsOutOfRangeError](./error/cql/enum.Error.html#variant.DimensionsOutOfRangeError) will be returned if the provided `location.len()` is greater
+///   than the number of dimensions in the database - 1.
+/// - An [IndexOutOfRangeError](./error/cql/enum.Error.html#variant.IndexOutOfRangeError) will be returned if any of the provided indexes in `location` are less than 1,
+///   or greater than that dimension's capacity.
+/// - An [ElementsNotLinkedError](./error/cql/enum.Error.html#variant.ElementsNotLinkedError) will be returned if the provided indexes in `location` have not
+///   been linked.
+/// - A [FormatVersionError](./error/cql/enum.Error.html#variant.FormatVersionError) will be returned if the database was created by a version of cql_db
+///   using a different [format version](constant.FORMAT_VERSION.html).
+/// - A [TypeMismatchError](./error/cql/enum.Error.html#variant.TypeMismatchError) will be returned if the database was created with a different
+///   storage type to `TStore`.
+/// - A [LockTimeoutError](./error/cql/enum.Error.html#variant.LockTimeoutError) will be returned if the database could not be locked within the
+///   [DEFAULT_LOCK_TIMEOUT](constant.DEFAULT_LOCK_TIMEOUT.html).
+///
+/// # Panics
+///
+/// Function should not panic.  If you get it to panic, please raise an issue in [github](https://github.com/AndrewSisley/CQLDb/issues).
+///
+/// # Examples
+/// ```
+/// # use cql_u64::U64;
+/// # const DATABASE_LOCATION: &str = "./.test_db";
+/// #
+/// # use std::error::Error;
+/// # use std::fs::remove_file;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/db"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/ax"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/key1_2"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/key2_3"));
+/// cql_db::create_db::<U64>(
+///     DATABASE_LOCATION,
+///     &[2, 5, 3, 2]
+/// )?;
+///
+/// cql_db::link_dimensions::<U64>(DATABASE_LOCATION, &[2, 4, 3])?;
+/// cql_db::link_dimensions::<U64>(DATABASE_LOCATION, &[2, 1, 2])?;
+/// cql_db::link_dimensions::<U64>(DATABASE_LOCATION, &[1, 1, 2])?;
+///
+/// // every day for which data exists for Turbine 2
+/// for row in cql_db::walk_links::<U64>(DATABASE_LOCATION, &[2])? {
+///     cql_db::write_value::<U64>(DATABASE_LOCATION, &[row[0], row[1], row[2], 1], 5)?;
+/// }
+///
+/// let rows: Vec<Vec<u64>> = cql_db::walk_links::<U64>(DATABASE_LOCATION, &[2])?.collect();
+///
+/// assert_eq!(rows, vec![vec![2, 1, 2], vec![2, 4, 3]]);
+/// assert_eq!(cql_db::read_value::<U64>(DATABASE_LOCATION, &[2, 4, 3, 1])?, 5);
+/// assert_eq!(cql_db::read_value::<U64>(DATABASE_LOCATION, &[1, 1, 2, 1])?, 0);
+/// # Ok(())
+/// # }
+/// ```
+pub fn walk_links<TStore: CqlType>(db_location: &str, location: &[u64]) -> result::Result<impl Iterator<Item = Vec<u64>>> {
+    open_read_only::<TStore>(db_location)?.walk_links(location)
+}
+
+/// Writes the given value to the given location in the database.  Does not validate given parameters.
+///
+/// Can result in writing to an 'alternative' location if provided with an invalid location in the final dimension, other invalid dimensions will likely
+/// result in a panic.
+///
+/// # Errors
+///
+/// Will return any [I/O errors](https://doc.rust-lang.org/nightly/std/io/enum.ErrorKind.html) encountered during the execution of the function.
+/// If an error is returned it is not guaranteed that no bytes have been written to the requested location.
+///
+/// # Panics
+///
+/// Function does not actively defend against panics, and may do so if given invalid parameters.  If the function panics, no changes will have been made to the
+/// file system and the previous value should still be present.
+///
+/// # Examples
+/// ```
+/// # use cql_u64::U64;
+/// # const DATABASE_LOCATION: &str = "./.test_db";
+/// #
+/// # use std::error::Error;
+/// # use std::fs::remove_file;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/db"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/ax"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/key1_2"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/key2_3"));
+/// cql_db::create_db::<U64>(
+///     DATABASE_LOCATION,
+///     &[2, 5, 3, 2]
+/// )?;
+///
+/// // higher order elements must be linked before they can be writen to
+/// cql_db::link_dimensions::<U64>(
+///     DATABASE_LOCATION,
+///     &[2, 4, 3],
+/// )?;
+///
+/// // Write `5` to location `[2, 4, 3, 1]`
+/// cql_db::write_value_unchecked::<U64>(
+///     DATABASE_LOCATION,
+///     &[2, 4, 3, 1],
+///     5
+/// )?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn write_value_unchecked<TStore: CqlBufferWritable>(db_location: &str, location: &[u64], value: TStore::ValueType) -> io::Result<()> {
+    Database::<TStore>::open(FileStorage::new(db_location))?.write_value_unchecked(location, value)
+}
+
+/// Writes the given value to the given location in the database.
+///
+/// There is an [unchecked](fn.write_value_unchecked.html) version of this function if required.
+///
+/// # Errors
+///
+/// Will return any [I/O errors](https://doc.rust-lang.org/nightly/std/io/enum.ErrorKind.html) encountered during the execution of the function.
+/// If an error is returned it is not guaranteed that no bytes have been written to the requested location.
+///
+/// Additionally, the following [Cql errors](./error/cql/enum.Error.html) may be returned:
+/// - A [DimensionsOutOfRangeError](./error/cql/enum.Error.html#variant.DimensionsOutOfRangeError) will be returned if the provided `location.len()` is not equal
+///   to the number of dimensions in the database.
+/// - An [IndexOutOfRangeError](./error/cql/enum.Error.html#variant.IndexOutOfRangeError) will be returned if any of the provided indexes in `location` are less than 1,
+///   or greater than that dimension's capacity.
+/// - An [ElementsNotLinkedError](./error/cql/enum.Error.html#variant.ElementsNotLinkedError) will be returned if the provided elements have not been linked.
+/// - A [FormatVersionError](./error/cql/enum.Error.html#variant.FormatVersionError) will be returned if the database was created by a version of cql_db
+///   using a different [format version](constant.FORMAT_VERSION.html).
+/// - A [TypeMismatchError](./error/cql/enum.Error.html#variant.TypeMismatchError) will be returned if the database was created with a different
+///   storage type to `TStore`.
+/// - A [LockTimeoutError](./error/cql/enum.Error.html#variant.LockTimeoutError) will be returned if the database could not be locked within the
+///   [DEFAULT_LOCK_TIMEOUT](constant.DEFAULT_LOCK_TIMEOUT.html).
+/// ```
+/// # use cql_u64::U64;
+/// # use cql_db::error;
+/// # use cql_db::error::cql::Error;
+/// # const DATABASE_LOCATION: &str = "./.test_db";
+/// #
+/// # use std::error::Error as StdError;
+/// # use std::fs::remove_file;
+/// # fn main() -> Result<(), Box<dyn StdError>> {
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/db"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/ax"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/key1_2"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/key2_3"));
+/// // Create a database with a maximum capacity of `[2, 5, 3, 2]`
+/// cql_db::create_db::<U64>(
+///     DATABASE_LOCATION,
+///     &[2, 5, 6]
+/// )?;
+///
+/// cql_db::link_dimensions::<U64>(
+///     DATABASE_LOCATION,
+///     &[2, 4],
+/// )?;
+///
+/// let result = match cql_db::write_value::<U64>(
+///     DATABASE_LOCATION,
+///     // not enough dimensions
+///     &[2, 4],
+///     10
+/// ) {
+///     Err(error) => match error {
+///         error::Error::Cql(cql_error) => Some(cql_error),
+///         _ => None,
+///     }
+///     _ => None,
+/// };
+///
+/// assert_eq!(
+///     result.unwrap(),
+///     Error::DimensionsOutOfRangeError {
+///         requested: 2,
+///         min: 3, // 4 - 1
+///         max: 3, // 4 - 1
+///     }
+/// );
+///
+/// let result2 = match cql_db::write_value::<U64>(
+///     DATABASE_LOCATION,
+///     // dimension[2] index is too large
+///     &[2, 4, 7],
+///     10
+/// ) {
+///     Err(error) => match error {
+///         error::Error::Cql(cql_error) => Some(cql_error),
+///         _ => None,
+///     }
+///     _ => None,
+/// };
+///
+/// assert_eq!(
+///     result2.unwrap(),
+///     Error::IndexOutOfRangeError {
+///         dimension_index: 2,
+///         requested: 7,
+///         min: 1,
+///         max: 6,
+///     }
+/// );
+///
+/// let result3 = match cql_db::write_value::<U64>(
+///     DATABASE_LOCATION,
+///     // location[2, 3] has not been linked
+///     &[2, 3, 3],
+///     10
+/// ) {
+///     Err(error) => match error {
+///         error::Error::Cql(cql_error) => Some(cql_error),
+///         _ => None,
+///     }
+///     _ => None,
+/// };
+///
+/// assert_eq!(
+///     result3.unwrap(),
+///     Error::ElementsNotLinkedError {
+///         x_dimension: 0,
+///         x: 2,
+///         y_dimension: 1,
+///         y: 3,
+///     }
+/// );
+/// # Ok(())
+/// # }
+/// ```
+///
+/// # Panics
+///
+/// Function should not panic.  If you get it to panic, please raise an issue in [github](https://github.com/AndrewSisley/CQLDb/issues).
+///
+/// # Examples
+/// ```
+/// # use cql_u64::U64;
+/// # const DATABASE_LOCATION: &str = "./.test_db";
+/// #
+/// # use std::error::Error;
+/// # use std::fs::remove_file;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/db"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/ax"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/key1_2"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/key2_3"));
+/// cql_db::create_db::<U64>(
+///     DATABASE_LOCATION,
+///     &[2, 5, 3, 2]
+/// )?;
+///
+/// // higher order elements must be linked before they can be writen to
+/// cql_db::link_dimensions::<U64>(
+///     DATABASE_LOCATION,
+///     &[2, 4, 3],
+/// )?;
+///
+/// // Write `5` to location `[2, 4, 3, 1]`
+/// cql_db::write_value::<U64>(
+///     DATABASE_LOCATION,
+///     &[2, 4, 3, 1],
+///     5
+/// )?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn write_value<TStore: CqlBufferWritable>(db_location: &str, location: &[u64], value: TStore::ValueType) -> result::Result<()> {
+    open::<TStore>(db_location)?.write_value(location, value)
+}
+
+/// Writes the given values to the given locations in the database.  Does not validate given parameters.
+///
+/// Locations are resolved together, walking the key libraries once per shared prefix, and the values are then written in a single pass
+/// ordered by their position in the database file.  Should the same location be given more than once, the last value given for it will be stored.
+///
+/// Can result in writing to an 'alternative' location if provided with an invalid location in the final dimension, other invalid dimensions will likely
+/// result in a panic.
+///
+/// # Errors
+///
+/// Will return any [I/O errors](https://doc.rust-lang.org/nightly/std/io/enum.ErrorKind.html) encountered during the execution of the function.
+/// If an error is returned it is not guaranteed that no values have been written to the database.
+///
+/// # Panics
+///
+/// Function does not actively defend against panics, and may do so if given invalid parameters.  If the function panics, no changes will have been made to the
+/// file system.
+///
+/// # Examples
+/// ```
+/// # use cql_u64::U64;
+/// # const DATABASE_LOCATION: &str = "./.test_db";
+/// #
+/// # use std::error::Error;
+/// # use std::fs::remove_file;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/db"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/ax"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/key1_2"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/key2_3"));
+/// cql_db::create_db::<U64>(
+///     DATABASE_LOCATION,
+///     &[2, 5, 3, 2]
+/// )?;
+///
+/// cql_db::link_dimensions::<U64>(
+///     DATABASE_LOCATION,
+///     &[2, 4, 3],
+/// )?;
+///
+/// // Write `5` to location `[2, 4, 3, 1]`, and `6` to `[2, 4, 3, 2]`
+/// cql_db::write_values_unchecked::<U64>(
+///     DATABASE_LOCATION,
+///     &[
+///         (&[2, 4, 3, 1], 5),
+///         (&[2, 4, 3, 2], 6),
+///     ]
+/// )?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn write_values_unchecked<TStore: CqlBufferWritable>(db_location: &str, values: &[(&[u64], TStore::ValueType)]) -> io::Result<()>
+    where TStore::ValueType: Clone {
+    Database::<TStore>::open(FileStorage::new(db_location))?.write_values_unchecked(values)
+}
+
+/// Writes the given values to the given locations in the database.
+///
+/// Locations are resolved together, walking the key libraries once per shared prefix, and the values are then written in a single pass
+/// ordered by their position in the database file.  Should the same location be given more than once, the last value given for it will be stored.
+/// There is an [unchecked](fn.write_values_unchecked.html) version of this function if required.
+///
+/// # Errors
+///
+/// Will return any [I/O errors](https://doc.rust-lang.org/nightly/std/io/enum.ErrorKind.html) encountered during the execution of the function.
+/// If an error is returned it is not guaranteed that no values have been written to the database.
+///
+/// Additionally, the following [Cql errors](./error/cql/enum.Error.html) may be returned.  All locations are validated before any value is written,
+/// so if one of these is returned no changes will have been made to the database:
+/// - A [DimensionsOutOfRangeError](./error/cql/enum.Error.html#variant.DimensionsOutOfRangeError) will be returned if any of the provided locations'
+///   `.len()` is not equal to the number of dimensions in the database.
+/// - An [IndexOutOfRangeError](./error/cql/enum.Error.html#variant.IndexOutOfRangeError) will be returned if any of the provided indexes in any of the
+///   locations are less than 1, or greater than that dimension's capacity.
+/// - An [ElementsNotLinkedError](./error/cql/enum.Error.html#variant.ElementsNotLinkedError) will be returned if the elements of any of the provided
+///   locations have not been linked.
+/// - A [FormatVersionError](./error/cql/enum.Error.html#variant.FormatVersionError) will be returned if the database was created by a version of cql_db
+///   using a different [format version](constant.FORMAT_VERSION.html).
+/// - A [TypeMismatchError](./error/cql/enum.Error.html#variant.TypeMismatchError) will be returned if the database was created with a different
+///   storage type to `TStore`.
+/// - A [LockTimeoutError](./error/cql/enum.Error.html#variant.LockTimeoutError) will be returned if the database could not be locked within the
+///   [DEFAULT_LOCK_TIMEOUT](constant.DEFAULT_LOCK_TIMEOUT.html).
+/// ```
+/// # use cql_u64::U64;
+/// # use cql_db::error;
+/// # use cql_db::error::cql::Error;
+/// # const DATABASE_LOCATION: &str = "./.test_db";
+/// #
+/// # use std::error::Error as StdError;
+/// # use std::fs::remove_file;
+/// # fn main() -> Result<(), Box<dyn StdError>> {
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/db"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/ax"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/key1_2"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/key2_3"));
+/// cql_db::create_db::<U64>(
+///     DATABASE_LOCATION,
+///     &[2, 5, 6]
+/// )?;
+///
+/// cql_db::link_dimensions::<U64>(
+///     DATABASE_LOCATION,
+///     &[2, 4],
+/// )?;
+///
+/// let result = match cql_db::write_values::<U64>(
+///     DATABASE_LOCATION,
+///     &[
+///         (&[2, 4, 1], 10),
+///         // location[2, 3] has not been linked
+///         (&[2, 3, 3], 10),
+///     ]
+/// ) {
+///     Err(error::Error::Cql(cql_error)) => Some(cql_error),
+///     _ => None,
+/// };
+///
+/// assert_eq!(
+///     result.unwrap(),
+///     Error::ElementsNotLinkedError {
+///         x_dimension: 0,
+///         x: 2,
+///         y_dimension: 1,
+///         y: 3,
+///     }
+/// );
+///
+/// // the valid location will not have been written to
+/// assert_eq!(cql_db::read_value::<U64>(DATABASE_LOCATION, &[2, 4, 1])?, 0);
+/// # Ok(())
+/// # }
+/// ```
+///
+/// # Panics
+///
+/// Function should not panic.  If you get it to panic, please raise an issue in [github](https://github.com/AndrewSisley/CQLDb/issues).
+///
+/// # Examples
+/// ```
+/// # use cql_u64::U64;
+/// # const DATABASE_LOCATION: &str = "./.test_db";
+/// #
+/// # use std::error::Error;
+/// # use std::fs::remove_file;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/db"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/ax"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/key1_2"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/key2_3"));
+/// cql_db::create_db::<U64>(
+///     DATABASE_LOCATION,
+///     &[2, 5, 3, 2]
+/// )?;
+///
+/// cql_db::link_dimensions::<U64>(
+///     DATABASE_LOCATION,
+///     &[2, 4, 3],
+/// )?;
+///
+/// cql_db::link_dimensions::<U64>(
+///     DATABASE_LOCATION,
+///     &[1, 1, 1],
+/// )?;
+///
+/// cql_db::write_values::<U64>(
+///     DATABASE_LOCATION,
+///     &[
+///         (&[2, 4, 3, 1], 5),
+///         (&[1, 1, 1, 2], 6),
+///         (&[2, 4, 3, 2], 7),
+///     ]
+/// )?;
+///
+/// assert_eq!(cql_db::read_value::<U64>(DATABASE_LOCATION, &[1, 1, 1, 2])?, 6);
+/// # Ok(())
+/// # }
+/// ```
+pub fn write_values<TStore: CqlBufferWritable>(db_location: &str, values: &[(&[u64], TStore::ValueType)]) -> result::Result<()>
+    where TStore::ValueType: Clone {
+    open::<TStore>(db_location)?.write_values(values)
+}
+
+/// Reads the value at the given location from the database.  Does not validate given parameters.
+///
+/// Can result in reading from an 'alternative' location if provided with an invalid location in the final dimension, other invalid dimensions will likely
+/// result in a panic.
+///
+/// # Errors
+///
+/// Will return any [I/O errors](https://doc.rust-lang.org/nightly/std/io/enum.ErrorKind.html) encountered during the execution of the function.
+///
+/// # Panics
+///
+/// Function does not actively defend against panics, and may do so if given invalid parameters.
+///
+/// # Examples
+/// ```
+/// # use cql_u64::U64;
+/// # const DATABASE_LOCATION: &str = "./.test_db";
+/// #
+/// # use std::error::Error;
+/// # use std::fs::remove_file;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/db"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/ax"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/key1_2"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/key2_3"));
+/// let point = [2, 4, 3, 1];
+/// let value = 5;
+///
+/// cql_db::create_db::<U64>(
+///     DATABASE_LOCATION,
+///     &[2, 5, 3, 2]
+/// )?;
+///
+/// // higher order elements must be linked before they can be read from
+/// cql_db::link_dimensions::<U64>(
+///     DATABASE_LOCATION,
+///     &point[0..3],
+/// )?;
+///
+/// // Read the default value from point `point`
+/// let result1 = cql_db::read_value_unchecked::<U64>(
+///     DATABASE_LOCATION,
+///     &point
+/// )?;
+///
+/// assert_eq!(0, result1);
+///
+/// // Write `value` to location `[2, 4, 3, 1]`
+/// cql_db::write_value::<U64>(
+///     DATABASE_LOCATION,
+///     &point,
+///     value
+/// )?;
+///
+/// // Read the now-populated value from point `point`
+/// let result2 = cql_db::read_value_unchecked::<U64>(
+///     DATABASE_LOCATION,
+///     &point
+/// )?;
+///
+/// assert_eq!(value, result2);
+/// # Ok(())
+/// # }
+/// ```
+pub fn read_value_unchecked<TStore: CqlRangeReadable>(db_location: &str, location: &[u64]) -> io::Result<TStore::ValueType> {
+    Database::<TStore>::open_read_only(FileStorage::new(db_location))?.read_value_unchecked(location)
+}
+
+/// Reads the value at the given location from the database.
+///
+/// There is an [unchecked](fn.read_value_unchecked.html) version of this function if required.
+///
+/// # Errors
+///
+/// Will return any [I/O errors](https://doc.rust-lang.org/nightly/std/io/enum.ErrorKind.html) encountered during the execution of the function.
+///
+/// Additionally, the following [Cql errors](./error/cql/enum.Error.html) may be returned:
+/// - A [DimensionsOutOfRangeError](./error/cql/enum.Error.html#variant.DimensionsOutOfRangeError) will be returned if the provided `location.len()` is not equal
+///   to the number of dimensions in the database.
+/// - An [IndexOutOfRangeError](./error/cql/enum.Error.html#variant.IndexOutOfRangeError) will be returned if any of the provided indexes in `location` are less than 1,
+///   or greater than that dimension's capacity.
+/// - An [ElementsNotLinkedError](./error/cql/enum.Error.html#variant.ElementsNotLinkedError) will be returned if the provided elements have not been linked.
+/// - A [FormatVersionError](./error/cql/enum.Error.html#variant.FormatVersionError) will be returned if the database was created by a version of cql_db
+///   using a different [format version](constant.FORMAT_VERSION.html).
+/// - A [TypeMismatchError](./error/cql/enum.Error.html#variant.TypeMismatchError) will be returned if the database was created with a different
+///   storage type to `TStore`.
+/// - A [LockTimeoutError](./error/cql/enum.Error.html#variant.LockTimeoutError) will be returned if the database could not be locked within the
+///   [DEFAULT_LOCK_TIMEOUT](constant.DEFAULT_LOCK_TIMEOUT.html).
+/// ```
+/// # use cql_u64::U64;
+/// # use cql_db::error;
+/// # use cql_db::error::cql::Error;
+/// # const DATABASE_LOCATION: &str = "./.test_db";
+/// #
+/// # use std::error::Error as StdError;
+/// # use std::fs::remove_file;
+/// # fn main() -> Result<(), Box<dyn StdError>> {
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/db"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/ax"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/key1_2"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/key2_3"));
+/// // Create a database with a maximum capacity of `[2, 5, 3, 2]`
+/// cql_db::create_db::<U64>(
+///     DATABASE_LOCATION,
+///     &[2, 5, 6]
+/// )?;
+///
+/// cql_db::link_dimensions::<U64>(
+///     DATABASE_LOCATION,
+///     &[2, 4],
+/// )?;
+///
+/// let result = match cql_db::read_value::<U64>(
+///     DATABASE_LOCATION,
+///     // not enough dimensions
+///     &[2, 4]
+/// ) {
+///     Err(error) => match error {
+///         error::Error::Cql(cql_error) => Some(cql_error),
+///         _ => None,
+///     }
+///     _ => None,
+/// };
+///
+/// assert_eq!(
+///     result.unwrap(),
+///     Error::DimensionsOutOfRangeError {
+///         requested: 2,
+///         min: 3, // 4 - 1
+///         max: 3, // 4 - 1
+///     }
+/// );
+///
+/// let result2 = match cql_db::read_value::<U64>(
+///     DATABASE_LOCATION,
+///     // dimension[2] index is too large
+///     &[2, 4, 7]
+/// ) {
+///     Err(error) => match error {
+///         error::Error::Cql(cql_error) => Some(cql_error),
+///         _ => None,
+///     }
+///     _ => None,
+/// };
+///
+/// assert_eq!(
+///     result2.unwrap(),
+///     Error::IndexOutOfRangeError {
+///         dimension_index: 2,
+///         requested: 7,
+///         min: 1,
+///         max: 6,
+///     }
+/// );
+///
+/// let result3 = match cql_db::read_value::<U64>(
+///     DATABASE_LOCATION,
+///     // location[2, 3] has not been linked
+///     &[2, 3, 3]
+/// ) {
+///     Err(error) => match error {
+///         error::Error::Cql(cql_error) => Some(cql_error),
+///         _ => None,
+///     }
+///     _ => None,
+/// };
+///
+/// assert_eq!(
+///     result3.unwrap(),
+///     Error::ElementsNotLinkedError {
+///         x_dimension: 0,
+///         x: 2,
+///         y_dimension: 1,
+///         y: 3,
+///     }
+/// );
+/// # Ok(())
+/// # }
+/// ```
+///
+/// # Panics
+///
+/// Function should not panic.  If you get it to panic, please raise an issue in [github](https://github.com/AndrewSisley/CQLDb/issues).
+///
+/// # Examples
+/// ```
+/// # use cql_u64::U64;
+/// # const DATABASE_LOCATION: &str = "./.test_db";
+/// #
+/// # use std::error::Error;
+/// # use std::fs::remove_file;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/db"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/ax"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/key1_2"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/key2_3"));
+/// let point = [2, 4, 3, 1];
+/// let value = 5;
+///
+/// cql_db::create_db::<U64>(
+///     DATABASE_LOCATION,
+///     &[2, 5, 3, 2]
+/// )?;
+///
+/// // higher order elements must be linked before they can be read from
+/// cql_db::link_dimensions::<U64>(
+///     DATABASE_LOCATION,
+///     &point[0..3],
+/// )?;
+///
+/// // Read the default value from point `point`
+/// let result1 = cql_db::read_value::<U64>(
+///     DATABASE_LOCATION,
+///     &point
+/// )?;
+///
+/// assert_eq!(0, result1);
+///
+/// // Write `value` to location `[2, 4, 3, 1]`
+/// cql_db::write_value::<U64>(
+///     DATABASE_LOCATION,
+///     &point,
+///     value
+/// )?;
+///
+/// // Read the now-populated value from point `point`
+/// let result2 = cql_db::read_value::<U64>(
+///     DATABASE_LOCATION,
+///     &point
+/// )?;
+///
+/// assert_eq!(value, result2);
+/// # Ok(())
+/// # }
+/// ```
+pub fn read_value<TStore: CqlRangeReadable>(db_location: &str, location: &[u64]) -> result::Result<TStore::ValueType> {
+    open_read_only::<TStore>(db_location)?.read_value(location)
+}
+
+/// Returns true if a value has been written to the given location.  Does not validate given parameters.
+///
+/// Locations that have not been written to read as the default value of the type, for example `0` for a [U64](https://docs.rs/cql_u64/0.3/cql_u64/struct.U64.html),
+/// which this allows to be told apart from a written value that happens to equal the default.  Values cleared by
+/// [unlinking](fn.unlink_dimensions.html) the location are no longer considered written.  Values in databases created by versions of this crate that did
+/// not record writes are considered written should they not be stored as zeroed bytes.
+///
+/// # Errors
+///
+/// Will return any [I/O errors](https://doc.rust-lang.org/nightly/std/io/enum.ErrorKind.html) encountered during the execution of the function.
+///
+/// # Panics
+///
+/// Function does not actively defend against panics, and may do so if given invalid parameters.
+///
+/// # Examples
+/// ```
+/// # use cql_u64::U64;
+/// # const DATABASE_LOCATION: &str = "./.test_db";
+/// #
+/// # use std::error::Error;
+/// # use std::fs::remove_file;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/db"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/ax"));
+/// cql_db::create_db::<U64>(
+///     DATABASE_LOCATION,
+///     &[3]
+/// )?;
+///
+/// cql_db::write_value::<U64>(
+///     DATABASE_LOCATION,
+///     &[2],
+///     0
+/// )?;
+///
+/// assert!(!cql_db::is_written_unchecked::<U64>(DATABASE_LOCATION, &[1])?);
+/// assert!(cql_db::is_written_unchecked::<U64>(DATABASE_LOCATION, &[2])?);
+/// # Ok(())
+/// # }
+/// ```
+pub fn is_written_unchecked<TStore: CqlType>(db_location: &str, location: &[u64]) -> io::Result<bool> {
+    Database::<TStore>::open_read_only(FileStorage::new(db_location))?.is_written_unchecked(location)
+}
+
+/// Returns true if a value has been written to the given location.
+///
+/// Locations that have not been written to read as the default value of the type, for example `0` for a [U64](https://docs.rs/cql_u64/0.3/cql_u64/struct.U64.html),
+/// which this allows to be told apart from a written value that happens to equal the default.  Locations whose dimensions are not linked have never been
+/// written to, and values cleared by [unlinking](fn.unlink_dimensions.html) the location are no longer considered written.  Values in databases created by
+/// versions of this crate that did not record writes are considered written should they not be stored as zeroed bytes.  There is an
+/// [unchecked](fn.is_written_unchecked.html) version of this function if required.
+///
+/// # Errors
+///
+/// Will return any [I/O errors](https://doc.rust-lang.org/nightly/std/io/enum.ErrorKind.html) encountered during the execution of the function.
+///
+/// Additionally, the following [Cql errors](./error/cql/enum.Error.html) may be returned:
+/// - A [DimensionsOutOfRangeError](./error/cql/enum.Error.html#variant.DimensionsOutOfRangeError) will be returned if the provided `location.len()` is not
+///   equal to the number of dimensions in the database.
+/// - An [IndexOutOfRangeError](./error/cql/enum.Error.html#variant.IndexOutOfRangeError) will be returned if any of the provided indexes in `location` are less than 1,
+///   or greater than that dimension's capacity.
+/// - A [FormatVersionError](./error/cql/enum.Error.html#variant.FormatVersionError) will be returned if the database was created by a version of cql_db
+///   using a different [format version](constant.FORMAT_VERSION.html).
+/// - A [TypeMismatchError](./error/cql/enum.Error.html#variant.TypeMismatchError) will be returned if the database was created with a different
+///   storage type to `TStore`.
+/// - A [LockTimeoutError](./error/cql/enum.Error.html#variant.LockTimeoutError) will be returned if the database could not be locked within the
+///   [DEFAULT_LOCK_TIMEOUT](constant.DEFAULT_LOCK_TIMEOUT.html).
+///
+/// # Panics
+///
+/// Function should not panic.  If you get it to panic, please raise an issue in [github](https://github.com/AndrewSisley/CQLDb/issues).
+///
+/// # Examples
+/// ```
+/// # use cql_u64::U64;
+/// # const DATABASE_LOCATION: &str = "./.test_db";
+/// #
+/// # use std::error::Error;
+/// # use std::fs::remove_file;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/db"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/ax"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/key1_2"));
+/// cql_db::create_db::<U64>(
+///     DATABASE_LOCATION,
+///     &[2, 5, 3]
+/// )?;
+///
+/// cql_db::link_dimensions::<U64>(
+///     DATABASE_LOCATION,
+///     &[2, 4],
+/// )?;
+///
+/// cql_db::write_value::<U64>(
+///     DATABASE_LOCATION,
+///     &[2, 4, 1],
+///     0
+/// )?;
+///
+/// // both read as 0, but only one of them has been written to
+/// assert_eq!(cql_db::read_value::<U64>(DATABASE_LOCATION, &[2, 4, 1])?, 0);
+/// assert_eq!(cql_db::read_value::<U64>(DATABASE_LOCATION, &[2, 4, 2])?, 0);
+///
+/// assert!(cql_db::is_written::<U64>(DATABASE_LOCATION, &[2, 4, 1])?);
+/// assert!(!cql_db::is_written::<U64>(DATABASE_LOCATION, &[2, 4, 2])?);
+/// assert!(!cql_db::is_written::<U64>(DATABASE_LOCATION, &[1, 4, 1])?);
+/// # Ok(())
+/// # }
+/// ```
+pub fn is_written<TStore: CqlType>(db_location: &str, location: &[u64]) -> result::Result<bool> {
+    open_read_only::<TStore>(db_location)?.is_written(location)
+}
+
+/// Reads `n_values` from the given location onward into the given stream.  Does not validate given parameters.
+///
+/// Can result in reading from an 'alternative' location if provided with an invalid location in the final dimension, other invalid dimensions will likely
+/// result in a panic.
+///
+/// # Errors
+///
+/// Will return any [I/O errors](https://doc.rust-lang.org/nightly/std/io/enum.ErrorKind.html) encountered during the execution of the function.  If an error
+/// is returned, it may be that values have already been written to the stream.
+///
+/// # Panics
+///
+/// Function does not actively defend against panics, and may do so if given invalid parameters.  If the function panics it may be that values have
+/// already been written to the stream.
+///
+/// # Examples
+/// ```
+/// # use std::io::{ Cursor, SeekFrom, Seek };
+/// # const DATABASE_LOCATION: &str = "./.test_db";
+/// #
+/// use cql_u64::{ U64, unpack_stream };
+///
+/// # use std::error::Error;
+/// # use std::fs::remove_file;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/db"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/ax"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/key1_2"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/key2_3"));
+/// let base_point = [1, 1, 1, 2];
+/// const N_VALUES_TO_READ: usize = 3;
+/// let value1 = 42;
+/// let value2 = 16;
+/// let value3 = 80;
+///
+/// cql_db::create_db::<U64>(
+///     DATABASE_LOCATION,
+///     &[1, 1, 1, 10]
+/// )?;
+///
+/// cql_db::link_dimensions::<U64>(
+///     DATABASE_LOCATION,
+///     &base_point[0..3]
+/// )?;
+///
+/// cql_db::write_value::<U64>(
+///     DATABASE_LOCATION,
+///     &base_point,
+///     value1
+/// )?;
+///
+/// cql_db::write_value::<U64>(
+///     DATABASE_LOCATION,
+///     &[1, 1, 1, base_point[3] + 1],
+///     value2
+/// )?;
+///
+/// cql_db::write_value::<U64>(
+///     DATABASE_LOCATION,
+///     &[1, 1, 1, base_point[3] + 2],
+///     value3
+/// )?;
+///
+/// let mut result = [0; N_VALUES_TO_READ];
+/// let mut stream = Cursor::new(Vec::new());
+///
+/// cql_db::read_to_stream_unchecked::<U64>(
+///     DATABASE_LOCATION,
+///     &mut stream,
+///     &base_point,
+///     N_VALUES_TO_READ as u64
+/// )?;
+///
+/// stream.seek(SeekFrom::Start(0));
+///
+/// unpack_stream(&mut stream, N_VALUES_TO_READ, |idx, value| {
+///     result[idx] = value
+/// })?;
+///
+/// assert_eq!(result[0], value1);
+/// assert_eq!(result[1], value2);
+/// assert_eq!(result[2], value3);
+/// # Ok(())
+/// # }
+/// ```
+pub fn read_to_stream_unchecked<TStore: CqlBufferStreamReadable>(db_location: &str, stream: &mut dyn Write, location: &[u64], n_values: u64) -> io::Result<()> {
+    Database::<TStore>::open_read_only(FileStorage::new(db_location))?.read_to_stream_unchecked(stream, location, n_values)
+}
+
+/// Reads `n_values` from the given location onward into the given stream.
+///
+/// There is an [unchecked](fn.read_to_stream_unchecked.html) version of this function if required.
+///
+/// # Errors
+///
+/// Will return any [I/O errors](https://doc.rust-lang.org/nightly/std/io/enum.ErrorKind.html) encountered during the execution of the function.
+///
+/// Additionally, the following [Cql errors](./error/cql/enum.Error.html) may be returned:
+/// - A [DimensionsOutOfRangeError](./error/cql/enum.Error.html#variant.DimensionsOutOfRangeError) will be returned if the provided `location.len()` is not equal
+///   to the number of dimensions in the database.
+/// - An [IndexOutOfRangeError](./error/cql/enum.Error.html#variant.IndexOutOfRangeError) will be returned if any of the provided indexes in `location` are less than 1,
+///   or greater than that dimension's capacity, this includes the final requested point - for example if `n_values` is too large.
+/// - An [ElementsNotLinkedError](./error/cql/enum.Error.html#variant.ElementsNotLinkedError) will be returned if the provided elements have not been linked.
+/// - A [FormatVersionError](./error/cql/enum.Error.html#variant.FormatVersionError) will be returned if the database was created by a version of cql_db
+///   using a different [format version](constant.FORMAT_VERSION.html).
+/// - A [TypeMismatchError](./error/cql/enum.Error.html#variant.TypeMismatchError) will be returned if the database was created with a different
+///   storage type to `TStore`.
+/// - A [LockTimeoutError](./error/cql/enum.Error.html#variant.LockTimeoutError) will be returned if the database could not be locked within the
+///   [DEFAULT_LOCK_TIMEOUT](constant.DEFAULT_LOCK_TIMEOUT.html).
+/// ```
+/// # use cql_u64::U64;
+/// # use cql_db::error;
+/// # use cql_db::error::cql::Error;
+/// # use std::io::{ Cursor };
+/// # const DATABASE_LOCATION: &str = "./.test_db";
+/// #
+/// # use std::error::Error as StdError;
+/// # use std::fs::remove_file;
+/// # fn main() -> Result<(), Box<dyn StdError>> {
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/db"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/ax"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/key1_2"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/key2_3"));
+/// // Create a database with a maximum capacity of `[2, 5, 3, 2]`
+/// cql_db::create_db::<U64>(
+///     DATABASE_LOCATION,
+///     &[2, 5, 6]
+/// )?;
+///
+/// cql_db::link_dimensions::<U64>(
+///     DATABASE_LOCATION,
+///     &[2, 4],
+/// )?;
+///
+/// let mut stream = Cursor::new(Vec::new());
+///
+/// let result = match cql_db::read_to_stream::<U64>(
+///     DATABASE_LOCATION,
+///     &mut stream,
+///     // not enough dimensions
+///     &[2, 4],
+///     2
+/// ) {
+///     Err(error) => match error {
+///         error::Error::Cql(cql_error) => Some(cql_error),
+///         _ => None,
+///     }
+///     _ => None,
+/// };
+///
+/// assert_eq!(
+///     result.unwrap(),
+///     Error::DimensionsOutOfRangeError {
+///         requested: 2,
+///         min: 3, // 4 - 1
+///         max: 3, // 4 - 1
+///     }
+/// );
+///
+/// let result2 = match cql_db::read_to_stream::<U64>(
+///     DATABASE_LOCATION,
+///     &mut stream,
+///     // dimension[2] index (6) + n_values (2) - 1 is too large (7)
+///     &[2, 4, 6],
+///     2
+/// ) {
+///     Err(error) => match error {
+///         error::Error::Cql(cql_error) => Some(cql_error),
+///         _ => None,
+///     }
+///     _ => None,
+/// };
+///
+/// assert_eq!(
+///     result2.unwrap(),
+///     Error::IndexOutOfRangeError {
+///         dimension_index: 2,
+///         requested: 7,
+///         min: 1,
+///         max: 6,
+///     }
+/// );
+///
+/// let result3 = match cql_db::read_to_stream::<U64>(
+///     DATABASE_LOCATION,
+///     &mut stream,
+///     // location[2, 3] has not been linked
+///     &[2, 3, 3],
+///     2
+/// ) {
+///     Err(error) => match error {
+///         error::Error::Cql(cql_error) => Some(cql_error),
+///         _ => None,
+///     }
+///     _ => None,
+/// };
+///
+/// assert_eq!(
+///     result3.unwrap(),
+///     Error::ElementsNotLinkedError {
+///         x_dimension: 0,
+///         x: 2,
+///         y_dimension: 1,
+///         y: 3,
+///     }
+/// );
+/// # Ok(())
+/// # }
+/// ```
+///
+/// # Panics
+///
+/// Function should not panic.  If you get it to panic, please raise an issue in [github](https://github.com/AndrewSisley/CQLDb/issues).
+///
+/// # Examples
+/// ```
+/// # use std::io::{ Cursor, SeekFrom, Seek };
+/// # const DATABASE_LOCATION: &str = "./.test_db";
+/// #
+/// use cql_u64::{ U64, unpack_stream };
+///
+/// # use std::error::Error;
+/// # use std::fs::remove_file;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/db"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/ax"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/key1_2"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/key2_3"));
+/// let base_point = [1, 1, 1, 2];
+/// const N_VALUES_TO_READ: usize = 3;
+/// let value1 = 42;
+/// let value2 = 16;
+/// let value3 = 80;
+///
+/// cql_db::create_db::<U64>(
+///     DATABASE_LOCATION,
+///     &[1, 1, 1, 10]
+/// )?;
+///
+/// cql_db::link_dimensions::<U64>(
+///     DATABASE_LOCATION,
+///     &base_point[0..3]
+/// )?;
+///
+/// cql_db::write_value::<U64>(
+///     DATABASE_LOCATION,
+///     &base_point,
+///     value1
+/// )?;
+///
+/// cql_db::write_value::<U64>(
+///     DATABASE_LOCATION,
+///     &[1, 1, 1, base_point[3] + 1],
+///     value2
+/// )?;
+///
+/// cql_db::write_value::<U64>(
+///     DATABASE_LOCATION,
+///     &[1, 1, 1, base_point[3] + 2],
+///     value3
+/// )?;
+///
+/// let mut result = [0; N_VALUES_TO_READ];
+/// let mut stream = Cursor::new(Vec::new());
+///
+/// cql_db::read_to_stream::<U64>(
+///     DATABASE_LOCATION,
+///     &mut stream,
+///     &base_point,
+///     N_VALUES_TO_READ as u64
+/// )?;
+///
+/// stream.seek(SeekFrom::Start(0));
+///
+/// unpack_stream(&mut stream, N_VALUES_TO_READ, |idx, value| {
+///     result[idx] = value
+/// })?;
+///
+/// assert_eq!(result[0], value1);
+/// assert_eq!(result[1], value2);
+/// assert_eq!(result[2], value3);
+/// # Ok(())
+/// # }
+/// ```
+pub fn read_to_stream<TStore: CqlBufferStreamReadable>(db_location: &str, stream: &mut dyn Write, location: &[u64], n_values: u64) -> result::Result<()> {
+    open_read_only::<TStore>(db_location)?.read_to_stream(stream, location, n_values)
+}
+
+/// Reads `n_values` from the given location onward into the given stream, `stride` values apart, in the given direction.  Does not validate given
+/// parameters.
+///
+/// Values are read along the final dimension, starting at the given location and skipping `stride - 1` values after each value read - a stride of `1`
+/// reads the same values as [read_to_stream](fn.read_to_stream.html).  Should the [direction](enum.Direction.html) be reversed, the same values are
+/// written to the stream latest first.
+///
+/// Values close together are picked out of blocks read from the database file in one go, whilst values further apart are read on their own, so that
+/// large strides do not read the values between those requested.
+///
+/// # Errors
+///
+/// Will return any [I/O errors](https://doc.rust-lang.org/nightly/std/io/enum.ErrorKind.html) encountered during the execution of the function.
+///
+/// # Panics
+///
+/// Function does not actively defend against panics, and may do so if given invalid parameters.
+///
+/// # Examples
+/// ```
+/// # use std::io::{ Cursor, SeekFrom, Seek };
+/// # const DATABASE_LOCATION: &str = "./.test_db";
+/// #
+/// use cql_u64::{ U64, unpack_stream };
+/// use cql_db::Direction;
+///
+/// # use std::error::Error;
+/// # use std::fs::remove_file;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/db"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/ax"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/key1_2"));
+/// cql_db::create_db::<U64>(
+///     DATABASE_LOCATION,
+///     &[2, 2, 10]
+/// )?;
+///
+/// cql_db::link_dimensions::<U64>(DATABASE_LOCATION, &[1, 2])?;
+/// for value in 1..=10 {
+///     cql_db::write_value::<U64>(DATABASE_LOCATION, &[1, 2, value], value)?;
+/// }
+///
+/// let mut stream = Cursor::new(Vec::new());
+/// cql_db::read_strided_to_stream_unchecked::<U64>(DATABASE_LOCATION, &mut stream, &[1, 2, 2], 3, 4, Direction::Forward)?;
+///
+/// let mut result = Vec::new();
+/// stream.seek(SeekFrom::Start(0));
+/// unpack_stream(&mut stream, 3, |_, value| {
+///     result.push(value)
+/// })?;
+///
+/// assert_eq!(result, vec![2, 6, 10]);
+/// # Ok(())
+/// # }
+/// ```
+pub fn read_strided_to_stream_unchecked<TStore: CqlBufferStreamReadable>(
+    db_location: &str,
+    stream: &mut dyn Write,
+    location: &[u64],
+    n_values: u64,
+    stride: u64,
+    direction: Direction
+) -> io::Result<()> {
+    Database::<TStore>::open_read_only(FileStorage::new(db_location))?.read_strided_to_stream_unchecked(stream, location, n_values, stride, direction)
+}
+
+/// Reads `n_values` from the given location onward into the given stream, `stride` values apart, in the given direction.
+///
+/// Values are read along the final dimension, starting at the given location and skipping `stride - 1` values after each value read - a stride of `1`
+/// reads the same values as [read_to_stream](fn.read_to_stream.html).  Should the [direction](enum.Direction.html) be reversed, the same values are
+/// written to the stream latest first, allowing a time series to be downsampled, or read from its most recent values backwards.  There is an
+/// [unchecked](fn.read_strided_to_stream_unchecked.html) version of this function if required.
+///
+/// Values close together are picked out of blocks read from the database file in one go, whilst values further apart are read on their own, so that
+/// large strides do not read the values between those requested.
+///
+/// # Errors
+///
+/// Will return any [I/O errors](https://doc.rust-lang.org/nightly/std/io/enum.ErrorKind.html) encountered during the execution of the function.
+///
+/// Additionally, the following [Cql errors](./error/cql/enum.Error.html) may be returned:
+/// - A [DimensionsOutOfRangeError](./error/cql/enum.Error.html#variant.DimensionsOutOfRangeError) will be returned if the provided `location.len()` is not
+///   equal to the number of dimensions in the database.
+/// - An [IndexOutOfRangeError](./error/cql/enum.Error.html#variant.IndexOutOfRangeError) will be returned if any of the provided indexes in `location` are less than 1,
+///   or greater than that dimension's capacity, or if the last of the values to be read is beyond the capacity of the final dimension.
+/// - A [StrideTooSmallError](./error/cql/enum.Error.html#variant.StrideTooSmallError) will be returned if the provided `stride` is zero.
+/// - An [ElementsNotLinkedError](./error/cql/enum.Error.html#variant.ElementsNotLinkedError) will be returned if the provided indexes in `location` have not
+///   been linked.
+/// - A [FormatVersionError](./error/cql/enum.Error.html#variant.FormatVersionError) will be returned if the database was created by a version of cql_db
+///   using a different [format version](constant.FORMAT_VERSION.html).
+/// - A [TypeMismatchError](./error/cql/enum.Error.html#variant.TypeMismatchError) will be returned if the database was created with a different
+///   storage type to `TStore`.
+/// - A [LockTimeoutError](./error/cql/enum.Error.html#variant.LockTimeoutError) will be returned if the database could not be locked within the
+///   [DEFAULT_LOCK_TIMEOUT](constant.DEFAULT_LOCK_TIMEOUT.html).
+///
+/// # Panics
+///
+/// Function should not panic.  If you get it to panic, please raise an issue in [github](https://github.com/AndrewSisley/CQLDb/issues).
+///
+/// # Examples
+/// ```
+/// # use std::io::{ Cursor, SeekFrom, Seek };
+/// # const DATABASE_LOCATION: &str = "./.test_db";
+/// #
+/// use cql_u64::{ U64, unpack_stream };
+/// use cql_db::Direction;
+///
+/// # use std::error::Error;
+/// # use std::fs::remove_file;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/db"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/ax"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/key1_2"));
+/// cql_db::create_db::<U64>(
+///     DATABASE_LOCATION,
+///     &[2, 2, 24]
+/// )?;
+///
+/// cql_db::link_dimensions::<U64>(DATABASE_LOCATION, &[1, 2])?;
+/// for hour in 1..=24 {
+///     cql_db::write_value::<U64>(DATABASE_LOCATION, &[1, 2, hour], hour * 100)?;
+/// }
+///
+/// // every sixth hour, latest first
+/// let mut stream = Cursor::new(Vec::new());
+/// cql_db::read_strided_to_stream::<U64>(DATABASE_LOCATION, &mut stream, &[1, 2, 6], 4, 6, Direction::Reverse)?;
+///
+/// let mut result = Vec::new();
+/// stream.seek(SeekFrom::Start(0));
+/// unpack_stream(&mut stream, 4, |_, value| {
+///     result.push(value)
+/// })?;
+///
+/// assert_eq!(result, vec![2400, 1800, 1200, 600]);
+/// # Ok(())
+/// # }
+/// ```
+pub fn read_strided_to_stream<TStore: CqlBufferStreamReadable>(
+    db_location: &str,
+    stream: &mut dyn Write,
+    location: &[u64],
+    n_values: u64,
+    stride: u64,
+    direction: Direction
+) -> result::Result<()> {
+    open_read_only::<TStore>(db_location)?.read_strided_to_stream(stream, location, n_values, stride, direction)
+}
+
+/// Reads the values within the given range of each dimension into the given stream, one row at a time.  Does not validate given parameters.
+///
+/// Every combination of the indexes within the ranges of the first `N - 1` dimensions that has been [linked](fn.link_dimensions.html) is read in ascending
+/// order, with combinations that have not been linked skipped using the key libraries.  For each of them the values within the range of the final
+/// dimension are written to the stream in the same way as [read_to_stream](fn.read_to_stream.html) would.  Ranges exclude their end, in the same way
+/// as any other [Range](https://doc.rust-lang.org/std/ops/struct.Range.html).
+///
+/// Returns the location of each row (the first `N - 1` dimensions) read, in the order in which they were written to the stream.
+///
+/// # Errors
+///
+/// Will return any [I/O errors](https://doc.rust-lang.org/nightly/std/io/enum.ErrorKind.html) encountered during the execution of the function.  If an
+/// error is returned, part of the slice may still have been written to the stream.
+///
+/// # Panics
+///
+/// Function does not actively defend against panics, and may do so if given invalid parameters.
+///
+/// # Examples
+/// ```
+/// # use std::io::{ Cursor, SeekFrom, Seek };
+/// # const DATABASE_LOCATION: &str = "./.test_db";
+/// #
+/// use cql_u64::{ U64, unpack_stream };
+///
+/// # use std::error::Error;
+/// # use std::fs::remove_file;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/db"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/ax"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/key1_2"));
+/// cql_db::create_db::<U64>(
+///     DATABASE_LOCATION,
+///     &[3, 3, 5]
+/// )?;
+///
+/// cql_db::link_dimensions::<U64>(DATABASE_LOCATION, &[1, 2])?;
+/// cql_db::link_dimensions::<U64>(DATABASE_LOCATION, &[2, 2])?;
+/// cql_db::write_value::<U64>(DATABASE_LOCATION, &[1, 2, 3], 12)?;
+/// cql_db::write_value::<U64>(DATABASE_LOCATION, &[2, 2, 4], 22)?;
+///
+/// let mut stream = Cursor::new(Vec::new());
+///
+/// let rows = cql_db::read_slice_unchecked::<U64>(
+///     DATABASE_LOCATION,
+///     &mut stream,
+///     &[1..3, 1..4, 3..5]
+/// )?;
+///
+/// let mut result = Vec::new();
+/// stream.seek(SeekFrom::Start(0));
+/// unpack_stream(&mut stream, 4, |_, value| {
+///     result.push(value)
+/// })?;
+///
+/// assert_eq!(rows, vec![vec![1, 2], vec![2, 2]]);
+/// assert_eq!(result, vec![12, 0, 0, 22]);
+/// # Ok(())
+/// # }
+/// ```
+pub fn read_slice_unchecked<TStore: CqlBufferStreamReadable>(db_location: &str, stream: &mut dyn Write, ranges: &[Range<u64>]) -> io::Result<Vec<Vec<u64>>> {
+    Database::<TStore>::open_read_only(FileStorage::new(db_location))?.read_slice_unchecked(stream, ranges)
+}
+
+/// Reads the values within the given range of each dimension into the given stream, one row at a time.
+///
+/// Every combination of the indexes within the ranges of the first `N - 1` dimensions that has been [linked](fn.link_dimensions.html) is read in ascending
+/// order, with combinations that have not been linked skipped using the key libraries.  For each of them the values within the range of the final
+/// dimension are written to the stream in the same way as [read_to_stream](fn.read_to_stream.html) would, allowing a query such as "signals 1 to 20 for
+/// turbines 1 to 50 for days 100 to 130" to be made in a single call.  Ranges exclude their end, in the same way as any other
+/// [Range](https://doc.rust-lang.org/std/ops/struct.Range.html).  There is an [unchecked](fn.read_slice_unchecked.html) version of this function if required.
+///
+/// Returns the location of each row (the first `N - 1` dimensions) read, in the order in which they were written to the stream.
+///
+/// # Errors
+///
+/// Will return any [I/O errors](https://doc.rust-lang.org/nightly/std/io/enum.ErrorKind.html) encountered during the execution of the function.  If an
+/// I/O error is returned, part of the slice may still have been written to the stream.
+///
+/// Additionally, the following [Cql errors](./error/cql/enum.Error.html) may be returned, in which case nothing will have been written to the stream:
+/// - A [DimensionsOutOfRangeError](./error/cql/enum.Error.html#variant.DimensionsOutOfRangeError) will be returned if the provided `ranges.len()` is not
+///   equal to the number of dimensions in the database.
+/// - An [IndexOutOfRangeError](./error/cql/enum.Error.html#variant.IndexOutOfRangeError) will be returned if any of the provided `ranges` start at less than 1,
+///   or end beyond that dimension's capacity.
+/// - A [FormatVersionError](./error/cql/enum.Error.html#variant.FormatVersionError) will be returned if the database was created by a version of cql_db
+///   using a different [format version](constant.FORMAT_VERSION.html).
+/// - A [TypeMismatchError](./error/cql/enum.Error.html#variant.TypeMismatchError) will be returned if the database was created with a different
+///   storage type to `TStore`.
+/// - A [LockTimeoutError](./error/cql/enum.Error.html#variant.LockTimeoutError) will be returned if the database could not be locked within the
+///   [DEFAULT_LOCK_TIMEOUT](constant.DEFAULT_LOCK_TIMEOUT.html).
+///
+/// # Panics
+///
+/// Function should not panic.  If you get it to panic, please raise an issue in [github](https://github.com/AndrewSisley/CQLDb/issues).
+///
+/// # Examples
+/// ```
+/// # use std::io::{ Cursor, SeekFrom, Seek };
+/// # const DATABASE_LOCATION: &str = "./.test_db";
+/// #
+/// use cql_u64::{ U64, unpack_stream };
+///
+/// # use std::error::Error;
+/// # use std::fs::remove_file;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/db"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/ax"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/key1_2"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/key2_3"));
+/// cql_db::create_db::<U64>(
+///     DATABASE_LOCATION,
+///     &[50, 20, 365, 24]
+/// )?;
+///
+/// cql_db::link_dimensions::<U64>(DATABASE_LOCATION, &[2, 4, 100])?;
+/// cql_db::link_dimensions::<U64>(DATABASE_LOCATION, &[2, 4, 200])?;
+/// cql_db::link_dimensions::<U64>(DATABASE_LOCATION, &[7, 1, 101])?;
+/// cql_db::write_value::<U64>(DATABASE_LOCATION, &[2, 4, 100, 1], 5)?;
+/// cql_db::write_value::<U64>(DATABASE_LOCATION, &[7, 1, 101, 2], 6)?;
+///
+/// let mut stream = Cursor::new(Vec::new());
+///
+/// // the first two hours of days 100 to 130, of signals 1 to 20, for turbines 1 to 50
+/// let rows = cql_db::read_slice::<U64>(
+///     DATABASE_LOCATION,
+///     &mut stream,
+///     &[1..51, 1..21, 100..131, 1..3]
+/// )?;
+///
+/// let mut result = Vec::new();
+/// stream.seek(SeekFrom::Start(0));
+/// unpack_stream(&mut stream, rows.len() * 2, |_, value| {
+///     result.push(value)
+/// })?;
+///
+/// assert_eq!(rows, vec![vec![2, 4, 100], vec![7, 1, 101]]);
+/// assert_eq!(result, vec![5, 0, 0, 6]);
+/// # Ok(())
+/// # }
+/// ```
+pub fn read_slice<TStore: CqlBufferStreamReadable>(db_location: &str, stream: &mut dyn Write, ranges: &[Range<u64>]) -> result::Result<Vec<Vec<u64>>> {
+    open_read_only::<TStore>(db_location)?.read_slice(stream, ranges)
+}
+
+/// Aggregates `n_values` from the given location onward.  Does not validate given parameters.
+///
+/// Values are read along the final dimension, and each number they hold is added to the given [aggregation](aggregation/index.html) as the values are
+/// read, a block at a time, without writing them to a stream.  Values not holding a number, such as nulls, are skipped.
+///
+/// Can result in reading from an 'alternative' location if provided with an invalid location in the final dimension, other invalid dimensions will likely
+/// result in a panic.
+///
+/// # Errors
+///
+/// Will return any [I/O errors](https://doc.rust-lang.org/nightly/std/io/enum.ErrorKind.html) encountered during the execution of the function.
+///
+/// # Panics
+///
+/// Function does not actively defend against panics, and may do so if given invalid parameters.
+///
+/// # Examples
+/// ```
+/// # const DATABASE_LOCATION: &str = "./.test_db";
+/// #
+/// use cql_u64::U64;
+/// use cql_db::aggregation::{ Sum, Count };
+///
+/// # use std::error::Error;
+/// # use std::fs::remove_file;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/db"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/ax"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/key1_2"));
+/// cql_db::create_db::<U64>(
+///     DATABASE_LOCATION,
+///     &[2, 2, 10]
+/// )?;
+///
+/// cql_db::link_dimensions::<U64>(DATABASE_LOCATION, &[1, 2])?;
+/// for value in 1..=10 {
+///     cql_db::write_value::<U64>(DATABASE_LOCATION, &[1, 2, value], value)?;
+/// }
+///
+/// assert_eq!(cql_db::aggregate_unchecked::<U64, Sum>(DATABASE_LOCATION, &[1, 2, 3], 4)?, 18);
+/// assert_eq!(cql_db::aggregate_unchecked::<U64, Count>(DATABASE_LOCATION, &[1, 2, 3], 4)?, 4);
+/// # Ok(())
+/// # }
+/// ```
+pub fn aggregate_unchecked<TStore: CqlAggregatable, TAggregation: Aggregation<TStore>>(db_location: &str, location: &[u64], n_values: u64)
+    -> io::Result<TAggregation::Output> {
+    Database::<TStore>::open_read_only(FileStorage::new(db_location))?.aggregate_unchecked::<TAggregation>(location, n_values)
+}
+
+/// Aggregates `n_values` from the given location onward.
+///
+/// Values are read along the final dimension, and each number they hold is added to the given [aggregation](aggregation/index.html) as the values are
+/// read, a block at a time, without writing them to a stream - allowing, for example, the [mean](aggregation/struct.Mean.html) of a year of hourly
+/// readings to be found without reading them out of the database.  Values not holding a number, such as the nulls of a
+/// [NullableF64](https://docs.rs/cql_nullable_f64/0.3/cql_nullable_f64/struct.NullableF64.html), are skipped.  There is an
+/// [unchecked](fn.aggregate_unchecked.html) version of this function if required.
+///
+/// # Errors
+///
+/// Will return any [I/O errors](https://doc.rust-lang.org/nightly/std/io/enum.ErrorKind.html) encountered during the execution of the function.
+///
+/// Additionally, the following [Cql errors](./error/cql/enum.Error.html) may be returned:
+/// - A [DimensionsOutOfRangeError](./error/cql/enum.Error.html#variant.DimensionsOutOfRangeError) will be returned if the provided `location.len()` is not
+///   equal to the number of dimensions in the database.
+/// - An [IndexOutOfRangeError](./error/cql/enum.Error.html#variant.IndexOutOfRangeError) will be returned if any of the provided indexes in `location` are less than 1,
+///   or greater than that dimension's capacity, or if the last of the values to be aggregated is beyond the capacity of the final dimension.
+/// - An [ElementsNotLinkedError](./error/cql/enum.Error.html#variant.ElementsNotLinkedError) will be returned if the provided indexes in `location` have not
+///   been linked.
+/// - A [FormatVersionError](./error/cql/enum.Error.html#variant.FormatVersionError) will be returned if the database was created by a version of cql_db
+///   using a different [format version](constant.FORMAT_VERSION.html).
+/// - A [TypeMismatchError](./error/cql/enum.Error.html#variant.TypeMismatchError) will be returned if the database was created with a different
+///   storage type to `TStore`.
+/// - A [LockTimeoutError](./error/cql/enum.Error.html#variant.LockTimeoutError) will be returned if the database could not be locked within the
+///   [DEFAULT_LOCK_TIMEOUT](constant.DEFAULT_LOCK_TIMEOUT.html).
+///
+/// # Panics
+///
+/// Function should not panic.  If you get it to panic, please raise an issue in [github](https://github.com/AndrewSisley/CQLDb/issues).
+///
+/// # Examples
+/// ```
+/// # const DATABASE_LOCATION: &str = "./.test_db";
+/// #
+/// use cql_u64::U64;
+/// use cql_db::aggregation::{ Min, Max, Mean };
+///
+/// # use std::error::Error;
+/// # use std::fs::remove_file;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/db"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/ax"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/key1_2"));
+/// cql_db::create_db::<U64>(
+///     DATABASE_LOCATION,
+///     &[2, 2, 24]
+/// )?;
+///
+/// cql_db::link_dimensions::<U64>(DATABASE_LOCATION, &[1, 2])?;
+/// for hour in 1..=24 {
+///     cql_db::write_value::<U64>(DATABASE_LOCATION, &[1, 2, hour], hour * 100)?;
+/// }
+///
+/// assert_eq!(cql_db::aggregate::<U64, Min>(DATABASE_LOCATION, &[1, 2, 1], 24)?, Some(100));
+/// assert_eq!(cql_db::aggregate::<U64, Max>(DATABASE_LOCATION, &[1, 2, 1], 24)?, Some(2400));
+/// assert_eq!(cql_db::aggregate::<U64, Mean>(DATABASE_LOCATION, &[1, 2, 1], 24)?, Some(1250.0));
+/// # Ok(())
+/// # }
+/// ```
+pub fn aggregate<TStore: CqlAggregatable, TAggregation: Aggregation<TStore>>(db_location: &str, location: &[u64], n_values: u64)
+    -> result::Result<TAggregation::Output> {
+    open_read_only::<TStore>(db_location)?.aggregate::<TAggregation>(location, n_values)
+}
+
+/// Aggregates each `bucket_size` values of the `n_values` from the given location onward separately.  Does not validate given parameters.
+///
+/// Values are read along the final dimension, and split into consecutive buckets of `bucket_size` values, with the last bucket holding any values left
+/// over.  Each bucket is aggregated in the same way as [aggregate_unchecked](fn.aggregate_unchecked.html) would, with the values read a block at a time
+/// in a single pass.  Should the database have been [created with rollup levels](fn.create_db_with_rollups.html) that line up with the buckets, the
+/// buckets are instead aggregated from the coarsest such level.
+///
+/// Returns the result of the given [aggregation](aggregation/index.html) for each bucket, in order.
+///
+/// Can result in reading from an 'alternative' location if provided with an invalid location in the final dimension, other invalid dimensions will likely
+/// result in a panic.
+///
+/// # Errors
+///
+/// Will return any [I/O errors](https://doc.rust-lang.org/nightly/std/io/enum.ErrorKind.html) encountered during the execution of the function.
+///
+/// # Panics
+///
+/// Function does not actively defend against panics, and may do so if given invalid parameters, such as a `bucket_size` of zero.
+///
+/// # Examples
+/// ```
+/// # const DATABASE_LOCATION: &str = "./.test_db";
+/// #
+/// use cql_u64::U64;
+/// use cql_db::aggregation::Max;
+///
+/// # use std::error::Error;
+/// # use std::fs::remove_file;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/db"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/ax"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/key1_2"));
+/// cql_db::create_db::<U64>(
+///     DATABASE_LOCATION,
+///     &[2, 2, 10]
+/// )?;
+///
+/// cql_db::link_dimensions::<U64>(DATABASE_LOCATION, &[1, 2])?;
+/// for value in 1..=10 {
+///     cql_db::write_value::<U64>(DATABASE_LOCATION, &[1, 2, value], value)?;
+/// }
+///
+/// let result = cql_db::rollup_unchecked::<U64, Max>(DATABASE_LOCATION, &[1, 2, 1], 10, 4)?;
+///
+/// assert_eq!(result, vec![Some(4), Some(8), Some(10)]);
+/// # Ok(())
+/// # }
+/// ```
+pub fn rollup_unchecked<TStore: CqlAggregatable, TAggregation: Aggregation<TStore>>(
+    db_location: &str,
+    location: &[u64],
+    n_values: u64,
+    bucket_size: u64
+) -> io::Result<Vec<TAggregation::Output>> {
+    Database::<TStore>::open_read_only(FileStorage::new(db_location))?.rollup_unchecked::<TAggregation>(location, n_values, bucket_size)
+}
+
+/// Aggregates each `bucket_size` values of the `n_values` from the given location onward separately.
+///
+/// Values are read along the final dimension, and split into consecutive buckets of `bucket_size` values, with the last bucket holding any values left
+/// over.  Each bucket is aggregated in the same way as [aggregate](fn.aggregate.html) would, with the values read a block at a time in a single pass -
+/// allowing, for example, an hourly series of [means](aggregation/struct.Mean.html) to be read from a final dimension of per-second values.  Should the
+/// database have been [created with rollup levels](fn.create_db_with_rollups.html) that line up with the buckets, the buckets are instead aggregated from
+/// the coarsest such level.  There is an [unchecked](fn.rollup_unchecked.html) version of this function if required.
+///
+/// Returns the result of the given [aggregation](aggregation/index.html) for each bucket, in order.
+///
+/// # Errors
+///
+/// Will return any [I/O errors](https://doc.rust-lang.org/nightly/std/io/enum.ErrorKind.html) encountered during the execution of the function.
+///
+/// Additionally, the following [Cql errors](./error/cql/enum.Error.html) may be returned:
+/// - A [DimensionsOutOfRangeError](./error/cql/enum.Error.html#variant.DimensionsOutOfRangeError) will be returned if the provided `location.len()` is not
+///   equal to the number of dimensions in the database.
+/// - An [IndexOutOfRangeError](./error/cql/enum.Error.html#variant.IndexOutOfRangeError) will be returned if any of the provided indexes in `location` are less than 1,
+///   or greater than that dimension's capacity, or if the last of the values to be aggregated is beyond the capacity of the final dimension.
+/// - An [ElementsNotLinkedError](./error/cql/enum.Error.html#variant.ElementsNotLinkedError) will be returned if the provided indexes in `location` have not
+///   been linked.
+/// - A [BucketTooSmallError](./error/cql/enum.Error.html#variant.BucketTooSmallError) will be returned if the provided `bucket_size` is zero.
+/// - A [FormatVersionError](./error/cql/enum.Error.html#variant.FormatVersionError) will be returned if the database was created by a version of cql_db
+///   using a different [format version](constant.FORMAT_VERSION.html).
+/// - A [TypeMismatchError](./error/cql/enum.Error.html#variant.TypeMismatchError) will be returned if the database was created with a different
+///   storage type to `TStore`.
+/// - A [LockTimeoutError](./error/cql/enum.Error.html#variant.LockTimeoutError) will be returned if the database could not be locked within the
+///   [DEFAULT_LOCK_TIMEOUT](constant.DEFAULT_LOCK_TIMEOUT.html).
+///
+/// # Panics
+///
+/// Function should not panic.  If you get it to panic, please raise an issue in [github](https://github.com/AndrewSisley/CQLDb/issues).
+///
+/// # Examples
+/// ```
+/// # const DATABASE_LOCATION: &str = "./.test_db";
+/// #
+/// use cql_u64::U64;
+/// use cql_db::aggregation::Mean;
+///
+/// # use std::error::Error;
+/// # use std::fs::remove_file;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/db"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/ax"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/key1_2"));
+/// const SECONDS_PER_HOUR: u64 = 60 * 60;
+///
+/// cql_db::create_db::<U64>(
+///     DATABASE_LOCATION,
+///     &[2, 2, 24 * SECONDS_PER_HOUR]
+/// )?;
+///
+/// cql_db::link_dimensions::<U64>(DATABASE_LOCATION, &[1, 2])?;
+/// cql_db::write_value::<U64>(DATABASE_LOCATION, &[1, 2, 1], 3600)?;
+/// cql_db::write_value::<U64>(DATABASE_LOCATION, &[1, 2, SECONDS_PER_HOUR + 1], 7200)?;
+///
+/// // the hourly mean of the first three hours of per-second values
+/// let result = cql_db::rollup::<U64, Mean>(DATABASE_LOCATION, &[1, 2, 1], 3 * SECONDS_PER_HOUR, SECONDS_PER_HOUR)?;
+///
+/// assert_eq!(result, vec![Some(1.0), Some(2.0), Some(0.0)]);
+/// # Ok(())
+/// # }
+/// ```
+pub fn rollup<TStore: CqlAggregatable, TAggregation: Aggregation<TStore>>(
+    db_location: &str,
+    location: &[u64],
+    n_values: u64,
+    bucket_size: u64
+) -> result::Result<Vec<TAggregation::Output>> {
+    open_read_only::<TStore>(db_location)?.rollup::<TAggregation>(location, n_values, bucket_size)
+}
+
+/// Returns an iterator over `n_values` from the given location onward.  Does not validate given parameters.
+///
+/// Values are read along the final dimension, and are decoded by the given `TStore` as the iterator is consumed.
+///
+/// Can result in reading from an 'alternative' location if provided with an invalid location in the final dimension, other invalid dimensions will likely
+/// result in a panic.
+///
+/// # Errors
+///
+/// Will return any [I/O errors](https://doc.rust-lang.org/nightly/std/io/enum.ErrorKind.html) encountered whilst opening the database.  Errors encountered
+/// whilst reading or decoding an individual value are returned by the iterator in place of that value, after which the iterator ends.
+///
+/// # Panics
+///
+/// Function does not actively defend against panics, and may do so if given invalid parameters.
+///
+/// # Examples
+/// ```
+/// # const DATABASE_LOCATION: &str = "./.test_db";
+/// #
+/// use cql_u64::U64;
+///
+/// # use std::error::Error;
+/// # use std::fs::remove_file;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/db"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/ax"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/key1_2"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/key2_3"));
+/// let base_point = [1, 1, 1, 2];
+/// let value1 = 42;
+/// let value3 = 80;
+///
+/// cql_db::create_db::<U64>(
+///     DATABASE_LOCATION,
+///     &[1, 1, 1, 10]
+/// )?;
+///
+/// cql_db::link_dimensions::<U64>(
+///     DATABASE_LOCATION,
+///     &base_point[0..3]
 /// )?;
 ///
-/// assert_eq!(0, result1);
-///
-/// // Write `value` to location `[2, 4, 3, 1]`
 /// cql_db::write_value::<U64>(
 ///     DATABASE_LOCATION,
-///     &point,
-///     value
+///     &base_point,
+///     value1
 /// )?;
 ///
-/// // Read the now-populated value from point `point`
-/// let result2 = cql_db::read_value_unchecked::<U64>(
+/// cql_db::write_value::<U64>(
 ///     DATABASE_LOCATION,
-///     &point
+///     &[1, 1, 1, base_point[3] + 2],
+///     value3
 /// )?;
 ///
-/// assert_eq!(value, result2);
+/// let result = cql_db::read_range_unchecked::<U64>(
+///     DATABASE_LOCATION,
+///     &base_point,
+///     3
+/// )?.collect::<Result<Vec<u64>, _>>()?;
+///
+/// assert_eq!(result, vec![value1, 0, value3]);
 /// # Ok(())
 /// # }
 /// ```
-pub fn read_value_unchecked<TStore: CqlReadable>(db_location: &str, location: &[u64]) -> Result<TStore::ValueType, io::Error> {
-	let position = calculate_position(db_location, location)?;
-	database::read_value::<TStore>(&db_location, position)
+pub fn read_range_unchecked<TStore: CqlRangeReadable>(db_location: &str, location: &[u64], n_values: u64)
+    -> io::Result<impl Iterator<Item = io::Result<TStore::ValueType>>> {
+    Database::<TStore>::open_read_only(FileStorage::new(db_location))?.read_range_unchecked(location, n_values)
 }
 
-/// Reads the value at the given location from the database.
+/// Returns an iterator over `n_values` from the given location onward.
 ///
-/// There is an [unchecked](fn.read_value_unchecked.html) version of this function if required.
+/// Values are read along the final dimension, and are decoded by the given `TStore` as the iterator is consumed.
+///
+/// There is an [unchecked](fn.read_range_unchecked.html) version of this function if required.
 ///
 /// # Errors
 ///
-/// Will return any [I/O errors](https://doc.rust-lang.org/nightly/std/io/enum.ErrorKind.html) encountered during the execution of the function.
+/// Will return any [I/O errors](https://doc.rust-lang.org/nightly/std/io/enum.ErrorKind.html) encountered whilst opening the database.  Errors encountered
+/// whilst reading or decoding an individual value are returned by the iterator in place of that value, after which the iterator ends.
 ///
 /// Additionally, the following [Cql errors](./error/cql/enum.Error.html) may be returned:
 /// - A [DimensionsOutOfRangeError](./error/cql/enum.Error.html#variant.DimensionsOutOfRangeError) will be returned if the provided `location.len()` is not equal
-/// to the number of dimensions in the database.
+///   to the number of dimensions in the database.
 /// - An [IndexOutOfRangeError](./error/cql/enum.Error.html#variant.IndexOutOfRangeError) will be returned if any of the provided indexes in `location` are less than 1,
-/// or greater than that dimension's capacity.
+///   or greater than that dimension's capacity, this includes the final requested point - for example if `n_values` is too large.
 /// - An [ElementsNotLinkedError](./error/cql/enum.Error.html#variant.ElementsNotLinkedError) will be returned if the provided elements have not been linked.
+/// - A [FormatVersionError](./error/cql/enum.Error.html#variant.FormatVersionError) will be returned if the database was created by a version of cql_db
+///   using a different [format version](constant.FORMAT_VERSION.html).
+/// - A [TypeMismatchError](./error/cql/enum.Error.html#variant.TypeMismatchError) will be returned if the database was created with a different
+///   storage type to `TStore`.
+/// - A [LockTimeoutError](./error/cql/enum.Error.html#variant.LockTimeoutError) will be returned if the database could not be locked within the
+///   [DEFAULT_LOCK_TIMEOUT](constant.DEFAULT_LOCK_TIMEOUT.html).
 /// ```
 /// # use cql_u64::U64;
 /// # use cql_db::error;
@@ -934,7 +3652,6 @@ pub fn read_value_unchecked<TStore: CqlReadable>(db_location: &str, location: &[
 /// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/ax"));
 /// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/key1_2"));
 /// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/key2_3"));
-/// // Create a database with a maximum capacity of `[2, 5, 3, 2]`
 /// cql_db::create_db::<U64>(
 ///     DATABASE_LOCATION,
 ///     &[2, 5, 6]
@@ -945,41 +3662,18 @@ pub fn read_value_unchecked<TStore: CqlReadable>(db_location: &str, location: &[
 ///     &[2, 4],
 /// )?;
 ///
-/// let result = match cql_db::read_value::<U64>(
+/// let result = match cql_db::read_range::<U64>(
 ///     DATABASE_LOCATION,
-///     // not enough dimensions
-///     &[2, 4]
+///     // dimension[2] index (6) + n_values (2) - 1 is too large (7)
+///     &[2, 4, 6],
+///     2
 /// ) {
-///     Err(error) => match error {
-///         error::Error::Cql(cql_error) => Some(cql_error),
-///         _ => None,
-///     }
+///     Err(error::Error::Cql(cql_error)) => Some(cql_error),
 ///     _ => None,
 /// };
 ///
 /// assert_eq!(
 ///     result.unwrap(),
-///     Error::DimensionsOutOfRangeError {
-///         requested: 2,
-///         min: 3, // 4 - 1
-///         max: 3, // 4 - 1
-///     }
-/// );
-///
-/// let result2 = match cql_db::read_value::<U64>(
-///     DATABASE_LOCATION,
-///     // dimension[2] index is too large
-///     &[2, 4, 7]
-/// ) {
-///     Err(error) => match error {
-///         error::Error::Cql(cql_error) => Some(cql_error),
-///         _ => None,
-///     }
-///     _ => None,
-/// };
-///
-/// assert_eq!(
-///     result2.unwrap(),
 ///     Error::IndexOutOfRangeError {
 ///         dimension_index: 2,
 ///         requested: 7,
@@ -988,20 +3682,18 @@ pub fn read_value_unchecked<TStore: CqlReadable>(db_location: &str, location: &[
 ///     }
 /// );
 ///
-/// let result3 = match cql_db::read_value::<U64>(
+/// let result2 = match cql_db::read_range::<U64>(
 ///     DATABASE_LOCATION,
 ///     // location[2, 3] has not been linked
-///     &[2, 3, 3]
+///     &[2, 3, 3],
+///     2
 /// ) {
-///     Err(error) => match error {
-///         error::Error::Cql(cql_error) => Some(cql_error),
-///         _ => None,
-///     }
+///     Err(error::Error::Cql(cql_error)) => Some(cql_error),
 ///     _ => None,
 /// };
 ///
 /// assert_eq!(
-///     result3.unwrap(),
+///     result2.unwrap(),
 ///     Error::ElementsNotLinkedError {
 ///         x_dimension: 0,
 ///         x: 2,
@@ -1019,9 +3711,10 @@ pub fn read_value_unchecked<TStore: CqlReadable>(db_location: &str, location: &[
 ///
 /// # Examples
 /// ```
-/// # use cql_u64::U64;
 /// # const DATABASE_LOCATION: &str = "./.test_db";
 /// #
+/// use cql_u64::U64;
+///
 /// # use std::error::Error;
 /// # use std::fs::remove_file;
 /// # fn main() -> Result<(), Box<dyn Error>> {
@@ -1029,72 +3722,72 @@ pub fn read_value_unchecked<TStore: CqlReadable>(db_location: &str, location: &[
 /// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/ax"));
 /// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/key1_2"));
 /// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/key2_3"));
-/// let point = [2, 4, 3, 1];
-/// let value = 5;
+/// let base_point = [1, 1, 1, 2];
+/// let value1 = 42;
+/// let value3 = 80;
 ///
 /// cql_db::create_db::<U64>(
 ///     DATABASE_LOCATION,
-///     &[2, 5, 3, 2]
+///     &[1, 1, 1, 10]
 /// )?;
 ///
-/// // higher order elements must be linked before they can be read from
 /// cql_db::link_dimensions::<U64>(
 ///     DATABASE_LOCATION,
-///     &point[0..3],
+///     &base_point[0..3]
 /// )?;
 ///
-/// // Read the default value from point `point`
-/// let result1 = cql_db::read_value::<U64>(
+/// cql_db::write_value::<U64>(
 ///     DATABASE_LOCATION,
-///     &point
+///     &base_point,
+///     value1
 /// )?;
 ///
-/// assert_eq!(0, result1);
-///
-/// // Write `value` to location `[2, 4, 3, 1]`
 /// cql_db::write_value::<U64>(
 ///     DATABASE_LOCATION,
-///     &point,
-///     value
+///     &[1, 1, 1, base_point[3] + 2],
+///     value3
 /// )?;
 ///
-/// // Read the now-populated value from point `point`
-/// let result2 = cql_db::read_value::<U64>(
+/// let result = cql_db::read_range::<U64>(
 ///     DATABASE_LOCATION,
-///     &point
-/// )?;
+///     &base_point,
+///     3
+/// )?.collect::<Result<Vec<u64>, _>>()?;
 ///
-/// assert_eq!(value, result2);
+/// assert_eq!(result, vec![value1, 0, value3]);
 /// # Ok(())
 /// # }
 /// ```
-pub fn read_value<TStore: CqlReadable>(db_location: &str, location: &[u64]) -> result::Result<TStore::ValueType> {
-	validate_read_write_location(db_location, location)?;
-    let value = read_value_unchecked::<TStore>(db_location, location)?;
-    Ok(value)
+pub fn read_range<TStore: CqlRangeReadable>(db_location: &str, location: &[u64], n_values: u64)
+    -> result::Result<impl Iterator<Item = io::Result<TStore::ValueType>>> {
+    open_read_only::<TStore>(db_location)?.read_range(location, n_values)
 }
 
-/// Reads `n_values` from the given location onward into the given stream.  Does not validate given parameters.
+/// Writes `n_values` from the given stream to the given location onward.  Does not validate given parameters.
 ///
-/// Can result in reading from an 'alternative' location if provided with an invalid location in the final dimension, other invalid dimensions will likely
+/// The stream should be in the format produced by [read_to_stream](fn.read_to_stream.html) for the given `TStore`, and will be written
+/// along the final dimension, starting at the given location.
+///
+/// Can result in writing to an 'alternative' location if provided with an invalid location in the final dimension, other invalid dimensions will likely
 /// result in a panic.
 ///
 /// # Errors
 ///
-/// Will return any [I/O errors](https://doc.rust-lang.org/nightly/std/io/enum.ErrorKind.html) encountered during the execution of the function.  If an error
-/// is returned, it may be that values have already been written to the stream.
+/// Will return any [I/O errors](https://doc.rust-lang.org/nightly/std/io/enum.ErrorKind.html) encountered during the execution of the function, including
+/// [io::ErrorKind::UnexpectedEof](https://doc.rust-lang.org/nightly/std/io/enum.ErrorKind.html#variant.UnexpectedEof) should the stream end before `n_values`
+/// have been read from it.  If an error is returned, it may be that values have already been written to the database.
 ///
 /// # Panics
 ///
 /// Function does not actively defend against panics, and may do so if given invalid parameters.  If the function panics it may be that values have
-/// already been written to the stream.
+/// already been written to the database.
 ///
 /// # Examples
 /// ```
-/// # use std::io::{ Cursor, SeekFrom, Seek };
+/// # use std::io::Cursor;
 /// # const DATABASE_LOCATION: &str = "./.test_db";
 /// #
-/// use cql_u64::{ U64, unpack_stream };
+/// use cql_u64::U64;
 ///
 /// # use std::error::Error;
 /// # use std::fs::remove_file;
@@ -1104,10 +3797,7 @@ pub fn read_value<TStore: CqlReadable>(db_location: &str, location: &[u64]) -> r
 /// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/key1_2"));
 /// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/key2_3"));
 /// let base_point = [1, 1, 1, 2];
-/// const N_VALUES_TO_READ: usize = 3;
-/// let value1 = 42;
-/// let value2 = 16;
-/// let value3 = 80;
+/// let values = [42, 16, 80];
 ///
 /// cql_db::create_db::<U64>(
 ///     DATABASE_LOCATION,
@@ -1119,65 +3809,54 @@ pub fn read_value<TStore: CqlReadable>(db_location: &str, location: &[u64]) -> r
 ///     &base_point[0..3]
 /// )?;
 ///
-/// cql_db::write_value::<U64>(
-///     DATABASE_LOCATION,
-///     &base_point,
-///     value1
-/// )?;
-///
-/// cql_db::write_value::<U64>(
-///     DATABASE_LOCATION,
-///     &[1, 1, 1, base_point[3] + 1],
-///     value2
-/// )?;
-///
-/// cql_db::write_value::<U64>(
-///     DATABASE_LOCATION,
-///     &[1, 1, 1, base_point[3] + 2],
-///     value3
-/// )?;
-///
-/// let mut result = [0; N_VALUES_TO_READ];
-/// let mut stream = Cursor::new(Vec::new());
+/// let mut buffer = Vec::new();
+/// for &value in values.iter() {
+///     buffer.extend_from_slice(&u64::to_le_bytes(value));
+/// }
 ///
-/// cql_db::read_to_stream_unchecked::<U64>(
+/// cql_db::write_from_stream_unchecked::<U64>(
 ///     DATABASE_LOCATION,
-///     &mut stream,
+///     &mut Cursor::new(buffer),
 ///     &base_point,
-///     N_VALUES_TO_READ as u64
+///     values.len() as u64
 /// )?;
 ///
-/// stream.seek(SeekFrom::Start(0));
-///
-/// unpack_stream(&mut stream, N_VALUES_TO_READ, |idx, value| {
-///     result[idx] = value
-/// })?;
-///
-/// assert_eq!(result[0], value1);
-/// assert_eq!(result[1], value2);
-/// assert_eq!(result[2], value3);
+/// assert_eq!(cql_db::read_value::<U64>(DATABASE_LOCATION, &base_point)?, values[0]);
+/// assert_eq!(cql_db::read_value::<U64>(DATABASE_LOCATION, &[1, 1, 1, base_point[3] + 1])?, values[1]);
+/// assert_eq!(cql_db::read_value::<U64>(DATABASE_LOCATION, &[1, 1, 1, base_point[3] + 2])?, values[2]);
 /// # Ok(())
 /// # }
 /// ```
-pub fn read_to_stream_unchecked<TStore: CqlStreamReadable>(db_location: &str, stream: &mut dyn Write, location: &[u64], n_values: u64) -> io::Result<()> {
-	let position = calculate_position(db_location, location)?;
-	database::read_to_stream::<TStore>(&db_location, stream, position, n_values)
+pub fn write_from_stream_unchecked<TStore: CqlBufferStreamWritable>(db_location: &str, stream: &mut dyn Read, location: &[u64], n_values: u64) -> io::Result<()> {
+    Database::<TStore>::open(FileStorage::new(db_location))?.write_from_stream_unchecked(stream, location, n_values)
 }
 
-/// Reads `n_values` from the given location onward into the given stream.
+/// Writes `n_values` from the given stream to the given location onward.
 ///
-/// There is an [unchecked](fn.read_to_stream_unchecked.html) version of this function if required.
+/// The stream should be in the format produced by [read_to_stream](fn.read_to_stream.html) for the given `TStore`, and will be written
+/// along the final dimension, starting at the given location.
+///
+/// There is an [unchecked](fn.write_from_stream_unchecked.html) version of this function if required.
 ///
 /// # Errors
 ///
-/// Will return any [I/O errors](https://doc.rust-lang.org/nightly/std/io/enum.ErrorKind.html) encountered during the execution of the function.
+/// Will return any [I/O errors](https://doc.rust-lang.org/nightly/std/io/enum.ErrorKind.html) encountered during the execution of the function, including
+/// [io::ErrorKind::UnexpectedEof](https://doc.rust-lang.org/nightly/std/io/enum.ErrorKind.html#variant.UnexpectedEof) should the stream end before `n_values`
+/// have been read from it.  If an I/O error is returned, it may be that values have already been written to the database.
 ///
-/// Additionally, the following [Cql errors](./error/cql/enum.Error.html) may be returned:
+/// Additionally, the following [Cql errors](./error/cql/enum.Error.html) may be returned, in which case nothing will have been read from the stream or written
+/// to the database:
 /// - A [DimensionsOutOfRangeError](./error/cql/enum.Error.html#variant.DimensionsOutOfRangeError) will be returned if the provided `location.len()` is not equal
-/// to the number of dimensions in the database.
+///   to the number of dimensions in the database.
 /// - An [IndexOutOfRangeError](./error/cql/enum.Error.html#variant.IndexOutOfRangeError) will be returned if any of the provided indexes in `location` are less than 1,
-/// or greater than that dimension's capacity, this includes the final requested point - for example if `n_values` is too large.
+///   or greater than that dimension's capacity, this includes the final written point - for example if `n_values` is too large.
 /// - An [ElementsNotLinkedError](./error/cql/enum.Error.html#variant.ElementsNotLinkedError) will be returned if the provided elements have not been linked.
+/// - A [FormatVersionError](./error/cql/enum.Error.html#variant.FormatVersionError) will be returned if the database was created by a version of cql_db
+///   using a different [format version](constant.FORMAT_VERSION.html).
+/// - A [TypeMismatchError](./error/cql/enum.Error.html#variant.TypeMismatchError) will be returned if the database was created with a different
+///   storage type to `TStore`.
+/// - A [LockTimeoutError](./error/cql/enum.Error.html#variant.LockTimeoutError) will be returned if the database could not be locked within the
+///   [DEFAULT_LOCK_TIMEOUT](constant.DEFAULT_LOCK_TIMEOUT.html).
 /// ```
 /// # use cql_u64::U64;
 /// # use cql_db::error;
@@ -1192,7 +3871,6 @@ pub fn read_to_stream_unchecked<TStore: CqlStreamReadable>(db_location: &str, st
 /// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/ax"));
 /// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/key1_2"));
 /// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/key2_3"));
-/// // Create a database with a maximum capacity of `[2, 5, 3, 2]`
 /// cql_db::create_db::<U64>(
 ///     DATABASE_LOCATION,
 ///     &[2, 5, 6]
@@ -1203,47 +3881,21 @@ pub fn read_to_stream_unchecked<TStore: CqlStreamReadable>(db_location: &str, st
 ///     &[2, 4],
 /// )?;
 ///
-/// let mut stream = Cursor::new(Vec::new());
-///
-/// let result = match cql_db::read_to_stream::<U64>(
-///     DATABASE_LOCATION,
-///     &mut stream,
-///     // not enough dimensions
-///     &[2, 4],
-///     2
-/// ) {
-///     Err(error) => match error {
-///         error::Error::Cql(cql_error) => Some(cql_error),
-///         _ => None,
-///     }
-///     _ => None,
-/// };
-///
-/// assert_eq!(
-///     result.unwrap(),
-///     Error::DimensionsOutOfRangeError {
-///         requested: 2,
-///         min: 3, // 4 - 1
-///         max: 3, // 4 - 1
-///     }
-/// );
+/// let mut stream = Cursor::new(vec![0; 16]);
 ///
-/// let result2 = match cql_db::read_to_stream::<U64>(
+/// let result = match cql_db::write_from_stream::<U64>(
 ///     DATABASE_LOCATION,
 ///     &mut stream,
 ///     // dimension[2] index (6) + n_values (2) - 1 is too large (7)
 ///     &[2, 4, 6],
 ///     2
 /// ) {
-///     Err(error) => match error {
-///         error::Error::Cql(cql_error) => Some(cql_error),
-///         _ => None,
-///     }
+///     Err(error::Error::Cql(cql_error)) => Some(cql_error),
 ///     _ => None,
 /// };
 ///
 /// assert_eq!(
-///     result2.unwrap(),
+///     result.unwrap(),
 ///     Error::IndexOutOfRangeError {
 ///         dimension_index: 2,
 ///         requested: 7,
@@ -1252,22 +3904,19 @@ pub fn read_to_stream_unchecked<TStore: CqlStreamReadable>(db_location: &str, st
 ///     }
 /// );
 ///
-/// let result3 = match cql_db::read_to_stream::<U64>(
+/// let result2 = match cql_db::write_from_stream::<U64>(
 ///     DATABASE_LOCATION,
 ///     &mut stream,
 ///     // location[2, 3] has not been linked
 ///     &[2, 3, 3],
 ///     2
 /// ) {
-///     Err(error) => match error {
-///         error::Error::Cql(cql_error) => Some(cql_error),
-///         _ => None,
-///     }
+///     Err(error::Error::Cql(cql_error)) => Some(cql_error),
 ///     _ => None,
 /// };
 ///
 /// assert_eq!(
-///     result3.unwrap(),
+///     result2.unwrap(),
 ///     Error::ElementsNotLinkedError {
 ///         x_dimension: 0,
 ///         x: 2,
@@ -1285,10 +3934,10 @@ pub fn read_to_stream_unchecked<TStore: CqlStreamReadable>(db_location: &str, st
 ///
 /// # Examples
 /// ```
-/// # use std::io::{ Cursor, SeekFrom, Seek };
+/// # use std::io::Cursor;
 /// # const DATABASE_LOCATION: &str = "./.test_db";
 /// #
-/// use cql_u64::{ U64, unpack_stream };
+/// use cql_u64::U64;
 ///
 /// # use std::error::Error;
 /// # use std::fs::remove_file;
@@ -1298,10 +3947,7 @@ pub fn read_to_stream_unchecked<TStore: CqlStreamReadable>(db_location: &str, st
 /// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/key1_2"));
 /// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/key2_3"));
 /// let base_point = [1, 1, 1, 2];
-/// const N_VALUES_TO_READ: usize = 3;
-/// let value1 = 42;
-/// let value2 = 16;
-/// let value3 = 80;
+/// let values = [42, 16, 80];
 ///
 /// cql_db::create_db::<U64>(
 ///     DATABASE_LOCATION,
@@ -1313,178 +3959,24 @@ pub fn read_to_stream_unchecked<TStore: CqlStreamReadable>(db_location: &str, st
 ///     &base_point[0..3]
 /// )?;
 ///
-/// cql_db::write_value::<U64>(
-///     DATABASE_LOCATION,
-///     &base_point,
-///     value1
-/// )?;
-///
-/// cql_db::write_value::<U64>(
-///     DATABASE_LOCATION,
-///     &[1, 1, 1, base_point[3] + 1],
-///     value2
-/// )?;
-///
-/// cql_db::write_value::<U64>(
-///     DATABASE_LOCATION,
-///     &[1, 1, 1, base_point[3] + 2],
-///     value3
-/// )?;
-///
-/// let mut result = [0; N_VALUES_TO_READ];
-/// let mut stream = Cursor::new(Vec::new());
+/// let mut buffer = Vec::new();
+/// for &value in values.iter() {
+///     buffer.extend_from_slice(&u64::to_le_bytes(value));
+/// }
 ///
-/// cql_db::read_to_stream::<U64>(
+/// cql_db::write_from_stream::<U64>(
 ///     DATABASE_LOCATION,
-///     &mut stream,
+///     &mut Cursor::new(buffer),
 ///     &base_point,
-///     N_VALUES_TO_READ as u64
+///     values.len() as u64
 /// )?;
 ///
-/// stream.seek(SeekFrom::Start(0));
-///
-/// unpack_stream(&mut stream, N_VALUES_TO_READ, |idx, value| {
-///     result[idx] = value
-/// })?;
-///
-/// assert_eq!(result[0], value1);
-/// assert_eq!(result[1], value2);
-/// assert_eq!(result[2], value3);
+/// assert_eq!(cql_db::read_value::<U64>(DATABASE_LOCATION, &base_point)?, values[0]);
+/// assert_eq!(cql_db::read_value::<U64>(DATABASE_LOCATION, &[1, 1, 1, base_point[3] + 1])?, values[1]);
+/// assert_eq!(cql_db::read_value::<U64>(DATABASE_LOCATION, &[1, 1, 1, base_point[3] + 2])?, values[2]);
 /// # Ok(())
 /// # }
 /// ```
-pub fn read_to_stream<TStore: CqlStreamReadable>(db_location: &str, stream: &mut dyn Write, location: &[u64], n_values: u64) -> result::Result<()> {
-    validate_read_to_stream(db_location, location, n_values)?;
-    read_to_stream_unchecked::<TStore>(db_location, stream, location, n_values)?;
-    Ok(())
-}
-
-fn validate_read_to_stream(db_location: &str, location: &[u64], n_values: u64) -> result::Result<()> {
-    validate_read_write_location(db_location, location)?;
-
-    let axis_id = location.len();
-    let axis_definition = axis_library::get_by_id(db_location, axis_id as u64)?;
-    let last_index = location[axis_id - 1] + n_values - 1;
-
-    if last_index > axis_definition.max {
-        return Err(
-            error::Error::Cql(
-                error::cql::Error::IndexOutOfRangeError {
-                    dimension_index: axis_id - 1,
-                    requested: last_index,
-                    min: 1,
-                    max: axis_definition.max
-                }
-            )
-        )
-    }
-
-    Ok(())
-}
-
-fn validate_element_within_range(db_location: &str, location: &[u64]) -> result::Result<()> {
-    for i in 0..location.len() {
-        let axis_id = i as u64 + 1;
-        let axis_definition = axis_library::get_by_id(db_location, axis_id)?;
-
-        if location[i] < 1 || location[i] > axis_definition.max {
-            return Err(
-                error::Error::Cql(
-                    error::cql::Error::IndexOutOfRangeError {
-                        dimension_index: i,
-                        requested: location[i],
-                        min: 1,
-                        max: axis_definition.max
-                    }
-                )
-            )
-        }
-    }
-
-    Ok(())
-}
-
-fn validate_read_write_location(db_location: &str, location: &[u64]) -> result::Result<()> {
-    let number_of_dimensions = axis_library::count(db_location)?;
-    if location.len() as u64 != number_of_dimensions {
-        return Err(
-            error::Error::Cql(
-                error::cql::Error::DimensionsOutOfRangeError {
-                    requested: location.len(),
-                    min: number_of_dimensions as usize,
-                    max: number_of_dimensions as usize,
-                }
-            )
-        )
-    }
-
-    validate_element_within_range(db_location, location)?;
-
-    if number_of_dimensions > 2 {
-        let last_index = location.len() as u64 - 1;
-
-        let mut x_position = location[0];
-        for x_axis_id in 1..last_index {
-            let y_axis_id = x_axis_id + 1;
-            let y_position = location[x_axis_id as usize];
-            let y_axis_definition = axis_library::get_by_id(db_location, y_axis_id)?;
-
-            let key = key_library::get(
-                db_location,
-                &key_library::AxisPoint { axis_id: x_axis_id, position: x_position },
-                &key_library::AxisPoint { axis_id: y_axis_id, position: y_position },
-                &y_axis_definition
-            )?;
-
-            if key == 0 {
-                return Err(
-                    error::Error::Cql(
-                        error::cql::Error::ElementsNotLinkedError {
-                            x_dimension: x_axis_id as usize - 1,
-                            x: location[x_axis_id as usize - 1],
-                            y_dimension: y_axis_id as usize - 1,
-                            y: y_position,
-                        }
-                    )
-                )
-            }
-
-            x_position = key;
-        }
-    }
-
-    Ok(())
-}
-
-fn calculate_position(db_location: &str, location: &[u64]) -> io::Result<u64> {
-    if location.len() == 1 {
-        // minus one to handle the one-indexing
-        return Ok(
-            location[0] - 1
-        )
-    }
-
-    let last_index = location.len() as u64 - 1;
-
-    let mut x_position = location[0];
-    for x_axis_id in 1..last_index {
-        let y_axis_id = x_axis_id + 1;
-        let y_position = location[x_axis_id as usize];
-        let y_axis_definition = axis_library::get_by_id(db_location, y_axis_id)?;
-
-        let key = key_library::get(
-            db_location,
-            &key_library::AxisPoint { axis_id: x_axis_id, position: x_position },
-            &key_library::AxisPoint { axis_id: y_axis_id, position: y_position },
-            &y_axis_definition
-        )?;
-
-        x_position = key;
-    }
-
-    let last_axis_definition = axis_library::get_by_id(db_location, last_index + 1)?;
-
-    Ok(
-        calculate_index(x_position, location[last_index as usize], last_axis_definition.max)
-    )
+pub fn write_from_stream<TStore: CqlBufferStreamWritable>(db_location: &str, stream: &mut dyn Read, location: &[u64], n_values: u64) -> result::Result<()> {
+    open::<TStore>(db_location)?.write_from_stream(stream, location, n_values)
 }
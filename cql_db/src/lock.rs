@@ -0,0 +1,117 @@
+use std::io;
+use std::sync::Arc;
+use std::sync::atomic::{ AtomicBool, AtomicUsize, Ordering };
+use std::thread;
+use std::time::{ Duration, Instant };
+
+use crate::error;
+use crate::storage::{ Storage, StorageLock };
+
+const RETRY_INTERVAL: Duration = Duration::from_millis(1);
+
+// Advisory locks are taken through the storage backend, and cover the key libraries as well as the data itself.  Every lock opened on the same
+// database conflicts with every other, be it in this process or (for databases kept in files) another.
+pub struct DatabaseLock {
+    state: Arc<LockState>,
+}
+
+struct LockState {
+    lock: Box<dyn StorageLock>,
+    // the number of guards currently held, the backend is only locked and unlocked by the outermost of them
+    holders: AtomicUsize,
+    // whether the outermost guard locked the backend exclusively
+    is_exclusive: AtomicBool,
+}
+
+pub struct LockGuard {
+    state: Arc<LockState>,
+    is_outermost: bool,
+}
+
+pub fn open<TStorage: Storage>(storage: &TStorage) -> io::Result<DatabaseLock> {
+    let lock = Box::new(storage.open_lock()?);
+
+    Ok(
+        DatabaseLock {
+            state: Arc::new(LockState { lock, holders: AtomicUsize::new(0), is_exclusive: AtomicBool::new(false) })
+        }
+    )
+}
+
+// Blocks until a lock shared with other readers is obtained, or the timeout expires.  If the lock is already held the existing lock is reused, whatever
+// its kind.
+pub fn shared(lock: &DatabaseLock, timeout: Duration) -> io::Result<LockGuard> {
+    acquire(lock, timeout, false)
+}
+
+// Blocks until a lock exclusive of all other readers and writers is obtained, or the timeout expires.  If the lock is already held exclusively the
+// existing lock is reused, should it only be held shared a LockUpgradeError is returned, as the shared lock could not be given up whilst its guards
+// are held.
+pub fn exclusive(lock: &DatabaseLock, timeout: Duration) -> io::Result<LockGuard> {
+    acquire(lock, timeout, true)
+}
+
+fn acquire(lock: &DatabaseLock, timeout: Duration, is_exclusive: bool) -> io::Result<LockGuard> {
+    let is_outermost = lock.state.holders.fetch_add(1, Ordering::SeqCst) == 0;
+    let guard = LockGuard { state: Arc::clone(&lock.state), is_outermost };
+    if !is_outermost {
+        if is_exclusive && !lock.state.is_exclusive.load(Ordering::SeqCst) {
+            return Err(io::Error::new(io::ErrorKind::Deadlock, error::cql::Error::LockUpgradeError))
+        }
+
+        return Ok(guard)
+    }
+
+    let start = Instant::now();
+    loop {
+        let result = if is_exclusive {
+            lock.state.lock.try_lock_exclusive()
+        } else {
+            lock.state.lock.try_lock_shared()
+        };
+
+        match result {
+            Ok(true) => {
+                lock.state.is_exclusive.store(is_exclusive, Ordering::SeqCst);
+                return Ok(guard)
+            },
+            Ok(false) => {
+                if start.elapsed() >= timeout {
+                    return Err(
+                        io::Error::new(io::ErrorKind::TimedOut, error::cql::Error::LockTimeoutError { timeout })
+                    )
+                }
+                thread::sleep(RETRY_INTERVAL);
+            },
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+// Returns the generation of the database, which is advanced each time its capacities are changed, the caller must hold the lock
+pub fn generation(lock: &DatabaseLock) -> io::Result<u64> {
+    lock.state.lock.generation()
+}
+
+// Advances the generation of the database, so that every handle re-reads its capacities the next time it is locked.  The caller must hold the lock
+// exclusively.
+pub fn advance_generation(lock: &DatabaseLock) -> io::Result<()> {
+    let generation = lock.state.lock.generation()?;
+    lock.state.lock.set_generation(generation.wrapping_add(1))
+}
+
+impl LockGuard {
+    // Whether the guard took the lock from the backend, rather than reusing a lock already held
+    pub fn is_outermost(&self) -> bool {
+        self.is_outermost
+    }
+}
+
+impl Drop for LockGuard {
+    fn drop(&mut self) {
+        if self.state.holders.fetch_sub(1, Ordering::SeqCst) == 1 {
+            // file locks are released when the file is closed should this fail
+            let _ = self.state.lock.unlock();
+        }
+    }
+}
@@ -0,0 +1,253 @@
+use std::borrow::Cow;
+use std::convert::TryInto;
+use std::io;
+use std::io::Write;
+use std::fs::File;
+use std::time::Duration;
+
+use memmap2::Mmap;
+use cql_model::{
+    CqlType,
+    CqlRangeReadable,
+    CqlBufferStreamReadable,
+};
+
+use crate::handle::Database;
+use crate::database;
+use crate::key_library;
+use crate::key_library::KEY_SIZE;
+use crate::error;
+use crate::result;
+use crate::vectors::calculate_index;
+
+/// A read only handle to a CQL database, serving reads from memory maps of its files.
+///
+/// The database file and key libraries are mapped into memory on [open](../fn.open_mapped.html), so that reads are served as copies from the mapped
+/// bytes rather than by seeking and reading the files.  Files that have grown since they were mapped, for example by dimensions being linked through another
+/// handle, are remapped as they are read.  Only available with the `mmap` feature.
+///
+/// Reads lock the database in the same way as those made through a [Database](../struct.Database.html) handle.  The mapped files must not be shrunk
+/// whilst the handle is open, so the database must not be [replaced](../fn.create_db.html) or [migrated](../migrate/index.html) until it has been dropped.
+///
+/// # Examples
+/// ```
+/// use cql_u64::U64;
+///
+/// # use std::error::Error;
+/// # use std::fs::remove_file;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// #
+/// # const DATABASE_LOCATION: &str = "./.test_db";
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/db"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/ax"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/key1_2"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/key2_3"));
+/// cql_db::create_db::<U64>(
+///     DATABASE_LOCATION,
+///     &[2, 5, 3, 2]
+/// )?;
+///
+/// cql_db::link_dimensions::<U64>(DATABASE_LOCATION, &[2, 4, 3])?;
+/// cql_db::write_value::<U64>(DATABASE_LOCATION, &[2, 4, 3, 1], 5)?;
+///
+/// let mut database = cql_db::open_mapped::<U64>(DATABASE_LOCATION)?;
+///
+/// assert_eq!(database.read_value(&[2, 4, 3, 1])?, 5);
+/// # Ok(())
+/// # }
+/// ```
+pub struct MappedDatabase<TStore: CqlType> {
+    database: Database<TStore>,
+    db_map: MappedFile,
+    // key_maps[i] maps axis (i + 1) to axis (i + 2)
+    key_maps: Vec<MappedFile>,
+}
+
+impl<TStore: CqlType> MappedDatabase<TStore> {
+    pub(crate) fn open(database: Database<TStore>) -> io::Result<MappedDatabase<TStore>> {
+        let axis_definitions = database.axis_definitions();
+
+        let mut key_maps = Vec::with_capacity(axis_definitions.len().saturating_sub(2));
+        for y_axis_index in 1..axis_definitions.len().saturating_sub(1) {
+            key_maps.push(
+                MappedFile::open(
+                    &key_library::library_location(database.storage().location(), axis_definitions[y_axis_index - 1].id, axis_definitions[y_axis_index].id)
+                )?
+            );
+        }
+
+        Ok(MappedDatabase {
+            db_map: MappedFile::open(&database::location(database.storage().location()))?,
+            key_maps,
+            database,
+        })
+    }
+
+    /// Returns the number of dimensions in the database.
+    pub fn dimensions(&self) -> usize {
+        self.database.dimensions()
+    }
+
+    /// Returns the capacity of each dimension in the database.
+    pub fn capacities(&self) -> Vec<u64> {
+        self.database.capacities()
+    }
+
+    /// Returns how long reads made through the handle will wait to lock the database before giving up.
+    pub fn lock_timeout(&self) -> Duration {
+        self.database.lock_timeout()
+    }
+
+    /// Sets how long reads made through the handle will wait to lock the database before giving up, defaults to
+    /// [DEFAULT_LOCK_TIMEOUT](../constant.DEFAULT_LOCK_TIMEOUT.html).
+    pub fn set_lock_timeout(&mut self, timeout: Duration) {
+        self.database.set_lock_timeout(timeout);
+    }
+
+    /// Reads the value at the given location from the database.  Does not validate given parameters.
+    ///
+    /// See [read_value_unchecked](../fn.read_value_unchecked.html) for more information.
+    pub fn read_value_unchecked(&mut self, location: &[u64]) -> io::Result<TStore::ValueType> where TStore: CqlRangeReadable {
+        let _lock = self.database.lock_shared()?;
+        let position = self.calculate_position(location)?.ok();
+        let buffer = self.read_bytes(position, 1)?;
+        TStore::read_from_buffer(&buffer)
+    }
+
+    /// Reads the value at the given location from the database.
+    ///
+    /// See [read_value](../fn.read_value.html) for more information.
+    pub fn read_value(&mut self, location: &[u64]) -> result::Result<TStore::ValueType> where TStore: CqlRangeReadable {
+        let _lock = self.database.lock_shared()?;
+        self.database.validate_location_shape(location)?;
+        let position = self.calculate_position(location)??;
+
+        let buffer = self.read_bytes(Some(position), 1)?;
+        let value = TStore::read_from_buffer(&buffer)?;
+        Ok(value)
+    }
+
+    /// Reads `n_values` from the given location onward into the given stream.  Does not validate given parameters.
+    ///
+    /// See [read_to_stream_unchecked](../fn.read_to_stream_unchecked.html) for more information.
+    pub fn read_to_stream_unchecked(&mut self, stream: &mut dyn Write, location: &[u64], n_values: u64) -> io::Result<()>
+        where TStore: CqlBufferStreamReadable {
+        let _lock = self.database.lock_shared()?;
+        let position = self.calculate_position(location)?.ok();
+        let buffer = self.read_bytes(position, n_values)?;
+        TStore::read_buffer_to_stream(&buffer, stream)
+    }
+
+    /// Reads `n_values` from the given location onward into the given stream.
+    ///
+    /// See [read_to_stream](../fn.read_to_stream.html) for more information.
+    pub fn read_to_stream(&mut self, stream: &mut dyn Write, location: &[u64], n_values: u64) -> result::Result<()>
+        where TStore: CqlBufferStreamReadable {
+        let _lock = self.database.lock_shared()?;
+        self.database.validate_location_shape(location)?;
+        let position = self.calculate_position(location)??;
+        self.database.validate_stream_length(location, n_values)?;
+
+        let buffer = self.read_bytes(Some(position), n_values)?;
+        TStore::read_buffer_to_stream(&buffer, stream)?;
+        Ok(())
+    }
+
+    // Returns the bytes stored for `n_values` from the given position onward, locations that are not linked read as zeroed bytes
+    fn read_bytes(&mut self, position: Option<u64>, n_values: u64) -> io::Result<Cow<'_, [u8]>> {
+        let length = n_values as usize * TStore::VALUE_SIZE;
+
+        match position {
+            Some(position) => self.db_map.read(position * TStore::VALUE_SIZE as u64, length),
+            None => Ok(Cow::Owned(vec![0; length])),
+        }
+    }
+
+    // Walks the key chain of the given location through the mapped key libraries, returning its position within the database file, or an
+    // ElementsNotLinkedError should any of its elements not be linked
+    fn calculate_position(&mut self, location: &[u64]) -> io::Result<result::cql::Result<u64>> {
+        let axis_definitions = self.database.axis_definitions();
+        let last_index = location.len() - 1;
+
+        let mut x_position = location[0];
+        if last_index == 0 {
+            // minus one to handle the one-indexing
+            return Ok(Ok(x_position - 1))
+        }
+
+        for (y_axis_index, &y_position) in location.iter().enumerate().take(last_index).skip(1) {
+            let key_bytes = self.key_maps[y_axis_index - 1].read(
+                key_library::key_position(x_position, y_position, &axis_definitions[y_axis_index]),
+                KEY_SIZE as usize
+            )?;
+            let key = u64::from_le_bytes(key_bytes[..].try_into().unwrap());
+
+            if key == 0 {
+                return Ok(
+                    Err(
+                        error::cql::Error::ElementsNotLinkedError {
+                            x_dimension: y_axis_index - 1,
+                            x: location[y_axis_index - 1],
+                            y_dimension: y_axis_index,
+                            y: y_position,
+                        }
+                    )
+                )
+            }
+
+            x_position = key;
+        }
+
+        Ok(Ok(calculate_index(x_position, location[last_index], axis_definitions[last_index].max)))
+    }
+}
+
+struct MappedFile {
+    file: File,
+    // empty files cannot be mapped
+    map: Option<Mmap>,
+}
+
+impl MappedFile {
+    fn open(location: &str) -> io::Result<MappedFile> {
+        let mut mapped_file = MappedFile { file: File::open(location)?, map: None };
+        mapped_file.remap()?;
+
+        Ok(mapped_file)
+    }
+
+    fn remap(&mut self) -> io::Result<()> {
+        self.map = if self.file.metadata()?.len() == 0 {
+            None
+        } else {
+            // safe so long as the file is not shrunk whilst mapped, which the database files are not whilst in use
+            Some(unsafe { Mmap::map(&self.file)? })
+        };
+
+        Ok(())
+    }
+
+    fn len(&self) -> u64 {
+        self.map.as_ref().map_or(0, |map| map.len() as u64)
+    }
+
+    // Returns `length` bytes from the given position, remapping the file should they lie beyond what was last mapped.  Bytes beyond the end of the
+    // file are zeroed, as they have not yet been written to.
+    fn read(&mut self, position: u64, length: usize) -> io::Result<Cow<'_, [u8]>> {
+        let end = position + length as u64;
+        if end > self.len() {
+            self.remap()?;
+        }
+
+        let mapped_length = self.len();
+        match &self.map {
+            Some(map) if end <= mapped_length => Ok(Cow::Borrowed(&map[position as usize..end as usize])),
+            Some(map) if position < mapped_length => {
+                let mut buffer = vec![0; length];
+                buffer[..(mapped_length - position) as usize].copy_from_slice(&map[position as usize..]);
+                Ok(Cow::Owned(buffer))
+            },
+            _ => Ok(Cow::Owned(vec![0; length])),
+        }
+    }
+}
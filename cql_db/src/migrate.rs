@@ -0,0 +1,206 @@
+/*!
+Functions for upgrading databases created by earlier versions of cql_db to the current [format version](../constant.FORMAT_VERSION.html).
+
+Migrations are performed in place, and will likely leave the database in an unusable state should they fail part way through, so you may wish to back
+up the database directory before migrating it.
+*/
+use std::cmp::min;
+use std::fs::OpenOptions;
+use std::io;
+use std::io::{ Read, Write, Seek, SeekFrom };
+
+use cql_model::CqlType;
+
+use crate::axis_library;
+use crate::key_library;
+use crate::type_library;
+use crate::layout;
+use crate::layout::{ Layout, FORMAT_VERSION_0_1 };
+use crate::database;
+use crate::error;
+use crate::lock;
+use crate::result;
+use crate::storage::FileStorage;
+use crate::FORMAT_VERSION;
+use crate::DEFAULT_LOCK_TIMEOUT;
+
+const BUFFER_SIZE: usize = 64 * 1024;
+
+/// Upgrades a database created by cql_db 0.1 to the current [format version](../constant.FORMAT_VERSION.html) in place.
+///
+/// Version 0.1 reserved an unused block at the start of each key library index and of the database file, this function moves the contents of each file
+/// forward by that block, records `TStore` as the type the database was created with, and then records the current format version, with each value that is
+/// not zeroed recorded as [written](../fn.is_written.html).  Neither version 0.1
+/// nor 0.2 recorded the format version, so the version that created the database is told from the layout of its files, and databases laid out by
+/// version 0.2 are refused.  Databases that have already been migrated are left unmodified.
+///
+/// # Errors
+///
+/// Will return any [I/O errors](https://doc.rust-lang.org/nightly/std/io/enum.ErrorKind.html) encountered during the execution of the function.  Function may
+/// partially succeed resulting in changes to the file system if such an error is returned, after which the database should be considered unusable.
+///
+/// Additionally, the following [Cql errors](../error/cql/enum.Error.html) may be returned, in which case the database will not have been modified:
+/// - A [FormatVersionError](../error/cql/enum.Error.html#variant.FormatVersionError) will be returned if the database has a recorded format version other
+///   than the current [format version](../constant.FORMAT_VERSION.html), or with a `stored` version of 2 and a `supported` version of 1 if the database
+///   is laid out by cql_db 0.2.
+/// - A [LockTimeoutError](../error/cql/enum.Error.html#variant.LockTimeoutError) will be returned if the database could not be locked within the
+///   [DEFAULT_LOCK_TIMEOUT](../constant.DEFAULT_LOCK_TIMEOUT.html).
+///
+/// # Panics
+///
+/// Function should not panic.  If you get it to panic, please raise an issue in [github](https://github.com/AndrewSisley/CQLDb/issues).
+///
+/// # Examples
+/// ```
+/// # use cql_u64::U64;
+/// # const DATABASE_LOCATION: &str = "./.test_db";
+/// #
+/// # use std::error::Error;
+/// # use std::fs::{ remove_file, write };
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/db"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/ax"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/ty"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/wr"));
+/// # let u64_blocks = |blocks: &[u64]| -> Vec<u8> { blocks.iter().flat_map(|block| block.to_le_bytes().to_vec()).collect() };
+/// # // a 1 dimensional database of capacity 3 created by cql_db 0.1, with values 10, 20 and 30 written to it
+/// # write(format!("{}{}", DATABASE_LOCATION, "/ax"), u64_blocks(&[1, 3]))?;
+/// # write(format!("{}{}", DATABASE_LOCATION, "/db"), u64_blocks(&[0, 10, 20, 30]))?;
+/// cql_db::migrate::from_0_1::<U64>(DATABASE_LOCATION)?;
+///
+/// assert_eq!(cql_db::read_value::<U64>(DATABASE_LOCATION, &[2])?, 20);
+/// # Ok(())
+/// # }
+/// ```
+pub fn from_0_1<TStore: CqlType>(db_location: &str) -> result::Result<()> {
+    let storage = FileStorage::new(db_location);
+    let _lock = lock::exclusive(&lock::open(&storage)?, DEFAULT_LOCK_TIMEOUT)?;
+    if is_migrated(&storage)? {
+        return Ok(())
+    }
+
+    let axis_definitions = axis_library::get_all(&storage)?;
+    if let Layout::Version0_2 = layout::detect::<TStore, _>(&storage, &axis_definitions)? {
+        return Err(format_version_error(FORMAT_VERSION, FORMAT_VERSION_0_1))
+    }
+
+    // the first block of each key library holds the last key added, the unused block follows it
+    for y_axis_index in 1..axis_definitions.len().saturating_sub(1) {
+        remove_bytes(
+            &key_library::library_location(db_location, axis_definitions[y_axis_index - 1].id, axis_definitions[y_axis_index].id),
+            8,
+            8
+        )?;
+    }
+
+    remove_bytes(&database::location(db_location), 0, TStore::VALUE_SIZE as u64)?;
+
+    type_library::create::<TStore, _>(&storage)?;
+    layout::set_format_version::<TStore, _>(&storage)?;
+    Ok(())
+}
+
+/// Records the current [format version](../constant.FORMAT_VERSION.html) against a database created by cql_db 0.2.
+///
+/// Version 0.2 laid out databases as the current version does, but did not record their format version, leaving it to be told from the layout of the
+/// database's files as they are opened, after which it is recorded.  The files of a database with fewer than three dimensions, whose first value is
+/// zeroed and whose last location was never written to, could also have been created by cql_db 0.1, so such databases will be refused until they are
+/// migrated by either this function or [from_0_1](fn.from_0_1.html).  Existing values are not moved, and those that are not zeroed are recorded as
+/// [written](../fn.is_written.html).  Databases that have already been migrated are left unmodified.
+///
+/// # Errors
+///
+/// Will return any [I/O errors](https://doc.rust-lang.org/nightly/std/io/enum.ErrorKind.html) encountered during the execution of the function.  Function may
+/// partially succeed resulting in changes to the file system if such an error is returned.
+///
+/// Additionally, the following [Cql errors](../error/cql/enum.Error.html) may be returned, in which case the database will not have been modified:
+/// - A [FormatVersionError](../error/cql/enum.Error.html#variant.FormatVersionError) will be returned if the database has a recorded format version other
+///   than the current [format version](../constant.FORMAT_VERSION.html), or with a `stored` version of 1 if the database is laid out by cql_db 0.1.
+/// - A [LockTimeoutError](../error/cql/enum.Error.html#variant.LockTimeoutError) will be returned if the database could not be locked within the
+///   [DEFAULT_LOCK_TIMEOUT](../constant.DEFAULT_LOCK_TIMEOUT.html).
+///
+/// # Panics
+///
+/// Function should not panic.  If you get it to panic, please raise an issue in [github](https://github.com/AndrewSisley/CQLDb/issues).
+///
+/// # Examples
+/// ```
+/// # use cql_u64::U64;
+/// # const DATABASE_LOCATION: &str = "./.test_db";
+/// #
+/// # use std::error::Error;
+/// # use std::fs::{ remove_file, write };
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/db"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/ax"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/ty"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/wr"));
+/// # let u64_blocks = |blocks: &[u64]| -> Vec<u8> { blocks.iter().flat_map(|block| block.to_le_bytes().to_vec()).collect() };
+/// # // a 1 dimensional database of capacity 3 created by cql_db 0.2, with 20 written to [2] - which could also have been created by cql_db 0.1
+/// # write(format!("{}{}", DATABASE_LOCATION, "/ax"), u64_blocks(&[1, 3]))?;
+/// # write(format!("{}{}", DATABASE_LOCATION, "/db"), u64_blocks(&[0, 20]))?;
+/// cql_db::migrate::from_0_2::<U64>(DATABASE_LOCATION)?;
+///
+/// assert_eq!(cql_db::read_value::<U64>(DATABASE_LOCATION, &[2])?, 20);
+/// # Ok(())
+/// # }
+/// ```
+pub fn from_0_2<TStore: CqlType>(db_location: &str) -> result::Result<()> {
+    let storage = FileStorage::new(db_location);
+    let _lock = lock::exclusive(&lock::open(&storage)?, DEFAULT_LOCK_TIMEOUT)?;
+    if is_migrated(&storage)? {
+        return Ok(())
+    }
+
+    let axis_definitions = axis_library::get_all(&storage)?;
+    if let Layout::Version0_1 = layout::detect::<TStore, _>(&storage, &axis_definitions)? {
+        return Err(format_version_error(FORMAT_VERSION_0_1, FORMAT_VERSION))
+    }
+
+    layout::set_format_version::<TStore, _>(&storage)?;
+    Ok(())
+}
+
+// Returns whether the database records the current format version, or an error should it record any other
+fn is_migrated(storage: &FileStorage) -> result::Result<bool> {
+    match axis_library::get_format_version(storage)? {
+        Some(FORMAT_VERSION) => Ok(true),
+        Some(stored) => Err(format_version_error(stored, FORMAT_VERSION)),
+        None => Ok(false),
+    }
+}
+
+fn format_version_error(stored: u64, supported: u64) -> error::Error {
+    error::Error::Cql(
+        error::cql::Error::FormatVersionError {
+            stored,
+            supported,
+        }
+    )
+}
+
+// Removes n_bytes from the file starting at the given offset, moving any subsequent bytes forward to fill the gap.
+fn remove_bytes(file_location: &str, offset: u64, n_bytes: u64) -> io::Result<()> {
+    let mut file = OpenOptions::new().read(true).write(true).open(file_location)?;
+    let length = file.metadata()?.len();
+
+    if length <= offset + n_bytes {
+        return file.set_len(min(length, offset))
+    }
+
+    let mut buffer = vec![0; BUFFER_SIZE];
+    let mut read_position = offset + n_bytes;
+    while read_position < length {
+        let n_read = min(BUFFER_SIZE as u64, length - read_position) as usize;
+
+        file.seek(SeekFrom::Start(read_position))?;
+        file.read_exact(&mut buffer[..n_read])?;
+        file.seek(SeekFrom::Start(read_position - n_bytes))?;
+        file.write_all(&buffer[..n_read])?;
+
+        read_position += n_read as u64;
+    }
+
+    file.set_len(length - n_bytes)?;
+    file.sync_all()
+}
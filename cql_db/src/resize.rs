@@ -0,0 +1,264 @@
+use std::io;
+
+use cql_model::CqlType;
+
+use crate::database;
+use crate::axis_library;
+use crate::axis_library::AxisDefinition;
+use crate::key_library;
+use crate::key_library::KEY_SIZE;
+use crate::lock;
+use crate::written;
+use crate::rollup_library;
+use crate::journal;
+use crate::journal::{ Batch, Operation };
+use crate::storage;
+use crate::storage::{ Storage, StorageFile, FileStorage, OptionalFile };
+use crate::DEFAULT_LOCK_TIMEOUT;
+
+const RESIZE_FILE_NAME: &str = "/rsz";
+const BLOCK_SIZE: usize = 8;
+// the most bytes read or written at once whilst moving rows, rows larger than this are moved one at a time
+const MAX_CHUNK_SIZE: u64 = 1024 * 1024;
+// the rows of the database file, or of the key library indexed by the resized dimension, are being moved
+const ROWS_STAGE: u64 = 1;
+// the bits of the written library are being moved, only once the rows of the database file have been
+const WRITTEN_STAGE: u64 = 2;
+
+// The progress of a resize, which moves the rows of several files a chunk at a time, each chunk in its own journal batch alongside the progress made.
+// The first `rows_remaining` rows of the file being moved still have the old row size, every row after them has the new one.  The axis library is only
+// given the new capacity once every row has been moved.
+#[derive(Clone, Copy)]
+struct Progress {
+    dimension_index: u64,
+    new_max: u64,
+    value_size: u64,
+    stage: u64,
+    rows_remaining: u64,
+}
+
+// Increases the capacity of the given dimension.  The key library (or database file, if it is the last dimension) indexed by the dimension uses its
+// capacity as the stride between rows, so that file is re-laid out with the new stride.  Any key library indexed from the dimension only grows in
+// length, which the key libraries already do on demand.
+pub fn resize_dimension<TStore: CqlType>(db_location: &str, dimension_index: usize, new_max: u64) -> io::Result<()> {
+    let storage = FileStorage::new(db_location);
+    let lock = lock::open(&storage)?;
+    let _lock = lock::exclusive(&lock, DEFAULT_LOCK_TIMEOUT)?;
+    journal::recover(&storage)?;
+    recover(&storage)?;
+
+    let axis_definitions = axis_library::get_all(&storage)?;
+    let axis = &axis_definitions[dimension_index];
+
+    // the first dimension is only ever used as a row index, and a single dimension is stored as one row, so only the capacity needs changing
+    if dimension_index == 0 || new_max == axis.max {
+        let mut batch = Batch::new();
+        axis_library::set_max_batched(&AxisDefinition { id: axis.id, max: new_max }, &mut batch)?;
+        batch.commit(&storage, true)?;
+        return lock::advance_generation(&lock)
+    }
+
+    let (file_name, offset, block_size) = rows_file(&axis_definitions, dimension_index, TStore::VALUE_SIZE as u64);
+    let progress = Progress {
+        dimension_index: dimension_index as u64,
+        new_max,
+        value_size: TStore::VALUE_SIZE as u64,
+        stage: ROWS_STAGE,
+        rows_remaining: count_rows(&storage.open(&file_name)?, offset, axis.max * block_size)?,
+    };
+
+    let mut batch = Batch::new();
+    batch.push(progress_operation(&progress));
+    batch.commit(&storage, true)?;
+
+    recover(&storage)?;
+    lock::advance_generation(&lock)?;
+
+    if dimension_index == axis_definitions.len() - 1 {
+        rebuild_rollups::<TStore>(&storage, new_max)?;
+    }
+
+    Ok(())
+}
+
+// Opens the progress of any resize so that it can be checked for being pending each time the database is locked, without being reopened
+pub fn open<TStorage: Storage>(storage: &TStorage, read_only: bool) -> io::Result<OptionalFile<TStorage::File>> {
+    storage::open_optional_file(storage, RESIZE_FILE_NAME, read_only)
+}
+
+pub fn is_pending<TStorage: Storage>(storage: &TStorage, progress_file: &OptionalFile<TStorage::File>) -> io::Result<bool> {
+    match progress_file {
+        OptionalFile::Present(progress_file) => Ok(progress_file.size()? > 0),
+        // the database may have been resized for the first time since the file was opened
+        OptionalFile::Missing => find_pending(storage),
+    }
+}
+
+fn find_pending<TStorage: Storage>(storage: &TStorage) -> io::Result<bool> {
+    match storage.open_read_only(RESIZE_FILE_NAME).and_then(|file| file.size()) {
+        Ok(length) => Ok(length > 0),
+        Err(e) => {
+            if e.kind() == io::ErrorKind::NotFound {
+                return Ok(false)
+            }
+            Err(e)
+        }
+    }
+}
+
+// Completes any interrupted resize, the caller must hold an exclusive lock on the database and have recovered its journal
+pub fn recover<TStorage: Storage>(storage: &TStorage) -> io::Result<()> {
+    while let Some(progress) = get(storage)? {
+        let axis_definitions = axis_library::get_all(storage)?;
+
+        let mut batch = Batch::new();
+        match advance(storage, &axis_definitions, &progress, &mut batch)? {
+            Some(next_progress) => batch.push(progress_operation(&next_progress)),
+            None => {
+                let dimension_index = progress.dimension_index as usize;
+                if dimension_index == axis_definitions.len() - 1 {
+                    // summaries are laid out by row, so they are discarded and rebuilt once the resize has completed
+                    rollup_library::reset_batched(storage, &mut batch)?;
+                }
+
+                axis_library::set_max_batched(&AxisDefinition { id: axis_definitions[dimension_index].id, max: progress.new_max }, &mut batch)?;
+                batch.push(Operation::SetLength { file_name: RESIZE_FILE_NAME.to_string(), length: 0 });
+            },
+        }
+
+        batch.commit(storage, true)?;
+    }
+
+    Ok(())
+}
+
+// Recomputes the summaries of the database's rollup levels, which were discarded once its rows had been moved to their new size.  Should this be
+// interrupted, the summaries not yet recomputed are instead computed from the values each time they are needed, until those values are next written.
+fn rebuild_rollups<TStore: CqlType>(storage: &FileStorage, row_size: u64) -> io::Result<()> {
+    let rollups = rollup_library::open::<TStore, _>(storage, false)?;
+    if rollups.is_empty() {
+        return Ok(())
+    }
+
+    let db_file = database::open(storage, false)?;
+    let n_rows = count_rows(&db_file, 0, row_size * TStore::VALUE_SIZE as u64)?;
+    rollup_library::rebuild(storage, &db_file, &rollups, row_size, n_rows, true)
+}
+
+// Adds the operations moving the next chunk of rows to the given batch, returning the progress that will have been made once it is applied, or None
+// should every row have already been moved
+fn advance<TStorage: Storage>(
+    storage: &TStorage,
+    axis_definitions: &[AxisDefinition],
+    progress: &Progress,
+    batch: &mut Batch
+) -> io::Result<Option<Progress>> {
+    let dimension_index = progress.dimension_index as usize;
+    let old_max = axis_definitions[dimension_index].max;
+    let is_last_dimension = dimension_index == axis_definitions.len() - 1;
+
+    match progress.stage {
+        ROWS_STAGE if progress.rows_remaining > 0 => {
+            let (file_name, offset, block_size) = rows_file(axis_definitions, dimension_index, progress.value_size);
+            let rows_remaining = move_rows(
+                &storage.open(&file_name)?,
+                &file_name,
+                offset,
+                old_max * block_size,
+                progress.new_max * block_size,
+                progress.rows_remaining,
+                batch
+            )?;
+
+            Ok(Some(Progress { rows_remaining, ..*progress }))
+        },
+        ROWS_STAGE if is_last_dimension => {
+            let rows_remaining = written::count_rows(&written::open(storage, false)?, old_max)?;
+            Ok(Some(Progress { stage: WRITTEN_STAGE, rows_remaining, ..*progress }))
+        },
+        WRITTEN_STAGE if progress.rows_remaining > 0 => {
+            let rows_remaining = written::move_rows_batched(&written::open(storage, false)?, old_max, progress.new_max, progress.rows_remaining, batch)?;
+            Ok(Some(Progress { rows_remaining, ..*progress }))
+        },
+        _ => Ok(None),
+    }
+}
+
+// Returns the name of the file laid out by the given dimension, the position of its first row, and the size of each of the blocks that its rows hold
+// one of per element of the dimension
+fn rows_file(axis_definitions: &[AxisDefinition], dimension_index: usize, value_size: u64) -> (String, u64, u64) {
+    if dimension_index == axis_definitions.len() - 1 {
+        return (database::DB_FILE_NAME.to_string(), 0, value_size)
+    }
+
+    // the first block of a key library holds the last key added, and is not part of any row
+    (key_library::library_file_name(axis_definitions[dimension_index - 1].id, axis_definitions[dimension_index].id), KEY_SIZE, KEY_SIZE)
+}
+
+fn count_rows(file: &impl StorageFile, offset: u64, row_size: u64) -> io::Result<u64> {
+    Ok(file.size()?.saturating_sub(offset).div_ceil(row_size))
+}
+
+// Adds the operation moving the last chunk of the first `n_rows` rows of the file to their positions under the new row size to the given batch, zeroing
+// the newly available space at the end of each row, and returns the number of rows left to move.  Moving from the last row backward means that no row
+// is overwritten before it has been moved, and that a chunk reads the same bytes should it be moved again after being interrupted.
+fn move_rows(
+    file: &impl StorageFile,
+    file_name: &str,
+    offset: u64,
+    old_row_size: u64,
+    new_row_size: u64,
+    n_rows: u64,
+    batch: &mut Batch
+) -> io::Result<u64> {
+    let start_row = n_rows.saturating_sub((MAX_CHUNK_SIZE / new_row_size).max(1));
+    let mut rows = vec![0; ((n_rows - start_row) * new_row_size) as usize];
+
+    for (row_index, row) in (start_row..n_rows).zip(rows.chunks_mut(new_row_size as usize)) {
+        file.read_at(offset + row_index * old_row_size, &mut row[..old_row_size as usize])?;
+    }
+
+    batch.push(Operation::Write { file_name: file_name.to_string(), position: offset + start_row * new_row_size, bytes: rows });
+    Ok(start_row)
+}
+
+// The progress of the resize in progress is stored in its own file as [dimension index][new max][value size][stage][rows remaining], the file is
+// emptied once the resize has completed
+fn get<TStorage: Storage>(storage: &TStorage) -> io::Result<Option<Progress>> {
+    if !find_pending(storage)? {
+        return Ok(None)
+    }
+
+    let mut buffer = [0; 5 * BLOCK_SIZE];
+    storage.open_read_only(RESIZE_FILE_NAME)?.read_at(0, &mut buffer)?;
+
+    let blocks: Vec<u64> = buffer
+        .chunks_exact(BLOCK_SIZE)
+        .map(|block| {
+            let mut block_buffer = [0; BLOCK_SIZE];
+            block_buffer.copy_from_slice(block);
+            u64::from_le_bytes(block_buffer)
+        })
+        .collect();
+
+    Ok(
+        Some(
+            Progress {
+                dimension_index: blocks[0],
+                new_max: blocks[1],
+                value_size: blocks[2],
+                stage: blocks[3],
+                rows_remaining: blocks[4],
+            }
+        )
+    )
+}
+
+fn progress_operation(progress: &Progress) -> Operation {
+    let mut bytes = Vec::with_capacity(5 * BLOCK_SIZE);
+    for block in [progress.dimension_index, progress.new_max, progress.value_size, progress.stage, progress.rows_remaining].iter() {
+        bytes.extend_from_slice(&block.to_le_bytes());
+    }
+
+    Operation::Write { file_name: RESIZE_FILE_NAME.to_string(), position: 0, bytes }
+}
@@ -0,0 +1,513 @@
+use std::io;
+use std::ops::Range;
+
+use cql_model::{ CqlType, CqlAggregatable, CqlAggregatableVisitor };
+
+use crate::database;
+use crate::error;
+use crate::aggregation::{ Aggregation, Summary };
+use crate::journal;
+use crate::journal::{ Batch, Operation };
+use crate::storage;
+use crate::storage::{ Storage, StorageFile, OptionalFile };
+
+const ROLLUP_FILE_NAME: &str = "/rl";
+const BLOCK_SIZE: usize = 8;
+const CHECKSUM_SIZE: usize = 8;
+const COUNT_SIZE: usize = 8;
+
+pub struct RollupDefinition {
+    pub summary_size: u64,
+    // the number of values covered by each summary of each level, in ascending order
+    pub levels: Vec<u64>,
+}
+
+impl RollupDefinition {
+    pub fn of<TStore: CqlAggregatable>(levels: &[u64]) -> RollupDefinition {
+        let mut levels = levels.to_vec();
+        levels.sort_unstable();
+        levels.dedup();
+
+        RollupDefinition {
+            summary_size: summary_size::<TStore>(),
+            levels,
+        }
+    }
+
+    pub fn none() -> RollupDefinition {
+        RollupDefinition {
+            summary_size: 0,
+            levels: Vec::new(),
+        }
+    }
+}
+
+pub struct RollupLevel<TFile> {
+    size: u64,
+    file: TFile,
+}
+
+pub struct Rollups<TFile> {
+    summary_size: u64,
+    levels: Vec<RollupLevel<TFile>>,
+    // None only should the database have no levels, as databases with levels cannot be opened with a type unable to summarise them
+    summariser: Option<Summariser>,
+}
+
+impl<TFile> Rollups<TFile> {
+    pub fn is_empty(&self) -> bool {
+        self.levels.is_empty()
+    }
+
+    pub fn sizes(&self) -> Vec<u64> {
+        self.levels.iter().map(|level| level.size).collect()
+    }
+}
+
+// Computes the summaries of an aggregatable type from their encoded values, so that they can be computed where the type is only known to be a CqlType
+#[derive(Clone, Copy)]
+struct Summariser {
+    value_size: u64,
+    summary_size: u64,
+    summarise_values: fn(&[u8]) -> io::Result<Vec<u8>>,
+    summarise_summaries: fn(&[u8]) -> io::Result<Vec<u8>>,
+}
+
+impl Summariser {
+    fn of<TStore: CqlAggregatable>() -> Summariser {
+        Summariser {
+            value_size: TStore::VALUE_SIZE as u64,
+            summary_size: summary_size::<TStore>(),
+            summarise_values: summarise_values::<TStore>,
+            summarise_summaries: summarise_summaries::<TStore>,
+        }
+    }
+}
+
+struct SummariserVisitor;
+
+impl CqlAggregatableVisitor for SummariserVisitor {
+    type Output = Summariser;
+
+    fn visit<TStore: CqlAggregatable>(self) -> Summariser {
+        Summariser::of::<TStore>()
+    }
+}
+
+// The rollup library records the levels of summaries kept alongside the database.  The first block contains the size of each summary, the second
+// the number of levels, and each block after that the number of values covered by each summary of a level.
+//
+// Each level is kept in its own file, holding one summary per `size` values of each row of the database (the last summary of a row covering whatever
+// is left of it), laid out row by row.  A summary holds a checksum of the rest of the summary, the count of the values holding a number, their sum
+// and the values holding the smallest and largest numbers.  Writes update the summaries covering the values they write in the same journal batch as
+// the values, and a summary failing its checksum, such as one never written, is computed from the values it covers each time it is needed.
+pub fn create<TStorage: Storage>(storage: &TStorage, rollup_definition: &RollupDefinition) -> io::Result<()> {
+    let mut buffer = Vec::with_capacity((2 + rollup_definition.levels.len()) * BLOCK_SIZE);
+    buffer.extend_from_slice(&rollup_definition.summary_size.to_le_bytes());
+    buffer.extend_from_slice(&(rollup_definition.levels.len() as u64).to_le_bytes());
+    for level in rollup_definition.levels.iter() {
+        buffer.extend_from_slice(&level.to_le_bytes());
+    }
+
+    // the levels belong to the database files created alongside them, so any existing files are always replaced
+    storage.create(ROLLUP_FILE_NAME, false)?.write_at(0, &buffer)?;
+    for &level in rollup_definition.levels.iter() {
+        storage.create(&level_file_name(level), false)?;
+    }
+
+    Ok(())
+}
+
+// Databases created before rollup levels were recorded have none
+pub fn get<TStorage: Storage>(storage: &TStorage) -> io::Result<RollupDefinition> {
+    let library = match storage.open_read_only(ROLLUP_FILE_NAME).and_then(|file| read_all(&file)) {
+        Ok(library) => library,
+        Err(e) => {
+            if e.kind() == io::ErrorKind::NotFound {
+                return Ok(RollupDefinition::none())
+            }
+            return Err(e)
+        }
+    };
+
+    let blocks: Vec<u64> = library
+        .chunks_exact(BLOCK_SIZE)
+        .map(|block| {
+            let mut block_buffer = [0; BLOCK_SIZE];
+            block_buffer.copy_from_slice(block);
+            u64::from_le_bytes(block_buffer)
+        })
+        .collect();
+
+    if blocks.len() < 2 || blocks.len() as u64 - 2 < blocks[1] {
+        return Err(io::Error::new(io::ErrorKind::UnexpectedEof, "rollup library is shorter than the levels it should contain"))
+    }
+
+    Ok(
+        RollupDefinition {
+            summary_size: blocks[0],
+            levels: blocks[2..2 + blocks[1] as usize].to_vec(),
+        }
+    )
+}
+
+pub fn open<TStore: CqlType, TStorage: Storage>(storage: &TStorage, read_only: bool) -> io::Result<Rollups<OptionalFile<TStorage::File>>> {
+    let rollup_definition = get(storage)?;
+    let summariser = summariser::<TStore>(&rollup_definition)?;
+
+    let mut levels = Vec::with_capacity(rollup_definition.levels.len());
+    for size in rollup_definition.levels {
+        // a missing level holds no summaries
+        levels.push(RollupLevel { size, file: storage::open_optional_file(storage, &level_file_name(size), read_only)? });
+    }
+
+    Ok(Rollups { summary_size: rollup_definition.summary_size, levels, summariser })
+}
+
+// Checks that the summaries of the given levels can be kept up to date as values of TStore are written
+pub fn validate<TStore: CqlType>(rollup_definition: &RollupDefinition) -> io::Result<()> {
+    summariser::<TStore>(rollup_definition).map(|_| ())
+}
+
+// Returns the summariser keeping the summaries of the given levels up to date, failing should TStore not provide one for them rather than leaving the
+// summaries to go stale.  Databases without levels need no summariser.
+fn summariser<TStore: CqlType>(rollup_definition: &RollupDefinition) -> io::Result<Option<Summariser>> {
+    match TStore::visit_aggregatable(SummariserVisitor) {
+        // summaries of a different size belong to a different type
+        Some(summariser) if summariser.summary_size == rollup_definition.summary_size => Ok(Some(summariser)),
+        _ if rollup_definition.levels.is_empty() => Ok(None),
+        _ => Err(
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                error::cql::Error::RollupTypeError { requested: TStore::TYPE_NAME.to_string() }
+            )
+        ),
+    }
+}
+
+// Adds the operations needed to discard every summary to the given batch, for use once the layout of the database's rows has changed
+pub fn reset_batched<TStorage: Storage>(storage: &TStorage, batch: &mut Batch) -> io::Result<()> {
+    for level in get(storage)?.levels {
+        batch.push(Operation::SetLength { file_name: level_file_name(level), length: 0 });
+    }
+
+    Ok(())
+}
+
+// Adds the operations needed to bring the summaries covering each of the given locations up to date with the values that the batch will hold to the
+// given batch, which must already hold the writes of those values.
+pub fn update_batched(db_file: &impl StorageFile, rollups: &Rollups<impl StorageFile>, row_size: u64, locations: &[u64], batch: &mut Batch)
+    -> io::Result<()> {
+    let summariser = match &rollups.summariser {
+        Some(summariser) => summariser,
+        // there are no levels to update
+        None => return Ok(()),
+    };
+
+    // finer levels are updated first, so that coarser summaries made up of them are computed from their updated summaries
+    for (level_index, level) in rollups.levels.iter().enumerate() {
+        let mut indexes: Vec<u64> = locations.iter().map(|&location| summary_index(level.size, row_size, location)).collect();
+        indexes.sort_unstable();
+        indexes.dedup();
+
+        for index in indexes {
+            let bytes = compute_summary(db_file, rollups, summariser, row_size, level_index, index, batch)?;
+            batch.push(Operation::Write { file_name: level_file_name(level.size), position: index * rollups.summary_size, bytes });
+        }
+    }
+
+    Ok(())
+}
+
+// Recomputes every summary of the first `n_rows` rows of the database from their values, a row at a time and each in its own batch, for use once the
+// values have been written without their summaries.
+pub fn rebuild<TStorage: Storage>(
+    storage: &TStorage,
+    db_file: &impl StorageFile,
+    rollups: &Rollups<impl StorageFile>,
+    row_size: u64,
+    n_rows: u64,
+    sync: bool
+) -> io::Result<()> {
+    if rollups.is_empty() {
+        return Ok(())
+    }
+
+    for row_index in 0..n_rows {
+        let locations: Vec<u64> = (row_index * row_size..(row_index + 1) * row_size).collect();
+
+        let mut batch = Batch::new();
+        update_batched(db_file, rollups, row_size, &locations, &mut batch)?;
+        batch.commit(storage, sync)?;
+    }
+
+    Ok(())
+}
+
+// Aggregates each `bucket_size` values of the `n_values` from the start location onward separately from the coarsest level whose summaries line
+// up with the buckets, computing any summaries failing their checksums from the values they cover without storing them.  Returns None should no
+// level line up with the buckets, or should the aggregation not be computable from summaries.
+pub fn rollup<TStore: CqlAggregatable, TAggregation: Aggregation<TStore>>(
+    db_file: &impl StorageFile,
+    rollups: &Rollups<impl StorageFile>,
+    row_size: u64,
+    start_location: u64,
+    n_values: u64,
+    bucket_size: u64
+) -> io::Result<Option<Vec<TAggregation::Output>>> {
+    let summariser = Summariser::of::<TStore>();
+    if rollups.summary_size != summariser.summary_size
+        || !TAggregation::add_summary(&mut TAggregation::State::default(), &StoredSummary::<TStore>::empty().summary) {
+        return Ok(None)
+    }
+
+    let offset = start_location % row_size;
+    let level_index = match rollups.levels.iter().rposition(|level| {
+        offset.is_multiple_of(level.size)
+            && bucket_size.is_multiple_of(level.size)
+            && (n_values.is_multiple_of(level.size) || offset + n_values == row_size)
+    }) {
+        Some(level_index) => level_index,
+        None => return Ok(None),
+    };
+
+    let level = &rollups.levels[level_index];
+    let summaries = read_summaries(
+        db_file,
+        rollups,
+        &summariser,
+        row_size,
+        level_index,
+        summary_range(level.size, row_size, start_location, n_values),
+        &Batch::new()
+    )?;
+
+    let summaries_per_bucket = (bucket_size / level.size) as usize;
+    let mut outputs = Vec::with_capacity(n_values.div_ceil(bucket_size) as usize);
+    for bucket in summaries.chunks(summaries_per_bucket * rollups.summary_size as usize) {
+        let mut state = TAggregation::State::default();
+        for bytes in bucket.chunks_exact(rollups.summary_size as usize) {
+            TAggregation::add_summary(&mut state, &StoredSummary::<TStore>::decode(bytes)?.summary);
+        }
+        outputs.push(TAggregation::result(state));
+    }
+
+    Ok(Some(outputs))
+}
+
+pub fn sync(rollups: &Rollups<impl StorageFile>) -> io::Result<()> {
+    for level in rollups.levels.iter() {
+        level.file.sync()?;
+    }
+
+    Ok(())
+}
+
+pub fn summary_size<TStore: CqlAggregatable>() -> u64 {
+    (CHECKSUM_SIZE + COUNT_SIZE + TStore::SUM_SIZE + 2 * TStore::VALUE_SIZE) as u64
+}
+
+fn level_file_name(level: u64) -> String {
+    format!("{}{}", ROLLUP_FILE_NAME, level)
+}
+
+// Returns the index of the summary of the given level covering the given location
+fn summary_index(level: u64, row_size: u64, location: u64) -> u64 {
+    (location / row_size) * row_size.div_ceil(level) + (location % row_size) / level
+}
+
+// Returns the indexes of the summaries of the given level covering `n_values` from the start location onward, which must begin a summary and all be
+// within the same row
+fn summary_range(level: u64, row_size: u64, start_location: u64, n_values: u64) -> Range<u64> {
+    let first_index = summary_index(level, row_size, start_location);
+    first_index..first_index + n_values.div_ceil(level)
+}
+
+// Returns the encoded summaries with the given indexes, as they will be once the batch has been applied, computing any failing their checksums
+fn read_summaries(
+    db_file: &impl StorageFile,
+    rollups: &Rollups<impl StorageFile>,
+    summariser: &Summariser,
+    row_size: u64,
+    level_index: usize,
+    indexes: Range<u64>,
+    batch: &Batch
+) -> io::Result<Vec<u8>> {
+    let level = &rollups.levels[level_index];
+    let summary_size = rollups.summary_size as usize;
+    let mut buffer = vec![0; (indexes.end - indexes.start) as usize * summary_size];
+    batch.read(&level_file_name(level.size), &level.file, indexes.start * rollups.summary_size, &mut buffer)?;
+
+    for (index, bytes) in indexes.zip(buffer.chunks_exact_mut(summary_size)) {
+        if !is_valid(bytes) {
+            bytes.copy_from_slice(&compute_summary(db_file, rollups, summariser, row_size, level_index, index, batch)?);
+        }
+    }
+
+    Ok(buffer)
+}
+
+// Computes the summary with the given index from the nearest finer level that it is made up of, or should there be none, from the values it covers,
+// as they will be once the batch has been applied
+fn compute_summary(
+    db_file: &impl StorageFile,
+    rollups: &Rollups<impl StorageFile>,
+    summariser: &Summariser,
+    row_size: u64,
+    level_index: usize,
+    index: u64,
+    batch: &Batch
+) -> io::Result<Vec<u8>> {
+    let level = &rollups.levels[level_index];
+    let summaries_per_row = row_size.div_ceil(level.size);
+    let start_location = (index / summaries_per_row) * row_size + (index % summaries_per_row) * level.size;
+    let n_values = level.size.min(row_size - (index % summaries_per_row) * level.size);
+
+    match rollups.levels[..level_index].iter().rposition(|finer_level| level.size.is_multiple_of(finer_level.size)) {
+        Some(finer_index) => {
+            let finer_level_size = rollups.levels[finer_index].size;
+            let finer_summaries = read_summaries(
+                db_file,
+                rollups,
+                summariser,
+                row_size,
+                finer_index,
+                summary_range(finer_level_size, row_size, start_location, n_values),
+                batch
+            )?;
+
+            (summariser.summarise_summaries)(&finer_summaries)
+        },
+        None => {
+            let mut values = vec![0; (n_values * summariser.value_size) as usize];
+            batch.read(database::DB_FILE_NAME, db_file, start_location * summariser.value_size, &mut values)?;
+
+            (summariser.summarise_values)(&values)
+        },
+    }
+}
+
+fn summarise_values<TStore: CqlAggregatable>(values: &[u8]) -> io::Result<Vec<u8>> {
+    let mut stored_summary = StoredSummary::<TStore>::empty();
+    for value in values.chunks_exact(TStore::VALUE_SIZE) {
+        if let Some(number) = TStore::to_number(TStore::read_from_buffer(value)?) {
+            stored_summary.add_value(number, value);
+        }
+    }
+
+    stored_summary.encode()
+}
+
+fn summarise_summaries<TStore: CqlAggregatable>(summaries: &[u8]) -> io::Result<Vec<u8>> {
+    let mut stored_summary = StoredSummary::<TStore>::empty();
+    for bytes in summaries.chunks_exact(summary_size::<TStore>() as usize) {
+        stored_summary.add_summary(&StoredSummary::decode(bytes)?);
+    }
+
+    stored_summary.encode()
+}
+
+// Summaries that were never written, or were only partially written, fail their checksum
+fn is_valid(bytes: &[u8]) -> bool {
+    let mut checksum = [0; CHECKSUM_SIZE];
+    checksum.copy_from_slice(&bytes[..CHECKSUM_SIZE]);
+    u64::from_le_bytes(checksum) == journal::checksum(&bytes[CHECKSUM_SIZE..])
+}
+
+// A summary along with the values holding its smallest and largest numbers, as numbers cannot be written by themselves
+struct StoredSummary<TStore: CqlAggregatable> {
+    summary: Summary<TStore>,
+    min_value: Vec<u8>,
+    max_value: Vec<u8>,
+}
+
+impl<TStore: CqlAggregatable> StoredSummary<TStore> {
+    fn empty() -> StoredSummary<TStore> {
+        StoredSummary {
+            summary: Summary { count: 0, sum: TStore::Sum::default(), min: None, max: None },
+            min_value: vec![0; TStore::VALUE_SIZE],
+            max_value: vec![0; TStore::VALUE_SIZE],
+        }
+    }
+
+    fn add_value(&mut self, number: TStore::Number, value: &[u8]) {
+        self.summary.count += 1;
+        self.summary.sum = self.summary.sum + TStore::Sum::from(number);
+        if self.summary.min.is_none_or(|min| number < min) {
+            self.summary.min = Some(number);
+            self.min_value.copy_from_slice(value);
+        }
+        if self.summary.max.is_none_or(|max| number > max) {
+            self.summary.max = Some(number);
+            self.max_value.copy_from_slice(value);
+        }
+    }
+
+    fn add_summary(&mut self, other: &StoredSummary<TStore>) {
+        self.summary.count += other.summary.count;
+        self.summary.sum = self.summary.sum + other.summary.sum;
+        if let Some(other_min) = other.summary.min {
+            if self.summary.min.is_none_or(|min| other_min < min) {
+                self.summary.min = Some(other_min);
+                self.min_value.copy_from_slice(&other.min_value);
+            }
+        }
+        if let Some(other_max) = other.summary.max {
+            if self.summary.max.is_none_or(|max| other_max > max) {
+                self.summary.max = Some(other_max);
+                self.max_value.copy_from_slice(&other.max_value);
+            }
+        }
+    }
+
+    fn encode(&self) -> io::Result<Vec<u8>> {
+        let count_start = CHECKSUM_SIZE;
+        let sum_start = count_start + COUNT_SIZE;
+        let min_start = sum_start + TStore::SUM_SIZE;
+        let max_start = min_start + TStore::VALUE_SIZE;
+
+        let mut buffer = vec![0; summary_size::<TStore>() as usize];
+        buffer[count_start..sum_start].copy_from_slice(&self.summary.count.to_le_bytes());
+        TStore::write_sum_to_buffer(self.summary.sum, &mut buffer[sum_start..min_start])?;
+        buffer[min_start..max_start].copy_from_slice(&self.min_value);
+        buffer[max_start..max_start + TStore::VALUE_SIZE].copy_from_slice(&self.max_value);
+
+        let checksum = journal::checksum(&buffer[CHECKSUM_SIZE..]);
+        buffer[..CHECKSUM_SIZE].copy_from_slice(&checksum.to_le_bytes());
+        Ok(buffer)
+    }
+
+    // The summary must have passed its checksum
+    fn decode(buffer: &[u8]) -> io::Result<StoredSummary<TStore>> {
+        let count_start = CHECKSUM_SIZE;
+        let sum_start = count_start + COUNT_SIZE;
+        let min_start = sum_start + TStore::SUM_SIZE;
+        let max_start = min_start + TStore::VALUE_SIZE;
+
+        let mut count_buffer = [0; COUNT_SIZE];
+        count_buffer.copy_from_slice(&buffer[count_start..sum_start]);
+        let count = u64::from_le_bytes(count_buffer);
+
+        let min_value = buffer[min_start..max_start].to_vec();
+        let max_value = buffer[max_start..max_start + TStore::VALUE_SIZE].to_vec();
+        let (min, max) = if count == 0 {
+            (None, None)
+        } else {
+            (TStore::to_number(TStore::read_from_buffer(&min_value)?), TStore::to_number(TStore::read_from_buffer(&max_value)?))
+        };
+
+        Ok(
+            StoredSummary {
+                summary: Summary { count, sum: TStore::read_sum_from_buffer(&buffer[sum_start..min_start])?, min, max },
+                min_value,
+                max_value,
+            }
+        )
+    }
+}
+
+fn read_all(file: &impl StorageFile) -> io::Result<Vec<u8>> {
+    let mut buffer = vec![0; file.size()? as usize];
+    file.read_at(0, &mut buffer)?;
+    Ok(buffer)
+}
@@ -0,0 +1,523 @@
+/*!
+Storage backends that CQL databases can be kept in.
+
+A database is made up of several named files (e.g. `/db` and `/ax`), which are held by a [Storage](trait.Storage.html) backend.  The free functions in this
+crate keep databases on the file system using [FileStorage](struct.FileStorage.html), whilst [MemoryStorage](struct.MemoryStorage.html) keeps them in memory,
+for example for tests that should not share a directory, or for caches that do not need to outlive the process.  Databases are created in, and opened from,
+a backend using [create_db_in](../fn.create_db_in.html) and [open_in](../fn.open_in.html), after which they behave the same whatever they are kept in.
+
+Backends only hold bytes - values are encoded and decoded by their [CqlType](https://docs.rs/cql_model/0.3/cql_model/trait.CqlType.html), using the buffer
+based traits of `cql_model` such as [CqlBufferWritable](https://docs.rs/cql_model/0.3/cql_model/trait.CqlBufferWritable.html), so any type can be stored
+in any backend.
+
+# Examples
+```
+use cql_u64::U64;
+use cql_db::storage::MemoryStorage;
+
+# use std::error::Error;
+# fn main() -> Result<(), Box<dyn Error>> {
+let storage = MemoryStorage::new();
+cql_db::create_db_in::<U64, _>(&storage, &[2, 5, 3])?;
+
+let mut database = cql_db::open_in::<U64, _>(storage)?;
+
+database.link_dimensions(&[2, 4])?;
+database.write_value(&[2, 4, 3], 5)?;
+
+assert_eq!(database.read_value(&[2, 4, 3])?, 5);
+# Ok(())
+# }
+```
+*/
+use std::collections::HashMap;
+use std::fs::{ File, OpenOptions, TryLockError };
+use std::io;
+use std::io::{ Read, Write, Seek, SeekFrom };
+use std::sync::{ Arc, Mutex, MutexGuard, RwLock, PoisonError };
+
+const LOCK_FILE_NAME: &str = "/lck";
+
+/// A backend holding the files of a single CQL database.
+///
+/// Files are identified by their name within the database (e.g. `/db`), and every file opened by the same backend with the same name refers to the same
+/// underlying bytes.
+pub trait Storage {
+    /// The handle to a single file held by the backend.
+    type File: StorageFile;
+    /// The lock used to coordinate access to the database between handles.
+    type Lock: StorageLock + 'static;
+
+    /// Opens the named file for reading and writing, returning a [NotFound](https://doc.rust-lang.org/nightly/std/io/enum.ErrorKind.html#variant.NotFound)
+    /// error if it does not exist.
+    fn open(&self, file_name: &str) -> io::Result<Self::File>;
+
+    /// Opens the named file for reading only, returning a [NotFound](https://doc.rust-lang.org/nightly/std/io/enum.ErrorKind.html#variant.NotFound)
+    /// error if it does not exist.  Writes made to the returned file may fail.
+    ///
+    /// Defaults to [open](#tymethod.open), for backends that do not restrict access to their files.
+    fn open_read_only(&self, file_name: &str) -> io::Result<Self::File> {
+        self.open(file_name)
+    }
+
+    /// Creates the named file, emptying it should it already exist.  If `create_new` is true an
+    /// [AlreadyExists](https://doc.rust-lang.org/nightly/std/io/enum.ErrorKind.html#variant.AlreadyExists) error is returned instead should the file
+    /// already exist.
+    fn create(&self, file_name: &str, create_new: bool) -> io::Result<Self::File>;
+
+    /// Opens the named file for reading and writing, creating it empty should it not exist.
+    fn open_or_create(&self, file_name: &str) -> io::Result<Self::File>;
+
+    /// Opens a lock on the database.  Every lock opened on the same database conflicts with every other, however it was opened.
+    fn open_lock(&self) -> io::Result<Self::Lock>;
+}
+
+/// A single file held by a [Storage](trait.Storage.html) backend.
+///
+/// Files grow as they are written to, and bytes that have not been written to read as zeroes.
+pub trait StorageFile {
+    /// Fills the buffer from the given position in the file, bytes beyond the end of the file are zeroed.
+    fn read_at(&self, position: u64, buffer: &mut [u8]) -> io::Result<()>;
+
+    /// Writes the given bytes to the given position in the file, extending it should they reach beyond its end.
+    fn write_at(&self, position: u64, bytes: &[u8]) -> io::Result<()>;
+
+    /// Returns the length of the file in bytes.
+    fn size(&self) -> io::Result<u64>;
+
+    /// Truncates or extends the file to the given length, bytes added by extending the file are zeroed.
+    fn set_size(&self, length: u64) -> io::Result<()>;
+
+    /// Ensures that everything written to the file is durably held by the backend.
+    fn sync(&self) -> io::Result<()>;
+}
+
+/// An advisory lock on a database held by a [Storage](trait.Storage.html) backend.
+///
+/// A lock is only ever taken whilst it is not already held.
+pub trait StorageLock: Send + Sync {
+    /// Attempts to take a lock shared with other readers, returning false should it be held exclusively elsewhere.
+    fn try_lock_shared(&self) -> io::Result<bool>;
+
+    /// Attempts to take a lock exclusive of all other readers and writers, returning false should it be held elsewhere.
+    fn try_lock_exclusive(&self) -> io::Result<bool>;
+
+    /// Releases the lock.
+    fn unlock(&self) -> io::Result<()>;
+
+    /// Returns the generation recorded alongside the lock, or 0 should none have been recorded.  The generation is advanced whenever the capacities of
+    /// the database are changed, so that handles need only re-read them once they have been.
+    fn generation(&self) -> io::Result<u64>;
+
+    /// Records the given generation alongside the lock.
+    fn set_generation(&self, generation: u64) -> io::Result<()>;
+}
+
+/// Keeps a database as files within a directory on the file system.
+///
+/// This is the backend used by the free functions in this crate, and locks the database using advisory locks on a lock file (`/lck`) within the
+/// directory, so that it can be shared with other processes.
+#[derive(Clone, Debug)]
+pub struct FileStorage {
+    db_location: String,
+}
+
+impl FileStorage {
+    /// Creates a backend keeping the database in the given directory, which must already exist.
+    pub fn new(db_location: &str) -> FileStorage {
+        FileStorage { db_location: db_location.to_string() }
+    }
+
+    /// Returns the directory that the database is kept in.
+    pub fn location(&self) -> &str {
+        &self.db_location
+    }
+
+    fn file_location(&self, file_name: &str) -> String {
+        format!("{}{}", self.db_location, file_name)
+    }
+}
+
+impl Storage for FileStorage {
+    type File = File;
+    type Lock = File;
+
+    fn open(&self, file_name: &str) -> io::Result<File> {
+        OpenOptions::new().read(true).write(true).open(self.file_location(file_name))
+    }
+
+    fn open_read_only(&self, file_name: &str) -> io::Result<File> {
+        OpenOptions::new().read(true).open(self.file_location(file_name))
+    }
+
+    fn create(&self, file_name: &str, create_new: bool) -> io::Result<File> {
+        OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .create_new(create_new)
+            .truncate(true)
+            .open(self.file_location(file_name))
+    }
+
+    fn open_or_create(&self, file_name: &str) -> io::Result<File> {
+        OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(self.file_location(file_name))
+    }
+
+    // locks are taken on a file of their own and are held per open file, as platforms with mandatory locks (such as Windows) would otherwise block
+    // writes to the locked file.  Databases in directories that cannot be written to are locked through an existing lock file, should they have one.
+    fn open_lock(&self) -> io::Result<File> {
+        let location = self.file_location(LOCK_FILE_NAME);
+
+        match OpenOptions::new().read(true).write(true).create(true).truncate(false).open(&location) {
+            Err(e) if e.kind() == io::ErrorKind::PermissionDenied => OpenOptions::new().read(true).open(&location).map_err(|_| e),
+            result => result,
+        }
+    }
+}
+
+impl StorageFile for File {
+    fn read_at(&self, position: u64, buffer: &mut [u8]) -> io::Result<()> {
+        let mut file = self;
+        file.seek(SeekFrom::Start(position))?;
+
+        let mut n_read = 0;
+        while n_read < buffer.len() {
+            match file.read(&mut buffer[n_read..]) {
+                Ok(0) => break,
+                Ok(n) => n_read += n,
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => { }
+                Err(e) => return Err(e),
+            }
+        }
+
+        for byte in buffer[n_read..].iter_mut() {
+            *byte = 0;
+        }
+
+        Ok(())
+    }
+
+    fn write_at(&self, position: u64, bytes: &[u8]) -> io::Result<()> {
+        let mut file = self;
+        file.seek(SeekFrom::Start(position))?;
+        file.write_all(bytes)
+    }
+
+    fn size(&self) -> io::Result<u64> {
+        Ok(self.metadata()?.len())
+    }
+
+    fn set_size(&self, length: u64) -> io::Result<()> {
+        self.set_len(length)
+    }
+
+    fn sync(&self) -> io::Result<()> {
+        self.sync_data()
+    }
+}
+
+impl StorageLock for File {
+    fn try_lock_shared(&self) -> io::Result<bool> {
+        lock_result(File::try_lock_shared(self))
+    }
+
+    fn try_lock_exclusive(&self) -> io::Result<bool> {
+        lock_result(File::try_lock(self))
+    }
+
+    fn unlock(&self) -> io::Result<()> {
+        File::unlock(self)
+    }
+
+    // the generation is held in the first block of the lock file
+    fn generation(&self) -> io::Result<u64> {
+        let mut buffer = [0; 8];
+        StorageFile::read_at(self, 0, &mut buffer)?;
+
+        Ok(u64::from_le_bytes(buffer))
+    }
+
+    fn set_generation(&self, generation: u64) -> io::Result<()> {
+        StorageFile::write_at(self, 0, &generation.to_le_bytes())
+    }
+}
+
+fn lock_result(result: Result<(), TryLockError>) -> io::Result<bool> {
+    match result {
+        Ok(()) => Ok(true),
+        Err(TryLockError::WouldBlock) => Ok(false),
+        Err(TryLockError::Error(e)) => Err(e),
+    }
+}
+
+// Opens the named file, for reading only should `read_only` be true
+pub(crate) fn open_file<TStorage: Storage>(storage: &TStorage, file_name: &str, read_only: bool) -> io::Result<TStorage::File> {
+    if read_only {
+        storage.open_read_only(file_name)
+    } else {
+        storage.open(file_name)
+    }
+}
+
+// Opens a file that databases created by earlier versions of this crate may not have.  Should it not exist it is created empty, unless it is to be
+// opened read only, in which case it is left missing and read as if it were empty.
+pub(crate) fn open_optional_file<TStorage: Storage>(
+    storage: &TStorage,
+    file_name: &str,
+    read_only: bool
+) -> io::Result<OptionalFile<TStorage::File>> {
+    if !read_only {
+        return storage.open_or_create(file_name).map(OptionalFile::Present)
+    }
+
+    match storage.open_read_only(file_name) {
+        Ok(file) => Ok(OptionalFile::Present(file)),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(OptionalFile::Missing),
+        Err(e) => Err(e),
+    }
+}
+
+pub(crate) enum OptionalFile<TFile> {
+    Present(TFile),
+    Missing,
+}
+
+impl<TFile: StorageFile> StorageFile for OptionalFile<TFile> {
+    fn read_at(&self, position: u64, buffer: &mut [u8]) -> io::Result<()> {
+        match self {
+            OptionalFile::Present(file) => file.read_at(position, buffer),
+            OptionalFile::Missing => {
+                for byte in buffer.iter_mut() {
+                    *byte = 0;
+                }
+                Ok(())
+            },
+        }
+    }
+
+    fn write_at(&self, position: u64, bytes: &[u8]) -> io::Result<()> {
+        match self {
+            OptionalFile::Present(file) => file.write_at(position, bytes),
+            OptionalFile::Missing => Err(missing_file_error()),
+        }
+    }
+
+    fn size(&self) -> io::Result<u64> {
+        match self {
+            OptionalFile::Present(file) => file.size(),
+            OptionalFile::Missing => Ok(0),
+        }
+    }
+
+    fn set_size(&self, length: u64) -> io::Result<()> {
+        match self {
+            OptionalFile::Present(file) => file.set_size(length),
+            OptionalFile::Missing => Err(missing_file_error()),
+        }
+    }
+
+    fn sync(&self) -> io::Result<()> {
+        match self {
+            OptionalFile::Present(file) => file.sync(),
+            OptionalFile::Missing => Ok(()),
+        }
+    }
+}
+
+fn missing_file_error() -> io::Error {
+    io::Error::new(io::ErrorKind::PermissionDenied, "the file does not exist, and cannot be created by a handle opened read only")
+}
+
+/// Keeps a database in memory.
+///
+/// Each file of the database is held in a `Vec<u8>`, with the same semantics as when the database is kept in files, so that anything that can be done with
+/// a database kept in files can be done with one kept in memory.  Clones of the backend share the same database, allowing several handles to be
+/// [opened](../fn.open_in.html) on it, which lock it against each other as they would a database kept in files.  The database is dropped along with the
+/// last clone of the backend, and any handle opened on it.
+///
+/// Syncing does nothing, as there is nothing to sync to.
+#[derive(Clone, Debug, Default)]
+pub struct MemoryStorage {
+    files: Arc<Mutex<HashMap<String, MemoryFile>>>,
+    lock_state: Arc<Mutex<MemoryLockState>>,
+}
+
+impl MemoryStorage {
+    /// Creates an empty backend, a database must be [created](../fn.create_db_in.html) in it before it can be opened.
+    pub fn new() -> MemoryStorage {
+        MemoryStorage::default()
+    }
+
+    fn files(&self) -> MutexGuard<'_, HashMap<String, MemoryFile>> {
+        self.files.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl Storage for MemoryStorage {
+    type File = MemoryFile;
+    type Lock = MemoryLock;
+
+    fn open(&self, file_name: &str) -> io::Result<MemoryFile> {
+        match self.files().get(file_name) {
+            Some(file) => Ok(file.clone()),
+            None => Err(io::Error::new(io::ErrorKind::NotFound, format!("{} does not exist", file_name))),
+        }
+    }
+
+    fn create(&self, file_name: &str, create_new: bool) -> io::Result<MemoryFile> {
+        let mut files = self.files();
+
+        match files.get(file_name) {
+            Some(_) if create_new => Err(io::Error::new(io::ErrorKind::AlreadyExists, format!("{} already exists", file_name))),
+            Some(file) => {
+                // the file is emptied in place, so that anything already holding it sees it emptied as it would a truncated file
+                file.bytes_mut().clear();
+                Ok(file.clone())
+            },
+            None => {
+                let file = MemoryFile::default();
+                files.insert(file_name.to_string(), file.clone());
+                Ok(file)
+            },
+        }
+    }
+
+    fn open_or_create(&self, file_name: &str) -> io::Result<MemoryFile> {
+        Ok(self.files().entry(file_name.to_string()).or_default().clone())
+    }
+
+    fn open_lock(&self) -> io::Result<MemoryLock> {
+        Ok(MemoryLock { state: Arc::clone(&self.lock_state), held: Mutex::new(None) })
+    }
+}
+
+/// A single file held by a [MemoryStorage](struct.MemoryStorage.html) backend.
+#[derive(Clone, Debug, Default)]
+pub struct MemoryFile {
+    bytes: Arc<RwLock<Vec<u8>>>,
+}
+
+impl MemoryFile {
+    fn bytes_mut(&self) -> std::sync::RwLockWriteGuard<'_, Vec<u8>> {
+        self.bytes.write().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl StorageFile for MemoryFile {
+    fn read_at(&self, position: u64, buffer: &mut [u8]) -> io::Result<()> {
+        let bytes = self.bytes.read().unwrap_or_else(PoisonError::into_inner);
+
+        let start = (position as usize).min(bytes.len());
+        let n_read = buffer.len().min(bytes.len() - start);
+        buffer[..n_read].copy_from_slice(&bytes[start..start + n_read]);
+
+        for byte in buffer[n_read..].iter_mut() {
+            *byte = 0;
+        }
+
+        Ok(())
+    }
+
+    fn write_at(&self, position: u64, input: &[u8]) -> io::Result<()> {
+        let mut bytes = self.bytes_mut();
+
+        let start = position as usize;
+        let end = start + input.len();
+        if bytes.len() < end {
+            bytes.resize(end, 0);
+        }
+        bytes[start..end].copy_from_slice(input);
+
+        Ok(())
+    }
+
+    fn size(&self) -> io::Result<u64> {
+        Ok(self.bytes.read().unwrap_or_else(PoisonError::into_inner).len() as u64)
+    }
+
+    fn set_size(&self, length: u64) -> io::Result<()> {
+        self.bytes_mut().resize(length as usize, 0);
+        Ok(())
+    }
+
+    fn sync(&self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[derive(Debug, Default)]
+struct MemoryLockState {
+    readers: usize,
+    writer: bool,
+    generation: u64,
+}
+
+/// A lock on a database held by a [MemoryStorage](struct.MemoryStorage.html) backend.
+#[derive(Debug)]
+pub struct MemoryLock {
+    state: Arc<Mutex<MemoryLockState>>,
+    // whether the lock is held exclusively, if it is held at all
+    held: Mutex<Option<bool>>,
+}
+
+impl MemoryLock {
+    fn try_lock(&self, exclusive: bool) -> io::Result<bool> {
+        let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+        if state.writer || (exclusive && state.readers > 0) {
+            return Ok(false)
+        }
+
+        if exclusive {
+            state.writer = true;
+        } else {
+            state.readers += 1;
+        }
+        *self.held.lock().unwrap_or_else(PoisonError::into_inner) = Some(exclusive);
+
+        Ok(true)
+    }
+}
+
+impl StorageLock for MemoryLock {
+    fn try_lock_shared(&self) -> io::Result<bool> {
+        self.try_lock(false)
+    }
+
+    fn try_lock_exclusive(&self) -> io::Result<bool> {
+        self.try_lock(true)
+    }
+
+    fn unlock(&self) -> io::Result<()> {
+        let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+
+        match self.held.lock().unwrap_or_else(PoisonError::into_inner).take() {
+            Some(true) => state.writer = false,
+            Some(false) => state.readers -= 1,
+            None => { },
+        }
+
+        Ok(())
+    }
+
+    fn generation(&self) -> io::Result<u64> {
+        Ok(self.state.lock().unwrap_or_else(PoisonError::into_inner).generation)
+    }
+
+    fn set_generation(&self, generation: u64) -> io::Result<()> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner).generation = generation;
+        Ok(())
+    }
+}
+
+impl Drop for MemoryLock {
+    // unlike a file, nothing releases the lock should it be dropped whilst held
+    fn drop(&mut self) {
+        let _ = self.unlock();
+    }
+}
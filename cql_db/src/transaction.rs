@@ -0,0 +1,142 @@
+use cql_model::{ CqlType, CqlBufferWritable };
+
+use crate::database;
+use crate::journal::Batch;
+use crate::written;
+use crate::rollup_library;
+use crate::handle::Database;
+use crate::result;
+use crate::storage::{ Storage, FileStorage };
+
+/// A group of links and writes against a CQL database, applied all at once.
+///
+/// Links and writes are buffered by the transaction, and are only validated against the shape of the database when they are made.  On
+/// [commit](#method.commit) the database is locked exclusively, everything is validated against the database as it will be once the transaction's links
+/// have been made, and all of the changes are recorded in the database's journal before any of them are applied - so should the commit be interrupted, the
+/// whole transaction will be completed the next time the database is used.  Readers will never see part of a transaction.
+///
+/// Dropping a transaction without committing it discards everything buffered by it, leaving the database untouched.
+///
+/// # Examples
+/// ```
+/// use cql_u64::U64;
+///
+/// # use std::error::Error;
+/// # use std::fs::remove_file;
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// #
+/// # const DATABASE_LOCATION: &str = "./.test_db";
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/db"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/ax"));
+/// # let _ = remove_file(format!("{}{}", DATABASE_LOCATION, "/key1_2"));
+/// cql_db::create_db::<U64>(
+///     DATABASE_LOCATION,
+///     &[2, 5, 3]
+/// )?;
+///
+/// let mut database = cql_db::open::<U64>(DATABASE_LOCATION)?;
+///
+/// let mut transaction = database.transaction();
+/// transaction.link_dimensions(&[2, 4])?;
+/// transaction.write_value(&[2, 4, 1], 5)?;
+/// transaction.write_value(&[2, 4, 3], 6)?;
+/// transaction.commit()?;
+///
+/// assert_eq!(database.read_value(&[2, 4, 1])?, 5);
+/// assert_eq!(database.read_value(&[2, 4, 3])?, 6);
+/// # Ok(())
+/// # }
+/// ```
+pub struct Transaction<'a, TStore: CqlType, TStorage: Storage = FileStorage> {
+    database: &'a mut Database<TStore, TStorage>,
+    links: Vec<Vec<u64>>,
+    values: Vec<(Vec<u64>, TStore::ValueType)>,
+}
+
+impl<'a, TStore: CqlBufferWritable, TStorage: Storage> Transaction<'a, TStore, TStorage> {
+    pub(crate) fn new(database: &'a mut Database<TStore, TStorage>) -> Transaction<'a, TStore, TStorage> {
+        Transaction {
+            database,
+            links: Vec::new(),
+            values: Vec::new(),
+        }
+    }
+
+    /// Buffers the linking of the given dimension indexes, to be made on commit.
+    ///
+    /// # Errors
+    ///
+    /// The following [Cql errors](../error/cql/enum.Error.html) may be returned, in which case nothing will have been buffered:
+    /// - A [DimensionsOutOfRangeError](../error/cql/enum.Error.html#variant.DimensionsOutOfRangeError) will be returned if the provided `location.len()` is
+    ///   less than 2, or greater than the number of dimensions in the database - 1.
+    /// - An [IndexOutOfRangeError](../error/cql/enum.Error.html#variant.IndexOutOfRangeError) will be returned if any of the provided indexes in `location`
+    ///   are less than 1, or greater than that dimension's capacity.
+    pub fn link_dimensions(&mut self, location: &[u64]) -> result::Result<()> {
+        self.database.validate_link_dimensions_params(location)?;
+        self.links.push(location.to_vec());
+        Ok(())
+    }
+
+    /// Buffers the writing of the given value to the given location, to be made on commit.  Writes are made after all of the transaction's links, in the
+    /// order that they were buffered.
+    ///
+    /// # Errors
+    ///
+    /// The following [Cql errors](../error/cql/enum.Error.html) may be returned, in which case nothing will have been buffered:
+    /// - A [DimensionsOutOfRangeError](../error/cql/enum.Error.html#variant.DimensionsOutOfRangeError) will be returned if the provided `location.len()` is
+    ///   not equal to the number of dimensions in the database.
+    /// - An [IndexOutOfRangeError](../error/cql/enum.Error.html#variant.IndexOutOfRangeError) will be returned if any of the provided indexes in `location`
+    ///   are less than 1, or greater than that dimension's capacity.
+    pub fn write_value(&mut self, location: &[u64], value: TStore::ValueType) -> result::Result<()> {
+        self.database.validate_location_shape(location)?;
+        self.values.push((location.to_vec(), value));
+        Ok(())
+    }
+
+    /// Applies everything buffered by the transaction to the database, all at once.
+    ///
+    /// # Errors
+    ///
+    /// Will return any [I/O errors](https://doc.rust-lang.org/nightly/std/io/enum.ErrorKind.html) encountered during the execution of the function.  If
+    /// an error is returned once the transaction has been recorded in the journal, it will be completed the next time the database is used.
+    ///
+    /// Additionally, the following [Cql errors](../error/cql/enum.Error.html) may be returned, in which case nothing will have been applied:
+    /// - An [ElementsNotLinkedError](../error/cql/enum.Error.html#variant.ElementsNotLinkedError) will be returned if the elements of any of the written
+    ///   locations are neither linked in the database, nor by the transaction.
+    /// - A [LockTimeoutError](../error/cql/enum.Error.html#variant.LockTimeoutError) will be returned if the database could not be locked within the
+    ///   handle's [lock timeout](../struct.Database.html#method.lock_timeout).
+    pub fn commit(self) -> result::Result<()> {
+        let Transaction { database, links, values } = self;
+        let _lock = database.lock_exclusive()?;
+
+        let mut batch = Batch::new();
+        for location in links.iter() {
+            database.link_dimensions_batched(location, &mut batch)?;
+        }
+
+        let mut positions = Vec::with_capacity(values.len());
+        for (location, _) in values.iter() {
+            positions.push(database.calculate_batched_position(location, &batch)?);
+        }
+
+        written::mark_batched(database.written_file(), &positions, &mut batch)?;
+
+        let encoded_values = database::encode_values::<TStore>(values.into_iter().map(|(_, value)| value).collect())?;
+        for (index, &position) in positions.iter().enumerate() {
+            database::write_bytes_batched::<TStore>(
+                position,
+                &encoded_values[index * TStore::VALUE_SIZE..(index + 1) * TStore::VALUE_SIZE],
+                &mut batch
+            );
+        }
+
+        // the summaries are computed from the values that the batch will hold, so can only be updated once every value has been added to it
+        rollup_library::update_batched(database.db_file(), database.rollups(), database.row_size(), &positions, &mut batch)?;
+
+        batch.commit(database.storage(), database.syncs_journal())?;
+        Ok(())
+    }
+
+    /// Discards everything buffered by the transaction, the same as dropping it.
+    pub fn rollback(self) { }
+}
@@ -0,0 +1,81 @@
+use std::io;
+
+use cql_model::CqlType;
+
+use crate::storage::{ Storage, StorageFile };
+
+const TYPE_FILE_NAME: &str = "/ty";
+const VALUE_SIZE_SIZE: usize = 8;
+
+pub struct TypeDefinition {
+    pub name: String,
+    pub value_size: usize,
+}
+
+impl TypeDefinition {
+    pub fn of<TStore: CqlType>() -> TypeDefinition {
+        TypeDefinition {
+            name: TStore::TYPE_NAME.to_string(),
+            value_size: TStore::VALUE_SIZE,
+        }
+    }
+}
+
+impl PartialEq for TypeDefinition {
+    fn eq(&self, other: &TypeDefinition) -> bool {
+        self.name == other.name && self.value_size == other.value_size
+    }
+}
+
+// The type library records which CqlType the database was created with.  The first block contains the VALUE_SIZE of the type, and the remainder
+// of the file contains the (utf8) name of the type.
+pub fn create<TStore: CqlType, TStorage: Storage>(storage: &TStorage) -> io::Result<()> {
+    let type_definition = TypeDefinition::of::<TStore>();
+
+    let mut buffer = Vec::with_capacity(VALUE_SIZE_SIZE + type_definition.name.len());
+    buffer.extend_from_slice(&(type_definition.value_size as u64).to_le_bytes());
+    buffer.extend_from_slice(type_definition.name.as_bytes());
+
+    // the type belongs to the database files created alongside it, so any existing file is always replaced
+    storage.create(TYPE_FILE_NAME, false)?.write_at(0, &buffer)
+}
+
+// Returns None for databases created before the type was recorded
+pub fn get<TStorage: Storage>(storage: &TStorage) -> io::Result<Option<TypeDefinition>> {
+    let library = match storage.open_read_only(TYPE_FILE_NAME).and_then(|file| read_all(&file)) {
+        Ok(library) => library,
+        Err(e) => {
+            if e.kind() == io::ErrorKind::NotFound {
+                return Ok(None)
+            }
+            return Err(e)
+        }
+    };
+
+    if library.len() < VALUE_SIZE_SIZE {
+        return Err(io::Error::new(io::ErrorKind::UnexpectedEof, "type library is shorter than the value size it should contain"))
+    }
+
+    let mut value_size_buffer = [0; VALUE_SIZE_SIZE];
+    value_size_buffer.copy_from_slice(&library[0..VALUE_SIZE_SIZE]);
+
+    let name = match String::from_utf8(library[VALUE_SIZE_SIZE..].to_vec()) {
+        Ok(name) => name,
+        Err(e) => return Err(io::Error::new(io::ErrorKind::InvalidData, e)),
+    };
+
+    Ok(
+        Some(
+            TypeDefinition {
+                name,
+                value_size: u64::from_le_bytes(value_size_buffer) as usize,
+            }
+        )
+    )
+}
+
+fn read_all(file: &impl StorageFile) -> io::Result<Vec<u8>> {
+    let mut buffer = vec![0; file.size()? as usize];
+    file.read_at(0, &mut buffer)?;
+    Ok(buffer)
+}
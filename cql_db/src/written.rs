@@ -0,0 +1,139 @@
+use std::io;
+
+use crate::journal::{ Batch, Operation };
+use crate::storage;
+use crate::storage::{ Storage, StorageFile, OptionalFile };
+
+const WRITTEN_FILE_NAME: &str = "/wr";
+// the most bits read or written at once whilst moving rows, rows larger than this are moved one at a time
+const MAX_CHUNK_BITS: u64 = 64 * 1024 * 8;
+
+// The written library records which value locations have been written to, so that a written value that is stored as zeroed bytes can be told apart
+// from a value that was never written.  It holds one bit per location in the database file, with the bit for location `n` being bit `n % 8` of byte
+// `n / 8`.  Bits beyond the end of the file are unset.
+pub fn create<TStorage: Storage>(storage: &TStorage) -> io::Result<()> {
+    // the bits belong to the database file created alongside them, so any existing file is always replaced
+    storage.create(WRITTEN_FILE_NAME, false)?;
+    Ok(())
+}
+
+// Databases created before writes were recorded will not have a written library, in which case an empty one is created, or should the database be
+// opened read only, nothing is recorded as written
+pub fn open<TStorage: Storage>(storage: &TStorage, read_only: bool) -> io::Result<OptionalFile<TStorage::File>> {
+    storage::open_optional_file(storage, WRITTEN_FILE_NAME, read_only)
+}
+
+pub fn mark(file: &impl StorageFile, start_location: u64, n_values: u64) -> io::Result<()> {
+    set(file, start_location, &vec![true; n_values as usize])
+}
+
+// Adds the operation unsetting the bits of the given locations to the given batch, without extending the file should they be beyond its end
+pub fn clear_batched(file: &impl StorageFile, start_location: u64, n_values: u64, batch: &mut Batch) -> io::Result<()> {
+    let n_bits = batch.length(WRITTEN_FILE_NAME, file)? * 8;
+    if start_location >= n_bits {
+        return Ok(())
+    }
+
+    set_batched(file, start_location, &vec![false; n_values.min(n_bits - start_location) as usize], batch)
+}
+
+// Adds the operations needed to mark each of the given locations as written to the given batch, on top of any changes already in it
+pub fn mark_batched(file: &impl StorageFile, locations: &[u64], batch: &mut Batch) -> io::Result<()> {
+    for &location in locations {
+        let mut byte = [0];
+        batch.read(WRITTEN_FILE_NAME, file, location / 8, &mut byte)?;
+        byte[0] |= 1 << (location % 8);
+
+        batch.push(Operation::Write { file_name: WRITTEN_FILE_NAME.to_string(), position: location / 8, bytes: byte.to_vec() });
+    }
+
+    Ok(())
+}
+
+pub fn is_marked(file: &impl StorageFile, location: u64) -> io::Result<bool> {
+    Ok(get(file, location, 1)?[0])
+}
+
+// Returns whether each of the `n_values` from the start location onward has been marked as written
+pub fn get(file: &impl StorageFile, start_location: u64, n_values: u64) -> io::Result<Vec<bool>> {
+    if n_values == 0 {
+        return Ok(Vec::new())
+    }
+
+    let first_byte = start_location / 8;
+    let mut bytes = vec![0; ((start_location + n_values - 1) / 8 - first_byte + 1) as usize];
+    file.read_at(first_byte, &mut bytes)?;
+
+    Ok(
+        (start_location..start_location + n_values)
+            .map(|location| bytes[(location / 8 - first_byte) as usize] & (1 << (location % 8)) != 0)
+            .collect()
+    )
+}
+
+// Sets the bits of the locations from the start location onward to the given values
+pub fn set(file: &impl StorageFile, start_location: u64, written: &[bool]) -> io::Result<()> {
+    if written.is_empty() {
+        return Ok(())
+    }
+
+    let first_byte = start_location / 8;
+    let mut bytes = vec![0; ((start_location + written.len() as u64 - 1) / 8 - first_byte + 1) as usize];
+    file.read_at(first_byte, &mut bytes)?;
+    set_bits(&mut bytes, start_location, written);
+
+    file.write_at(first_byte, &bytes)
+}
+
+pub fn count_rows(file: &impl StorageFile, row_size: u64) -> io::Result<u64> {
+    Ok((file.size()? * 8).div_ceil(row_size))
+}
+
+// Adds the operations moving the bits of the last chunk of the first `n_rows` rows to match rows of the database file re-laid out from the old row size
+// to the new (larger) one to the given batch, leaving the newly available locations at the end of each row unset, and returns the number of rows left
+// to move.  Rows are moved from the last backward, so that no bit is overwritten before it has been moved.
+pub fn move_rows_batched(file: &impl StorageFile, old_row_size: u64, new_row_size: u64, n_rows: u64, batch: &mut Batch) -> io::Result<u64> {
+    let start_row = n_rows.saturating_sub((MAX_CHUNK_BITS / new_row_size).max(1));
+    let written = get(file, start_row * old_row_size, (n_rows - start_row) * old_row_size)?;
+    let mut moved = vec![false; ((n_rows - start_row) * new_row_size) as usize];
+
+    for (old_row, new_row) in written.chunks(old_row_size as usize).zip(moved.chunks_mut(new_row_size as usize)) {
+        new_row[..old_row.len()].copy_from_slice(old_row);
+    }
+
+    set_batched(file, start_row * new_row_size, &moved, batch)?;
+    Ok(start_row)
+}
+
+// Adds the operation setting the bits of the locations from the start location onward to the given values to the given batch, on top of any changes
+// already in it
+fn set_batched(file: &impl StorageFile, start_location: u64, written: &[bool], batch: &mut Batch) -> io::Result<()> {
+    if written.is_empty() {
+        return Ok(())
+    }
+
+    let first_byte = start_location / 8;
+    let mut bytes = vec![0; ((start_location + written.len() as u64 - 1) / 8 - first_byte + 1) as usize];
+    batch.read(WRITTEN_FILE_NAME, file, first_byte, &mut bytes)?;
+    set_bits(&mut bytes, start_location, written);
+
+    batch.push(Operation::Write { file_name: WRITTEN_FILE_NAME.to_string(), position: first_byte, bytes });
+    Ok(())
+}
+
+// Sets the bits of the locations from the start location onward within the given bytes, which start from the byte holding the start location
+fn set_bits(bytes: &mut [u8], start_location: u64, written: &[bool]) {
+    let first_byte = start_location / 8;
+    for (location, &is_written) in (start_location..).zip(written) {
+        let byte = &mut bytes[(location / 8 - first_byte) as usize];
+        if is_written {
+            *byte |= 1 << (location % 8);
+        } else {
+            *byte &= !(1 << (location % 8));
+        }
+    }
+}
+
+pub fn sync(file: &impl StorageFile) -> io::Result<()> {
+    file.sync()
+}
@@ -0,0 +1,132 @@
+#![allow(non_snake_case)]
+
+mod constants;
+
+use serial_test::serial;
+
+use constants::DATABASE_LOCATION;
+use cql_u64::U64;
+use cql_db::error;
+use cql_db::aggregation::{ Sum, Min, Max, Mean, Count };
+use cql_db::storage::MemoryStorage;
+
+#[test]
+#[serial]
+fn _3d_u64_database_aggregates_values_spanning_several_blocks() {
+    const N_VALUES: u64 = 20_000;
+
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[2, 2, N_VALUES]
+    ).unwrap();
+
+    cql_db::link_dimensions::<U64>(DATABASE_LOCATION, &[1, 2]).unwrap();
+    cql_db::link_dimensions::<U64>(DATABASE_LOCATION, &[2, 1]).unwrap();
+
+    let values: Vec<u64> = (1..=N_VALUES).collect();
+    let stream: Vec<u8> = values.iter().flat_map(|value| value.to_le_bytes().to_vec()).collect();
+    cql_db::write_from_stream::<U64>(DATABASE_LOCATION, &mut stream.as_slice(), &[2, 1, 1], N_VALUES).unwrap();
+
+    assert_eq!(cql_db::aggregate::<U64, Sum>(DATABASE_LOCATION, &[2, 1, 1], N_VALUES).unwrap(), (N_VALUES * (N_VALUES + 1) / 2) as u128);
+    assert_eq!(cql_db::aggregate::<U64, Min>(DATABASE_LOCATION, &[2, 1, 2], N_VALUES - 1).unwrap(), Some(2));
+    assert_eq!(cql_db::aggregate::<U64, Max>(DATABASE_LOCATION, &[2, 1, 1], N_VALUES - 1).unwrap(), Some(N_VALUES - 1));
+    assert_eq!(cql_db::aggregate::<U64, Mean>(DATABASE_LOCATION, &[2, 1, 1], N_VALUES).unwrap(), Some((N_VALUES + 1) as f64 / 2.0));
+    assert_eq!(cql_db::aggregate_unchecked::<U64, Count>(DATABASE_LOCATION, &[2, 1, 1], N_VALUES).unwrap(), N_VALUES);
+
+    // the row linked first is untouched by the values written to the second
+    assert_eq!(cql_db::aggregate::<U64, Max>(DATABASE_LOCATION, &[1, 2, 1], N_VALUES).unwrap(), Some(0));
+}
+
+#[test]
+#[serial]
+fn _1d_u64_database_sums_without_overflowing() {
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[3]
+    ).unwrap();
+
+    cql_db::write_values::<U64>(DATABASE_LOCATION, &[(&[1], u64::MAX), (&[2], u64::MAX), (&[3], 2)]).unwrap();
+
+    assert_eq!(cql_db::aggregate::<U64, Sum>(DATABASE_LOCATION, &[1], 3).unwrap(), u64::MAX as u128 * 2 + 2);
+    assert_eq!(cql_db::aggregate::<U64, Max>(DATABASE_LOCATION, &[1], 3).unwrap(), Some(u64::MAX));
+}
+
+#[test]
+#[serial]
+fn _1d_u64_database_returns_empty_aggregations_given_no_values() {
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[3]
+    ).unwrap();
+
+    cql_db::write_value::<U64>(DATABASE_LOCATION, &[2], 5).unwrap();
+
+    assert_eq!(cql_db::aggregate::<U64, Sum>(DATABASE_LOCATION, &[2], 0).unwrap(), 0);
+    assert_eq!(cql_db::aggregate::<U64, Min>(DATABASE_LOCATION, &[2], 0).unwrap(), None);
+    assert_eq!(cql_db::aggregate::<U64, Max>(DATABASE_LOCATION, &[2], 0).unwrap(), None);
+    assert_eq!(cql_db::aggregate::<U64, Mean>(DATABASE_LOCATION, &[2], 0).unwrap(), None);
+    assert_eq!(cql_db::aggregate::<U64, Count>(DATABASE_LOCATION, &[2], 0).unwrap(), 0);
+}
+
+#[test]
+fn _2d_u64_memory_database_aggregates_values() {
+    let storage = MemoryStorage::new();
+    cql_db::create_db_in::<U64, _>(&storage, &[2, 4]).unwrap();
+
+    let mut database = cql_db::open_in::<U64, _>(storage).unwrap();
+    database.write_values(&[(&[2, 1], 3), (&[2, 2], 1), (&[2, 4], 8)]).unwrap();
+
+    assert_eq!(database.aggregate::<Sum>(&[2, 1], 4).unwrap(), 12);
+    assert_eq!(database.aggregate::<Min>(&[2, 1], 4).unwrap(), Some(0));
+    assert_eq!(database.aggregate_unchecked::<Mean>(&[2, 1], 4).unwrap(), Some(3.0));
+}
+
+#[test]
+#[serial]
+fn aggregate__returns_IndexOutOfRangeError__given_3d_u64_database_and_too_many_values() {
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[2, 2, 3]
+    ).unwrap();
+
+    cql_db::link_dimensions::<U64>(DATABASE_LOCATION, &[1, 1]).unwrap();
+
+    let result = match cql_db::aggregate::<U64, Sum>(DATABASE_LOCATION, &[1, 1, 2], 3) {
+        Err(error::Error::Cql(cql_error)) => Some(cql_error),
+        _ => None,
+    };
+
+    assert_eq!(
+        result.unwrap(),
+        error::cql::Error::IndexOutOfRangeError {
+            dimension_index: 2,
+            requested: 4,
+            min: 1,
+            max: 3,
+        }
+    );
+}
+
+#[test]
+#[serial]
+fn aggregate__returns_ElementsNotLinkedError__given_3d_u64_database_and_unlinked_location() {
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[2, 2, 3]
+    ).unwrap();
+
+    let result = match cql_db::aggregate::<U64, Count>(DATABASE_LOCATION, &[2, 1, 1], 3) {
+        Err(error::Error::Cql(cql_error)) => Some(cql_error),
+        _ => None,
+    };
+
+    assert_eq!(
+        result.unwrap(),
+        error::cql::Error::ElementsNotLinkedError {
+            x_dimension: 0,
+            x: 2,
+            y_dimension: 1,
+            y: 1,
+        }
+    );
+}
@@ -0,0 +1,240 @@
+#![cfg(feature = "async")]
+#![allow(non_snake_case)]
+
+mod constants;
+
+use serial_test::serial;
+use std::future::Future;
+use std::io;
+use std::io::Cursor;
+use std::pin::Pin;
+use std::task::{ Context, Poll };
+use tokio::io::AsyncWrite;
+
+use constants::DATABASE_LOCATION;
+use cql_u64::U64;
+use cql_db::asynchronous;
+use cql_db::error;
+
+fn block_on<F: Future>(future: F) -> F::Output {
+    tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(future)
+}
+
+#[test]
+#[serial]
+fn _4d_u64_database_allows_for_async_links_and_single_point_read_writes() {
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[2, 3, 2, 4]
+    ).unwrap();
+
+    block_on(async {
+        asynchronous::link_dimensions::<U64>(DATABASE_LOCATION, &[2, 3, 1]).await.unwrap();
+        asynchronous::link_dimensions_unchecked::<U64>(DATABASE_LOCATION, &[1, 1, 2]).await.unwrap();
+
+        asynchronous::write_value::<U64>(DATABASE_LOCATION, &[2, 3, 1, 4], 42).await.unwrap();
+        asynchronous::write_value_unchecked::<U64>(DATABASE_LOCATION, &[1, 1, 2, 1], 7).await.unwrap();
+
+        assert_eq!(asynchronous::read_value::<U64>(DATABASE_LOCATION, &[2, 3, 1, 4]).await.unwrap(), 42);
+        assert_eq!(asynchronous::read_value_unchecked::<U64>(DATABASE_LOCATION, &[1, 1, 2, 1]).await.unwrap(), 7);
+        assert_eq!(asynchronous::read_value::<U64>(DATABASE_LOCATION, &[1, 1, 2, 2]).await.unwrap(), 0);
+    });
+
+    assert_eq!(cql_db::read_value::<U64>(DATABASE_LOCATION, &[2, 3, 1, 4]).unwrap(), 42);
+}
+
+#[test]
+#[serial]
+fn _3d_u64_database_async_read_to_stream_matches_read_to_stream() {
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[2, 2, 4]
+    ).unwrap();
+
+    cql_db::link_dimensions::<U64>(DATABASE_LOCATION, &[1, 2]).unwrap();
+    cql_db::write_values::<U64>(DATABASE_LOCATION, &[(&[1, 2, 1], 3), (&[1, 2, 3], 5)]).unwrap();
+
+    let mut stream = Cursor::new(Vec::new());
+    cql_db::read_to_stream::<U64>(DATABASE_LOCATION, &mut stream, &[1, 2, 1], 4).unwrap();
+
+    let (async_stream, unchecked_async_stream) = block_on(async {
+        let mut async_stream = Vec::new();
+        asynchronous::read_to_stream::<U64>(DATABASE_LOCATION, &mut async_stream, &[1, 2, 1], 4).await.unwrap();
+
+        let mut unchecked_async_stream = Vec::new();
+        asynchronous::read_to_stream_unchecked::<U64>(DATABASE_LOCATION, &mut unchecked_async_stream, &[1, 2, 1], 4).await.unwrap();
+
+        (async_stream, unchecked_async_stream)
+    });
+
+    assert_eq!(async_stream.len(), 4 * 8);
+    assert_eq!(&async_stream, stream.get_ref());
+    assert_eq!(&unchecked_async_stream, stream.get_ref());
+}
+
+#[test]
+#[serial]
+fn _3d_u64_database_allows_for_links_and_single_point_read_writes_through_async_handle() {
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[2, 3, 4]
+    ).unwrap();
+
+    block_on(async {
+        let mut database = asynchronous::open::<U64>(DATABASE_LOCATION).await.unwrap();
+
+        database.link_dimensions(&[2, 3]).await.unwrap();
+        database.link_dimensions_unchecked(&[1, 1]).await.unwrap();
+
+        database.write_value(&[2, 3, 4], 42).await.unwrap();
+        database.write_value_unchecked(&[1, 1, 2], 7).await.unwrap();
+
+        assert_eq!(database.read_value(&[2, 3, 4]).await.unwrap(), 42);
+        assert_eq!(database.read_value_unchecked(&[1, 1, 2]).await.unwrap(), 7);
+        assert_eq!(database.read_value(&[1, 1, 3]).await.unwrap(), 0);
+    });
+
+    assert_eq!(cql_db::read_value::<U64>(DATABASE_LOCATION, &[2, 3, 4]).unwrap(), 42);
+}
+
+#[test]
+#[serial]
+fn _1d_u64_database_async_read_to_stream_matches_read_to_stream__given_values_spanning_several_blocks() {
+    let n_values = 20000;
+
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[n_values]
+    ).unwrap();
+
+    let mut database = cql_db::open::<U64>(DATABASE_LOCATION).unwrap();
+    for index in (1..=n_values).step_by(7) {
+        database.write_value(&[index], index * 3).unwrap();
+    }
+
+    let mut stream = Cursor::new(Vec::new());
+    cql_db::read_to_stream::<U64>(DATABASE_LOCATION, &mut stream, &[1], n_values).unwrap();
+
+    let async_stream = block_on(async {
+        let mut database = asynchronous::open::<U64>(DATABASE_LOCATION).await.unwrap();
+
+        let mut async_stream = Vec::new();
+        database.read_to_stream(&mut async_stream, &[1], n_values).await.unwrap();
+        async_stream
+    });
+
+    assert_eq!(async_stream.len(), n_values as usize * 8);
+    assert_eq!(&async_stream, stream.get_ref());
+}
+
+#[test]
+#[serial]
+fn read_to_stream__returns_stream_error__given_async_1d_u64_database_and_failing_stream() {
+    let n_values = 20000;
+
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[n_values]
+    ).unwrap();
+
+    let mut stream = FailingStream;
+
+    let (result, value) = block_on(async {
+        let mut database = asynchronous::open::<U64>(DATABASE_LOCATION).await.unwrap();
+
+        let result = match database.read_to_stream(&mut stream, &[1], n_values).await {
+            Err(error::Error::Io(io_error)) => Some(io_error),
+            _ => None,
+        };
+
+        // the handle must have been released by the abandoned read
+        database.write_value(&[1], 5).await.unwrap();
+        (result, database.read_value(&[1]).await.unwrap())
+    });
+
+    assert_eq!(result.unwrap().kind(), io::ErrorKind::ConnectionReset);
+    assert_eq!(value, 5);
+}
+
+#[test]
+#[serial]
+fn write_value__returns_ElementsNotLinkedError__given_async_3d_u64_database_and_unlinked_location() {
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[2, 2, 2]
+    ).unwrap();
+
+    let result = match block_on(asynchronous::write_value::<U64>(DATABASE_LOCATION, &[2, 1, 1], 3)) {
+        Err(error::Error::Cql(cql_error)) => Some(cql_error),
+        _ => None,
+    };
+
+    assert_eq!(
+        result.unwrap(),
+        error::cql::Error::ElementsNotLinkedError {
+            x_dimension: 0,
+            x: 2,
+            y_dimension: 1,
+            y: 1,
+        }
+    );
+}
+
+#[test]
+#[serial]
+fn read_to_stream__returns_IndexOutOfRangeError_and_writes_nothing__given_async_2d_u64_database_and_too_many_values() {
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[2, 3]
+    ).unwrap();
+
+    let mut stream = Vec::new();
+
+    let result = match block_on(asynchronous::read_to_stream::<U64>(DATABASE_LOCATION, &mut stream, &[1, 2], 3)) {
+        Err(error::Error::Cql(cql_error)) => Some(cql_error),
+        _ => None,
+    };
+
+    assert_eq!(
+        result.unwrap(),
+        error::cql::Error::IndexOutOfRangeError {
+            dimension_index: 1,
+            requested: 4,
+            min: 1,
+            max: 3,
+        }
+    );
+    assert!(stream.is_empty());
+}
+
+#[test]
+#[serial]
+fn link_dimensions__returns_DimensionsOutOfRangeError__given_async_3d_u64_database_and_1d_location() {
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[2, 2, 2]
+    ).unwrap();
+
+    let result = match block_on(asynchronous::link_dimensions::<U64>(DATABASE_LOCATION, &[1])) {
+        Err(error::Error::Cql(cql_error)) => Some(cql_error),
+        _ => None,
+    };
+
+    assert_eq!(result.unwrap(), error::cql::Error::DimensionsOutOfRangeError { requested: 1, min: 2, max: 2 });
+}
+
+struct FailingStream;
+
+impl AsyncWrite for FailingStream {
+    fn poll_write(self: Pin<&mut Self>, _context: &mut Context<'_>, _buffer: &[u8]) -> Poll<io::Result<usize>> {
+        Poll::Ready(Err(io::Error::new(io::ErrorKind::ConnectionReset, "stream closed")))
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _context: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, _context: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+}
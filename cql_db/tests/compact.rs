@@ -0,0 +1,116 @@
+#![allow(non_snake_case)]
+
+mod constants;
+
+use serial_test::serial;
+use std::fs;
+
+use constants::DATABASE_LOCATION;
+use cql_u64::U64;
+use cql_db::error;
+
+const COMPACTED_DATABASE_LOCATION: &str = "./.test_db/compacted";
+
+fn create_compacted_db_directory() {
+    let _ = fs::remove_dir_all(COMPACTED_DATABASE_LOCATION);
+    fs::create_dir_all(COMPACTED_DATABASE_LOCATION).unwrap();
+}
+
+fn file_size(db_location: &str, file_name: &str) -> u64 {
+    fs::metadata(format!("{}/{}", db_location, file_name)).unwrap().len()
+}
+
+#[test]
+#[serial]
+fn _4d_u64_database_compacts_to_only_the_linked_rows() {
+    create_compacted_db_directory();
+
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[3, 3, 3, 4]
+    ).unwrap();
+
+    let mut database = cql_db::open::<U64>(DATABASE_LOCATION).unwrap();
+    for i in 1..4 {
+        for j in 1..4 {
+            database.link_dimensions(&[i, j, 2]).unwrap();
+            database.write_value(&[i, j, 2, j], i * 10 + j).unwrap();
+        }
+    }
+    database.unlink_dimensions(&[1, 2]).unwrap();
+    database.unlink_dimensions(&[3]).unwrap_err();
+    database.unlink_dimensions(&[3, 1, 2]).unwrap();
+    drop(database);
+
+    cql_db::compact::<U64>(
+        DATABASE_LOCATION,
+        COMPACTED_DATABASE_LOCATION
+    ).unwrap();
+
+    for i in 1..4 {
+        for j in 1..4 {
+            let expected = cql_db::read_value::<U64>(DATABASE_LOCATION, &[i, j, 2, j]).ok();
+            let result = cql_db::read_value::<U64>(COMPACTED_DATABASE_LOCATION, &[i, j, 2, j]).ok();
+
+            assert_eq!(result, expected);
+        }
+    }
+
+    let n_linked_rows = 9 - 2;
+    assert_eq!(file_size(COMPACTED_DATABASE_LOCATION, "db"), n_linked_rows * 4 * 8);
+    assert!(file_size(COMPACTED_DATABASE_LOCATION, "key1_2") <= file_size(DATABASE_LOCATION, "key1_2"));
+    assert!(file_size(COMPACTED_DATABASE_LOCATION, "key2_3") < file_size(DATABASE_LOCATION, "key2_3"));
+}
+
+#[test]
+#[serial]
+fn _2d_u64_database_compacts_to_identical_values() {
+    create_compacted_db_directory();
+
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[3, 2]
+    ).unwrap();
+
+    cql_db::write_value::<U64>(DATABASE_LOCATION, &[2, 1], 5).unwrap();
+
+    cql_db::compact::<U64>(
+        DATABASE_LOCATION,
+        COMPACTED_DATABASE_LOCATION
+    ).unwrap();
+
+    for i in 1..4 {
+        for j in 1..3 {
+            assert_eq!(
+                cql_db::read_value::<U64>(COMPACTED_DATABASE_LOCATION, &[i, j]).unwrap(),
+                cql_db::read_value::<U64>(DATABASE_LOCATION, &[i, j]).unwrap()
+            );
+        }
+    }
+}
+
+#[test]
+#[serial]
+fn compact__returns_Io_AlreadyExists__given_target_containing_a_database() {
+    create_compacted_db_directory();
+
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[2, 2, 2]
+    ).unwrap();
+
+    cql_db::create_db_unchecked::<U64>(
+        COMPACTED_DATABASE_LOCATION,
+        &[2, 2, 2]
+    ).unwrap();
+
+    let result = match cql_db::compact::<U64>(
+        DATABASE_LOCATION,
+        COMPACTED_DATABASE_LOCATION
+    ) {
+        Err(error::Error::Io(io_error)) => Some(io_error),
+        _ => None,
+    };
+
+    assert_eq!(result.unwrap().kind(), std::io::ErrorKind::AlreadyExists);
+}
@@ -238,4 +238,5 @@ struct DummyType;
 impl CqlType for DummyType {
     type ValueType = Option<f64>;
     const VALUE_SIZE: usize = 9;
+    const TYPE_NAME: &'static str = "create_db::DummyType";
 }
@@ -0,0 +1,81 @@
+mod constants;
+
+use serial_test::serial;
+use std::fs;
+use std::io::Cursor;
+
+use constants::DATABASE_LOCATION;
+use cql_u64::U64;
+use cql_db::Durability;
+
+const DURABILITIES: [Durability; 4] = [Durability::None, Durability::Explicit, Durability::PerBatch, Durability::PerCall];
+
+#[test]
+#[serial]
+fn open_uses_default_durability() {
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[2, 2]
+    ).unwrap();
+
+    let database = cql_db::open::<U64>(DATABASE_LOCATION).unwrap();
+
+    assert_eq!(database.durability(), cql_db::DEFAULT_DURABILITY);
+}
+
+#[test]
+#[serial]
+fn _3d_u64_database_allows_for_changes_to_be_made_under_any_durability() {
+    for &durability in DURABILITIES.iter() {
+        cql_db::create_db_unchecked::<U64>(
+            DATABASE_LOCATION,
+            &[2, 2, 4]
+        ).unwrap();
+
+        let mut database = cql_db::open::<U64>(DATABASE_LOCATION).unwrap();
+        database.set_durability(durability);
+        assert_eq!(database.durability(), durability);
+
+        database.link_dimensions(&[1, 2]).unwrap();
+        database.link_dimensions(&[2, 1]).unwrap();
+        database.write_value(&[1, 2, 1], 1).unwrap();
+        database.write_values(&[(&[1, 2, 2], 2), (&[2, 1, 4], 3)]).unwrap();
+
+        let mut stream = Cursor::new(Vec::new());
+        for value in 4..6u64 {
+            stream.get_mut().extend_from_slice(&value.to_le_bytes());
+        }
+        database.write_from_stream(&mut stream, &[1, 2, 3], 2).unwrap();
+
+        let mut transaction = database.transaction();
+        transaction.link_dimensions(&[2, 2]).unwrap();
+        transaction.write_value(&[2, 2, 1], 6).unwrap();
+        transaction.commit().unwrap();
+
+        database.unlink_dimensions(&[2, 1]).unwrap();
+        database.flush().unwrap();
+
+        assert_eq!(database.read_value(&[1, 2, 1]).unwrap(), 1);
+        assert_eq!(database.read_value(&[1, 2, 2]).unwrap(), 2);
+        assert_eq!(database.read_value(&[1, 2, 3]).unwrap(), 4);
+        assert_eq!(database.read_value(&[1, 2, 4]).unwrap(), 5);
+        assert_eq!(database.read_value(&[2, 2, 1]).unwrap(), 6);
+        assert!(database.read_value(&[2, 1, 4]).is_err());
+        assert_eq!(fs::metadata(format!("{}{}", DATABASE_LOCATION, "/jnl")).unwrap().len(), 0);
+    }
+}
+
+#[test]
+#[serial]
+fn flush_succeeds_given_1d_u64_database() {
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[2]
+    ).unwrap();
+
+    let mut database = cql_db::open::<U64>(DATABASE_LOCATION).unwrap();
+    database.write_value(&[2], 5).unwrap();
+    database.flush().unwrap();
+
+    assert_eq!(database.read_value(&[2]).unwrap(), 5);
+}
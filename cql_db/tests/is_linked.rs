@@ -0,0 +1,85 @@
+#![allow(non_snake_case)]
+
+mod constants;
+
+use serial_test::serial;
+
+use constants::DATABASE_LOCATION;
+use cql_u64::U64;
+use cql_db::error;
+
+#[test]
+#[serial]
+fn _4d_u64_database_reports_linked_partial_and_full_locations() {
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[2, 3, 3, 2]
+    ).unwrap();
+
+    cql_db::link_dimensions::<U64>(DATABASE_LOCATION, &[2, 3, 1]).unwrap();
+
+    assert!(cql_db::is_linked::<U64>(DATABASE_LOCATION, &[2, 3]).unwrap());
+    assert!(cql_db::is_linked::<U64>(DATABASE_LOCATION, &[2, 3, 1]).unwrap());
+    assert!(cql_db::is_linked_unchecked::<U64>(DATABASE_LOCATION, &[2, 3, 1]).unwrap());
+
+    assert!(!cql_db::is_linked::<U64>(DATABASE_LOCATION, &[2, 3, 2]).unwrap());
+    assert!(!cql_db::is_linked::<U64>(DATABASE_LOCATION, &[1, 3]).unwrap());
+    assert!(!cql_db::is_linked_unchecked::<U64>(DATABASE_LOCATION, &[1, 3, 1]).unwrap());
+}
+
+#[test]
+#[serial]
+fn _3d_u64_database_reports_unlinked_locations_as_not_linked() {
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[2, 2, 3]
+    ).unwrap();
+
+    let mut database = cql_db::open::<U64>(DATABASE_LOCATION).unwrap();
+    database.link_dimensions(&[1, 2]).unwrap();
+    database.link_dimensions(&[2, 1]).unwrap();
+    database.unlink_dimensions(&[1, 2]).unwrap();
+
+    assert!(!database.is_linked(&[1, 2]).unwrap());
+    assert!(database.is_linked(&[2, 1]).unwrap());
+}
+
+#[test]
+#[serial]
+fn is_linked__returns_DimensionsOutOfRangeError__given_3d_u64_database_and_full_location() {
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[2, 2, 2]
+    ).unwrap();
+
+    let result = match cql_db::is_linked::<U64>(DATABASE_LOCATION, &[1, 1, 1]) {
+        Err(error::Error::Cql(cql_error)) => Some(cql_error),
+        _ => None,
+    };
+
+    assert_eq!(result.unwrap(), error::cql::Error::DimensionsOutOfRangeError { requested: 3, min: 2, max: 2 });
+}
+
+#[test]
+#[serial]
+fn is_linked__returns_IndexOutOfRangeError__given_3d_u64_database_and_out_of_range_index() {
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[2, 2, 2]
+    ).unwrap();
+
+    let result = match cql_db::is_linked::<U64>(DATABASE_LOCATION, &[1, 3]) {
+        Err(error::Error::Cql(cql_error)) => Some(cql_error),
+        _ => None,
+    };
+
+    assert_eq!(
+        result.unwrap(),
+        error::cql::Error::IndexOutOfRangeError {
+            dimension_index: 1,
+            requested: 3,
+            min: 1,
+            max: 2,
+        }
+    );
+}
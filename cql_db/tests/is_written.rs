@@ -0,0 +1,205 @@
+#![allow(non_snake_case)]
+
+mod constants;
+
+use serial_test::serial;
+use std::fs;
+use std::io::Cursor;
+
+use constants::DATABASE_LOCATION;
+use cql_u64::U64;
+use cql_db::error;
+use cql_db::storage::MemoryStorage;
+
+const COMPACTED_DATABASE_LOCATION: &str = "./.test_db/written_compacted";
+
+#[test]
+#[serial]
+fn _1d_u64_database_tells_written_zero_from_unwritten_value() {
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[3]
+    ).unwrap();
+
+    cql_db::write_value::<U64>(DATABASE_LOCATION, &[2], 0).unwrap();
+    cql_db::write_value::<U64>(DATABASE_LOCATION, &[3], 5).unwrap();
+
+    assert_eq!(cql_db::read_value::<U64>(DATABASE_LOCATION, &[1]).unwrap(), 0);
+    assert_eq!(cql_db::read_value::<U64>(DATABASE_LOCATION, &[2]).unwrap(), 0);
+
+    assert!(!cql_db::is_written::<U64>(DATABASE_LOCATION, &[1]).unwrap());
+    assert!(cql_db::is_written::<U64>(DATABASE_LOCATION, &[2]).unwrap());
+    assert!(cql_db::is_written_unchecked::<U64>(DATABASE_LOCATION, &[3]).unwrap());
+}
+
+#[test]
+#[serial]
+fn _3d_u64_database_reports_unlinked_locations_as_unwritten() {
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[2, 2, 3]
+    ).unwrap();
+
+    let mut database = cql_db::open::<U64>(DATABASE_LOCATION).unwrap();
+    database.link_dimensions(&[1, 1]).unwrap();
+    database.write_value(&[1, 1, 2], 0).unwrap();
+
+    assert!(database.is_written(&[1, 1, 2]).unwrap());
+    assert!(!database.is_written(&[2, 1, 2]).unwrap());
+
+    database.unlink_dimensions(&[1, 1]).unwrap();
+    database.link_dimensions(&[2, 2]).unwrap();
+
+    // the row released by the unlink is reused by the new link
+    assert!(!database.is_written(&[1, 1, 2]).unwrap());
+    assert!(!database.is_written(&[2, 2, 2]).unwrap());
+}
+
+#[test]
+#[serial]
+fn _3d_u64_database_marks_values_written_by_batches_streams_and_transactions() {
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[2, 2, 4]
+    ).unwrap();
+
+    let mut database = cql_db::open::<U64>(DATABASE_LOCATION).unwrap();
+    database.link_dimensions(&[1, 1]).unwrap();
+    database.write_values(&[(&[1, 1, 1], 0)]).unwrap();
+    database.write_from_stream(&mut Cursor::new(vec![0; 16]), &[1, 1, 2], 2).unwrap();
+
+    let mut transaction = database.transaction();
+    transaction.link_dimensions(&[2, 2]).unwrap();
+    transaction.write_value(&[2, 2, 4], 0).unwrap();
+    transaction.commit().unwrap();
+
+    let written: Vec<bool> = (1..5).map(|i| database.is_written(&[1, 1, i]).unwrap()).collect();
+
+    assert_eq!(written, vec![true, true, true, false]);
+    assert!(database.is_written(&[2, 2, 4]).unwrap());
+    assert!(!database.is_written(&[2, 2, 3]).unwrap());
+}
+
+#[test]
+#[serial]
+fn _3d_u64_database_keeps_written_values_through_resize_and_compaction() {
+    let _ = fs::remove_dir_all(COMPACTED_DATABASE_LOCATION);
+    fs::create_dir_all(COMPACTED_DATABASE_LOCATION).unwrap();
+
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[2, 2, 2]
+    ).unwrap();
+
+    cql_db::link_dimensions::<U64>(DATABASE_LOCATION, &[1, 2]).unwrap();
+    cql_db::link_dimensions::<U64>(DATABASE_LOCATION, &[2, 1]).unwrap();
+    cql_db::write_value::<U64>(DATABASE_LOCATION, &[1, 2, 2], 0).unwrap();
+    cql_db::write_value::<U64>(DATABASE_LOCATION, &[2, 1, 1], 0).unwrap();
+    cql_db::unlink_dimensions::<U64>(DATABASE_LOCATION, &[1, 2]).unwrap();
+
+    cql_db::resize_last_dimension::<U64>(DATABASE_LOCATION, 3).unwrap();
+    cql_db::compact::<U64>(DATABASE_LOCATION, COMPACTED_DATABASE_LOCATION).unwrap();
+
+    for db_location in &[DATABASE_LOCATION, COMPACTED_DATABASE_LOCATION] {
+        let written: Vec<bool> = (1..4).map(|i| cql_db::is_written::<U64>(db_location, &[2, 1, i]).unwrap()).collect();
+
+        assert_eq!(written, vec![true, false, false]);
+    }
+}
+
+#[test]
+#[serial]
+fn _2d_u64_database_keeps_written_values_through_resizing_many_rows() {
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[100_000, 6]
+    ).unwrap();
+
+    let points = [[1, 1], [1, 6], [3, 2], [50_000, 3], [99_999, 6], [100_000, 1]];
+    for point in &points {
+        cql_db::write_value::<U64>(DATABASE_LOCATION, point, 0).unwrap();
+    }
+
+    cql_db::resize_last_dimension::<U64>(DATABASE_LOCATION, 7).unwrap();
+
+    for point in &points {
+        assert!(cql_db::is_written::<U64>(DATABASE_LOCATION, point).unwrap());
+    }
+    for point in &[[1, 2], [1, 7], [2, 7], [50_000, 7], [99_999, 7], [100_000, 2], [100_000, 7]] {
+        assert!(!cql_db::is_written::<U64>(DATABASE_LOCATION, point).unwrap());
+    }
+}
+
+#[test]
+#[serial]
+fn _2d_u64_database_keeps_written_values_through_resizing_large_rows() {
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[2, 600_000]
+    ).unwrap();
+
+    let points = [[1, 1], [1, 600_000], [2, 1], [2, 300_000], [2, 600_000]];
+    for point in &points {
+        cql_db::write_value::<U64>(DATABASE_LOCATION, point, 0).unwrap();
+    }
+
+    cql_db::resize_last_dimension::<U64>(DATABASE_LOCATION, 1_200_000).unwrap();
+
+    for point in &points {
+        assert!(cql_db::is_written::<U64>(DATABASE_LOCATION, point).unwrap());
+    }
+    for point in &[[1, 2], [1, 600_001], [1, 900_000], [1, 1_200_000], [2, 600_001], [2, 1_200_000]] {
+        assert!(!cql_db::is_written::<U64>(DATABASE_LOCATION, point).unwrap());
+    }
+}
+
+#[test]
+#[serial]
+fn _2d_u64_database_only_assumes_unmarked_values_were_written_for_databases_predating_written_library() {
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[2, 2]
+    ).unwrap();
+
+    // the first value is not zeroed, so that the database could not have been created by cql_db 0.1
+    cql_db::write_value::<U64>(DATABASE_LOCATION, &[1, 1], 3).unwrap();
+    cql_db::write_value::<U64>(DATABASE_LOCATION, &[2, 1], 5).unwrap();
+    fs::OpenOptions::new().write(true).open(format!("{}/wr", DATABASE_LOCATION)).unwrap().set_len(0).unwrap();
+
+    assert!(!cql_db::is_written::<U64>(DATABASE_LOCATION, &[2, 1]).unwrap());
+
+    // databases created before writes were recorded do not record their format version either
+    fs::OpenOptions::new().write(true).open(format!("{}/ax", DATABASE_LOCATION)).unwrap().set_len(3 * 8).unwrap();
+
+    assert!(cql_db::is_written::<U64>(DATABASE_LOCATION, &[2, 1]).unwrap());
+    assert!(!cql_db::is_written::<U64>(DATABASE_LOCATION, &[2, 2]).unwrap());
+}
+
+#[test]
+fn _2d_u64_memory_database_tells_written_zero_from_unwritten_value() {
+    let storage = MemoryStorage::new();
+    cql_db::create_db_in::<U64, _>(&storage, &[2, 2]).unwrap();
+
+    let mut database = cql_db::open_in::<U64, _>(storage).unwrap();
+    database.write_value(&[2, 1], 0).unwrap();
+
+    assert!(database.is_written(&[2, 1]).unwrap());
+    assert!(!database.is_written(&[2, 2]).unwrap());
+    assert!(!database.is_written_unchecked(&[1, 1]).unwrap());
+}
+
+#[test]
+#[serial]
+fn is_written__returns_DimensionsOutOfRangeError__given_3d_u64_database_and_partial_location() {
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[2, 2, 2]
+    ).unwrap();
+
+    let result = match cql_db::is_written::<U64>(DATABASE_LOCATION, &[1, 1]) {
+        Err(error::Error::Cql(cql_error)) => Some(cql_error),
+        _ => None,
+    };
+
+    assert_eq!(result.unwrap(), error::cql::Error::DimensionsOutOfRangeError { requested: 2, min: 3, max: 3 });
+}
@@ -0,0 +1,161 @@
+#![allow(non_snake_case)]
+
+mod constants;
+
+use serial_test::serial;
+use std::fs;
+
+use constants::DATABASE_LOCATION;
+use cql_u64::U64;
+use cql_db::error;
+
+const JOURNAL_FILE_NAME: &str = "/jnl";
+
+#[test]
+#[serial]
+fn _3d_u64_database_completes_interrupted_link_on_next_use() {
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[2, 2, 2]
+    ).unwrap();
+
+    write_journal(&interrupted_link_journal());
+
+    assert_eq!(cql_db::read_value::<U64>(DATABASE_LOCATION, &[2, 1, 2]).unwrap(), 0);
+
+    // the interrupted link must have claimed the first key, so the next link gets the second row
+    cql_db::link_dimensions::<U64>(DATABASE_LOCATION, &[1, 2]).unwrap();
+    cql_db::write_value::<U64>(DATABASE_LOCATION, &[1, 2, 1], 3).unwrap();
+    cql_db::write_value::<U64>(DATABASE_LOCATION, &[2, 1, 2], 4).unwrap();
+
+    assert_eq!(cql_db::read_value::<U64>(DATABASE_LOCATION, &[1, 2, 1]).unwrap(), 3);
+    assert_eq!(cql_db::read_value::<U64>(DATABASE_LOCATION, &[2, 1, 2]).unwrap(), 4);
+    assert_eq!(fs::metadata(format!("{}{}", DATABASE_LOCATION, "/db")).unwrap().len(), 32);
+    assert_eq!(fs::metadata(format!("{}{}", DATABASE_LOCATION, JOURNAL_FILE_NAME)).unwrap().len(), 0);
+}
+
+#[test]
+#[serial]
+fn _3d_u64_database_completes_interrupted_unlink_on_next_use() {
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[2, 2, 2]
+    ).unwrap();
+
+    cql_db::link_dimensions::<U64>(DATABASE_LOCATION, &[2, 1]).unwrap();
+    cql_db::write_value::<U64>(DATABASE_LOCATION, &[2, 1, 2], 5).unwrap();
+
+    write_journal(&interrupted_unlink_journal());
+
+    assert!(!cql_db::is_linked::<U64>(DATABASE_LOCATION, &[2, 1]).unwrap());
+
+    // the interrupted unlink must have released the first key, so the next link reuses its (cleared) row
+    cql_db::link_dimensions::<U64>(DATABASE_LOCATION, &[1, 2]).unwrap();
+
+    assert_eq!(cql_db::read_value::<U64>(DATABASE_LOCATION, &[1, 2, 2]).unwrap(), 0);
+    assert!(!cql_db::is_written::<U64>(DATABASE_LOCATION, &[1, 2, 2]).unwrap());
+    assert_eq!(fs::metadata(format!("{}{}", DATABASE_LOCATION, "/db")).unwrap().len(), 16);
+    assert_eq!(fs::metadata(format!("{}{}", DATABASE_LOCATION, JOURNAL_FILE_NAME)).unwrap().len(), 0);
+}
+
+#[test]
+#[serial]
+fn read_value__returns_ElementsNotLinkedError__given_3d_u64_database_and_partially_written_journal() {
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[2, 2, 2]
+    ).unwrap();
+
+    let journal = interrupted_link_journal();
+    write_journal(&journal[..journal.len() - 1]);
+
+    let result = match cql_db::read_value::<U64>(DATABASE_LOCATION, &[2, 1, 2]) {
+        Err(error::Error::Cql(cql_error)) => Some(cql_error),
+        _ => None,
+    };
+
+    assert_eq!(
+        result.unwrap(),
+        error::cql::Error::ElementsNotLinkedError {
+            x_dimension: 0,
+            x: 2,
+            y_dimension: 1,
+            y: 1,
+        }
+    );
+    assert_eq!(fs::metadata(format!("{}{}", DATABASE_LOCATION, JOURNAL_FILE_NAME)).unwrap().len(), 0);
+}
+
+#[test]
+#[serial]
+fn create_db_unchecked__discards_journal_of_replaced_3d_u64_database() {
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[2, 2, 2]
+    ).unwrap();
+
+    write_journal(&interrupted_link_journal());
+
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[2, 2, 2]
+    ).unwrap();
+
+    let result = match cql_db::read_value::<U64>(DATABASE_LOCATION, &[2, 1, 2]) {
+        Err(error::Error::Cql(cql_error)) => Some(cql_error),
+        _ => None,
+    };
+
+    assert_eq!(
+        result.unwrap(),
+        error::cql::Error::ElementsNotLinkedError {
+            x_dimension: 0,
+            x: 2,
+            y_dimension: 1,
+            y: 1,
+        }
+    );
+}
+
+// The journal of linking [2, 1] in a [2, 2, 2] database: the last key, the key index, and the growth of the database file
+fn interrupted_link_journal() -> Vec<u8> {
+    let mut journal = Vec::new();
+    push_operation(&mut journal, 1, "/key1_2", 0, &1u64.to_le_bytes());
+    push_operation(&mut journal, 1, "/key1_2", 3 * 8, &1u64.to_le_bytes());
+    push_operation(&mut journal, 2, "/db", 16, &[]);
+
+    journal.extend_from_slice(&3u64.to_le_bytes());
+    let checksum = journal.iter().fold(0xcbf2_9ce4_8422_2325u64, |hash, &byte| (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3));
+    journal.extend_from_slice(&checksum.to_le_bytes());
+
+    journal
+}
+
+// The journal of unlinking [2, 1] from a [2, 2, 2] database linked only at [2, 1]: the key index, the cleared row and its written bits, and the
+// release of the key
+fn interrupted_unlink_journal() -> Vec<u8> {
+    let mut journal = Vec::new();
+    push_operation(&mut journal, 1, "/key1_2", 3 * 8, &0u64.to_le_bytes());
+    push_operation(&mut journal, 1, "/db", 0, &[0; 16]);
+    push_operation(&mut journal, 1, "/wr", 0, &[0]);
+    push_operation(&mut journal, 1, "/freekey1_2", 0, &1u64.to_le_bytes());
+
+    journal.extend_from_slice(&4u64.to_le_bytes());
+    let checksum = journal.iter().fold(0xcbf2_9ce4_8422_2325u64, |hash, &byte| (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3));
+    journal.extend_from_slice(&checksum.to_le_bytes());
+
+    journal
+}
+
+fn push_operation(journal: &mut Vec<u8>, kind: u8, file_name: &str, value: u64, bytes: &[u8]) {
+    journal.push(kind);
+    journal.extend_from_slice(&(file_name.len() as u64).to_le_bytes());
+    journal.extend_from_slice(file_name.as_bytes());
+    journal.extend_from_slice(&value.to_le_bytes());
+    journal.extend_from_slice(&(bytes.len() as u64).to_le_bytes());
+    journal.extend_from_slice(bytes);
+}
+
+fn write_journal(journal: &[u8]) {
+    fs::write(format!("{}{}", DATABASE_LOCATION, JOURNAL_FILE_NAME), journal).unwrap();
+}
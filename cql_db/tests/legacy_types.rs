@@ -0,0 +1,234 @@
+#![allow(non_snake_case)]
+#![allow(deprecated)]
+
+mod constants;
+
+use serial_test::serial;
+use std::fs::{ File, OpenOptions };
+use std::io;
+use std::io::{ Cursor, Read, Write, Seek, SeekFrom };
+
+use constants::DATABASE_LOCATION;
+use cql_model::{
+    CqlType,
+    CqlWritable,
+    CqlReadable,
+    CqlStreamReadable,
+    CqlBufferWritable,
+    CqlRangeReadable,
+    CqlBufferStreamReadable,
+};
+use cql_db::error;
+use cql_db::storage::MemoryStorage;
+
+// A type part way through being migrated, implementing the buffer based traits alongside the file based ones that third party types written before
+// them implement
+struct LegacyU32;
+
+impl CqlType for LegacyU32 {
+    type ValueType = u32;
+    const VALUE_SIZE: usize = 4;
+    const TYPE_NAME: &'static str = "legacy_types::LegacyU32";
+}
+
+impl CqlWritable for LegacyU32 {
+    fn write_to_db(db_location: &str, value_location: u64, input_value: u32) -> io::Result<()> {
+        let mut file = OpenOptions::new().write(true).open(db_location)?;
+        file.seek(SeekFrom::Start(value_location * Self::VALUE_SIZE as u64))?;
+        file.write_all(&input_value.to_le_bytes())
+    }
+}
+
+impl CqlReadable for LegacyU32 {
+    fn read_from_db(db_location: &str, value_location: u64) -> io::Result<u32> {
+        let mut file = File::open(db_location)?;
+        file.seek(SeekFrom::Start(value_location * Self::VALUE_SIZE as u64))?;
+
+        let mut buffer = [0; 4];
+        match file.read_exact(&mut buffer) {
+            Err(e) if e.kind() != io::ErrorKind::UnexpectedEof => Err(e),
+            _ => Ok(u32::from_le_bytes(buffer)),
+        }
+    }
+}
+
+impl CqlStreamReadable for LegacyU32 {
+    fn read_to_stream(db_location: &str, stream: &mut dyn Write, value_location: u64, n_values: u64) -> io::Result<()> {
+        for index in 0..n_values {
+            stream.write_all(&LegacyU32::read_from_db(db_location, value_location + index)?.to_le_bytes())?;
+        }
+
+        stream.flush()
+    }
+}
+
+impl CqlBufferWritable for LegacyU32 {
+    fn write_to_buffer(input_value: u32, buffer: &mut [u8]) -> io::Result<()> {
+        buffer.copy_from_slice(&input_value.to_le_bytes());
+        Ok(())
+    }
+}
+
+impl CqlRangeReadable for LegacyU32 {
+    fn read_from_buffer(buffer: &[u8]) -> io::Result<u32> {
+        let mut value = [0; 4];
+        value.copy_from_slice(&buffer[..4]);
+        Ok(u32::from_le_bytes(value))
+    }
+}
+
+impl CqlBufferStreamReadable for LegacyU32 {
+    fn read_buffer_to_stream(buffer: &[u8], stream: &mut dyn Write) -> io::Result<()> {
+        stream.write_all(buffer)?;
+        stream.flush()
+    }
+}
+
+// A third party type written before the buffer based traits, which only implements the file based ones
+struct FileOnlyU32;
+
+impl CqlType for FileOnlyU32 {
+    type ValueType = u32;
+    const VALUE_SIZE: usize = 4;
+    const TYPE_NAME: &'static str = "legacy_types::FileOnlyU32";
+}
+
+impl CqlWritable for FileOnlyU32 {
+    fn write_to_db(db_location: &str, value_location: u64, input_value: u32) -> io::Result<()> {
+        LegacyU32::write_to_db(db_location, value_location, input_value)
+    }
+}
+
+impl CqlReadable for FileOnlyU32 {
+    fn read_from_db(db_location: &str, value_location: u64) -> io::Result<u32> {
+        LegacyU32::read_from_db(db_location, value_location)
+    }
+}
+
+impl CqlStreamReadable for FileOnlyU32 {
+    fn read_to_stream(db_location: &str, stream: &mut dyn Write, value_location: u64, n_values: u64) -> io::Result<()> {
+        <LegacyU32 as CqlStreamReadable>::read_to_stream(db_location, stream, value_location, n_values)
+    }
+}
+
+fn stream_of(values: &[u32]) -> Cursor<Vec<u8>> {
+    Cursor::new(values.iter().flat_map(|value| value.to_le_bytes().to_vec()).collect())
+}
+
+#[test]
+#[serial]
+fn _3d_legacy_type_database_allows_for_point_and_stream_read_writes() {
+    cql_db::create_db_unchecked::<LegacyU32>(DATABASE_LOCATION, &[2, 2, 4]).unwrap();
+    cql_db::link_dimensions::<LegacyU32>(DATABASE_LOCATION, &[2, 1]).unwrap();
+
+    cql_db::write_value::<LegacyU32>(DATABASE_LOCATION, &[2, 1, 1], 7).unwrap();
+    cql_db::write_values::<LegacyU32>(DATABASE_LOCATION, &[(&[2, 1, 3], 8), (&[2, 1, 4], 9)]).unwrap();
+
+    let mut stream = Cursor::new(Vec::new());
+    cql_db::read_to_stream::<LegacyU32>(DATABASE_LOCATION, &mut stream, &[2, 1, 1], 4).unwrap();
+
+    assert_eq!(cql_db::read_value::<LegacyU32>(DATABASE_LOCATION, &[2, 1, 1]).unwrap(), 7);
+    assert_eq!(stream.into_inner(), stream_of(&[7, 0, 8, 9]).into_inner());
+}
+
+#[test]
+#[serial]
+fn _3d_legacy_type_database_allows_for_transactions_and_ranges() {
+    cql_db::create_db_unchecked::<LegacyU32>(DATABASE_LOCATION, &[2, 2, 3]).unwrap();
+
+    let mut database = cql_db::open::<LegacyU32>(DATABASE_LOCATION).unwrap();
+    let mut transaction = database.transaction();
+    transaction.link_dimensions(&[1, 2]).unwrap();
+    transaction.write_value(&[1, 2, 2], 4).unwrap();
+    transaction.write_value(&[1, 2, 3], 5).unwrap();
+    transaction.commit().unwrap();
+
+    let values: Vec<u32> = database.read_range(&[1, 2, 1], 3).unwrap().map(|value| value.unwrap()).collect();
+
+    assert_eq!(values, vec![0, 4, 5]);
+}
+
+#[test]
+fn _3d_legacy_type_memory_database_allows_for_point_and_stream_read_writes() {
+    let storage = MemoryStorage::new();
+    cql_db::create_db_in::<LegacyU32, _>(&storage, &[2, 2, 4]).unwrap();
+
+    let mut database = cql_db::open_in::<LegacyU32, _>(storage).unwrap();
+    database.link_dimensions(&[1, 2]).unwrap();
+    database.write_value(&[1, 2, 4], 3).unwrap();
+    database.write_values(&[(&[1, 2, 1], 1), (&[1, 2, 2], 2)]).unwrap();
+
+    let mut stream = Cursor::new(Vec::new());
+    database.read_to_stream(&mut stream, &[1, 2, 1], 4).unwrap();
+
+    assert_eq!(database.read_value(&[1, 2, 4]).unwrap(), 3);
+    assert_eq!(stream.into_inner(), stream_of(&[1, 2, 0, 3]).into_inner());
+}
+
+#[test]
+#[serial]
+fn _3d_file_only_type_database_allows_for_legacy_point_and_stream_read_writes() {
+    cql_db::create_db_unchecked::<FileOnlyU32>(DATABASE_LOCATION, &[2, 2, 4]).unwrap();
+    cql_db::link_dimensions::<FileOnlyU32>(DATABASE_LOCATION, &[2, 1]).unwrap();
+
+    cql_db::legacy::write_value::<FileOnlyU32>(DATABASE_LOCATION, &[2, 1, 1], 7).unwrap();
+    cql_db::legacy::write_value_unchecked::<FileOnlyU32>(DATABASE_LOCATION, &[2, 1, 3], 8).unwrap();
+
+    let mut stream = Cursor::new(Vec::new());
+    cql_db::legacy::read_to_stream::<FileOnlyU32>(DATABASE_LOCATION, &mut stream, &[2, 1, 1], 4).unwrap();
+
+    let mut unchecked_stream = Cursor::new(Vec::new());
+    cql_db::legacy::read_to_stream_unchecked::<FileOnlyU32>(DATABASE_LOCATION, &mut unchecked_stream, &[2, 1, 2], 3).unwrap();
+
+    assert_eq!(cql_db::legacy::read_value::<FileOnlyU32>(DATABASE_LOCATION, &[2, 1, 1]).unwrap(), 7);
+    assert_eq!(cql_db::legacy::read_value_unchecked::<FileOnlyU32>(DATABASE_LOCATION, &[2, 1, 3]).unwrap(), 8);
+    assert_eq!(stream.into_inner(), stream_of(&[7, 0, 8, 0]).into_inner());
+    assert_eq!(unchecked_stream.into_inner(), stream_of(&[0, 8, 0]).into_inner());
+    assert!(cql_db::is_written::<FileOnlyU32>(DATABASE_LOCATION, &[2, 1, 3]).unwrap());
+    assert!(!cql_db::is_written::<FileOnlyU32>(DATABASE_LOCATION, &[2, 1, 2]).unwrap());
+}
+
+#[test]
+#[serial]
+fn legacy_write_value__returns_ElementsNotLinkedError__given_3d_file_only_type_database_and_unlinked_location() {
+    cql_db::create_db_unchecked::<FileOnlyU32>(DATABASE_LOCATION, &[2, 2, 4]).unwrap();
+
+    let result = match cql_db::legacy::write_value::<FileOnlyU32>(DATABASE_LOCATION, &[1, 2, 1], 3) {
+        Err(error::Error::Cql(cql_error)) => Some(cql_error),
+        _ => None,
+    };
+
+    assert_eq!(
+        result.unwrap(),
+        error::cql::Error::ElementsNotLinkedError {
+            x_dimension: 0,
+            x: 1,
+            y_dimension: 1,
+            y: 2,
+        }
+    );
+}
+
+#[test]
+#[serial]
+fn legacy_read_to_stream__returns_IndexOutOfRangeError__given_1d_file_only_type_database_and_too_many_values() {
+    cql_db::create_db_unchecked::<FileOnlyU32>(DATABASE_LOCATION, &[4]).unwrap();
+
+    let mut stream = Cursor::new(Vec::new());
+
+    let result = match cql_db::legacy::read_to_stream::<FileOnlyU32>(DATABASE_LOCATION, &mut stream, &[3], 3) {
+        Err(error::Error::Cql(cql_error)) => Some(cql_error),
+        _ => None,
+    };
+
+    assert_eq!(
+        result.unwrap(),
+        error::cql::Error::IndexOutOfRangeError {
+            dimension_index: 0,
+            requested: 5,
+            min: 1,
+            max: 4,
+        }
+    );
+    assert!(stream.into_inner().is_empty());
+}
@@ -0,0 +1,145 @@
+#![allow(non_snake_case)]
+
+mod constants;
+
+use serial_test::serial;
+
+use constants::DATABASE_LOCATION;
+use cql_u64::U64;
+use cql_db::error;
+use cql_db::storage::MemoryStorage;
+
+#[test]
+#[serial]
+fn _4d_u64_database_lists_linked_children_of_each_dimension() {
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[2, 5, 3, 2]
+    ).unwrap();
+
+    cql_db::link_dimensions::<U64>(DATABASE_LOCATION, &[2, 5, 3]).unwrap();
+    cql_db::link_dimensions::<U64>(DATABASE_LOCATION, &[2, 2, 1]).unwrap();
+    cql_db::link_dimensions::<U64>(DATABASE_LOCATION, &[2, 2, 3]).unwrap();
+    cql_db::link_dimensions::<U64>(DATABASE_LOCATION, &[1, 4, 2]).unwrap();
+
+    let first: Vec<u64> = cql_db::linked_children::<U64>(DATABASE_LOCATION, &[1]).unwrap().collect();
+    let second: Vec<u64> = cql_db::linked_children::<U64>(DATABASE_LOCATION, &[2]).unwrap().collect();
+    let second_second: Vec<u64> = cql_db::linked_children::<U64>(DATABASE_LOCATION, &[2, 2]).unwrap().collect();
+    let unchecked: Vec<u64> = cql_db::linked_children_unchecked::<U64>(DATABASE_LOCATION, &[2, 5]).unwrap().collect();
+
+    assert_eq!(first, vec![4]);
+    assert_eq!(second, vec![2, 5]);
+    assert_eq!(second_second, vec![1, 3]);
+    assert_eq!(unchecked, vec![3]);
+}
+
+#[test]
+#[serial]
+fn _3d_u64_database_does_not_list_unlinked_children() {
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[2, 4, 2]
+    ).unwrap();
+
+    let mut database = cql_db::open::<U64>(DATABASE_LOCATION).unwrap();
+    database.link_dimensions(&[1, 1]).unwrap();
+    database.link_dimensions(&[1, 3]).unwrap();
+    database.link_dimensions(&[1, 4]).unwrap();
+    database.unlink_dimensions(&[1, 3]).unwrap();
+
+    let first: Vec<u64> = database.linked_children(&[1]).unwrap().collect();
+    let second: Vec<u64> = database.linked_children(&[2]).unwrap().collect();
+
+    assert_eq!(first, vec![1, 4]);
+    assert!(second.is_empty());
+}
+
+#[test]
+fn _3d_u64_memory_database_lists_linked_children() {
+    let storage = MemoryStorage::new();
+    cql_db::create_db_in::<U64, _>(&storage, &[3, 3, 2]).unwrap();
+
+    let mut database = cql_db::open_in::<U64, _>(storage).unwrap();
+    database.link_dimensions(&[3, 2]).unwrap();
+
+    let children: Vec<u64> = database.linked_children(&[3]).unwrap().collect();
+
+    assert_eq!(children, vec![2]);
+}
+
+#[test]
+#[serial]
+fn linked_children_unchecked__returns_nothing__given_4d_u64_database_and_unlinked_location() {
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[2, 2, 2, 2]
+    ).unwrap();
+
+    cql_db::link_dimensions::<U64>(DATABASE_LOCATION, &[1, 1, 1]).unwrap();
+
+    assert_eq!(cql_db::linked_children_unchecked::<U64>(DATABASE_LOCATION, &[1, 2]).unwrap().count(), 0);
+}
+
+#[test]
+#[serial]
+fn linked_children__returns_ElementsNotLinkedError__given_4d_u64_database_and_unlinked_location() {
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[2, 2, 2, 2]
+    ).unwrap();
+
+    let result = match cql_db::linked_children::<U64>(DATABASE_LOCATION, &[2, 1]) {
+        Err(error::Error::Cql(cql_error)) => Some(cql_error),
+        _ => None,
+    };
+
+    assert_eq!(
+        result.unwrap(),
+        error::cql::Error::ElementsNotLinkedError {
+            x_dimension: 0,
+            x: 2,
+            y_dimension: 1,
+            y: 1,
+        }
+    );
+}
+
+#[test]
+#[serial]
+fn linked_children__returns_DimensionsOutOfRangeError__given_3d_u64_database_and_2d_location() {
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[2, 2, 2]
+    ).unwrap();
+
+    let result = match cql_db::linked_children::<U64>(DATABASE_LOCATION, &[1, 1]) {
+        Err(error::Error::Cql(cql_error)) => Some(cql_error),
+        _ => None,
+    };
+
+    assert_eq!(result.unwrap(), error::cql::Error::DimensionsOutOfRangeError { requested: 2, min: 1, max: 1 });
+}
+
+#[test]
+#[serial]
+fn linked_children__returns_IndexOutOfRangeError__given_3d_u64_database_and_out_of_range_index() {
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[2, 2, 2]
+    ).unwrap();
+
+    let result = match cql_db::linked_children::<U64>(DATABASE_LOCATION, &[3]) {
+        Err(error::Error::Cql(cql_error)) => Some(cql_error),
+        _ => None,
+    };
+
+    assert_eq!(
+        result.unwrap(),
+        error::cql::Error::IndexOutOfRangeError {
+            dimension_index: 0,
+            requested: 3,
+            min: 1,
+            max: 2,
+        }
+    );
+}
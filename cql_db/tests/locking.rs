@@ -0,0 +1,208 @@
+#![allow(non_snake_case)]
+
+mod constants;
+
+use serial_test::serial;
+use std::fs::{ File, OpenOptions };
+use std::thread;
+use std::time::Duration;
+
+use constants::DATABASE_LOCATION;
+use cql_u64::U64;
+use cql_db::error;
+use cql_db::aggregation::Sum;
+
+const TIMEOUT: Duration = Duration::from_millis(20);
+
+#[test]
+#[serial]
+fn read_value__returns_LockTimeoutError__given_exclusively_locked_2d_u64_database() {
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[2, 2]
+    ).unwrap();
+
+    let mut database = cql_db::open::<U64>(DATABASE_LOCATION).unwrap();
+    database.set_lock_timeout(TIMEOUT);
+
+    let lock_file = open_lock_file();
+    lock_file.lock().unwrap();
+
+    let result = match database.read_value(&[1, 1]) {
+        Err(error::Error::Cql(cql_error)) => Some(cql_error),
+        _ => None,
+    };
+
+    assert_eq!(
+        result.unwrap(),
+        error::cql::Error::LockTimeoutError {
+            timeout: TIMEOUT,
+        }
+    );
+}
+
+#[test]
+#[serial]
+fn write_value_unchecked__returns_Io_TimedOut__given_shared_locked_2d_u64_database() {
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[2, 2]
+    ).unwrap();
+
+    let mut database = cql_db::open::<U64>(DATABASE_LOCATION).unwrap();
+    database.set_lock_timeout(TIMEOUT);
+
+    let lock_file = open_lock_file();
+    lock_file.lock_shared().unwrap();
+
+    let result = database.write_value_unchecked(&[1, 1], 5);
+
+    assert_eq!(result.unwrap_err().kind(), std::io::ErrorKind::TimedOut);
+
+    // reads share the lock
+    assert_eq!(database.read_value(&[1, 1]).unwrap(), 0);
+
+    lock_file.unlock().unwrap();
+    database.write_value(&[1, 1], 5).unwrap();
+
+    assert_eq!(database.read_value(&[1, 1]).unwrap(), 5);
+}
+
+#[test]
+#[serial]
+fn write_value__returns_LockTimeoutError__given_3d_u64_database_and_unfinished_range_read() {
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[2, 2, 2]
+    ).unwrap();
+
+    cql_db::link_dimensions::<U64>(DATABASE_LOCATION, &[1, 1]).unwrap();
+
+    let mut reader = cql_db::open::<U64>(DATABASE_LOCATION).unwrap();
+    let mut writer = cql_db::open::<U64>(DATABASE_LOCATION).unwrap();
+    writer.set_lock_timeout(TIMEOUT);
+
+    let range = reader.read_range(&[1, 1, 1], 2).unwrap();
+
+    let result = match writer.write_value(&[1, 1, 2], 5) {
+        Err(error::Error::Cql(cql_error)) => Some(cql_error),
+        _ => None,
+    };
+
+    assert_eq!(
+        result.unwrap(),
+        error::cql::Error::LockTimeoutError {
+            timeout: TIMEOUT,
+        }
+    );
+
+    drop(range);
+    writer.write_value(&[1, 1, 2], 5).unwrap();
+
+    assert_eq!(reader.read_value(&[1, 1, 2]).unwrap(), 5);
+}
+
+#[test]
+#[serial]
+fn write_value__returns_LockUpgradeError__given_3d_u64_database_and_unfinished_range_read_from_same_handle() {
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[2, 2, 2]
+    ).unwrap();
+
+    let mut database = cql_db::open::<U64>(DATABASE_LOCATION).unwrap();
+    database.link_dimensions(&[1, 1]).unwrap();
+
+    let range = database.read_range(&[1, 1, 1], 2).unwrap();
+
+    let result = match database.write_value(&[1, 1, 2], 5) {
+        Err(error::Error::Cql(cql_error)) => Some(cql_error),
+        _ => None,
+    };
+
+    assert_eq!(result.unwrap(), error::cql::Error::LockUpgradeError);
+    assert_eq!(range.map(|value| value.unwrap()).collect::<Vec<u64>>(), vec![0, 0]);
+
+    database.write_value(&[1, 1, 2], 5).unwrap();
+
+    assert_eq!(database.read_value(&[1, 1, 2]).unwrap(), 5);
+}
+
+#[test]
+#[serial]
+fn _2d_u64_database_allows_for_writes_whilst_database_file_is_locked() {
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[2, 2]
+    ).unwrap();
+
+    let mut database = cql_db::open::<U64>(DATABASE_LOCATION).unwrap();
+    database.set_lock_timeout(TIMEOUT);
+
+    // the database is locked through a file of its own, so locks on the database file do not block it
+    let db_file = OpenOptions::new().read(true).open(format!("{}{}", DATABASE_LOCATION, "/db")).unwrap();
+    db_file.lock().unwrap();
+
+    database.write_value(&[1, 1], 5).unwrap();
+
+    assert_eq!(database.read_value(&[1, 1]).unwrap(), 5);
+}
+
+#[test]
+#[serial]
+fn _2d_u64_database_with_rollup_levels_allows_for_rollups_whilst_shared_locked() {
+    cql_db::create_db_with_rollups_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[2, 4],
+        &[2]
+    ).unwrap();
+
+    cql_db::write_values::<U64>(DATABASE_LOCATION, &[(&[1, 1], 3), (&[1, 2], 4), (&[1, 4], 5)]).unwrap();
+
+    let mut database = cql_db::open::<U64>(DATABASE_LOCATION).unwrap();
+    database.set_lock_timeout(TIMEOUT);
+
+    let lock_file = open_lock_file();
+    lock_file.lock_shared().unwrap();
+
+    let result = database.rollup::<Sum>(&[1, 1], 4, 2).unwrap();
+
+    assert_eq!(result, vec![7, 5]);
+}
+
+#[test]
+#[serial]
+fn _3d_u64_database_allows_for_concurrent_links_and_writes() {
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[4, 8, 2]
+    ).unwrap();
+
+    let threads: Vec<thread::JoinHandle<()>> = (1..5)
+        .map(|x| {
+            thread::spawn(move || {
+                let mut database = cql_db::open::<U64>(DATABASE_LOCATION).unwrap();
+                for y in 1..9 {
+                    database.link_dimensions(&[x, y]).unwrap();
+                    database.write_value(&[x, y, 2], x * 10 + y).unwrap();
+                }
+            })
+        })
+        .collect();
+
+    for handle in threads {
+        handle.join().unwrap();
+    }
+
+    for x in 1..5 {
+        for y in 1..9 {
+            let result = cql_db::read_value::<U64>(DATABASE_LOCATION, &[x, y, 2]).unwrap();
+
+            assert_eq!(result, x * 10 + y);
+        }
+    }
+}
+
+fn open_lock_file() -> File {
+    OpenOptions::new().read(true).write(true).create(true).truncate(false).open(format!("{}{}", DATABASE_LOCATION, "/lck")).unwrap()
+}
@@ -0,0 +1,133 @@
+#![cfg(feature = "mmap")]
+#![allow(non_snake_case)]
+
+mod constants;
+
+use serial_test::serial;
+use std::io::Cursor;
+
+use constants::DATABASE_LOCATION;
+use cql_u64::U64;
+use cql_db::error;
+
+#[test]
+#[serial]
+fn _4d_u64_database_allows_for_values_written_after_mapping_to_be_read() {
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[2, 3, 2, 4]
+    ).unwrap();
+
+    let mut mapped_database = cql_db::open_mapped::<U64>(DATABASE_LOCATION).unwrap();
+    let mut database = cql_db::open::<U64>(DATABASE_LOCATION).unwrap();
+
+    for (i, row) in [[1, 1, 1], [2, 3, 2], [1, 3, 1]].iter().enumerate() {
+        database.link_dimensions(row).unwrap();
+        database.write_value(&[row[0], row[1], row[2], 4], i as u64 + 10).unwrap();
+    }
+
+    assert_eq!(mapped_database.read_value(&[1, 1, 1, 4]).unwrap(), 10);
+    assert_eq!(mapped_database.read_value(&[2, 3, 2, 4]).unwrap(), 11);
+    assert_eq!(mapped_database.read_value(&[1, 3, 1, 4]).unwrap(), 12);
+    assert_eq!(mapped_database.read_value(&[1, 3, 1, 1]).unwrap(), 0);
+
+    database.write_value(&[1, 1, 1, 4], 20).unwrap();
+
+    assert_eq!(mapped_database.read_value_unchecked(&[1, 1, 1, 4]).unwrap(), 20);
+}
+
+#[test]
+#[serial]
+fn _4d_u64_database_read_to_stream_matches_unmapped_read_to_stream() {
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[2, 3, 2, 4]
+    ).unwrap();
+
+    let mut database = cql_db::open::<U64>(DATABASE_LOCATION).unwrap();
+    database.link_dimensions(&[2, 2, 2]).unwrap();
+    database.write_values(&[(&[2, 2, 2, 1], 3), (&[2, 2, 2, 3], 5)]).unwrap();
+
+    let mut mapped_database = cql_db::open_mapped::<U64>(DATABASE_LOCATION).unwrap();
+
+    let mut stream = Cursor::new(Vec::new());
+    database.read_to_stream(&mut stream, &[2, 2, 2, 1], 4).unwrap();
+
+    let mut mapped_stream = Cursor::new(Vec::new());
+    mapped_database.read_to_stream(&mut mapped_stream, &[2, 2, 2, 1], 4).unwrap();
+
+    let mut unchecked_mapped_stream = Cursor::new(Vec::new());
+    mapped_database.read_to_stream_unchecked(&mut unchecked_mapped_stream, &[2, 2, 2, 1], 4).unwrap();
+
+    assert_eq!(mapped_stream.get_ref().len(), 4 * 8);
+    assert_eq!(mapped_stream.get_ref(), stream.get_ref());
+    assert_eq!(unchecked_mapped_stream.get_ref(), stream.get_ref());
+}
+
+#[test]
+#[serial]
+fn read_value_unchecked__returns_default__given_mapped_3d_u64_database_and_unlinked_location() {
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[2, 2, 2]
+    ).unwrap();
+
+    let mut mapped_database = cql_db::open_mapped::<U64>(DATABASE_LOCATION).unwrap();
+
+    assert_eq!(mapped_database.read_value_unchecked(&[2, 1, 2]).unwrap(), 0);
+}
+
+#[test]
+#[serial]
+fn read_value__returns_ElementsNotLinkedError__given_mapped_4d_u64_database_and_unlinked_location() {
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[2, 2, 2, 2]
+    ).unwrap();
+
+    cql_db::link_dimensions::<U64>(DATABASE_LOCATION, &[1, 1, 1]).unwrap();
+
+    let mut mapped_database = cql_db::open_mapped::<U64>(DATABASE_LOCATION).unwrap();
+
+    let result = match mapped_database.read_value(&[1, 1, 2, 1]) {
+        Err(error::Error::Cql(cql_error)) => Some(cql_error),
+        _ => None,
+    };
+
+    assert_eq!(
+        result.unwrap(),
+        error::cql::Error::ElementsNotLinkedError {
+            x_dimension: 1,
+            x: 1,
+            y_dimension: 2,
+            y: 2,
+        }
+    );
+}
+
+#[test]
+#[serial]
+fn read_to_stream__returns_IndexOutOfRangeError__given_mapped_2d_u64_database_and_too_many_values() {
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[2, 3]
+    ).unwrap();
+
+    let mut mapped_database = cql_db::open_mapped::<U64>(DATABASE_LOCATION).unwrap();
+    let mut stream = Cursor::new(Vec::new());
+
+    let result = match mapped_database.read_to_stream(&mut stream, &[1, 2], 3) {
+        Err(error::Error::Cql(cql_error)) => Some(cql_error),
+        _ => None,
+    };
+
+    assert_eq!(
+        result.unwrap(),
+        error::cql::Error::IndexOutOfRangeError {
+            dimension_index: 1,
+            requested: 4,
+            min: 1,
+            max: 3,
+        }
+    );
+}
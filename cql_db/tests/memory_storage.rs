@@ -0,0 +1,134 @@
+#![allow(non_snake_case)]
+
+use std::io::{ Cursor, Seek, SeekFrom };
+use std::time::Duration;
+
+use cql_u64::U64;
+use cql_db::error;
+use cql_db::storage::MemoryStorage;
+
+// Databases kept in memory do not share a directory, so unlike the other tests these are not serial
+
+#[test]
+fn _4d_u64_memory_database_allows_for_single_point_read_writes() {
+    let storage = MemoryStorage::new();
+    cql_db::create_db_in::<U64, _>(&storage, &[2, 3, 2, 3]).unwrap();
+
+    let mut database = cql_db::open_in::<U64, _>(storage).unwrap();
+    for i in 1..3 {
+        for j in 1..4 {
+            database.link_dimensions(&[i, j, 2]).unwrap();
+            database.write_value(&[i, j, 2, 3], i * 100 + j * 10).unwrap();
+        }
+    }
+
+    for i in 1..3 {
+        for j in 1..4 {
+            assert_eq!(database.read_value(&[i, j, 2, 3]).unwrap(), i * 100 + j * 10);
+            assert_eq!(database.read_value(&[i, j, 2, 1]).unwrap(), 0);
+        }
+    }
+}
+
+#[test]
+fn _3d_u64_memory_database_allows_for_streams_and_ranges() {
+    let storage = MemoryStorage::new();
+    cql_db::create_db_in::<U64, _>(&storage, &[2, 2, 4]).unwrap();
+
+    let mut database = cql_db::open_in::<U64, _>(storage).unwrap();
+    database.link_dimensions(&[2, 1]).unwrap();
+
+    let mut write_stream = Cursor::new(Vec::new());
+    for value in [5u64, 6, 7].iter() {
+        write_stream.get_mut().extend_from_slice(&value.to_le_bytes());
+    }
+    database.write_from_stream(&mut write_stream, &[2, 1, 2], 3).unwrap();
+
+    let mut read_stream = Cursor::new(Vec::new());
+    database.read_to_stream(&mut read_stream, &[2, 1, 1], 4).unwrap();
+    read_stream.seek(SeekFrom::Start(0)).unwrap();
+
+    let mut streamed_values = Vec::new();
+    cql_u64::unpack_stream(&mut read_stream, 4, |_, value| streamed_values.push(value)).unwrap();
+
+    let range_values: Vec<u64> = database.read_range(&[2, 1, 1], 4).unwrap().map(|value| value.unwrap()).collect();
+
+    assert_eq!(streamed_values, vec![0, 5, 6, 7]);
+    assert_eq!(range_values, vec![0, 5, 6, 7]);
+}
+
+#[test]
+fn _3d_u64_memory_database_handles_share_clones_of_the_storage() {
+    let storage = MemoryStorage::new();
+    cql_db::create_db_in::<U64, _>(&storage, &[2, 2, 2]).unwrap();
+
+    let mut writer = cql_db::open_in::<U64, _>(storage.clone()).unwrap();
+    let mut reader = cql_db::open_in::<U64, _>(storage).unwrap();
+
+    let mut transaction = writer.transaction();
+    transaction.link_dimensions(&[1, 2]).unwrap();
+    transaction.write_value(&[1, 2, 2], 8).unwrap();
+    transaction.commit().unwrap();
+
+    assert_eq!(reader.read_value(&[1, 2, 2]).unwrap(), 8);
+}
+
+#[test]
+fn _3d_u64_memory_database_reuses_unlinked_rows() {
+    let storage = MemoryStorage::new();
+    cql_db::create_db_in::<U64, _>(&storage, &[2, 2, 2]).unwrap();
+
+    let mut database = cql_db::open_in::<U64, _>(storage).unwrap();
+    database.link_dimensions(&[1, 1]).unwrap();
+    database.write_value(&[1, 1, 2], 3).unwrap();
+    database.unlink_dimensions(&[1, 1]).unwrap();
+    database.link_dimensions(&[2, 2]).unwrap();
+
+    assert_eq!(database.read_value(&[2, 2, 2]).unwrap(), 0);
+}
+
+#[test]
+fn memory_database_locks_against_other_handles() {
+    let storage = MemoryStorage::new();
+    cql_db::create_db_in::<U64, _>(&storage, &[2, 2, 2]).unwrap();
+
+    let mut reader = cql_db::open_in::<U64, _>(storage.clone()).unwrap();
+    let mut writer = cql_db::open_in::<U64, _>(storage).unwrap();
+    writer.set_lock_timeout(Duration::from_millis(10));
+    writer.link_dimensions(&[1, 1]).unwrap();
+
+    let range = reader.read_range(&[1, 1, 1], 2).unwrap();
+
+    let result = match writer.link_dimensions(&[1, 2]) {
+        Err(error::Error::Cql(cql_error)) => Some(cql_error),
+        _ => None,
+    };
+
+    assert_eq!(result.unwrap(), error::cql::Error::LockTimeoutError { timeout: Duration::from_millis(10) });
+
+    drop(range);
+    writer.link_dimensions(&[1, 2]).unwrap();
+}
+
+#[test]
+fn create_db_in_returns_error_given_existing_memory_database() {
+    let storage = MemoryStorage::new();
+    cql_db::create_db_in::<U64, _>(&storage, &[2, 2]).unwrap();
+
+    let result = cql_db::create_db_in::<U64, _>(&storage, &[2, 2]);
+
+    match result {
+        Err(error::Error::Io(io_error)) => assert_eq!(io_error.kind(), std::io::ErrorKind::AlreadyExists),
+        _ => panic!("expected an AlreadyExists error"),
+    }
+}
+
+#[test]
+fn open_in_returns_error_given_empty_memory_storage() {
+    let result = cql_db::open_in::<U64, _>(MemoryStorage::new());
+
+    match result {
+        Err(error::Error::Io(io_error)) => assert_eq!(io_error.kind(), std::io::ErrorKind::NotFound),
+        _ => panic!("expected a NotFound error"),
+    }
+}
@@ -0,0 +1,323 @@
+#![allow(non_snake_case)]
+
+mod constants;
+
+use serial_test::serial;
+use std::convert::TryInto;
+use std::fs::{ read, remove_file, write };
+
+use constants::DATABASE_LOCATION;
+use cql_u64::U64;
+use cql_db::error;
+
+#[test]
+#[serial]
+fn from_0_1__upgrades_1d_u64_database() {
+    delete_existing_db();
+    write_0_1_file("/ax", &[1, 3]);
+    write_0_1_file("/db", &[0, 10, 20, 30]);
+
+    cql_db::migrate::from_0_1::<U64>(DATABASE_LOCATION).unwrap();
+
+    for (index, expected) in [10, 20, 30].iter().enumerate() {
+        let result = cql_db::read_value::<U64>(
+            DATABASE_LOCATION,
+            &[index as u64 + 1]
+        ).unwrap();
+
+        assert_eq!(result, *expected);
+    }
+}
+
+#[test]
+#[serial]
+fn from_0_1__upgrades_3d_u64_database_allowing_further_links() {
+    delete_existing_db();
+    // a [2, 3, 2] database with [2, 3] linked, and 5 written to [2, 3, 1] and 6 to [2, 3, 2]
+    write_0_1_file("/ax", &[3, 2, 3, 2]);
+    write_0_1_file("/key1_2", &[1, 0, 0, 0, 0, 0, 0, 1]);
+    write_0_1_file("/db", &[0, 5, 6]);
+
+    cql_db::migrate::from_0_1::<U64>(DATABASE_LOCATION).unwrap();
+
+    assert_eq!(cql_db::read_value::<U64>(DATABASE_LOCATION, &[2, 3, 1]).unwrap(), 5);
+    assert_eq!(cql_db::read_value::<U64>(DATABASE_LOCATION, &[2, 3, 2]).unwrap(), 6);
+
+    cql_db::link_dimensions::<U64>(DATABASE_LOCATION, &[1, 1]).unwrap();
+    cql_db::write_value::<U64>(DATABASE_LOCATION, &[1, 1, 2], 7).unwrap();
+
+    assert_eq!(cql_db::read_value::<U64>(DATABASE_LOCATION, &[1, 1, 2]).unwrap(), 7);
+    assert_eq!(cql_db::read_value::<U64>(DATABASE_LOCATION, &[2, 3, 2]).unwrap(), 6);
+}
+
+#[test]
+#[serial]
+fn from_0_1__marks_existing_values_as_written() {
+    delete_existing_db();
+    // a [2, 3, 2] database with [2, 3] linked, and 5 written to [2, 3, 1] and 0 (or nothing) to [2, 3, 2]
+    write_0_1_file("/ax", &[3, 2, 3, 2]);
+    write_0_1_file("/key1_2", &[1, 0, 0, 0, 0, 0, 0, 1]);
+    write_0_1_file("/db", &[0, 5, 0]);
+
+    cql_db::migrate::from_0_1::<U64>(DATABASE_LOCATION).unwrap();
+
+    assert!(cql_db::is_written::<U64>(DATABASE_LOCATION, &[2, 3, 1]).unwrap());
+    assert!(!cql_db::is_written::<U64>(DATABASE_LOCATION, &[2, 3, 2]).unwrap());
+
+    cql_db::write_value::<U64>(DATABASE_LOCATION, &[2, 3, 2], 0).unwrap();
+
+    assert!(cql_db::is_written::<U64>(DATABASE_LOCATION, &[2, 3, 2]).unwrap());
+}
+
+#[test]
+#[serial]
+fn from_0_1__does_nothing__given_current_u64_database() {
+    let point = [2, 2, 1];
+
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[2, 2, 2]
+    ).unwrap();
+
+    cql_db::link_dimensions::<U64>(DATABASE_LOCATION, &point[0..2]).unwrap();
+    cql_db::write_value::<U64>(DATABASE_LOCATION, &point, 3).unwrap();
+
+    cql_db::migrate::from_0_1::<U64>(DATABASE_LOCATION).unwrap();
+
+    assert_eq!(cql_db::read_value::<U64>(DATABASE_LOCATION, &point).unwrap(), 3);
+}
+
+#[test]
+#[serial]
+fn from_0_1__returns_FormatVersionError__given_database_with_unsupported_format_version() {
+    delete_existing_db();
+    write_0_1_file("/ax", &[1, 3, 99]);
+
+    let result = match cql_db::migrate::from_0_1::<U64>(DATABASE_LOCATION) {
+        Err(error::Error::Cql(cql_error)) => Some(cql_error),
+        _ => None,
+    };
+
+    assert_eq!(
+        result.unwrap(),
+        error::cql::Error::FormatVersionError {
+            stored: 99,
+            supported: cql_db::FORMAT_VERSION,
+        }
+    );
+}
+
+#[test]
+#[serial]
+fn open__returns_FormatVersionError__given_database_with_unsupported_format_version() {
+    delete_existing_db();
+    write_0_1_file("/ax", &[1, 3, 99]);
+    write_0_1_file("/db", &[]);
+
+    let result = match cql_db::open::<U64>(DATABASE_LOCATION) {
+        Err(error::Error::Cql(cql_error)) => Some(cql_error),
+        _ => None,
+    };
+
+    assert_eq!(
+        result.unwrap(),
+        error::cql::Error::FormatVersionError {
+            stored: 99,
+            supported: cql_db::FORMAT_VERSION,
+        }
+    );
+}
+
+#[test]
+#[serial]
+fn read_value_unchecked__returns_Io_InvalidData__given_database_with_unsupported_format_version() {
+    delete_existing_db();
+    write_0_1_file("/ax", &[1, 3, 99]);
+    write_0_1_file("/db", &[]);
+
+    let result = cql_db::read_value_unchecked::<U64>(DATABASE_LOCATION, &[1]);
+
+    assert_eq!(result.unwrap_err().kind(), std::io::ErrorKind::InvalidData);
+}
+
+#[test]
+#[serial]
+fn from_0_1__returns_FormatVersionError__given_unversioned_0_2_database() {
+    delete_existing_db();
+    write_0_1_file("/ax", &[1, 3]);
+    write_0_1_file("/db", &[10, 20, 30]);
+
+    let result = match cql_db::migrate::from_0_1::<U64>(DATABASE_LOCATION) {
+        Err(error::Error::Cql(cql_error)) => Some(cql_error),
+        _ => None,
+    };
+
+    assert_eq!(
+        result.unwrap(),
+        error::cql::Error::FormatVersionError {
+            stored: cql_db::FORMAT_VERSION,
+            supported: 1,
+        }
+    );
+    assert_eq!(cql_db::read_value::<U64>(DATABASE_LOCATION, &[2]).unwrap(), 20);
+}
+
+#[test]
+#[serial]
+fn open__returns_FormatVersionError__given_unversioned_1d_0_1_database() {
+    delete_existing_db();
+    write_0_1_file("/ax", &[1, 3]);
+    write_0_1_file("/db", &[0, 10, 20, 30]);
+
+    let result = match cql_db::open::<U64>(DATABASE_LOCATION) {
+        Err(error::Error::Cql(cql_error)) => Some(cql_error),
+        _ => None,
+    };
+
+    assert_eq!(
+        result.unwrap(),
+        error::cql::Error::FormatVersionError {
+            stored: 1,
+            supported: cql_db::FORMAT_VERSION,
+        }
+    );
+}
+
+#[test]
+#[serial]
+fn read_value__returns_FormatVersionError__given_unversioned_3d_0_1_database() {
+    delete_existing_db();
+    write_0_1_file("/ax", &[3, 2, 3, 2]);
+    write_0_1_file("/key1_2", &[1, 0, 0, 0, 0, 0, 1]);
+    write_0_1_file("/db", &[0, 5]);
+
+    let result = match cql_db::read_value::<U64>(DATABASE_LOCATION, &[2, 2, 1]) {
+        Err(error::Error::Cql(cql_error)) => Some(cql_error),
+        _ => None,
+    };
+
+    assert_eq!(
+        result.unwrap(),
+        error::cql::Error::FormatVersionError {
+            stored: 1,
+            supported: cql_db::FORMAT_VERSION,
+        }
+    );
+}
+
+#[test]
+#[serial]
+fn open__returns_FormatVersionError__given_unversioned_database_that_could_be_0_1_or_0_2() {
+    delete_existing_db();
+    write_0_1_file("/ax", &[1, 3]);
+    write_0_1_file("/db", &[0, 20]);
+
+    let result = match cql_db::open::<U64>(DATABASE_LOCATION) {
+        Err(error::Error::Cql(cql_error)) => Some(cql_error),
+        _ => None,
+    };
+
+    assert_eq!(
+        result.unwrap(),
+        error::cql::Error::FormatVersionError {
+            stored: 1,
+            supported: cql_db::FORMAT_VERSION,
+        }
+    );
+}
+
+#[test]
+#[serial]
+fn open__records_format_version__given_unversioned_1d_0_2_database() {
+    delete_existing_db();
+    write_0_1_file("/ax", &[1, 3]);
+    write_0_1_file("/db", &[10, 20, 30]);
+
+    let mut database = cql_db::open::<U64>(DATABASE_LOCATION).unwrap();
+
+    assert_eq!(database.read_value(&[2]).unwrap(), 20);
+    assert_eq!(read_blocks("/ax"), vec![1, 3, cql_db::FORMAT_VERSION]);
+}
+
+#[test]
+#[serial]
+fn open__records_format_version__given_unversioned_3d_0_2_database() {
+    delete_existing_db();
+    // a [2, 3, 2] database with [1, 2] linked, and 5 written to [1, 2, 1] and 6 to [1, 2, 2]
+    write_0_1_file("/ax", &[3, 2, 3, 2]);
+    write_0_1_file("/key1_2", &[1, 0, 1]);
+    write_0_1_file("/db", &[5, 6]);
+
+    let mut database = cql_db::open::<U64>(DATABASE_LOCATION).unwrap();
+
+    assert_eq!(database.read_value(&[1, 2, 1]).unwrap(), 5);
+    assert_eq!(database.read_value(&[1, 2, 2]).unwrap(), 6);
+    assert_eq!(read_blocks("/ax"), vec![3, 2, 3, 2, cql_db::FORMAT_VERSION]);
+}
+
+#[test]
+#[serial]
+fn read_value__does_not_record_format_version__given_unversioned_0_2_database() {
+    delete_existing_db();
+    write_0_1_file("/ax", &[1, 3]);
+    write_0_1_file("/db", &[10, 20, 30]);
+
+    let result = cql_db::read_value::<U64>(DATABASE_LOCATION, &[3]).unwrap();
+
+    assert_eq!(result, 30);
+    assert_eq!(read_blocks("/ax"), vec![1, 3]);
+}
+
+#[test]
+#[serial]
+fn from_0_2__records_format_version__given_unversioned_database_that_could_be_0_1_or_0_2() {
+    delete_existing_db();
+    write_0_1_file("/ax", &[1, 3]);
+    write_0_1_file("/db", &[0, 20]);
+
+    cql_db::migrate::from_0_2::<U64>(DATABASE_LOCATION).unwrap();
+
+    assert_eq!(cql_db::read_value::<U64>(DATABASE_LOCATION, &[2]).unwrap(), 20);
+    assert_eq!(read_blocks("/ax"), vec![1, 3, cql_db::FORMAT_VERSION]);
+}
+
+#[test]
+#[serial]
+fn from_0_2__returns_FormatVersionError__given_unversioned_0_1_database() {
+    delete_existing_db();
+    write_0_1_file("/ax", &[1, 3]);
+    write_0_1_file("/db", &[0, 10, 20, 30]);
+
+    let result = match cql_db::migrate::from_0_2::<U64>(DATABASE_LOCATION) {
+        Err(error::Error::Cql(cql_error)) => Some(cql_error),
+        _ => None,
+    };
+
+    assert_eq!(
+        result.unwrap(),
+        error::cql::Error::FormatVersionError {
+            stored: 1,
+            supported: cql_db::FORMAT_VERSION,
+        }
+    );
+    assert_eq!(read_blocks("/db"), vec![0, 10, 20, 30]);
+}
+
+fn write_0_1_file(file_name: &str, blocks: &[u64]) {
+    let bytes: Vec<u8> = blocks.iter().flat_map(|block| block.to_le_bytes().to_vec()).collect();
+    write(format!("{}{}", DATABASE_LOCATION, file_name), bytes).unwrap();
+}
+
+fn read_blocks(file_name: &str) -> Vec<u64> {
+    read(format!("{}{}", DATABASE_LOCATION, file_name))
+        .unwrap()
+        .chunks_exact(8)
+        .map(|block| u64::from_le_bytes(block.try_into().unwrap()))
+        .collect()
+}
+
+fn delete_existing_db() {
+    for file_name in ["/db", "/ax", "/ty", "/wr", "/rl", "/jnl", "/rsz", "/key1_2", "/key2_3", "/freekey1_2", "/freekey2_3"].iter() {
+        let _ = remove_file(format!("{}{}", DATABASE_LOCATION, file_name));
+    }
+}
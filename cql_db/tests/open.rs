@@ -0,0 +1,197 @@
+#![allow(non_snake_case)]
+
+mod constants;
+
+use serial_test::serial;
+use std::fs::{ remove_file, metadata, OpenOptions };
+use std::io::Cursor;
+
+use constants::DATABASE_LOCATION;
+use cql_u64::U64;
+use cql_db::error;
+
+#[test]
+#[serial]
+fn open__returns_Io_NotFound__given_directory_without_database() {
+    delete_existing_db();
+
+    let result = match cql_db::open::<U64>(
+        DATABASE_LOCATION
+    ) {
+        Err(error::Error::Io(io_error)) => Some(io_error),
+        _ => None,
+    };
+
+    assert_eq!(
+        result.unwrap().kind(),
+        std::io::ErrorKind::NotFound
+    );
+}
+
+#[test]
+#[serial]
+fn open__returns_database_with_created_capacities__given_4d_u64_database() {
+    let db_dimensions = [2, 3, 4, 5];
+
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &db_dimensions
+    ).unwrap();
+
+    let database = cql_db::open::<U64>(DATABASE_LOCATION).unwrap();
+
+    assert_eq!(database.dimensions(), db_dimensions.len());
+    assert_eq!(database.capacities(), db_dimensions.to_vec());
+}
+
+#[test]
+#[serial]
+fn _4d_u64_database_handle_allows_for_all_points_to_be_linked_written_and_read() {
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[2, 3, 2, 4]
+    ).unwrap();
+
+    let mut database = cql_db::open::<U64>(DATABASE_LOCATION).unwrap();
+
+    for i in 1..3 {
+        for j in 1..4 {
+            for k in 1..3 {
+                database.link_dimensions(&[i, j, k]).unwrap();
+
+                for l in 1..5 {
+                    database.write_value(&[i, j, k, l], i * j * k * l).unwrap();
+                }
+            }
+        }
+    }
+
+    for i in 1..3 {
+        for j in 1..4 {
+            for k in 1..3 {
+                for l in 1..5 {
+                    assert_eq!(database.read_value(&[i, j, k, l]).unwrap(), i * j * k * l);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+#[serial]
+fn _4d_u64_database_handle_values_are_visible_to_free_functions() {
+    let point = [2, 1, 3, 2];
+    let value = 42;
+
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[2, 3, 3, 4]
+    ).unwrap();
+
+    {
+        let mut database = cql_db::open::<U64>(DATABASE_LOCATION).unwrap();
+        database.link_dimensions(&point[0..3]).unwrap();
+        database.write_value(&point, value).unwrap();
+    }
+
+    let result = cql_db::read_value::<U64>(
+        DATABASE_LOCATION,
+        &point
+    ).unwrap();
+
+    assert_eq!(result, value);
+}
+
+#[test]
+#[serial]
+fn _3d_u64_database_handle_allows_for_stream_reads() {
+    let base_point = [1, 2, 2];
+
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[1, 2, 4]
+    ).unwrap();
+
+    let mut database = cql_db::open::<U64>(DATABASE_LOCATION).unwrap();
+    database.link_dimensions(&base_point[0..2]).unwrap();
+    database.write_value(&base_point, 3).unwrap();
+    database.write_value(&[1, 2, 4], 7).unwrap();
+
+    let mut stream = Cursor::new(Vec::new());
+    database.read_to_stream(&mut stream, &base_point, 3).unwrap();
+
+    let mut result = [0; 3];
+    stream.set_position(0);
+    cql_u64::unpack_stream(&mut stream, 3, |idx, value| {
+        result[idx] = value
+    }).unwrap();
+
+    assert_eq!(result, [3, 0, 7]);
+}
+
+#[test]
+#[serial]
+fn _3d_u64_database_handle_returns_ElementsNotLinkedError__given_unlinked_location() {
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[2, 2, 2]
+    ).unwrap();
+
+    let mut database = cql_db::open::<U64>(DATABASE_LOCATION).unwrap();
+    database.link_dimensions_unchecked(&[1, 1]).unwrap();
+
+    let result = match database.read_value(&[1, 2, 1]) {
+        Err(error::Error::Cql(cql_error)) => Some(cql_error),
+        _ => None,
+    };
+
+    assert_eq!(
+        result.unwrap(),
+        error::cql::Error::ElementsNotLinkedError {
+            x_dimension: 0,
+            x: 1,
+            y_dimension: 1,
+            y: 2,
+        }
+    );
+}
+
+#[test]
+#[serial]
+fn _3d_u64_database_reads_do_not_create_files_missing_from_older_databases() {
+    let point = [2, 1, 3];
+
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[2, 2, 4]
+    ).unwrap();
+    cql_db::link_dimensions_unchecked::<U64>(DATABASE_LOCATION, &point[0..2]).unwrap();
+    cql_db::write_value_unchecked::<U64>(DATABASE_LOCATION, &point, 5).unwrap();
+
+    // databases created before writes were recorded, or keys could be released, do not have these files, nor do they record their format version
+    remove_file(format!("{}/wr", DATABASE_LOCATION)).unwrap();
+    remove_file(format!("{}/freekey1_2", DATABASE_LOCATION)).unwrap();
+    OpenOptions::new().write(true).open(format!("{}/ax", DATABASE_LOCATION)).unwrap().set_len(4 * 8).unwrap();
+
+    assert_eq!(cql_db::read_value::<U64>(DATABASE_LOCATION, &point).unwrap(), 5);
+    assert!(cql_db::is_linked::<U64>(DATABASE_LOCATION, &point[0..2]).unwrap());
+    assert!(cql_db::is_written::<U64>(DATABASE_LOCATION, &point).unwrap());
+
+    let mut stream = Cursor::new(Vec::new());
+    cql_db::read_to_stream::<U64>(DATABASE_LOCATION, &mut stream, &[2, 1, 1], 4).unwrap();
+    assert_eq!(stream.into_inner().len(), 32);
+
+    assert!(metadata(format!("{}/wr", DATABASE_LOCATION)).is_err());
+    assert!(metadata(format!("{}/freekey1_2", DATABASE_LOCATION)).is_err());
+
+    // whilst writes create them as they are needed
+    cql_db::write_value::<U64>(DATABASE_LOCATION, &point, 6).unwrap();
+    assert!(metadata(format!("{}/wr", DATABASE_LOCATION)).is_ok());
+}
+
+fn delete_existing_db() {
+    let _ = remove_file(format!("{}/db", DATABASE_LOCATION));
+    let _ = remove_file(format!("{}/ax", DATABASE_LOCATION));
+    let _ = remove_file(format!("{}/key1_2", DATABASE_LOCATION));
+    let _ = remove_file(format!("{}/key2_3", DATABASE_LOCATION));
+}
@@ -0,0 +1,136 @@
+#![allow(non_snake_case)]
+
+mod constants;
+
+use serial_test::serial;
+use std::io;
+
+use constants::DATABASE_LOCATION;
+use cql_u64::U64;
+use cql_model::{ CqlType, CqlRangeReadable, CqlBufferWritable };
+use cql_db::error;
+use cql_db::storage::MemoryStorage;
+
+// Stores single digits, failing to decode any other byte
+struct Digit;
+
+impl CqlType for Digit {
+    type ValueType = u8;
+    const VALUE_SIZE: usize = 1;
+    const TYPE_NAME: &'static str = "read_range::Digit";
+}
+
+impl CqlRangeReadable for Digit {
+    fn read_from_buffer(buffer: &[u8]) -> io::Result<u8> {
+        match buffer[0] {
+            digit @ 0..=9 => Ok(digit),
+            _ => Err(io::Error::new(io::ErrorKind::InvalidData, "not a digit")),
+        }
+    }
+}
+
+impl CqlBufferWritable for Digit {
+    fn write_to_buffer(input_value: u8, buffer: &mut [u8]) -> io::Result<()> {
+        buffer[0] = input_value;
+        Ok(())
+    }
+}
+
+#[test]
+#[serial]
+fn _1d_u64_database_allows_for_range_reads_past_the_last_written_value() {
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[6]
+    ).unwrap();
+
+    cql_db::write_value::<U64>(
+        DATABASE_LOCATION,
+        &[2],
+        5
+    ).unwrap();
+
+    let result = cql_db::read_range::<U64>(
+        DATABASE_LOCATION,
+        &[1],
+        6
+    ).unwrap().collect::<Result<Vec<u64>, _>>().unwrap();
+
+    assert_eq!(result, vec![0, 5, 0, 0, 0, 0]);
+}
+
+#[test]
+#[serial]
+fn _4d_u64_database_range_reads_match_stream_reads() {
+    let base_point = [2, 1, 3, 2];
+
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[2, 2, 3, 5]
+    ).unwrap();
+
+    let mut database = cql_db::open::<U64>(DATABASE_LOCATION).unwrap();
+    database.link_dimensions(&[1, 1, 3]).unwrap();
+    database.link_dimensions(&base_point[0..3]).unwrap();
+    for l in 1..6 {
+        database.write_value(&[1, 1, 3, l], 100 + l).unwrap();
+        database.write_value(&[2, 1, 3, l], l).unwrap();
+    }
+
+    let mut stream = std::io::Cursor::new(Vec::new());
+    database.read_to_stream(&mut stream, &base_point, 4).unwrap();
+
+    let mut expected = [0; 4];
+    stream.set_position(0);
+    cql_u64::unpack_stream(&mut stream, 4, |idx, value| {
+        expected[idx] = value
+    }).unwrap();
+
+    let result: Vec<u64> = database.read_range(&base_point, 4).unwrap().map(|value| value.unwrap()).collect();
+
+    assert_eq!(result, expected.to_vec());
+    assert_eq!(result, vec![2, 3, 4, 5]);
+}
+
+#[test]
+fn _2d_database_range_reads_end_after_the_first_error() {
+    let storage = MemoryStorage::new();
+    cql_db::create_db_in::<Digit, _>(&storage, &[1, 5]).unwrap();
+
+    let mut database = cql_db::open_in::<Digit, _>(storage).unwrap();
+    database.write_values(&[(&[1, 1], 1), (&[1, 2], 42), (&[1, 3], 3)]).unwrap();
+
+    let result: Vec<io::Result<u8>> = database.read_range(&[1, 1], 5).unwrap().collect();
+
+    assert_eq!(result.len(), 2);
+    assert_eq!(result[0].as_ref().unwrap(), &1);
+    assert_eq!(result[1].as_ref().unwrap_err().kind(), io::ErrorKind::InvalidData);
+}
+
+#[test]
+#[serial]
+fn read_range__returns_IndexOutOfRangeError__given_too_many_values() {
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[2, 4]
+    ).unwrap();
+
+    let result = match cql_db::read_range::<U64>(
+        DATABASE_LOCATION,
+        &[1, 2],
+        4
+    ) {
+        Err(error::Error::Cql(cql_error)) => Some(cql_error),
+        _ => None,
+    };
+
+    assert_eq!(
+        result.unwrap(),
+        error::cql::Error::IndexOutOfRangeError {
+            dimension_index: 1,
+            requested: 5,
+            min: 1,
+            max: 4,
+        }
+    );
+}
@@ -0,0 +1,193 @@
+#![allow(non_snake_case)]
+
+mod constants;
+
+use serial_test::serial;
+use std::io::{ Cursor, SeekFrom, Seek };
+
+use constants::DATABASE_LOCATION;
+use cql_u64::{ U64, unpack_stream };
+use cql_db::error;
+use cql_db::storage::MemoryStorage;
+
+fn unpack(stream: &mut Cursor<Vec<u8>>) -> Vec<u64> {
+    let n_values = stream.get_ref().len() / 8;
+    let mut result = Vec::new();
+
+    stream.seek(SeekFrom::Start(0)).unwrap();
+    unpack_stream(stream, n_values, |_, value| result.push(value)).unwrap();
+
+    result
+}
+
+#[test]
+#[serial]
+#[allow(clippy::single_range_in_vec_init)]
+fn _1d_u64_database_reads_slice_of_range() {
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[5]
+    ).unwrap();
+
+    cql_db::write_value::<U64>(DATABASE_LOCATION, &[2], 20).unwrap();
+    cql_db::write_value::<U64>(DATABASE_LOCATION, &[4], 40).unwrap();
+
+    let mut stream = Cursor::new(Vec::new());
+    let rows = cql_db::read_slice::<U64>(DATABASE_LOCATION, &mut stream, &[2..5]).unwrap();
+
+    assert_eq!(rows, vec![Vec::<u64>::new()]);
+    assert_eq!(unpack(&mut stream), vec![20, 0, 40]);
+}
+
+#[test]
+#[serial]
+fn _2d_u64_database_reads_slice_of_every_row_in_range() {
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[4, 3]
+    ).unwrap();
+
+    cql_db::write_value::<U64>(DATABASE_LOCATION, &[2, 3], 23).unwrap();
+    cql_db::write_value::<U64>(DATABASE_LOCATION, &[3, 2], 32).unwrap();
+    cql_db::write_value::<U64>(DATABASE_LOCATION, &[4, 2], 42).unwrap();
+
+    let mut stream = Cursor::new(Vec::new());
+    let rows = cql_db::read_slice::<U64>(DATABASE_LOCATION, &mut stream, &[2..4, 2..4]).unwrap();
+
+    assert_eq!(rows, vec![vec![2], vec![3]]);
+    assert_eq!(unpack(&mut stream), vec![0, 23, 32, 0]);
+}
+
+#[test]
+#[serial]
+fn _4d_u64_database_reads_slice_skipping_unlinked_rows() {
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[3, 3, 4, 5]
+    ).unwrap();
+
+    let mut database = cql_db::open::<U64>(DATABASE_LOCATION).unwrap();
+    for location in &[[1, 1, 1], [1, 2, 3], [2, 2, 2], [2, 3, 2], [3, 2, 2]] {
+        database.link_dimensions(location).unwrap();
+        database.write_value(&[location[0], location[1], location[2], 2], location[0] * 100 + location[1] * 10 + location[2]).unwrap();
+        database.write_value(&[location[0], location[1], location[2], 4], 1).unwrap();
+    }
+
+    let mut stream = Cursor::new(Vec::new());
+    let rows = database.read_slice(&mut stream, &[1..3, 2..4, 2..4, 2..5]).unwrap();
+
+    assert_eq!(rows, vec![vec![1, 2, 3], vec![2, 2, 2], vec![2, 3, 2]]);
+    assert_eq!(unpack(&mut stream), vec![123, 0, 1, 222, 0, 1, 232, 0, 1]);
+
+    let mut unchecked_stream = Cursor::new(Vec::new());
+    let unchecked_rows = cql_db::read_slice_unchecked::<U64>(DATABASE_LOCATION, &mut unchecked_stream, &[1..3, 2..4, 2..4, 2..5]).unwrap();
+
+    assert_eq!(unchecked_rows, rows);
+    assert_eq!(unchecked_stream.into_inner(), stream.into_inner());
+}
+
+#[test]
+#[serial]
+fn _3d_u64_database_reads_nothing_given_empty_range() {
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[2, 2, 2]
+    ).unwrap();
+
+    cql_db::link_dimensions::<U64>(DATABASE_LOCATION, &[1, 1]).unwrap();
+
+    let mut stream = Cursor::new(Vec::new());
+    let rows = cql_db::read_slice::<U64>(DATABASE_LOCATION, &mut stream, &[1..3, 2..2, 1..3]).unwrap();
+
+    assert!(rows.is_empty());
+    assert!(stream.get_ref().is_empty());
+}
+
+#[test]
+fn _3d_u64_memory_database_reads_slice() {
+    let storage = MemoryStorage::new();
+    cql_db::create_db_in::<U64, _>(&storage, &[2, 2, 3]).unwrap();
+
+    let mut database = cql_db::open_in::<U64, _>(storage).unwrap();
+    database.link_dimensions(&[2, 1]).unwrap();
+    database.write_value(&[2, 1, 3], 7).unwrap();
+
+    let mut stream = Cursor::new(Vec::new());
+    let rows = database.read_slice(&mut stream, &[1..3, 1..3, 1..4]).unwrap();
+
+    assert_eq!(rows, vec![vec![2, 1]]);
+    assert_eq!(unpack(&mut stream), vec![0, 0, 7]);
+}
+
+#[test]
+#[serial]
+fn read_slice__returns_DimensionsOutOfRangeError__given_3d_u64_database_and_2_ranges() {
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[2, 2, 2]
+    ).unwrap();
+
+    let mut stream = Cursor::new(Vec::new());
+
+    let result = match cql_db::read_slice::<U64>(DATABASE_LOCATION, &mut stream, &[1..2, 1..2]) {
+        Err(error::Error::Cql(cql_error)) => Some(cql_error),
+        _ => None,
+    };
+
+    assert_eq!(result.unwrap(), error::cql::Error::DimensionsOutOfRangeError { requested: 2, min: 3, max: 3 });
+}
+
+#[test]
+#[serial]
+fn read_slice__returns_IndexOutOfRangeError_and_writes_nothing__given_3d_u64_database_and_range_beyond_capacity() {
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[2, 2, 2]
+    ).unwrap();
+
+    cql_db::link_dimensions::<U64>(DATABASE_LOCATION, &[1, 1]).unwrap();
+
+    let mut stream = Cursor::new(Vec::new());
+
+    let result = match cql_db::read_slice::<U64>(DATABASE_LOCATION, &mut stream, &[1..2, 1..2, 1..4]) {
+        Err(error::Error::Cql(cql_error)) => Some(cql_error),
+        _ => None,
+    };
+
+    assert_eq!(
+        result.unwrap(),
+        error::cql::Error::IndexOutOfRangeError {
+            dimension_index: 2,
+            requested: 3,
+            min: 1,
+            max: 2,
+        }
+    );
+    assert!(stream.get_ref().is_empty());
+}
+
+#[test]
+#[serial]
+fn read_slice__returns_IndexOutOfRangeError__given_3d_u64_database_and_range_starting_at_0() {
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[2, 2, 2]
+    ).unwrap();
+
+    let mut stream = Cursor::new(Vec::new());
+
+    let result = match cql_db::read_slice::<U64>(DATABASE_LOCATION, &mut stream, &[0..2, 1..2, 1..2]) {
+        Err(error::Error::Cql(cql_error)) => Some(cql_error),
+        _ => None,
+    };
+
+    assert_eq!(
+        result.unwrap(),
+        error::cql::Error::IndexOutOfRangeError {
+            dimension_index: 0,
+            requested: 0,
+            min: 1,
+            max: 2,
+        }
+    );
+}
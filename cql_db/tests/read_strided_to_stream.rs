@@ -0,0 +1,185 @@
+#![allow(non_snake_case)]
+
+mod constants;
+
+use serial_test::serial;
+use std::io::{ Cursor, SeekFrom, Seek };
+
+use constants::DATABASE_LOCATION;
+use cql_u64::{ U64, unpack_stream };
+use cql_db::error;
+use cql_db::Direction;
+use cql_db::storage::MemoryStorage;
+
+fn unpack(stream: &mut Cursor<Vec<u8>>) -> Vec<u64> {
+    let n_values = stream.get_ref().len() / 8;
+    let mut result = Vec::new();
+
+    stream.seek(SeekFrom::Start(0)).unwrap();
+    unpack_stream(stream, n_values, |_, value| result.push(value)).unwrap();
+
+    result
+}
+
+fn write_indexes(location: &[u64], n_values: u64) {
+    let mut stream = Cursor::new((1..=n_values).flat_map(|value| value.to_le_bytes().to_vec()).collect::<Vec<u8>>());
+    cql_db::write_from_stream::<U64>(DATABASE_LOCATION, &mut stream, location, n_values).unwrap();
+}
+
+#[test]
+#[serial]
+fn _1d_u64_database_reads_every_value_forward_and_reversed_given_stride_of_1() {
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[5]
+    ).unwrap();
+
+    write_indexes(&[1], 5);
+
+    let mut forward = Cursor::new(Vec::new());
+    cql_db::read_strided_to_stream::<U64>(DATABASE_LOCATION, &mut forward, &[2], 4, 1, Direction::Forward).unwrap();
+
+    let mut reversed = Cursor::new(Vec::new());
+    cql_db::read_strided_to_stream::<U64>(DATABASE_LOCATION, &mut reversed, &[2], 4, 1, Direction::Reverse).unwrap();
+
+    assert_eq!(unpack(&mut forward), vec![2, 3, 4, 5]);
+    assert_eq!(unpack(&mut reversed), vec![5, 4, 3, 2]);
+}
+
+#[test]
+#[serial]
+fn _3d_u64_database_reads_strided_values_across_many_blocks() {
+    const N_VALUES: u64 = 50_000;
+
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[2, 2, N_VALUES]
+    ).unwrap();
+
+    cql_db::link_dimensions::<U64>(DATABASE_LOCATION, &[2, 1]).unwrap();
+    write_indexes(&[2, 1, 1], N_VALUES);
+
+    for &stride in &[3, 700, 9_999] {
+        let n_values = (N_VALUES - 1) / stride + 1;
+        let expected: Vec<u64> = (0..n_values).map(|index| 1 + index * stride).collect();
+
+        let mut forward = Cursor::new(Vec::new());
+        cql_db::read_strided_to_stream::<U64>(DATABASE_LOCATION, &mut forward, &[2, 1, 1], n_values, stride, Direction::Forward).unwrap();
+
+        let mut reversed = Cursor::new(Vec::new());
+        cql_db::read_strided_to_stream_unchecked::<U64>(DATABASE_LOCATION, &mut reversed, &[2, 1, 1], n_values, stride, Direction::Reverse).unwrap();
+
+        assert_eq!(unpack(&mut forward), expected);
+        assert_eq!(unpack(&mut reversed), expected.into_iter().rev().collect::<Vec<u64>>());
+    }
+}
+
+#[test]
+fn _2d_u64_memory_database_reads_strided_values() {
+    let storage = MemoryStorage::new();
+    cql_db::create_db_in::<U64, _>(&storage, &[2, 9]).unwrap();
+
+    let mut database = cql_db::open_in::<U64, _>(storage).unwrap();
+    for index in 1..=9 {
+        database.write_value(&[2, index], index * 2).unwrap();
+    }
+
+    let mut stream = Cursor::new(Vec::new());
+    database.read_strided_to_stream(&mut stream, &[2, 3], 3, 3, Direction::Reverse).unwrap();
+
+    assert_eq!(unpack(&mut stream), vec![18, 12, 6]);
+}
+
+#[test]
+#[serial]
+fn read_strided_to_stream__returns_IndexOutOfRangeError_and_writes_nothing__given_3d_u64_database_and_stride_beyond_capacity() {
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[2, 2, 10]
+    ).unwrap();
+
+    cql_db::link_dimensions::<U64>(DATABASE_LOCATION, &[1, 1]).unwrap();
+
+    let mut stream = Cursor::new(Vec::new());
+
+    let result = match cql_db::read_strided_to_stream::<U64>(DATABASE_LOCATION, &mut stream, &[1, 1, 2], 4, 3, Direction::Forward) {
+        Err(error::Error::Cql(cql_error)) => Some(cql_error),
+        _ => None,
+    };
+
+    assert_eq!(
+        result.unwrap(),
+        error::cql::Error::IndexOutOfRangeError {
+            dimension_index: 2,
+            requested: 11,
+            min: 1,
+            max: 10,
+        }
+    );
+    assert!(stream.get_ref().is_empty());
+}
+
+#[test]
+#[serial]
+fn read_strided_to_stream__returns_StrideTooSmallError_and_writes_nothing__given_3d_u64_database_and_stride_0() {
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[2, 2, 10]
+    ).unwrap();
+
+    cql_db::link_dimensions::<U64>(DATABASE_LOCATION, &[1, 1]).unwrap();
+
+    let mut stream = Cursor::new(Vec::new());
+
+    let result = match cql_db::read_strided_to_stream::<U64>(DATABASE_LOCATION, &mut stream, &[1, 1, 2], 4, 0, Direction::Forward) {
+        Err(error::Error::Cql(cql_error)) => Some(cql_error),
+        _ => None,
+    };
+
+    assert_eq!(result.unwrap(), error::cql::Error::StrideTooSmallError);
+    assert!(stream.get_ref().is_empty());
+}
+
+#[test]
+#[serial]
+fn read_strided_to_stream__returns_ElementsNotLinkedError__given_3d_u64_database_and_unlinked_location() {
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[2, 2, 10]
+    ).unwrap();
+
+    let mut stream = Cursor::new(Vec::new());
+
+    let result = match cql_db::read_strided_to_stream::<U64>(DATABASE_LOCATION, &mut stream, &[1, 2, 1], 2, 2, Direction::Reverse) {
+        Err(error::Error::Cql(cql_error)) => Some(cql_error),
+        _ => None,
+    };
+
+    assert_eq!(
+        result.unwrap(),
+        error::cql::Error::ElementsNotLinkedError {
+            x_dimension: 0,
+            x: 1,
+            y_dimension: 1,
+            y: 2,
+        }
+    );
+}
+
+#[test]
+#[serial]
+fn read_strided_to_stream__returns_DimensionsOutOfRangeError__given_3d_u64_database_and_2d_location() {
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[2, 2, 10]
+    ).unwrap();
+
+    let mut stream = Cursor::new(Vec::new());
+
+    let result = match cql_db::read_strided_to_stream::<U64>(DATABASE_LOCATION, &mut stream, &[1, 2], 2, 2, Direction::Forward) {
+        Err(error::Error::Cql(cql_error)) => Some(cql_error),
+        _ => None,
+    };
+
+    assert_eq!(result.unwrap(), error::cql::Error::DimensionsOutOfRangeError { requested: 2, min: 3, max: 3 });
+}
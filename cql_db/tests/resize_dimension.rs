@@ -0,0 +1,143 @@
+#![allow(non_snake_case)]
+
+mod constants;
+
+use serial_test::serial;
+
+use constants::DATABASE_LOCATION;
+use cql_u64::U64;
+use cql_db::error;
+
+#[test]
+#[serial]
+fn _2d_u64_database_allows_for_first_dimension_to_be_resized() {
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[2, 2]
+    ).unwrap();
+
+    cql_db::write_value::<U64>(DATABASE_LOCATION, &[2, 2], 4).unwrap();
+
+    cql_db::resize_dimension::<U64>(DATABASE_LOCATION, 0, 3).unwrap();
+
+    cql_db::write_value::<U64>(DATABASE_LOCATION, &[3, 1], 5).unwrap();
+
+    assert_eq!(cql_db::read_value::<U64>(DATABASE_LOCATION, &[2, 2]).unwrap(), 4);
+    assert_eq!(cql_db::read_value::<U64>(DATABASE_LOCATION, &[3, 1]).unwrap(), 5);
+}
+
+#[test]
+#[serial]
+fn _4d_u64_database_allows_for_each_dimension_to_be_resized() {
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[2, 2, 2, 2]
+    ).unwrap();
+
+    let mut points = Vec::new();
+    for i in 1..3 {
+        for j in 1..3 {
+            for k in 1..3 {
+                cql_db::link_dimensions::<U64>(DATABASE_LOCATION, &[i, j, k]).unwrap();
+                for l in 1..3 {
+                    let point = [i, j, k, l];
+                    cql_db::write_value::<U64>(DATABASE_LOCATION, &point, i * 1000 + j * 100 + k * 10 + l).unwrap();
+                    points.push(point);
+                }
+            }
+        }
+    }
+
+    for (dimension_index, &new_max) in [3, 4, 5, 6].iter().enumerate() {
+        cql_db::resize_dimension::<U64>(DATABASE_LOCATION, dimension_index, new_max).unwrap();
+    }
+
+    let database = cql_db::open::<U64>(DATABASE_LOCATION).unwrap();
+    assert_eq!(database.capacities(), vec![3, 4, 5, 6]);
+
+    for point in points.iter() {
+        let result = cql_db::read_value::<U64>(DATABASE_LOCATION, point).unwrap();
+
+        assert_eq!(result, point[0] * 1000 + point[1] * 100 + point[2] * 10 + point[3]);
+    }
+
+    let new_point = [3, 4, 5, 6];
+    cql_db::link_dimensions::<U64>(DATABASE_LOCATION, &new_point[0..3]).unwrap();
+    cql_db::write_value::<U64>(DATABASE_LOCATION, &new_point, 7).unwrap();
+
+    assert_eq!(cql_db::read_value::<U64>(DATABASE_LOCATION, &new_point).unwrap(), 7);
+    assert_eq!(cql_db::read_value::<U64>(DATABASE_LOCATION, &[2, 2, 2, 2]).unwrap(), 2222);
+}
+
+#[test]
+#[serial]
+fn resize_dimension__returns_ElementsNotLinkedError__given_3d_u64_database_and_new_element() {
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[2, 2, 2]
+    ).unwrap();
+
+    cql_db::link_dimensions::<U64>(DATABASE_LOCATION, &[2, 2]).unwrap();
+
+    cql_db::resize_dimension::<U64>(DATABASE_LOCATION, 1, 3).unwrap();
+
+    let result = match cql_db::read_value::<U64>(DATABASE_LOCATION, &[2, 3, 1]) {
+        Err(error::Error::Cql(cql_error)) => Some(cql_error),
+        _ => None,
+    };
+
+    assert_eq!(
+        result.unwrap(),
+        error::cql::Error::ElementsNotLinkedError {
+            x_dimension: 0,
+            x: 2,
+            y_dimension: 1,
+            y: 3,
+        }
+    );
+}
+
+#[test]
+#[serial]
+fn resize_dimension__returns_DimensionsOutOfRangeError__given_3d_u64_database_and_dimension_index_3() {
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[2, 2, 2]
+    ).unwrap();
+
+    let result = match cql_db::resize_dimension::<U64>(DATABASE_LOCATION, 3, 4) {
+        Err(error::Error::Cql(cql_error)) => Some(cql_error),
+        _ => None,
+    };
+
+    assert_eq!(
+        result.unwrap(),
+        error::cql::Error::DimensionsOutOfRangeError {
+            requested: 3,
+            min: 0,
+            max: 2,
+        }
+    );
+}
+
+#[test]
+#[serial]
+fn resize_dimension__returns_ResizeShrinkError__given_3d_u64_database_and_smaller_capacity() {
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[2, 3, 2]
+    ).unwrap();
+
+    let result = match cql_db::resize_dimension::<U64>(DATABASE_LOCATION, 1, 2) {
+        Err(error::Error::Cql(cql_error)) => Some(cql_error),
+        _ => None,
+    };
+
+    assert_eq!(
+        result.unwrap(),
+        error::cql::Error::ResizeShrinkError {
+            current: 3,
+            requested: 2,
+        }
+    );
+}
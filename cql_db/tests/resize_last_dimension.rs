@@ -0,0 +1,182 @@
+#![allow(non_snake_case)]
+
+mod constants;
+
+use serial_test::serial;
+use std::fs;
+use std::io::{ Seek, SeekFrom, Write };
+
+use constants::DATABASE_LOCATION;
+use cql_u64::U64;
+use cql_db::error;
+
+const RESIZE_FILE_NAME: &str = "/rsz";
+
+#[test]
+#[serial]
+fn _1d_u64_database_allows_for_last_dimension_to_be_resized() {
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[2]
+    ).unwrap();
+
+    cql_db::write_value::<U64>(DATABASE_LOCATION, &[2], 2).unwrap();
+
+    cql_db::resize_last_dimension::<U64>(DATABASE_LOCATION, 5).unwrap();
+
+    cql_db::write_value::<U64>(DATABASE_LOCATION, &[5], 5).unwrap();
+
+    for (index, expected) in [0, 2, 0, 0, 5].iter().enumerate() {
+        let result = cql_db::read_value::<U64>(
+            DATABASE_LOCATION,
+            &[index as u64 + 1]
+        ).unwrap();
+
+        assert_eq!(result, *expected);
+    }
+}
+
+#[test]
+#[serial]
+fn _2d_u64_database_allows_for_last_dimension_to_be_resized() {
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[3, 2]
+    ).unwrap();
+
+    for x in 1..4 {
+        for y in 1..3 {
+            cql_db::write_value::<U64>(DATABASE_LOCATION, &[x, y], x * 10 + y).unwrap();
+        }
+    }
+
+    cql_db::resize_last_dimension::<U64>(DATABASE_LOCATION, 3).unwrap();
+
+    for x in 1..4 {
+        for y in 1..4 {
+            let result = cql_db::read_value::<U64>(DATABASE_LOCATION, &[x, y]).unwrap();
+
+            assert_eq!(result, if y < 3 { x * 10 + y } else { 0 });
+        }
+    }
+}
+
+#[test]
+#[serial]
+fn _4d_u64_database_allows_for_last_dimension_to_be_resized_and_further_links() {
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[2, 2, 2, 3]
+    ).unwrap();
+
+    let rows = [[2, 1, 2], [1, 2, 1], [2, 2, 2]];
+    for row in rows.iter() {
+        cql_db::link_dimensions::<U64>(DATABASE_LOCATION, row).unwrap();
+        for l in 1..4 {
+            cql_db::write_value::<U64>(DATABASE_LOCATION, &[row[0], row[1], row[2], l], row[0] * 1000 + row[1] * 100 + row[2] * 10 + l).unwrap();
+        }
+    }
+
+    cql_db::resize_last_dimension::<U64>(DATABASE_LOCATION, 5).unwrap();
+
+    cql_db::link_dimensions::<U64>(DATABASE_LOCATION, &[1, 1, 1]).unwrap();
+    cql_db::write_value::<U64>(DATABASE_LOCATION, &[1, 1, 1, 5], 9).unwrap();
+    cql_db::write_value::<U64>(DATABASE_LOCATION, &[2, 2, 2, 4], 8).unwrap();
+
+    for row in rows.iter() {
+        for l in 1..4 {
+            let result = cql_db::read_value::<U64>(DATABASE_LOCATION, &[row[0], row[1], row[2], l]).unwrap();
+
+            assert_eq!(result, row[0] * 1000 + row[1] * 100 + row[2] * 10 + l);
+        }
+    }
+
+    assert_eq!(cql_db::read_value::<U64>(DATABASE_LOCATION, &[2, 1, 2, 4]).unwrap(), 0);
+    assert_eq!(cql_db::read_value::<U64>(DATABASE_LOCATION, &[1, 2, 1, 5]).unwrap(), 0);
+    assert_eq!(cql_db::read_value::<U64>(DATABASE_LOCATION, &[2, 2, 2, 4]).unwrap(), 8);
+    assert_eq!(cql_db::read_value::<U64>(DATABASE_LOCATION, &[1, 1, 1, 5]).unwrap(), 9);
+}
+
+#[test]
+#[serial]
+fn _2d_u64_database_completes_interrupted_resize_of_last_dimension_on_next_use() {
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[3, 2]
+    ).unwrap();
+
+    for x in 1..4 {
+        for y in 1..3 {
+            cql_db::write_value::<U64>(DATABASE_LOCATION, &[x, y], x * 10 + y).unwrap();
+        }
+    }
+
+    // a resize to 3 interrupted once the last row had been moved, leaving the first two rows in their old positions
+    let moved_row: Vec<u8> = [31u64, 32, 0].iter().flat_map(|value| value.to_le_bytes().to_vec()).collect();
+    write_at(&format!("{}{}", DATABASE_LOCATION, "/db"), 48, &moved_row);
+    let progress: Vec<u8> = [1u64, 3, 8, 1, 2].iter().flat_map(|block| block.to_le_bytes().to_vec()).collect();
+    fs::write(format!("{}{}", DATABASE_LOCATION, RESIZE_FILE_NAME), &progress).unwrap();
+
+    for x in 1..4 {
+        for y in 1..4 {
+            assert_eq!(cql_db::read_value::<U64>(DATABASE_LOCATION, &[x, y]).unwrap(), if y < 3 { x * 10 + y } else { 0 });
+            assert_eq!(cql_db::is_written::<U64>(DATABASE_LOCATION, &[x, y]).unwrap(), y < 3);
+        }
+    }
+
+    assert_eq!(cql_db::open::<U64>(DATABASE_LOCATION).unwrap().capacities(), vec![3, 3]);
+    assert_eq!(fs::metadata(format!("{}{}", DATABASE_LOCATION, RESIZE_FILE_NAME)).unwrap().len(), 0);
+}
+
+#[test]
+#[serial]
+fn _2d_u64_database_resized_elsewhere_is_seen_by_open_handle() {
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[2, 2]
+    ).unwrap();
+
+    let mut database = cql_db::open::<U64>(DATABASE_LOCATION).unwrap();
+    database.write_value(&[2, 2], 22).unwrap();
+
+    cql_db::resize_last_dimension::<U64>(DATABASE_LOCATION, 3).unwrap();
+
+    database.write_value(&[2, 3], 23).unwrap();
+    database.write_value(&[1, 3], 13).unwrap();
+
+    assert_eq!(database.capacities(), vec![2, 3]);
+    assert_eq!(database.read_value(&[2, 2]).unwrap(), 22);
+    assert_eq!(cql_db::read_value::<U64>(DATABASE_LOCATION, &[2, 3]).unwrap(), 23);
+    assert_eq!(cql_db::read_value::<U64>(DATABASE_LOCATION, &[1, 3]).unwrap(), 13);
+}
+
+#[test]
+#[serial]
+fn resize_last_dimension__returns_ResizeShrinkError__given_3d_u64_database_and_smaller_capacity() {
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[2, 2, 4]
+    ).unwrap();
+
+    let result = match cql_db::resize_last_dimension::<U64>(DATABASE_LOCATION, 3) {
+        Err(error::Error::Cql(cql_error)) => Some(cql_error),
+        _ => None,
+    };
+
+    assert_eq!(
+        result.unwrap(),
+        error::cql::Error::ResizeShrinkError {
+            current: 4,
+            requested: 3,
+        }
+    );
+
+    let database = cql_db::open::<U64>(DATABASE_LOCATION).unwrap();
+    assert_eq!(database.capacities(), vec![2, 2, 4]);
+}
+
+fn write_at(location: &str, position: u64, bytes: &[u8]) {
+    let mut file = fs::OpenOptions::new().write(true).open(location).unwrap();
+    file.seek(SeekFrom::Start(position)).unwrap();
+    file.write_all(bytes).unwrap();
+}
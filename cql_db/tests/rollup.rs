@@ -0,0 +1,118 @@
+#![allow(non_snake_case)]
+
+mod constants;
+
+use serial_test::serial;
+
+use constants::DATABASE_LOCATION;
+use cql_u64::U64;
+use cql_db::error;
+use cql_db::aggregation::{ Sum, Min, Mean, Count };
+use cql_db::storage::MemoryStorage;
+
+#[test]
+#[serial]
+fn _3d_u64_database_rolls_up_buckets_spanning_several_blocks() {
+    const N_VALUES: u64 = 20_000;
+    const BUCKET_SIZE: u64 = 3_000;
+
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[2, 2, N_VALUES]
+    ).unwrap();
+
+    cql_db::link_dimensions::<U64>(DATABASE_LOCATION, &[2, 1]).unwrap();
+
+    let values: Vec<u64> = (1..=N_VALUES).collect();
+    let stream: Vec<u8> = values.iter().flat_map(|value| value.to_le_bytes().to_vec()).collect();
+    cql_db::write_from_stream::<U64>(DATABASE_LOCATION, &mut stream.as_slice(), &[2, 1, 1], N_VALUES).unwrap();
+
+    let sums = cql_db::rollup::<U64, Sum>(DATABASE_LOCATION, &[2, 1, 1], N_VALUES, BUCKET_SIZE).unwrap();
+    let expected_sums: Vec<u128> = values.chunks(BUCKET_SIZE as usize).map(|bucket| bucket.iter().map(|&value| value as u128).sum()).collect();
+
+    let counts = cql_db::rollup_unchecked::<U64, Count>(DATABASE_LOCATION, &[2, 1, 1], N_VALUES, BUCKET_SIZE).unwrap();
+
+    assert_eq!(sums.len(), 7);
+    assert_eq!(sums, expected_sums);
+    assert_eq!(counts, vec![3_000, 3_000, 3_000, 3_000, 3_000, 3_000, 2_000]);
+}
+
+#[test]
+#[serial]
+fn _1d_u64_database_rolls_up_each_bucket_separately() {
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[10]
+    ).unwrap();
+
+    cql_db::write_values::<U64>(DATABASE_LOCATION, &[(&[2], 4), (&[3], 8), (&[5], 1), (&[6], 3), (&[7], 5), (&[9], 7)]).unwrap();
+
+    assert_eq!(cql_db::rollup::<U64, Mean>(DATABASE_LOCATION, &[2], 6, 2).unwrap(), vec![Some(6.0), Some(0.5), Some(4.0)]);
+    assert_eq!(cql_db::rollup::<U64, Min>(DATABASE_LOCATION, &[5], 5, 10).unwrap(), vec![Some(0)]);
+    assert_eq!(cql_db::rollup::<U64, Sum>(DATABASE_LOCATION, &[1], 10, 1).unwrap(), vec![0, 4, 8, 0, 1, 3, 5, 0, 7, 0]);
+}
+
+#[test]
+#[serial]
+fn _1d_u64_database_rolls_up_no_buckets_given_no_values() {
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[3]
+    ).unwrap();
+
+    assert!(cql_db::rollup::<U64, Sum>(DATABASE_LOCATION, &[2], 0, 2).unwrap().is_empty());
+}
+
+#[test]
+fn _2d_u64_memory_database_rolls_up_values() {
+    let storage = MemoryStorage::new();
+    cql_db::create_db_in::<U64, _>(&storage, &[2, 6]).unwrap();
+
+    let mut database = cql_db::open_in::<U64, _>(storage).unwrap();
+    database.write_values(&[(&[1, 1], 3), (&[1, 2], 1), (&[1, 6], 8)]).unwrap();
+
+    assert_eq!(database.rollup::<Sum>(&[1, 1], 6, 3).unwrap(), vec![4, 8]);
+    assert_eq!(database.rollup_unchecked::<Min>(&[1, 1], 6, 2).unwrap(), vec![Some(1), Some(0), Some(0)]);
+}
+
+#[test]
+#[serial]
+fn rollup__returns_BucketTooSmallError__given_1d_u64_database_and_empty_buckets() {
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[3]
+    ).unwrap();
+
+    let result = match cql_db::rollup::<U64, Sum>(DATABASE_LOCATION, &[1], 3, 0) {
+        Err(error::Error::Cql(cql_error)) => Some(cql_error),
+        _ => None,
+    };
+
+    assert_eq!(result.unwrap(), error::cql::Error::BucketTooSmallError);
+}
+
+#[test]
+#[serial]
+fn rollup__returns_IndexOutOfRangeError__given_3d_u64_database_and_too_many_values() {
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[2, 2, 4]
+    ).unwrap();
+
+    cql_db::link_dimensions::<U64>(DATABASE_LOCATION, &[1, 1]).unwrap();
+
+    let result = match cql_db::rollup::<U64, Count>(DATABASE_LOCATION, &[1, 1, 3], 4, 2) {
+        Err(error::Error::Cql(cql_error)) => Some(cql_error),
+        _ => None,
+    };
+
+    assert_eq!(
+        result.unwrap(),
+        error::cql::Error::IndexOutOfRangeError {
+            dimension_index: 2,
+            requested: 6,
+            min: 1,
+            max: 4,
+        }
+    );
+}
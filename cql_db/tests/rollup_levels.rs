@@ -0,0 +1,340 @@
+#![allow(non_snake_case)]
+
+mod constants;
+
+use serial_test::serial;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use constants::DATABASE_LOCATION;
+use cql_u64::U64;
+use cql_model::{ CqlType, CqlRangeReadable, CqlBufferWritable, CqlAggregatable };
+use cql_db::error;
+use cql_db::aggregation::{ Aggregation, Sum, Min, Max, Mean, Count };
+
+const COMPACTED_DATABASE_LOCATION: &str = "./.test_db/compacted";
+
+fn file_size(db_location: &str, file_name: &str) -> u64 {
+    fs::metadata(format!("{}/{}", db_location, file_name)).map(|metadata| metadata.len()).unwrap_or(0)
+}
+
+// Aggregates each bucket by itself, which never reads from the rollup levels
+fn expected_rollup<TAggregation: Aggregation<U64>>(db_location: &str, location: &[u64], n_values: u64, bucket_size: u64) -> Vec<TAggregation::Output> {
+    let last_index = location.len() - 1;
+    (0..n_values)
+        .step_by(bucket_size as usize)
+        .map(|offset| {
+            let mut bucket_location = location.to_vec();
+            bucket_location[last_index] += offset;
+            cql_db::aggregate::<U64, TAggregation>(db_location, &bucket_location, bucket_size.min(n_values - offset)).unwrap()
+        })
+        .collect()
+}
+
+fn assert_rollups_match(db_location: &str, location: &[u64], n_values: u64, bucket_size: u64) {
+    assert_eq!(
+        cql_db::rollup::<U64, Sum>(db_location, location, n_values, bucket_size).unwrap(),
+        expected_rollup::<Sum>(db_location, location, n_values, bucket_size)
+    );
+    assert_eq!(
+        cql_db::rollup::<U64, Min>(db_location, location, n_values, bucket_size).unwrap(),
+        expected_rollup::<Min>(db_location, location, n_values, bucket_size)
+    );
+    assert_eq!(
+        cql_db::rollup::<U64, Max>(db_location, location, n_values, bucket_size).unwrap(),
+        expected_rollup::<Max>(db_location, location, n_values, bucket_size)
+    );
+    assert_eq!(
+        cql_db::rollup_unchecked::<U64, Mean>(db_location, location, n_values, bucket_size).unwrap(),
+        expected_rollup::<Mean>(db_location, location, n_values, bucket_size)
+    );
+    assert_eq!(
+        cql_db::rollup_unchecked::<U64, Count>(db_location, location, n_values, bucket_size).unwrap(),
+        expected_rollup::<Count>(db_location, location, n_values, bucket_size)
+    );
+}
+
+// A checksum and count, a u128 sum, and the values holding the smallest and largest numbers
+fn u64_summary_size() -> u64 {
+    8 + 8 + 16 + 2 * 8
+}
+
+fn stream_of(values: &[u64]) -> Vec<u8> {
+    values.iter().flat_map(|value| value.to_le_bytes().to_vec()).collect()
+}
+
+#[test]
+#[serial]
+fn _3d_u64_database_rolls_up_from_levels_kept_up_to_date_by_writes() {
+    cql_db::create_db_with_rollups_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[2, 2, 100],
+        &[10, 5, 50]
+    ).unwrap();
+
+    cql_db::link_dimensions::<U64>(DATABASE_LOCATION, &[2, 1]).unwrap();
+    let values: Vec<u64> = (1..=100).collect();
+    cql_db::write_from_stream::<U64>(DATABASE_LOCATION, &mut stream_of(&values).as_slice(), &[2, 1, 1], 100).unwrap();
+    assert!(file_size(DATABASE_LOCATION, "rl50") > 0);
+
+    assert_rollups_match(DATABASE_LOCATION, &[2, 1, 1], 100, 50);
+    assert_rollups_match(DATABASE_LOCATION, &[2, 1, 11], 90, 10);
+
+    cql_db::write_value::<U64>(DATABASE_LOCATION, &[2, 1, 12], 1_000).unwrap();
+    cql_db::write_values::<U64>(DATABASE_LOCATION, &[(&[2, 1, 55], 0), (&[2, 1, 100], 7)]).unwrap();
+    cql_db::write_from_stream::<U64>(DATABASE_LOCATION, &mut stream_of(&[3, 2, 1]).as_slice(), &[2, 1, 29], 3).unwrap();
+
+    assert_rollups_match(DATABASE_LOCATION, &[2, 1, 1], 100, 50);
+    assert_rollups_match(DATABASE_LOCATION, &[2, 1, 1], 100, 20);
+    assert_rollups_match(DATABASE_LOCATION, &[2, 1, 6], 95, 5);
+}
+
+#[test]
+#[serial]
+fn _2d_u64_database_rolls_up_from_levels_not_dividing_the_row() {
+    cql_db::create_db_with_rollups_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[2, 23],
+        &[4, 6]
+    ).unwrap();
+
+    let values: Vec<u64> = (1..=23).map(|value| value * 3 % 17).collect();
+    cql_db::write_from_stream::<U64>(DATABASE_LOCATION, &mut stream_of(&values).as_slice(), &[2, 1], 23).unwrap();
+
+    assert_rollups_match(DATABASE_LOCATION, &[2, 1], 23, 12);
+    assert_rollups_match(DATABASE_LOCATION, &[2, 5], 19, 8);
+    assert_rollups_match(DATABASE_LOCATION, &[2, 7], 17, 6);
+
+    cql_db::write_value::<U64>(DATABASE_LOCATION, &[2, 23], 40).unwrap();
+    cql_db::write_value::<U64>(DATABASE_LOCATION, &[1, 3], 40).unwrap();
+
+    assert_rollups_match(DATABASE_LOCATION, &[2, 1], 23, 12);
+    assert_rollups_match(DATABASE_LOCATION, &[1, 1], 23, 4);
+}
+
+#[test]
+#[serial]
+fn _3d_u64_database_rolls_up_from_levels_after_transactions_and_unlinks() {
+    cql_db::create_db_with_rollups_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[2, 2, 12],
+        &[3, 6]
+    ).unwrap();
+
+    let mut database = cql_db::open::<U64>(DATABASE_LOCATION).unwrap();
+    let mut transaction = database.transaction();
+    transaction.link_dimensions(&[1, 2]).unwrap();
+    transaction.write_value(&[1, 2, 2], 9).unwrap();
+    transaction.write_value(&[1, 2, 12], 4).unwrap();
+    transaction.commit().unwrap();
+
+    assert_eq!(database.rollup::<Sum>(&[1, 2, 1], 12, 6).unwrap(), vec![9, 4]);
+
+    let mut transaction = database.transaction();
+    transaction.write_value(&[1, 2, 11], 5).unwrap();
+    transaction.commit().unwrap();
+
+    assert_eq!(database.rollup::<Sum>(&[1, 2, 1], 12, 6).unwrap(), vec![9, 9]);
+
+    database.unlink_dimensions(&[1, 2]).unwrap();
+    database.link_dimensions(&[2, 1]).unwrap();
+    database.write_value(&[2, 1, 1], 1).unwrap();
+
+    // the released row is reused by the new link
+    assert_eq!(database.rollup::<Sum>(&[2, 1, 1], 12, 6).unwrap(), vec![1, 0]);
+    assert_eq!(database.rollup::<Max>(&[2, 1, 1], 12, 12).unwrap(), vec![Some(1)]);
+}
+
+#[test]
+#[serial]
+fn _2d_u64_database_rolls_up_from_levels_after_resizing_and_compacting() {
+    let _ = fs::remove_dir_all(COMPACTED_DATABASE_LOCATION);
+    fs::create_dir_all(COMPACTED_DATABASE_LOCATION).unwrap();
+
+    cql_db::create_db_with_rollups_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[2, 4],
+        &[2]
+    ).unwrap();
+
+    cql_db::write_from_stream::<U64>(DATABASE_LOCATION, &mut stream_of(&[1, 2, 3, 4]).as_slice(), &[1, 1], 4).unwrap();
+    cql_db::write_from_stream::<U64>(DATABASE_LOCATION, &mut stream_of(&[5, 6, 7, 8]).as_slice(), &[2, 1], 4).unwrap();
+    assert_eq!(cql_db::rollup::<U64, Sum>(DATABASE_LOCATION, &[2, 1], 4, 2).unwrap(), vec![11, 15]);
+
+    cql_db::resize_last_dimension::<U64>(DATABASE_LOCATION, 6).unwrap();
+    assert_eq!(file_size(DATABASE_LOCATION, "rl2"), 6 * u64_summary_size());
+    cql_db::write_value::<U64>(DATABASE_LOCATION, &[1, 6], 10).unwrap();
+
+    assert_eq!(cql_db::rollup::<U64, Sum>(DATABASE_LOCATION, &[1, 1], 6, 2).unwrap(), vec![3, 7, 10]);
+    assert_eq!(cql_db::rollup::<U64, Sum>(DATABASE_LOCATION, &[2, 1], 6, 2).unwrap(), vec![11, 15, 0]);
+
+    cql_db::compact::<U64>(DATABASE_LOCATION, COMPACTED_DATABASE_LOCATION).unwrap();
+
+    assert_eq!(cql_db::open::<U64>(COMPACTED_DATABASE_LOCATION).unwrap().rollup_levels(), vec![2]);
+    assert_eq!(file_size(COMPACTED_DATABASE_LOCATION, "rl2"), 6 * u64_summary_size());
+    assert_eq!(cql_db::rollup::<U64, Sum>(COMPACTED_DATABASE_LOCATION, &[1, 1], 6, 2).unwrap(), vec![3, 7, 10]);
+    assert_eq!(cql_db::rollup::<U64, Sum>(COMPACTED_DATABASE_LOCATION, &[2, 1], 6, 6).unwrap(), vec![26]);
+}
+
+#[test]
+#[serial]
+fn _2d_u64_database_rolls_up_from_levels_without_writing_to_them() {
+    cql_db::create_db_with_rollups_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[2, 12],
+        &[3, 6]
+    ).unwrap();
+
+    let values: Vec<u64> = (1..=12).collect();
+    cql_db::write_from_stream::<U64>(DATABASE_LOCATION, &mut stream_of(&values).as_slice(), &[1, 1], 12).unwrap();
+    let levels = (fs::read(format!("{}/rl3", DATABASE_LOCATION)).unwrap(), fs::read(format!("{}/rl6", DATABASE_LOCATION)).unwrap());
+
+    assert_rollups_match(DATABASE_LOCATION, &[1, 1], 12, 6);
+    assert_rollups_match(DATABASE_LOCATION, &[2, 1], 12, 3);
+
+    assert_eq!((fs::read(format!("{}/rl3", DATABASE_LOCATION)).unwrap(), fs::read(format!("{}/rl6", DATABASE_LOCATION)).unwrap()), levels);
+}
+
+#[test]
+#[serial]
+fn _2d_u64_database_rolls_up_from_levels_given_summaries_failing_their_checksums() {
+    cql_db::create_db_with_rollups_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[2, 8],
+        &[2, 4]
+    ).unwrap();
+
+    let values: Vec<u64> = (1..=8).map(|value| value * 5).collect();
+    cql_db::write_from_stream::<U64>(DATABASE_LOCATION, &mut stream_of(&values).as_slice(), &[1, 1], 8).unwrap();
+
+    // as if the write of the second summary of each level had been torn part way through
+    for file_name in ["rl2", "rl4"].iter() {
+        let file_location = format!("{}/{}", DATABASE_LOCATION, file_name);
+        let mut level = fs::read(&file_location).unwrap();
+        level[u64_summary_size() as usize + 9] ^= 0xff;
+        fs::write(&file_location, &level).unwrap();
+    }
+
+    assert_rollups_match(DATABASE_LOCATION, &[1, 1], 8, 4);
+    assert_rollups_match(DATABASE_LOCATION, &[1, 1], 8, 2);
+    assert_eq!(cql_db::rollup::<U64, Sum>(DATABASE_LOCATION, &[1, 1], 8, 4).unwrap(), vec![50, 130]);
+}
+
+#[test]
+#[serial]
+fn _2d_u64_database_records_its_rollup_levels() {
+    cql_db::create_db_with_rollups_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[2, 60],
+        &[60, 10, 10]
+    ).unwrap();
+
+    assert_eq!(cql_db::open::<U64>(DATABASE_LOCATION).unwrap().rollup_levels(), vec![10, 60]);
+
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[2, 60]
+    ).unwrap();
+
+    assert!(cql_db::open::<U64>(DATABASE_LOCATION).unwrap().rollup_levels().is_empty());
+}
+
+#[test]
+#[serial]
+fn create_db_with_rollups__returns_BucketTooSmallError__given_empty_level() {
+    let result = match cql_db::create_db_with_rollups::<U64>(DATABASE_LOCATION, &[2, 60], &[10, 0]) {
+        Err(error::Error::Cql(cql_error)) => Some(cql_error),
+        _ => None,
+    };
+
+    assert_eq!(result.unwrap(), error::cql::Error::BucketTooSmallError);
+}
+
+#[test]
+#[serial]
+fn create_db_with_rollups__returns_RollupTypeError_and_creates_nothing__given_type_not_exposing_its_aggregatable_implementation() {
+    let _ = fs::remove_file(format!("{}/db", DATABASE_LOCATION));
+
+    let result = match cql_db::create_db_with_rollups::<Unexposed>(DATABASE_LOCATION, &[2, 60], &[10]) {
+        Err(error::Error::Cql(cql_error)) => Some(cql_error),
+        _ => None,
+    };
+
+    assert_eq!(result.unwrap(), error::cql::Error::RollupTypeError { requested: Unexposed::TYPE_NAME.to_string() });
+    assert!(!Path::new(&format!("{}/db", DATABASE_LOCATION)).exists());
+}
+
+#[test]
+#[serial]
+fn open__returns_RollupTypeError__given_database_with_rollup_levels_and_type_not_exposing_its_aggregatable_implementation() {
+    cql_db::create_db_with_rollups_unchecked::<U64>(DATABASE_LOCATION, &[2, 60], &[10]).unwrap();
+
+    let result = match cql_db::open::<Unexposed>(DATABASE_LOCATION) {
+        Err(error::Error::Cql(cql_error)) => Some(cql_error),
+        _ => None,
+    };
+
+    assert_eq!(result.unwrap(), error::cql::Error::RollupTypeError { requested: Unexposed::TYPE_NAME.to_string() });
+}
+
+#[test]
+#[serial]
+fn write_value_unchecked__fails_and_writes_nothing__given_database_with_rollup_levels_and_type_not_exposing_its_aggregatable_implementation() {
+    cql_db::create_db_with_rollups_unchecked::<U64>(DATABASE_LOCATION, &[2, 60], &[10]).unwrap();
+
+    let result = cql_db::write_value_unchecked::<Unexposed>(DATABASE_LOCATION, &[1, 5], 3);
+
+    assert_eq!(result.unwrap_err().kind(), io::ErrorKind::InvalidInput);
+    assert_eq!(cql_db::read_value::<U64>(DATABASE_LOCATION, &[1, 5]).unwrap(), 0);
+    assert_rollups_match(DATABASE_LOCATION, &[1, 1], 60, 10);
+}
+
+// Stores u64 values as U64 does, but does not override CqlType::visit_aggregatable, so cql_db cannot summarise them as they are written
+struct Unexposed;
+
+impl CqlType for Unexposed {
+    type ValueType = u64;
+    const VALUE_SIZE: usize = 8;
+    const TYPE_NAME: &'static str = "rollup_levels::Unexposed";
+}
+
+impl CqlRangeReadable for Unexposed {
+    fn read_from_buffer(buffer: &[u8]) -> io::Result<u64> {
+        let mut bytes = [0; 8];
+        bytes.copy_from_slice(buffer);
+        Ok(u64::from_le_bytes(bytes))
+    }
+}
+
+impl CqlBufferWritable for Unexposed {
+    fn write_to_buffer(input_value: u64, buffer: &mut [u8]) -> io::Result<()> {
+        buffer.copy_from_slice(&input_value.to_le_bytes());
+        Ok(())
+    }
+}
+
+impl CqlAggregatable for Unexposed {
+    type Number = u64;
+    type Sum = u128;
+    const SUM_SIZE: usize = 16;
+
+    fn to_number(value: u64) -> Option<u64> {
+        Some(value)
+    }
+
+    fn sum_to_f64(sum: u128) -> f64 {
+        sum as f64
+    }
+
+    fn write_sum_to_buffer(sum: u128, buffer: &mut [u8]) -> io::Result<()> {
+        buffer.copy_from_slice(&sum.to_le_bytes());
+        Ok(())
+    }
+
+    fn read_sum_from_buffer(buffer: &[u8]) -> io::Result<u128> {
+        let mut bytes = [0; 16];
+        bytes.copy_from_slice(buffer);
+        Ok(u128::from_le_bytes(bytes))
+    }
+}
@@ -0,0 +1,169 @@
+#![allow(non_snake_case)]
+
+mod constants;
+
+use serial_test::serial;
+
+use constants::DATABASE_LOCATION;
+use cql_u64::U64;
+use cql_db::error;
+
+#[test]
+#[serial]
+fn _4d_u64_database_transaction_allows_for_points_to_be_linked_and_written() {
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[2, 3, 2, 3]
+    ).unwrap();
+
+    let mut database = cql_db::open::<U64>(DATABASE_LOCATION).unwrap();
+
+    let mut transaction = database.transaction();
+    for i in 1..3 {
+        for j in 1..4 {
+            for k in 1..3 {
+                transaction.link_dimensions(&[i, j, k]).unwrap();
+                for l in 1..4 {
+                    transaction.write_value(&[i, j, k, l], i * 1000 + j * 100 + k * 10 + l).unwrap();
+                }
+            }
+        }
+    }
+    transaction.commit().unwrap();
+
+    for i in 1..3 {
+        for j in 1..4 {
+            for k in 1..3 {
+                for l in 1..4 {
+                    assert_eq!(database.read_value(&[i, j, k, l]).unwrap(), i * 1000 + j * 100 + k * 10 + l);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+#[serial]
+fn _3d_u64_database_transaction_reuses_released_rows() {
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[2, 2, 2]
+    ).unwrap();
+
+    let mut database = cql_db::open::<U64>(DATABASE_LOCATION).unwrap();
+    for (x, y) in [(1, 1), (1, 2), (2, 1)].iter() {
+        database.link_dimensions(&[*x, *y]).unwrap();
+    }
+    database.write_value(&[2, 1, 1], 9).unwrap();
+    database.unlink_dimensions(&[1, 1]).unwrap();
+    database.unlink_dimensions(&[1, 2]).unwrap();
+
+    let mut transaction = database.transaction();
+    transaction.link_dimensions(&[2, 2]).unwrap();
+    transaction.link_dimensions(&[1, 1]).unwrap();
+    transaction.write_value(&[2, 2, 1], 3).unwrap();
+    transaction.write_value(&[1, 1, 2], 4).unwrap();
+    transaction.commit().unwrap();
+
+    assert_eq!(database.read_value(&[2, 2, 1]).unwrap(), 3);
+    assert_eq!(database.read_value(&[1, 1, 2]).unwrap(), 4);
+    assert_eq!(database.read_value(&[2, 1, 1]).unwrap(), 9);
+    assert_eq!(std::fs::metadata(format!("{}{}", DATABASE_LOCATION, "/db")).unwrap().len(), 3 * 2 * 8);
+}
+
+#[test]
+#[serial]
+fn _3d_u64_database_transaction_dropped_without_commit_changes_nothing() {
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[2, 2, 2]
+    ).unwrap();
+
+    let mut database = cql_db::open::<U64>(DATABASE_LOCATION).unwrap();
+    database.link_dimensions(&[1, 1]).unwrap();
+
+    {
+        let mut transaction = database.transaction();
+        transaction.link_dimensions(&[2, 2]).unwrap();
+        transaction.write_value(&[2, 2, 1], 3).unwrap();
+        transaction.write_value(&[1, 1, 1], 4).unwrap();
+    }
+
+    assert_eq!(database.read_value(&[1, 1, 1]).unwrap(), 0);
+
+    let result = match database.read_value(&[2, 2, 1]) {
+        Err(error::Error::Cql(cql_error)) => Some(cql_error),
+        _ => None,
+    };
+
+    assert_eq!(
+        result.unwrap(),
+        error::cql::Error::ElementsNotLinkedError {
+            x_dimension: 0,
+            x: 2,
+            y_dimension: 1,
+            y: 2,
+        }
+    );
+}
+
+#[test]
+#[serial]
+fn commit__returns_ElementsNotLinkedError_and_changes_nothing__given_3d_u64_database_and_unlinked_location() {
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[2, 2, 2]
+    ).unwrap();
+
+    let mut database = cql_db::open::<U64>(DATABASE_LOCATION).unwrap();
+    database.link_dimensions(&[1, 1]).unwrap();
+
+    let mut transaction = database.transaction();
+    transaction.link_dimensions(&[2, 2]).unwrap();
+    transaction.write_value(&[1, 1, 1], 4).unwrap();
+    transaction.write_value(&[2, 1, 1], 3).unwrap();
+
+    let result = match transaction.commit() {
+        Err(error::Error::Cql(cql_error)) => Some(cql_error),
+        _ => None,
+    };
+
+    assert_eq!(
+        result.unwrap(),
+        error::cql::Error::ElementsNotLinkedError {
+            x_dimension: 0,
+            x: 2,
+            y_dimension: 1,
+            y: 1,
+        }
+    );
+
+    assert_eq!(database.read_value(&[1, 1, 1]).unwrap(), 0);
+    assert!(database.read_value(&[2, 2, 1]).is_err());
+}
+
+#[test]
+#[serial]
+fn write_value__returns_DimensionsOutOfRangeError__given_transaction_on_3d_u64_database_and_2d_location() {
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[2, 2, 2]
+    ).unwrap();
+
+    let mut database = cql_db::open::<U64>(DATABASE_LOCATION).unwrap();
+    let mut transaction = database.transaction();
+
+    let result = match transaction.write_value(&[1, 1], 2) {
+        Err(error::Error::Cql(cql_error)) => Some(cql_error),
+        _ => None,
+    };
+
+    assert_eq!(
+        result.unwrap(),
+        error::cql::Error::DimensionsOutOfRangeError {
+            requested: 2,
+            min: 3,
+            max: 3,
+        }
+    );
+}
@@ -0,0 +1,99 @@
+#![allow(non_snake_case)]
+
+mod constants;
+
+use serial_test::serial;
+use std::fs::remove_file;
+
+use constants::DATABASE_LOCATION;
+use cql_model::CqlType;
+use cql_u64::U64;
+use cql_db::error;
+
+const TYPE_FILE_NAME: &str = "/ty";
+
+#[test]
+#[serial]
+fn open__returns_TypeMismatchError__given_u64_database_and_different_type() {
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[2, 2]
+    ).unwrap();
+
+    let result = match cql_db::open::<DummyType>(DATABASE_LOCATION) {
+        Err(error::Error::Cql(cql_error)) => Some(cql_error),
+        _ => None,
+    };
+
+    assert_eq!(
+        result.unwrap(),
+        error::cql::Error::TypeMismatchError {
+            requested: DummyType::TYPE_NAME.to_string(),
+            requested_value_size: DummyType::VALUE_SIZE,
+            stored: U64::TYPE_NAME.to_string(),
+            stored_value_size: U64::VALUE_SIZE,
+        }
+    );
+}
+
+#[test]
+#[serial]
+fn link_dimensions__returns_TypeMismatchError__given_u64_database_and_different_type() {
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[2, 2, 2]
+    ).unwrap();
+
+    let result = match cql_db::link_dimensions::<DummyType>(DATABASE_LOCATION, &[1, 1]) {
+        Err(error::Error::Cql(cql_error)) => Some(cql_error),
+        _ => None,
+    };
+
+    assert_eq!(
+        result.unwrap(),
+        error::cql::Error::TypeMismatchError {
+            requested: DummyType::TYPE_NAME.to_string(),
+            requested_value_size: DummyType::VALUE_SIZE,
+            stored: U64::TYPE_NAME.to_string(),
+            stored_value_size: U64::VALUE_SIZE,
+        }
+    );
+}
+
+#[test]
+#[serial]
+fn link_dimensions_unchecked__does_not_check_type__given_u64_database_and_different_type() {
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[2, 2, 2]
+    ).unwrap();
+
+    cql_db::link_dimensions_unchecked::<DummyType>(DATABASE_LOCATION, &[1, 2]).unwrap();
+
+    cql_db::write_value::<U64>(DATABASE_LOCATION, &[1, 2, 1], 5).unwrap();
+    let result = cql_db::read_value::<U64>(DATABASE_LOCATION, &[1, 2, 1]).unwrap();
+
+    assert_eq!(result, 5);
+}
+
+#[test]
+#[serial]
+fn open__does_not_check_type__given_database_without_recorded_type() {
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[2, 2]
+    ).unwrap();
+
+    remove_file(format!("{}{}", DATABASE_LOCATION, TYPE_FILE_NAME)).unwrap();
+
+    let database = cql_db::open::<DummyType>(DATABASE_LOCATION).unwrap();
+
+    assert_eq!(database.capacities(), vec![2, 2]);
+}
+
+struct DummyType;
+impl CqlType for DummyType {
+    type ValueType = u64;
+    const VALUE_SIZE: usize = 8;
+    const TYPE_NAME: &'static str = "type_mismatch::DummyType";
+}
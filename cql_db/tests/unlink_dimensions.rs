@@ -0,0 +1,146 @@
+#![allow(non_snake_case)]
+
+mod constants;
+
+use serial_test::serial;
+use std::fs;
+
+use constants::DATABASE_LOCATION;
+use cql_u64::U64;
+use cql_db::error;
+
+fn db_file_size() -> u64 {
+    fs::metadata(format!("{}/db", DATABASE_LOCATION)).unwrap().len()
+}
+
+#[test]
+#[serial]
+fn _3d_u64_database_reuses_unlinked_row() {
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[2, 2, 3]
+    ).unwrap();
+
+    let mut database = cql_db::open::<U64>(DATABASE_LOCATION).unwrap();
+    database.link_dimensions(&[1, 1]).unwrap();
+    database.link_dimensions(&[1, 2]).unwrap();
+    database.write_value(&[1, 1, 3], 11).unwrap();
+    database.write_value(&[1, 2, 3], 12).unwrap();
+
+    let size_before_unlink = db_file_size();
+
+    database.unlink_dimensions(&[1, 1]).unwrap();
+    database.link_dimensions(&[2, 2]).unwrap();
+
+    assert_eq!(db_file_size(), size_before_unlink);
+    assert_eq!(database.read_value(&[2, 2, 3]).unwrap(), 0);
+    assert_eq!(database.read_value(&[1, 2, 3]).unwrap(), 12);
+
+    let result = match database.read_value(&[1, 1, 3]) {
+        Err(error::Error::Cql(cql_error)) => Some(cql_error),
+        _ => None,
+    };
+
+    assert_eq!(
+        result.unwrap(),
+        error::cql::Error::ElementsNotLinkedError {
+            x_dimension: 0,
+            x: 1,
+            y_dimension: 1,
+            y: 1,
+        }
+    );
+}
+
+#[test]
+#[serial]
+fn _4d_u64_database_unlinking_releases_everything_beneath_the_location() {
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[2, 3, 3, 2]
+    ).unwrap();
+
+    for k in 1..4 {
+        cql_db::link_dimensions::<U64>(DATABASE_LOCATION, &[2, 3, k]).unwrap();
+        cql_db::write_value::<U64>(DATABASE_LOCATION, &[2, 3, k, 2], k).unwrap();
+    }
+    cql_db::link_dimensions::<U64>(DATABASE_LOCATION, &[1, 1, 1]).unwrap();
+    cql_db::write_value::<U64>(DATABASE_LOCATION, &[1, 1, 1, 2], 42).unwrap();
+
+    let size_before_unlink = db_file_size();
+
+    cql_db::unlink_dimensions::<U64>(DATABASE_LOCATION, &[2, 3]).unwrap();
+
+    let result = match cql_db::read_value::<U64>(DATABASE_LOCATION, &[2, 3, 2, 2]) {
+        Err(error::Error::Cql(cql_error)) => Some(cql_error),
+        _ => None,
+    };
+
+    assert_eq!(
+        result.unwrap(),
+        error::cql::Error::ElementsNotLinkedError {
+            x_dimension: 0,
+            x: 2,
+            y_dimension: 1,
+            y: 3,
+        }
+    );
+
+    // relinking the same chain must not resurrect any of the released links or values
+    cql_db::link_dimensions::<U64>(DATABASE_LOCATION, &[2, 3, 1]).unwrap();
+    assert_eq!(cql_db::read_value::<U64>(DATABASE_LOCATION, &[2, 3, 1, 2]).unwrap(), 0);
+    assert!(cql_db::read_value::<U64>(DATABASE_LOCATION, &[2, 3, 2, 2]).is_err());
+
+    for j in 1..4 {
+        cql_db::link_dimensions::<U64>(DATABASE_LOCATION, &[1, j, 3]).unwrap();
+    }
+
+    assert_eq!(db_file_size(), size_before_unlink + 2 * 8);
+    assert_eq!(cql_db::read_value::<U64>(DATABASE_LOCATION, &[1, 1, 1, 2]).unwrap(), 42);
+    for j in 1..4 {
+        assert_eq!(cql_db::read_value::<U64>(DATABASE_LOCATION, &[1, j, 3, 2]).unwrap(), 0);
+    }
+}
+
+#[test]
+#[serial]
+fn _3d_u64_database_unlink_dimensions_unchecked_does_nothing_given_unlinked_location() {
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[2, 2, 3]
+    ).unwrap();
+
+    cql_db::link_dimensions::<U64>(DATABASE_LOCATION, &[1, 1]).unwrap();
+    cql_db::unlink_dimensions_unchecked::<U64>(DATABASE_LOCATION, &[2, 1]).unwrap();
+    cql_db::link_dimensions::<U64>(DATABASE_LOCATION, &[2, 1]).unwrap();
+
+    assert_eq!(db_file_size(), 2 * 3 * 8);
+}
+
+#[test]
+#[serial]
+fn unlink_dimensions__returns_DimensionsOutOfRangeError__given_3d_u64_database_and_full_location() {
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[2, 2, 3]
+    ).unwrap();
+
+    cql_db::link_dimensions::<U64>(DATABASE_LOCATION, &[1, 1]).unwrap();
+
+    let result = match cql_db::unlink_dimensions::<U64>(
+        DATABASE_LOCATION,
+        &[1, 1, 1]
+    ) {
+        Err(error::Error::Cql(cql_error)) => Some(cql_error),
+        _ => None,
+    };
+
+    assert_eq!(
+        result.unwrap(),
+        error::cql::Error::DimensionsOutOfRangeError {
+            requested: 3,
+            min: 2,
+            max: 2,
+        }
+    );
+}
@@ -0,0 +1,112 @@
+#![allow(non_snake_case)]
+
+mod constants;
+
+use serial_test::serial;
+
+use constants::DATABASE_LOCATION;
+use cql_u64::U64;
+use cql_db::error;
+
+#[test]
+#[serial]
+fn _4d_u64_database_walks_every_linked_row() {
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[2, 5, 3, 2]
+    ).unwrap();
+
+    cql_db::link_dimensions::<U64>(DATABASE_LOCATION, &[2, 5, 3]).unwrap();
+    cql_db::link_dimensions::<U64>(DATABASE_LOCATION, &[2, 2, 3]).unwrap();
+    cql_db::link_dimensions::<U64>(DATABASE_LOCATION, &[2, 2, 1]).unwrap();
+    cql_db::link_dimensions::<U64>(DATABASE_LOCATION, &[1, 4]).unwrap();
+
+    let rows: Vec<Vec<u64>> = cql_db::walk_links::<U64>(DATABASE_LOCATION, &[]).unwrap().collect();
+    let second: Vec<Vec<u64>> = cql_db::walk_links::<U64>(DATABASE_LOCATION, &[2]).unwrap().collect();
+    let second_second: Vec<Vec<u64>> = cql_db::walk_links_unchecked::<U64>(DATABASE_LOCATION, &[2, 2]).unwrap().collect();
+    let row: Vec<Vec<u64>> = cql_db::walk_links::<U64>(DATABASE_LOCATION, &[2, 5, 3]).unwrap().collect();
+
+    assert_eq!(rows, vec![vec![2, 2, 1], vec![2, 2, 3], vec![2, 5, 3]]);
+    assert_eq!(second, rows);
+    assert_eq!(second_second, vec![vec![2, 2, 1], vec![2, 2, 3]]);
+    assert_eq!(row, vec![vec![2, 5, 3]]);
+}
+
+#[test]
+#[serial]
+fn _2d_u64_database_walks_every_row() {
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[3, 2]
+    ).unwrap();
+
+    let rows: Vec<Vec<u64>> = cql_db::walk_links::<U64>(DATABASE_LOCATION, &[]).unwrap().collect();
+
+    assert_eq!(rows, vec![vec![1], vec![2], vec![3]]);
+}
+
+#[test]
+#[serial]
+fn _1d_u64_database_walks_its_only_row() {
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[3]
+    ).unwrap();
+
+    let rows: Vec<Vec<u64>> = cql_db::walk_links::<U64>(DATABASE_LOCATION, &[]).unwrap().collect();
+
+    assert_eq!(rows, vec![Vec::<u64>::new()]);
+}
+
+#[test]
+#[serial]
+fn walk_links_unchecked__returns_nothing__given_3d_u64_database_and_unlinked_location() {
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[2, 2, 2]
+    ).unwrap();
+
+    assert_eq!(cql_db::walk_links_unchecked::<U64>(DATABASE_LOCATION, &[1, 2]).unwrap().count(), 0);
+}
+
+#[test]
+#[serial]
+fn walk_links__returns_ElementsNotLinkedError__given_4d_u64_database_and_unlinked_location() {
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[2, 2, 2, 2]
+    ).unwrap();
+
+    cql_db::link_dimensions::<U64>(DATABASE_LOCATION, &[1, 2]).unwrap();
+
+    let result = match cql_db::walk_links::<U64>(DATABASE_LOCATION, &[1, 2, 1]) {
+        Err(error::Error::Cql(cql_error)) => Some(cql_error),
+        _ => None,
+    };
+
+    assert_eq!(
+        result.unwrap(),
+        error::cql::Error::ElementsNotLinkedError {
+            x_dimension: 1,
+            x: 2,
+            y_dimension: 2,
+            y: 1,
+        }
+    );
+}
+
+#[test]
+#[serial]
+fn walk_links__returns_DimensionsOutOfRangeError__given_3d_u64_database_and_full_location() {
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[2, 2, 2]
+    ).unwrap();
+
+    let result = match cql_db::walk_links::<U64>(DATABASE_LOCATION, &[1, 1, 1]) {
+        Err(error::Error::Cql(cql_error)) => Some(cql_error),
+        _ => None,
+    };
+
+    assert_eq!(result.unwrap(), error::cql::Error::DimensionsOutOfRangeError { requested: 3, min: 0, max: 2 });
+}
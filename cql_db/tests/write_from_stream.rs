@@ -0,0 +1,130 @@
+#![allow(non_snake_case)]
+
+mod constants;
+
+use serial_test::serial;
+use std::io::{ self, Cursor };
+
+use constants::DATABASE_LOCATION;
+use cql_u64::{ U64, unpack_stream };
+use cql_db::error;
+
+fn pack_stream(values: &[u64]) -> Cursor<Vec<u8>> {
+    let mut buffer = Vec::with_capacity(values.len() * 8);
+    for &value in values {
+        buffer.extend_from_slice(&value.to_le_bytes());
+    }
+    Cursor::new(buffer)
+}
+
+#[test]
+#[serial]
+fn _1d_u64_database_allows_for_a_full_stream_write() {
+    let values = [1, 2, 3, 4, 5];
+
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[5]
+    ).unwrap();
+
+    cql_db::write_from_stream::<U64>(
+        DATABASE_LOCATION,
+        &mut pack_stream(&values),
+        &[1],
+        values.len() as u64
+    ).unwrap();
+
+    let mut result = [0; 5];
+    let mut stream = Cursor::new(Vec::new());
+    cql_db::read_to_stream::<U64>(
+        DATABASE_LOCATION,
+        &mut stream,
+        &[1],
+        values.len() as u64
+    ).unwrap();
+
+    stream.set_position(0);
+    unpack_stream(&mut stream, values.len(), |idx, value| {
+        result[idx] = value
+    }).unwrap();
+
+    assert_eq!(result, values);
+}
+
+#[test]
+#[serial]
+fn _3d_u64_database_stream_write_only_writes_to_given_range() {
+    let base_point = [2, 1, 3];
+
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[2, 2, 6]
+    ).unwrap();
+
+    let mut database = cql_db::open::<U64>(DATABASE_LOCATION).unwrap();
+    database.link_dimensions(&[1, 1]).unwrap();
+    database.link_dimensions(&base_point[0..2]).unwrap();
+    database.write_value(&[2, 1, 2], 7).unwrap();
+    database.write_value(&[2, 1, 5], 8).unwrap();
+    database.write_value(&[1, 1, 3], 9).unwrap();
+
+    database.write_from_stream(&mut pack_stream(&[10, 20]), &base_point, 2).unwrap();
+
+    for (index, expected) in [0, 7, 10, 20, 8, 0].iter().enumerate() {
+        assert_eq!(database.read_value(&[2, 1, index as u64 + 1]).unwrap(), *expected);
+    }
+    assert_eq!(database.read_value(&[1, 1, 3]).unwrap(), 9);
+}
+
+#[test]
+#[serial]
+fn write_from_stream__returns_UnexpectedEof__given_stream_with_fewer_than_n_values() {
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[5]
+    ).unwrap();
+
+    let result = match cql_db::write_from_stream::<U64>(
+        DATABASE_LOCATION,
+        &mut pack_stream(&[1, 2]),
+        &[1],
+        3
+    ) {
+        Err(error::Error::Io(io_error)) => Some(io_error),
+        _ => None,
+    };
+
+    assert_eq!(result.unwrap().kind(), io::ErrorKind::UnexpectedEof);
+}
+
+#[test]
+#[serial]
+fn write_from_stream__returns_IndexOutOfRangeError_and_does_not_read_stream__given_too_many_values() {
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[2, 4]
+    ).unwrap();
+
+    let mut stream = pack_stream(&[1, 2, 3]);
+
+    let result = match cql_db::write_from_stream::<U64>(
+        DATABASE_LOCATION,
+        &mut stream,
+        &[1, 2],
+        4
+    ) {
+        Err(error::Error::Cql(cql_error)) => Some(cql_error),
+        _ => None,
+    };
+
+    assert_eq!(
+        result.unwrap(),
+        error::cql::Error::IndexOutOfRangeError {
+            dimension_index: 1,
+            requested: 5,
+            min: 1,
+            max: 4,
+        }
+    );
+    assert_eq!(stream.position(), 0);
+}
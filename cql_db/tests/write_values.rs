@@ -0,0 +1,244 @@
+#![allow(non_snake_case)]
+
+mod constants;
+
+use serial_test::serial;
+
+use constants::DATABASE_LOCATION;
+use cql_u64::U64;
+use cql_db::error;
+
+#[test]
+#[serial]
+fn _1d_u64_database_allows_for_values_to_be_written() {
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[5]
+    ).unwrap();
+
+    cql_db::write_values::<U64>(
+        DATABASE_LOCATION,
+        &[
+            (&[5], 50),
+            (&[1], 10),
+            (&[3], 30),
+        ]
+    ).unwrap();
+
+    for (index, expected) in [10, 0, 30, 0, 50].iter().enumerate() {
+        let result = cql_db::read_value::<U64>(
+            DATABASE_LOCATION,
+            &[index as u64 + 1]
+        ).unwrap();
+
+        assert_eq!(result, *expected);
+    }
+}
+
+#[test]
+#[serial]
+fn _4d_u64_database_allows_for_values_to_be_written_across_linked_rows() {
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[2, 3, 2, 3]
+    ).unwrap();
+
+    let mut points = Vec::new();
+    for i in 1..3 {
+        for j in 1..4 {
+            for k in 1..3 {
+                cql_db::link_dimensions_unchecked::<U64>(
+                    DATABASE_LOCATION,
+                    &[i, j, k],
+                ).unwrap();
+
+                for l in 1..4 {
+                    points.push([i, j, k, l]);
+                }
+            }
+        }
+    }
+
+    // reverse the points so that the input order does not match the file order
+    let values: Vec<(&[u64], u64)> = points
+        .iter()
+        .rev()
+        .map(|point| (&point[..], point[0] * 1000 + point[1] * 100 + point[2] * 10 + point[3]))
+        .collect();
+
+    cql_db::write_values::<U64>(
+        DATABASE_LOCATION,
+        &values
+    ).unwrap();
+
+    for point in points.iter() {
+        let result = cql_db::read_value::<U64>(
+            DATABASE_LOCATION,
+            point
+        ).unwrap();
+
+        assert_eq!(result, point[0] * 1000 + point[1] * 100 + point[2] * 10 + point[3]);
+    }
+}
+
+#[test]
+#[serial]
+fn _3d_u64_database_write_values_unchecked_writes_last_duplicate_value() {
+    let point = [2, 1, 2];
+
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[2, 2, 2]
+    ).unwrap();
+
+    cql_db::link_dimensions_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &point[0..2],
+    ).unwrap();
+
+    cql_db::write_values_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[
+            (&point, 1),
+            (&point, 2),
+            (&point, 3),
+        ]
+    ).unwrap();
+
+    let result = cql_db::read_value::<U64>(
+        DATABASE_LOCATION,
+        &point
+    ).unwrap();
+
+    assert_eq!(result, 3);
+}
+
+#[test]
+#[serial]
+fn _2d_u64_database_allows_for_adjacent_values_to_be_written_across_rows() {
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[2, 3]
+    ).unwrap();
+
+    cql_db::write_values::<U64>(
+        DATABASE_LOCATION,
+        &[
+            (&[2, 1], 4),
+            (&[1, 3], 3),
+            (&[1, 2], 1),
+            (&[2, 2], 5),
+            (&[1, 2], 2),
+        ]
+    ).unwrap();
+
+    for (index, expected) in [0, 2, 3, 4, 5, 0].iter().enumerate() {
+        let location = [index as u64 / 3 + 1, index as u64 % 3 + 1];
+
+        assert_eq!(cql_db::read_value::<U64>(DATABASE_LOCATION, &location).unwrap(), *expected);
+        assert_eq!(cql_db::is_written::<U64>(DATABASE_LOCATION, &location).unwrap(), *expected != 0);
+    }
+}
+
+#[test]
+#[serial]
+fn write_values__returns_DimensionsOutOfRangeError__given_2d_u64_database_and_1d_location() {
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[2, 2]
+    ).unwrap();
+
+    let result = match cql_db::write_values::<U64>(
+        DATABASE_LOCATION,
+        &[
+            (&[1, 1], 1),
+            (&[1], 2),
+        ]
+    ) {
+        Err(error::Error::Cql(cql_error)) => Some(cql_error),
+        _ => None,
+    };
+
+    assert_eq!(
+        result.unwrap(),
+        error::cql::Error::DimensionsOutOfRangeError {
+            requested: 1,
+            min: 2,
+            max: 2,
+        }
+    );
+}
+
+#[test]
+#[serial]
+fn write_values__returns_IndexOutOfRangeError__given_2d_u64_database_and_too_large_index() {
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[2, 2]
+    ).unwrap();
+
+    let result = match cql_db::write_values::<U64>(
+        DATABASE_LOCATION,
+        &[
+            (&[1, 1], 1),
+            (&[1, 3], 2),
+        ]
+    ) {
+        Err(error::Error::Cql(cql_error)) => Some(cql_error),
+        _ => None,
+    };
+
+    assert_eq!(
+        result.unwrap(),
+        error::cql::Error::IndexOutOfRangeError {
+            dimension_index: 1,
+            requested: 3,
+            min: 1,
+            max: 2,
+        }
+    );
+}
+
+#[test]
+#[serial]
+fn write_values__returns_ElementsNotLinkedError_and_writes_nothing__given_4d_u64_database_and_unlinked_location() {
+    let linked_point = [1, 2, 1, 1];
+
+    cql_db::create_db_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &[2, 2, 2, 2]
+    ).unwrap();
+
+    cql_db::link_dimensions_unchecked::<U64>(
+        DATABASE_LOCATION,
+        &linked_point[0..3],
+    ).unwrap();
+
+    let result = match cql_db::write_values::<U64>(
+        DATABASE_LOCATION,
+        &[
+            (&linked_point, 1),
+            (&[1, 2, 2, 1], 2),
+        ]
+    ) {
+        Err(error::Error::Cql(cql_error)) => Some(cql_error),
+        _ => None,
+    };
+
+    assert_eq!(
+        result.unwrap(),
+        error::cql::Error::ElementsNotLinkedError {
+            x_dimension: 1,
+            x: 2,
+            y_dimension: 2,
+            y: 2,
+        }
+    );
+
+    let stored_value = cql_db::read_value::<U64>(
+        DATABASE_LOCATION,
+        &linked_point
+    ).unwrap();
+
+    assert_eq!(stored_value, 0);
+}
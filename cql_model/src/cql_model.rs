@@ -1,11 +1,24 @@
 /*!
 This crate contains the core models/interfaces used by CQL Database.
 
-It does not contain any implementations.
+CQL Types encode and decode their values against the bytes stored for them, using [CqlBufferWritable](trait.CqlBufferWritable.html),
+[CqlRangeReadable](trait.CqlRangeReadable.html), [CqlBufferStreamReadable](trait.CqlBufferStreamReadable.html) and
+[CqlBufferStreamWritable](trait.CqlBufferStreamWritable.html), leaving all reading and writing of the database to `cql_db` - allowing a type to be stored
+in any of its storage backends.  Types holding numbers may also implement [CqlAggregatable](trait.CqlAggregatable.html), allowing `cql_db` to aggregate
+their values as it reads them.
+
+The file based traits, such as [CqlWritable](trait.CqlWritable.html), that types were previously required to implement are deprecated and will be
+removed in a future version.  Until then types implementing them still compile, and may be read and written through the functions of `cql_db`'s
+[legacy](https://docs.rs/cql_db/0.3/cql_db/legacy/index.html) module, but must also implement the matching buffer based traits to be used with the rest of
+`cql_db`.  Both may be implemented by the same type whilst it is migrated.
+
+It does not contain any other implementations.
 */
-#![doc(html_root_url = "https://docs.rs/cql_model/0.2.0")]
+#![doc(html_root_url = "https://docs.rs/cql_model/0.3.0")]
+
 use std::io;
-use std::io::{ Write };
+use std::io::{ Read, Write };
+use std::ops::Add;
 
 /// The base CQL Value Type
 ///
@@ -19,6 +32,7 @@ use std::io::{ Write };
 /// impl CqlType for TinyText {
 ///     type ValueType = String;
 ///     const VALUE_SIZE: usize = 255;
+///     const TYPE_NAME: &'static str = "my_crate::TinyText";
 /// }
 /// ```
 pub trait CqlType {
@@ -26,6 +40,21 @@ pub trait CqlType {
     type ValueType;
     /// The (maximum) size of the value to read/write from the database.
     const VALUE_SIZE: usize;
+    /// The name of the type, recorded against the databases created with it.
+    ///
+    /// `cql_db` compares it against the name recorded when a database is opened, returning an error should they differ.  It should be qualified, such
+    /// as by the name of the crate declaring the type, so that it is not shared with any other type, and should not change between releases that store
+    /// values in the same way.
+    const TYPE_NAME: &'static str;
+
+    /// Passes the type to the given visitor should it implement [CqlAggregatable](trait.CqlAggregatable.html), returning the visitor's output.
+    ///
+    /// Allows `cql_db` to keep the summaries of a database's rollup levels up to date as values are written, where all it knows of the type is that it
+    /// is a `CqlType`.  Returns `None` unless overridden - types implementing `CqlAggregatable` should override it to return
+    /// `Some(visitor.visit::<Self>())`, as `cql_db` will not create or open databases with rollup levels for types that do not.
+    fn visit_aggregatable<TVisitor: CqlAggregatableVisitor>(_visitor: TVisitor) -> Option<TVisitor::Output> {
+        None
+    }
 }
 
 /// A CQL Value Type with single point write capability.
@@ -33,6 +62,9 @@ pub trait CqlType {
 /// Allows the implementing type's [Self::ValueType](trait.CqlType.html#associatedtype.ValueType) to be written to a CQL database. It should not actively
 /// validate that the given parameters are valid.
 ///
+/// Deprecated in favour of [CqlBufferWritable](trait.CqlBufferWritable.html), which leaves reading and writing the database to `cql_db` - types implementing this trait
+/// must also implement it to be used with `cql_db`, other than through its [legacy](https://docs.rs/cql_db/0.3/cql_db/legacy/index.html) module.
+///
 /// # Errors
 ///
 /// Implementations of this function should return any [I/O errors](https://doc.rust-lang.org/nightly/std/io/enum.ErrorKind.html) encountered during the function.
@@ -44,6 +76,7 @@ pub trait CqlType {
 /// # Panics
 ///
 /// Implementations are allowed to panic if the given parameters are invalid, but they do not have to.
+#[deprecated(note = "values are now read and written by cql_db, implement CqlBufferWritable instead")]
 pub trait CqlWritable: CqlType {
     /// Writes the given `input_value` to the given `value_location` in the given `db_location` (file path).
     fn write_to_db(db_location: &str, value_location: u64, input_value: Self::ValueType) -> io::Result<()>;
@@ -54,6 +87,9 @@ pub trait CqlWritable: CqlType {
 /// Allows the implementing type's [Self::ValueType](trait.CqlType.html#associatedtype.ValueType) to be read point-by-point from a CQL database.
 /// It should not actively validate that the given parameters are valid.
 ///
+/// Deprecated in favour of [CqlRangeReadable](trait.CqlRangeReadable.html), which leaves reading and writing the database to `cql_db` - types implementing this trait
+/// must also implement it to be used with `cql_db`, other than through its [legacy](https://docs.rs/cql_db/0.3/cql_db/legacy/index.html) module.
+///
 /// # Errors
 ///
 /// Implementations of this function should return any [I/O errors](https://doc.rust-lang.org/nightly/std/io/enum.ErrorKind.html) encountered during the function,
@@ -66,6 +102,7 @@ pub trait CqlWritable: CqlType {
 /// # Panics
 ///
 /// Implementations are allowed to panic if the given parameters are invalid, but they do not have to.
+#[deprecated(note = "values are now read and written by cql_db, implement CqlRangeReadable instead")]
 pub trait CqlReadable: CqlType {
     /// Reads the value stored in the given `value_location` in the given `db_location` (file path).
     fn read_from_db(db_location: &str, value_location: u64) -> io::Result<Self::ValueType>;
@@ -76,6 +113,9 @@ pub trait CqlReadable: CqlType {
 /// Allows a range of the implementing type's [Self::ValueType](trait.CqlType.html#associatedtype.ValueType) to be read to stream from a CQL database.
 /// It should not actively validate that the given parameters are valid.
 ///
+/// Deprecated in favour of [CqlBufferStreamReadable](trait.CqlBufferStreamReadable.html), which leaves reading and writing the database to `cql_db` - types implementing this trait
+/// must also implement it to be used with `cql_db`, other than through its [legacy](https://docs.rs/cql_db/0.3/cql_db/legacy/index.html) module.
+///
 /// # Errors
 ///
 /// Implementations of this function should return any [I/O errors](https://doc.rust-lang.org/nightly/std/io/enum.ErrorKind.html) encountered during the function,
@@ -89,7 +129,180 @@ pub trait CqlReadable: CqlType {
 /// # Panics
 ///
 /// Implementations are allowed to panic if the given parameters are invalid, but they do not have to.
+#[deprecated(note = "values are now read and written by cql_db, implement CqlBufferStreamReadable instead")]
 pub trait CqlStreamReadable: CqlType {
     /// Reads `n_values` from the `value_location` in the given `db_location` (file path) to the given `stream`.
     fn read_to_stream(db_location: &str, stream: &mut dyn Write, value_location: u64, n_values: u64) -> io::Result<()>;
 }
+
+/// A CQL Value Type with range read capability.
+///
+/// Allows the implementing type's [Self::ValueType](trait.CqlType.html#associatedtype.ValueType) to be decoded from the bytes stored for it in a CQL database,
+/// leaving the reading of the database itself to `cql_db`.
+/// It should not actively validate that the given parameters are valid.
+///
+/// # Errors
+///
+/// Implementations of this function should return an [io::ErrorKind::InvalidData](https://doc.rust-lang.org/nightly/std/io/enum.ErrorKind.html#variant.InvalidData)
+/// error if the given `buffer` does not contain a valid value.  A `buffer` of zeroed bytes, as found at locations that have not been written to, should result in the
+/// default value being returned.
+///
+/// # Panics
+///
+/// Implementations are allowed to panic if the given `buffer` is shorter than [Self::VALUE_SIZE](trait.CqlType.html#associatedconstant.VALUE_SIZE).
+pub trait CqlRangeReadable: CqlType {
+    /// Decodes a single value from the given `buffer`, which holds the [Self::VALUE_SIZE](trait.CqlType.html#associatedconstant.VALUE_SIZE) bytes
+    /// stored at one value location.
+    fn read_from_buffer(buffer: &[u8]) -> io::Result<Self::ValueType>;
+}
+
+/// A CQL Value Type with stream read capability from stored bytes.
+///
+/// Allows a range of the implementing type's [Self::ValueType](trait.CqlType.html#associatedtype.ValueType) to be written to a stream from the bytes stored for
+/// them in a CQL database, leaving the reading of the database itself to `cql_db`.  It should not actively validate that the given parameters are valid.
+///
+/// # Errors
+///
+/// Implementations of this function should return any [I/O errors](https://doc.rust-lang.org/nightly/std/io/enum.ErrorKind.html) encountered whilst writing
+/// to the stream, and an [io::ErrorKind::InvalidData](https://doc.rust-lang.org/nightly/std/io/enum.ErrorKind.html#variant.InvalidData) error if the given
+/// `buffer` does not contain valid values.  A `buffer` of zeroed bytes, as found at locations that have not been written to, should result in default values
+/// being written to the stream.  If an error is returned it is not guaranteed that no bytes have been written to the stream.
+///
+/// # Panics
+///
+/// Implementations are allowed to panic if the length of the given `buffer` is not a multiple of
+/// [Self::VALUE_SIZE](trait.CqlType.html#associatedconstant.VALUE_SIZE), but they do not have to.
+pub trait CqlBufferStreamReadable: CqlType {
+    /// Writes the values held in the given `buffer`, which holds the [Self::VALUE_SIZE](trait.CqlType.html#associatedconstant.VALUE_SIZE) bytes
+    /// stored at each of a run of consecutive value locations, to the given `stream`.
+    fn read_buffer_to_stream(buffer: &[u8], stream: &mut dyn Write) -> io::Result<()>;
+}
+
+/// A CQL Value Type with single point write capability to stored bytes.
+///
+/// Allows the implementing type's [Self::ValueType](trait.CqlType.html#associatedtype.ValueType) to be encoded into the bytes stored for it in a CQL database,
+/// leaving the writing of the database itself to `cql_db`.  The bytes should be decodable by the type's [CqlRangeReadable](trait.CqlRangeReadable.html)
+/// implementation, if it has one.  It should not actively validate that the given parameters are valid.
+///
+/// # Errors
+///
+/// Implementations of this function should return an [io::ErrorKind::InvalidData](https://doc.rust-lang.org/nightly/std/io/enum.ErrorKind.html#variant.InvalidData)
+/// error if the given `input_value` cannot be stored.  If an error is returned it is not guaranteed that no bytes have been written to the `buffer`.
+///
+/// # Panics
+///
+/// Implementations are allowed to panic if the given `buffer` is shorter than [Self::VALUE_SIZE](trait.CqlType.html#associatedconstant.VALUE_SIZE).
+pub trait CqlBufferWritable: CqlType {
+    /// Encodes the given `input_value` into the given `buffer`, which holds the [Self::VALUE_SIZE](trait.CqlType.html#associatedconstant.VALUE_SIZE) bytes
+    /// to be stored at one value location.
+    fn write_to_buffer(input_value: Self::ValueType, buffer: &mut [u8]) -> io::Result<()>;
+}
+
+/// A CQL Value Type with stream write capability to stored bytes.
+///
+/// Allows a range of the implementing type's [Self::ValueType](trait.CqlType.html#associatedtype.ValueType) to be read from a stream into the bytes stored
+/// for them in a CQL database, leaving the writing of the database itself to `cql_db`.  The stream is expected to be in the same format as that written by
+/// the type's [CqlBufferStreamReadable](trait.CqlBufferStreamReadable.html) implementation, if it has one.  It should not actively validate that the given
+/// parameters are valid.
+///
+/// # Errors
+///
+/// Implementations of this function should return any [I/O errors](https://doc.rust-lang.org/nightly/std/io/enum.ErrorKind.html) encountered whilst reading
+/// from the stream, including [io::ErrorKind::UnexpectedEof](https://doc.rust-lang.org/nightly/std/io/enum.ErrorKind.html#variant.UnexpectedEof) errors should
+/// the stream contain fewer values than the `buffer` can hold, and an
+/// [io::ErrorKind::InvalidData](https://doc.rust-lang.org/nightly/std/io/enum.ErrorKind.html#variant.InvalidData) error if the stream does not contain valid
+/// values.  If an error is returned it is not guaranteed that no bytes have been written to the `buffer`.
+///
+/// # Panics
+///
+/// Implementations are allowed to panic if the length of the given `buffer` is not a multiple of
+/// [Self::VALUE_SIZE](trait.CqlType.html#associatedconstant.VALUE_SIZE), but they do not have to.
+pub trait CqlBufferStreamWritable: CqlType {
+    /// Fills the given `buffer`, which holds the [Self::VALUE_SIZE](trait.CqlType.html#associatedconstant.VALUE_SIZE) bytes to be stored at each of a run
+    /// of consecutive value locations, with values read from the given `stream`.
+    fn write_stream_to_buffer(stream: &mut dyn Read, buffer: &mut [u8]) -> io::Result<()>;
+}
+
+/// A CQL Value Type holding numbers that can be aggregated.
+///
+/// Allows the values of the implementing type to be summed, averaged, counted and compared by the aggregations in `cql_db` as they are read from the
+/// database, without them first being written to a stream.  Values holding no number, such as nulls, are skipped by every aggregation.
+///
+/// # Examples
+/// This declares a CQL Type storing `u32` values as aggregatable, summing them as `u64` values so that the sums do not overflow:
+/// ```
+/// # use std::io;
+/// # use cql_model::{ CqlType, CqlRangeReadable, CqlAggregatable, CqlAggregatableVisitor };
+/// pub struct U32;
+///
+/// impl CqlType for U32 {
+///     type ValueType = u32;
+///     const VALUE_SIZE: usize = 4;
+///     const TYPE_NAME: &'static str = "my_crate::U32";
+///
+///     fn visit_aggregatable<TVisitor: CqlAggregatableVisitor>(visitor: TVisitor) -> Option<TVisitor::Output> {
+///         Some(visitor.visit::<Self>())
+///     }
+/// }
+///
+/// impl CqlRangeReadable for U32 {
+///     fn read_from_buffer(buffer: &[u8]) -> io::Result<u32> {
+///         Ok(u32::from_le_bytes([buffer[0], buffer[1], buffer[2], buffer[3]]))
+///     }
+/// }
+///
+/// impl CqlAggregatable for U32 {
+///     type Number = u32;
+///     type Sum = u64;
+///     const SUM_SIZE: usize = 8;
+///
+///     fn to_number(value: u32) -> Option<u32> {
+///         Some(value)
+///     }
+///
+///     fn sum_to_f64(sum: u64) -> f64 {
+///         sum as f64
+///     }
+///
+///     fn write_sum_to_buffer(sum: u64, buffer: &mut [u8]) -> io::Result<()> {
+///         buffer[0..8].copy_from_slice(&sum.to_le_bytes());
+///         Ok(())
+///     }
+///
+///     fn read_sum_from_buffer(buffer: &[u8]) -> io::Result<u64> {
+///         let mut bytes = [0; 8];
+///         bytes.copy_from_slice(&buffer[0..8]);
+///         Ok(u64::from_le_bytes(bytes))
+///     }
+/// }
+/// ```
+pub trait CqlAggregatable: CqlRangeReadable {
+    /// The number held by a value, without any null state.
+    type Number: Copy + PartialOrd;
+    /// The type numbers are summed as, which should be large enough that sums of many numbers do not overflow.
+    type Sum: Copy + Default + Add<Output = Self::Sum> + From<Self::Number>;
+    /// The number of bytes a [Sum](#associatedtype.Sum) occupies once written to a buffer, allowing sums to be stored by the rollup levels of `cql_db`.
+    const SUM_SIZE: usize;
+
+    /// Returns the number held by the given value, or `None` should it not hold one.
+    fn to_number(value: Self::ValueType) -> Option<Self::Number>;
+
+    /// Converts the given sum to a `f64`, so that means can be calculated from it.
+    fn sum_to_f64(sum: Self::Sum) -> f64;
+
+    /// Writes the given sum to the start of the given buffer, which will be at least [SUM_SIZE](#associatedconstant.SUM_SIZE) bytes long.
+    fn write_sum_to_buffer(sum: Self::Sum, buffer: &mut [u8]) -> io::Result<()>;
+
+    /// Reads a sum written by [write_sum_to_buffer](#tymethod.write_sum_to_buffer) from the start of the given buffer.
+    fn read_sum_from_buffer(buffer: &[u8]) -> io::Result<Self::Sum>;
+}
+
+/// A function over the types implementing [CqlAggregatable](trait.CqlAggregatable.html), called by
+/// [CqlType::visit_aggregatable](trait.CqlType.html#method.visit_aggregatable).
+pub trait CqlAggregatableVisitor {
+    /// The type returned by the visitor.
+    type Output;
+
+    /// Returns the visitor's output for the given type.
+    fn visit<TStore: CqlAggregatable>(self) -> Self::Output;
+}
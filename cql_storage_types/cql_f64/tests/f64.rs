@@ -4,6 +4,13 @@ use std::io::{ Cursor };
 use cql_f64::{ unpack_stream };
 pub mod single_point_read_writes;
 pub mod stream_read_point_writes;
+pub mod stream_writes;
+pub mod range_reads;
+pub mod strided_reads;
+pub mod aggregations;
+pub mod rollup_levels;
+pub mod memory_storage;
+pub mod mapped_reads;
 
 fn unpack_f64_stream (stream: &mut Cursor<Vec<u8>>, n_values: usize, result: &mut [f64]) {
     unpack_stream(stream, n_values, |idx, value| {
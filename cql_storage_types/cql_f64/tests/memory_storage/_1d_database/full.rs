@@ -0,0 +1,20 @@
+use cql_f64::{ F64 };
+use cql_storage_type_testing_lib::tests::memory_storage::_1d_database::full;
+
+#[test]
+fn unchecked() {
+    full::unchecked::<F64>(
+        11.1,
+        22.2,
+        33.3
+    );
+}
+
+#[test]
+fn checked() {
+    full::checked::<F64>(
+        11.1,
+        22.2,
+        33.3
+    );
+}
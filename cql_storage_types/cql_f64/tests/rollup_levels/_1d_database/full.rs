@@ -0,0 +1,41 @@
+use serial_test::serial;
+use crate::constants::DATABASE_LOCATION;
+use cql_f64::{ F64 };
+use cql_storage_type_testing_lib::tests::aggregations::Expected;
+use cql_storage_type_testing_lib::tests::rollup_levels::_1d_database::full;
+
+#[test]
+#[serial]
+fn unchecked() {
+    full::unchecked::<F64>(
+        DATABASE_LOCATION,
+        1.5,
+        2.5,
+        5.0,
+        Expected {
+            sum: 9.0,
+            min: Some(1.5),
+            max: Some(5.0),
+            mean: Some(3.0),
+            count: 3,
+        }
+    );
+}
+
+#[test]
+#[serial]
+fn checked() {
+    full::checked::<F64>(
+        DATABASE_LOCATION,
+        1.5,
+        2.5,
+        5.0,
+        Expected {
+            sum: 9.0,
+            min: Some(1.5),
+            max: Some(5.0),
+            mean: Some(3.0),
+            count: 3,
+        }
+    );
+}
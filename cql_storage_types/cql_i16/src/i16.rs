@@ -1,7 +1,7 @@
 /*!
-This crate implements various [CqlType](https://docs.rs/cql_model/0.2/cql_model/trait.CqlType.html) derivatives for storing `i16` values in a CQL database.
+This crate implements various [CqlType](https://docs.rs/cql_model/0.3/cql_model/trait.CqlType.html) derivatives for storing `i16` values in a CQL database.
 
-Will allocate 2 bytes per value [linked](https://docs.rs/cql_db/0.2/cql_db/fn.link_dimensions.html).
+Will allocate 2 bytes per value [linked](https://docs.rs/cql_db/0.3/cql_db/fn.link_dimensions.html).
 
 # Benchmarks
 Benchmarks supplied below are fairly rudimentary (and rounded) and are there to give a rough idea of relative costs.
@@ -76,13 +76,12 @@ assert_eq!(result[2], value3);
 # }
 ```
 */
-#![doc(html_root_url = "https://docs.rs/cql_i16/0.2.0")]
-use std::fs::{ File, OpenOptions };
+#![doc(html_root_url = "https://docs.rs/cql_i16/0.3.0")]
 use std::io;
-use std::io::{ Read, Write, Cursor, SeekFrom, Seek };
+use std::io::{ Read, Write, Cursor };
 use byteorder::{ ReadBytesExt, WriteBytesExt, LittleEndian };
 
-use cql_model::{ CqlType, CqlWritable, CqlReadable, CqlStreamReadable };
+use cql_model::{ CqlType, CqlRangeReadable, CqlBufferStreamReadable, CqlBufferWritable, CqlBufferStreamWritable, CqlAggregatable, CqlAggregatableVisitor };
 
 /// Static struct for declaring that you want to work with `i16` values in a CQL database.
 ///
@@ -92,66 +91,61 @@ pub struct I16;
 impl CqlType for I16 {
     type ValueType = i16;
     const VALUE_SIZE: usize = 2;
+    const TYPE_NAME: &'static str = "cql_i16::I16";
+
+    fn visit_aggregatable<TVisitor: CqlAggregatableVisitor>(visitor: TVisitor) -> Option<TVisitor::Output> {
+        Some(visitor.visit::<Self>())
+    }
 }
 
-impl CqlWritable for I16 {
-    fn write_to_db(db_location: &str, value_location: u64, value: Self::ValueType) -> io::Result<()> {
-        let mut file = OpenOptions::new().write(true).open(db_location)?;
+impl CqlRangeReadable for I16 {
+    fn read_from_buffer(buffer: &[u8]) -> io::Result<Self::ValueType> {
+        let mut rdr = Cursor::new(buffer);
+        rdr.read_i16::<LittleEndian>()
+    }
+}
 
-        // unwrap should be considered safe by this point, with earlier checks in the cql_db crate (if not deliberately unchecked)
-        file.seek(SeekFrom::Start(value_location * Self::VALUE_SIZE as u64)).unwrap();
+impl CqlBufferStreamReadable for I16 {
+    fn read_buffer_to_stream(buffer: &[u8], stream: &mut dyn Write) -> io::Result<()> {
+        // the stream holds the values exactly as they are stored
+        stream.write_all(buffer)?;
+        stream.flush()
+    }
+}
 
-        let mut wtr = vec![];
-        wtr.write_i16::<LittleEndian>(value)?;
-        file.write_all(&wtr)
+impl CqlBufferWritable for I16 {
+    fn write_to_buffer(value: Self::ValueType, mut buffer: &mut [u8]) -> io::Result<()> {
+        buffer.write_i16::<LittleEndian>(value)
     }
 }
 
-impl CqlReadable for I16 {
-    fn read_from_db(db_location: &str, value_location: u64) -> io::Result<Self::ValueType> {
-        let mut file = File::open(&db_location)?;
+impl CqlBufferStreamWritable for I16 {
+    fn write_stream_to_buffer(stream: &mut dyn Read, buffer: &mut [u8]) -> io::Result<()> {
+        // the stream holds the values exactly as they are stored
+        stream.read_exact(buffer)
+    }
+}
 
-        // unwrap should be considered safe by this point, with earlier checks in the cql_db crate (if not deliberately unchecked)
-        file.seek(SeekFrom::Start(value_location * Self::VALUE_SIZE as u64)).unwrap();
+impl CqlAggregatable for I16 {
+    type Number = i16;
+    type Sum = i64;
+    const SUM_SIZE: usize = 8;
 
-        let mut buffer = [0; Self::VALUE_SIZE];
-        match file.read_exact(&mut buffer) {
-            Err(e) => {
-                // ignore io::ErrorKind::UnexpectedEof and continue
-                if e.kind() != io::ErrorKind::UnexpectedEof {
-                    return Err(e)
-                }
-            }
-            _ => { }
-        }
+    fn to_number(value: i16) -> Option<i16> {
+        Some(value)
+    }
 
-        let mut rdr = Cursor::new(buffer);
-        rdr.read_i16::<LittleEndian>()
+    fn sum_to_f64(sum: i64) -> f64 {
+        sum as f64
     }
-}
 
-impl CqlStreamReadable for I16 {
-    fn read_to_stream(db_location: &str, stream: &mut dyn Write, value_location: u64, n_values: u64) -> io::Result<()> {
-        let mut file = File::open(&db_location)?;
-
-        // unwrap should be considered safe by this point, with earlier checks in the cql_db crate (if not deliberately unchecked)
-        file.seek(SeekFrom::Start(value_location * Self::VALUE_SIZE as u64)).unwrap();
-
-        for _i in 0..n_values {
-            let mut buffer = [0; Self::VALUE_SIZE];
-            match file.read_exact(&mut buffer) {
-                Err(e) => {
-                    // ignore io::ErrorKind::UnexpectedEof and continue (to write '0' bytes to the writer)
-                    if e.kind() != io::ErrorKind::UnexpectedEof {
-                        return Err(e)
-                    }
-                }
-                _ => { }
-            }
-            stream.write_all(&mut buffer)?;
-        }
+    fn write_sum_to_buffer(sum: i64, mut buffer: &mut [u8]) -> io::Result<()> {
+        buffer.write_i64::<LittleEndian>(sum)
+    }
 
-        stream.flush()
+    fn read_sum_from_buffer(buffer: &[u8]) -> io::Result<i64> {
+        let mut rdr = Cursor::new(buffer);
+        rdr.read_i64::<LittleEndian>()
     }
 }
 
@@ -0,0 +1,26 @@
+use serial_test::serial;
+use crate::constants::DATABASE_LOCATION;
+use cql_i16::{ I16 };
+use cql_storage_type_testing_lib::tests::mapped_reads::_4d_database::full;
+
+#[test]
+#[serial]
+fn unchecked() {
+    full::unchecked::<I16>(
+        DATABASE_LOCATION,
+        11,
+        22,
+        33
+    );
+}
+
+#[test]
+#[serial]
+fn checked() {
+    full::checked::<I16>(
+        DATABASE_LOCATION,
+        11,
+        22,
+        33
+    );
+}
@@ -0,0 +1,20 @@
+use cql_i16::{ I16 };
+use cql_storage_type_testing_lib::tests::memory_storage::_1d_database::full;
+
+#[test]
+fn unchecked() {
+    full::unchecked::<I16>(
+        11,
+        22,
+        33
+    );
+}
+
+#[test]
+fn checked() {
+    full::checked::<I16>(
+        11,
+        22,
+        33
+    );
+}
@@ -0,0 +1,41 @@
+use serial_test::serial;
+use crate::constants::DATABASE_LOCATION;
+use cql_i16::{ I16 };
+use cql_storage_type_testing_lib::tests::aggregations::Expected;
+use cql_storage_type_testing_lib::tests::rollup_levels::_1d_database::full;
+
+#[test]
+#[serial]
+fn unchecked() {
+    full::unchecked::<I16>(
+        DATABASE_LOCATION,
+        -3,
+        7,
+        5,
+        Expected {
+            sum: 9,
+            min: Some(-3),
+            max: Some(7),
+            mean: Some(3.0),
+            count: 3,
+        }
+    );
+}
+
+#[test]
+#[serial]
+fn checked() {
+    full::checked::<I16>(
+        DATABASE_LOCATION,
+        -3,
+        7,
+        5,
+        Expected {
+            sum: 9,
+            min: Some(-3),
+            max: Some(7),
+            mean: Some(3.0),
+            count: 3,
+        }
+    );
+}
@@ -1,7 +1,7 @@
 /*!
-This crate implements various [CqlType](https://docs.rs/cql_model/0.2/cql_model/trait.CqlType.html) derivatives for storing `Option<f64>` values in a CQL database.
+This crate implements various [CqlType](https://docs.rs/cql_model/0.3/cql_model/trait.CqlType.html) derivatives for storing `Option<f64>` values in a CQL database.
 
-Will allocate 9 bytes per value [linked](https://docs.rs/cql_db/0.2/cql_db/fn.link_dimensions.html).
+Will allocate 9 bytes per value [linked](https://docs.rs/cql_db/0.3/cql_db/fn.link_dimensions.html).
 
 # Benchmarks
 Benchmarks supplied below are fairly rudimentary (and rounded) and are there to give a rough idea of relative costs.
@@ -76,13 +76,12 @@ assert_eq!(result[2], value3);
 # }
 ```
 */
-#![doc(html_root_url = "https://docs.rs/cql_nullable_f64/0.2.2")]
-use std::fs::{ File, OpenOptions };
+#![doc(html_root_url = "https://docs.rs/cql_nullable_f64/0.3.0")]
 use std::io;
-use std::io::{ Read, Write, Cursor, SeekFrom, Seek };
+use std::io::{ Read, Write, Cursor };
 use byteorder::{ ReadBytesExt, WriteBytesExt, LittleEndian };
 
-use cql_model::{ CqlType, CqlWritable, CqlReadable, CqlStreamReadable };
+use cql_model::{ CqlType, CqlRangeReadable, CqlBufferStreamReadable, CqlBufferWritable, CqlBufferStreamWritable, CqlAggregatable, CqlAggregatableVisitor };
 
 const HAS_VALUE_FLAG: u8 = 1;
 const NULL_FLAG: u8 = 0;
@@ -98,80 +97,75 @@ pub struct NullableF64;
 impl CqlType for NullableF64 {
     type ValueType = Option<f64>;
     const VALUE_SIZE: usize = HAS_VALUE_SIZE + CONTENT_SIZE;
+    const TYPE_NAME: &'static str = "cql_nullable_f64::NullableF64";
+
+    fn visit_aggregatable<TVisitor: CqlAggregatableVisitor>(visitor: TVisitor) -> Option<TVisitor::Output> {
+        Some(visitor.visit::<Self>())
+    }
 }
 
-impl CqlWritable for NullableF64 {
-    fn write_to_db(db_location: &str, value_location: u64, input_value: Self::ValueType) -> io::Result<()> {
-        let mut file = OpenOptions::new().write(true).open(db_location)?;
+impl CqlRangeReadable for NullableF64 {
+    fn read_from_buffer(buffer: &[u8]) -> io::Result<Self::ValueType> {
+        if buffer[0] == NULL_FLAG {
+            return Ok(None)
+        }
+
+        let mut rdr = Cursor::new(&buffer[HAS_VALUE_SIZE..]);
+        Ok(Some(rdr.read_f64::<LittleEndian>()?))
+    }
+}
 
-        // unwrap should be considered safe by this point, with earlier checks in the cql_db crate (if not deliberately unchecked)
-        file.seek(SeekFrom::Start(value_location * Self::VALUE_SIZE as u64)).unwrap();
+impl CqlBufferStreamReadable for NullableF64 {
+    fn read_buffer_to_stream(buffer: &[u8], stream: &mut dyn Write) -> io::Result<()> {
+        // the stream holds the values exactly as they are stored
+        stream.write_all(buffer)?;
+        stream.flush()
+    }
+}
 
+impl CqlBufferWritable for NullableF64 {
+    fn write_to_buffer(input_value: Self::ValueType, buffer: &mut [u8]) -> io::Result<()> {
         match input_value {
             None => {
-                file.write_all(&[NULL_FLAG; HAS_VALUE_SIZE])
+                buffer[0] = NULL_FLAG;
+                Ok(())
             }
             Some(value) => {
-                let mut buffer = vec![HAS_VALUE_FLAG];
-                buffer.write_f64::<LittleEndian>(value)?;
-                file.write_all(&buffer)
+                buffer[0] = HAS_VALUE_FLAG;
+                (&mut buffer[HAS_VALUE_SIZE..]).write_f64::<LittleEndian>(value)
             }
         }
     }
 }
 
-impl CqlReadable for NullableF64 {
-    fn read_from_db(db_location: &str, value_location: u64) -> io::Result<Self::ValueType> {
-        let mut file = File::open(&db_location)?;
-
-        // unwrap should be considered safe by this point, with earlier checks in the cql_db crate (if not deliberately unchecked)
-        file.seek(SeekFrom::Start(value_location * Self::VALUE_SIZE as u64)).unwrap();
-
-        let mut null_buffer = [0; HAS_VALUE_SIZE];
-        match file.read_exact(&mut null_buffer) {
-            Err(e) => {
-                // ignore io::ErrorKind::UnexpectedEof and continue
-                if e.kind() != io::ErrorKind::UnexpectedEof {
-                    return Err(e)
-                }
-            }
-            _ => { }
-        }
+impl CqlBufferStreamWritable for NullableF64 {
+    fn write_stream_to_buffer(stream: &mut dyn Read, buffer: &mut [u8]) -> io::Result<()> {
+        // the stream holds the values exactly as they are stored
+        stream.read_exact(buffer)
+    }
+}
 
-        if null_buffer[0] == NULL_FLAG {
-            return Ok(None)
-        }
+impl CqlAggregatable for NullableF64 {
+    type Number = f64;
+    type Sum = f64;
+    const SUM_SIZE: usize = 8;
 
-        let mut value_buffer = [0; CONTENT_SIZE];
-        file.read_exact(&mut value_buffer)?;
+    // null values are skipped by aggregations
+    fn to_number(value: Option<f64>) -> Option<f64> {
+        value
+    }
 
-        let mut rdr = Cursor::new(value_buffer);
-        Ok(Some(rdr.read_f64::<LittleEndian>()?))
+    fn sum_to_f64(sum: f64) -> f64 {
+        sum
     }
-}
 
-impl CqlStreamReadable for NullableF64 {
-    fn read_to_stream(db_location: &str, stream: &mut dyn Write, value_location: u64, n_values: u64) -> io::Result<()> {
-        let mut file = File::open(&db_location)?;
-
-        // unwrap should be considered safe by this point, with earlier checks in the cql_db crate (if not deliberately unchecked)
-        file.seek(SeekFrom::Start(value_location * Self::VALUE_SIZE as u64)).unwrap();
-
-        for _i in 0..n_values {
-            let mut buffer = [0; Self::VALUE_SIZE];
-            match file.read_exact(&mut buffer) {
-                Err(e) => {
-                    // ignore io::ErrorKind::UnexpectedEof and continue
-                    if e.kind() != io::ErrorKind::UnexpectedEof {
-                        return Err(e)
-                    }
-                }
-                _ => { }
-            }
-            stream.write_all(&mut buffer)?;
-        }
+    fn write_sum_to_buffer(sum: f64, mut buffer: &mut [u8]) -> io::Result<()> {
+        buffer.write_f64::<LittleEndian>(sum)
+    }
 
-        stream.flush()
+    fn read_sum_from_buffer(buffer: &[u8]) -> io::Result<f64> {
+        let mut rdr = Cursor::new(buffer);
+        rdr.read_f64::<LittleEndian>()
     }
 }
 
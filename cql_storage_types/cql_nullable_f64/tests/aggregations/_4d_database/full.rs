@@ -0,0 +1,41 @@
+use serial_test::serial;
+use crate::constants::DATABASE_LOCATION;
+use cql_nullable_f64::{ NullableF64 };
+use cql_storage_type_testing_lib::tests::aggregations::Expected;
+use cql_storage_type_testing_lib::tests::aggregations::_4d_database::full;
+
+#[test]
+#[serial]
+fn unchecked() {
+    full::unchecked::<NullableF64>(
+        DATABASE_LOCATION,
+        Some(1.5),
+        None,
+        Some(4.5),
+        Expected {
+            sum: 6.0,
+            min: Some(1.5),
+            max: Some(4.5),
+            mean: Some(3.0),
+            count: 2,
+        }
+    );
+}
+
+#[test]
+#[serial]
+fn checked() {
+    full::checked::<NullableF64>(
+        DATABASE_LOCATION,
+        Some(1.5),
+        None,
+        Some(4.5),
+        Expected {
+            sum: 6.0,
+            min: Some(1.5),
+            max: Some(4.5),
+            mean: Some(3.0),
+            count: 2,
+        }
+    );
+}
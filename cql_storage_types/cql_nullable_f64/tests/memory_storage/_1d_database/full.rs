@@ -0,0 +1,20 @@
+use cql_nullable_f64::{ NullableF64 };
+use cql_storage_type_testing_lib::tests::memory_storage::_1d_database::full;
+
+#[test]
+fn unchecked() {
+    full::unchecked::<NullableF64>(
+        Some(11.1),
+        Some(22.22),
+        Some(33.333)
+    );
+}
+
+#[test]
+fn checked() {
+    full::checked::<NullableF64>(
+        Some(11.1),
+        Some(22.22),
+        Some(33.333)
+    );
+}
@@ -0,0 +1,26 @@
+use serial_test::serial;
+use crate::constants::DATABASE_LOCATION;
+use cql_nullable_f64::{ NullableF64 };
+use cql_storage_type_testing_lib::tests::range_reads::_1d_database::full;
+
+#[test]
+#[serial]
+fn unchecked() {
+    full::unchecked::<NullableF64>(
+        DATABASE_LOCATION,
+        Some(11.1),
+        Some(22.22),
+        Some(33.333)
+    );
+}
+
+#[test]
+#[serial]
+fn checked() {
+    full::checked::<NullableF64>(
+        DATABASE_LOCATION,
+        Some(11.1),
+        Some(22.22),
+        Some(33.333)
+    );
+}
@@ -1,6 +1,6 @@
-use cql_model::{ CqlWritable, CqlReadable };
+use cql_model::{ CqlBufferWritable, CqlRangeReadable };
 
-pub fn _1d_read_location_1<'a, TStore: CqlWritable + CqlReadable>(db_location: &'a str, value: TStore::ValueType) -> impl Fn() + 'a {
+pub fn _1d_read_location_1<'a, TStore: CqlBufferWritable + CqlRangeReadable>(db_location: &'a str, value: TStore::ValueType) -> impl Fn() + 'a {
     let axis = [
         1,
     ];
@@ -26,7 +26,7 @@ pub fn _1d_read_location_1<'a, TStore: CqlWritable + CqlReadable>(db_location: &
     }
 }
 
-pub fn _1d_read_location_100000<'a, TStore: CqlWritable + CqlReadable>(db_location: &'a str, value: TStore::ValueType) -> impl Fn() + 'a {
+pub fn _1d_read_location_100000<'a, TStore: CqlBufferWritable + CqlRangeReadable>(db_location: &'a str, value: TStore::ValueType) -> impl Fn() + 'a {
     let axis = [
         100000,
     ];
@@ -52,7 +52,7 @@ pub fn _1d_read_location_100000<'a, TStore: CqlWritable + CqlReadable>(db_locati
     }
 }
 
-pub fn _4d_read_location_1_1_1_1<'a, TStore: CqlWritable + CqlReadable>(db_location: &'a str, value: TStore::ValueType) -> impl Fn() + 'a {
+pub fn _4d_read_location_1_1_1_1<'a, TStore: CqlBufferWritable + CqlRangeReadable>(db_location: &'a str, value: TStore::ValueType) -> impl Fn() + 'a {
     let axis = [
         1,
         1,
@@ -86,7 +86,7 @@ pub fn _4d_read_location_1_1_1_1<'a, TStore: CqlWritable + CqlReadable>(db_locat
     }
 }
 
-pub fn _4d_read_location_1_1_1_100000<'a, TStore: CqlWritable + CqlReadable>(db_location: &'a str, value: TStore::ValueType) -> impl Fn() + 'a {
+pub fn _4d_read_location_1_1_1_100000<'a, TStore: CqlBufferWritable + CqlRangeReadable>(db_location: &'a str, value: TStore::ValueType) -> impl Fn() + 'a {
     let axis = [
         1,
         1,
@@ -120,7 +120,7 @@ pub fn _4d_read_location_1_1_1_100000<'a, TStore: CqlWritable + CqlReadable>(db_
     }
 }
 
-pub fn _4d_read_location_1_100000_1_1<'a, TStore: CqlWritable + CqlReadable>(db_location: &'a str, value: TStore::ValueType) -> impl Fn() + 'a {
+pub fn _4d_read_location_1_100000_1_1<'a, TStore: CqlBufferWritable + CqlRangeReadable>(db_location: &'a str, value: TStore::ValueType) -> impl Fn() + 'a {
     let axis = [
         1,
         100000,
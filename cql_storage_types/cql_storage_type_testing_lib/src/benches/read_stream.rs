@@ -1,7 +1,7 @@
 use std::io::{ Cursor, SeekFrom, Seek };
-use cql_model::{ CqlStreamReadable, CqlWritable };
+use cql_model::{ CqlBufferStreamReadable, CqlBufferWritable };
 
-pub fn _1d_read_empty_location_1_to_1<'a, TStore: CqlStreamReadable>(
+pub fn _1d_read_empty_location_1_to_1<'a, TStore: CqlBufferStreamReadable>(
             db_location: &'a str,
             unpack_stream: &'a dyn Fn(&mut Cursor<Vec<u8>>, usize, &mut [TStore::ValueType])
         ) -> impl Fn() + 'a
@@ -32,7 +32,7 @@ pub fn _1d_read_empty_location_1_to_1<'a, TStore: CqlStreamReadable>(
     }
 }
 
-pub fn _1d_read_populated_location_1_to_1<'a, TStore: CqlStreamReadable + CqlWritable>(
+pub fn _1d_read_populated_location_1_to_1<'a, TStore: CqlBufferStreamReadable + CqlBufferWritable>(
             db_location: &'a str,
             value: TStore::ValueType,
             unpack_stream: &'a dyn Fn(&mut Cursor<Vec<u8>>, usize, &mut [TStore::ValueType])
@@ -70,7 +70,7 @@ pub fn _1d_read_populated_location_1_to_1<'a, TStore: CqlStreamReadable + CqlWri
     }
 }
 
-pub fn _1d_read_empty_location_50000_to_100000<'a, TStore: CqlStreamReadable>(
+pub fn _1d_read_empty_location_50000_to_100000<'a, TStore: CqlBufferStreamReadable>(
             db_location: &'a str,
             unpack_stream: &'a dyn Fn(&mut Cursor<Vec<u8>>, usize, &mut [TStore::ValueType])
         ) -> impl Fn() + 'a
@@ -101,7 +101,7 @@ pub fn _1d_read_empty_location_50000_to_100000<'a, TStore: CqlStreamReadable>(
     }
 }
 
-pub fn _1d_read_populated_location_50000_to_100000<'a, TStore: CqlStreamReadable + CqlWritable>(
+pub fn _1d_read_populated_location_50000_to_100000<'a, TStore: CqlBufferStreamReadable + CqlBufferWritable>(
             db_location: &'a str,
             value_generator: &'a dyn Fn(u64) -> TStore::ValueType,
             unpack_stream: &'a dyn Fn(&mut Cursor<Vec<u8>>, usize, &mut [TStore::ValueType])
@@ -141,7 +141,7 @@ pub fn _1d_read_populated_location_50000_to_100000<'a, TStore: CqlStreamReadable
     }
 }
 
-pub fn _4d_read_empty_location_1_1_1_1_to_1_1_1_1<'a, TStore: CqlStreamReadable>(
+pub fn _4d_read_empty_location_1_1_1_1_to_1_1_1_1<'a, TStore: CqlBufferStreamReadable>(
             db_location: &'a str,
             unpack_stream: &'a dyn Fn(&mut Cursor<Vec<u8>>, usize, &mut [TStore::ValueType])
         ) -> impl Fn() + 'a
@@ -177,7 +177,7 @@ pub fn _4d_read_empty_location_1_1_1_1_to_1_1_1_1<'a, TStore: CqlStreamReadable>
     }
 }
 
-pub fn _4d_read_populated_location_1_1_1_1_to_1_1_1_1<'a, TStore: CqlStreamReadable + CqlWritable>(
+pub fn _4d_read_populated_location_1_1_1_1_to_1_1_1_1<'a, TStore: CqlBufferStreamReadable + CqlBufferWritable>(
             db_location: &'a str,
             value: TStore::ValueType,
             unpack_stream: &'a dyn Fn(&mut Cursor<Vec<u8>>, usize, &mut [TStore::ValueType])
@@ -220,7 +220,7 @@ pub fn _4d_read_populated_location_1_1_1_1_to_1_1_1_1<'a, TStore: CqlStreamReada
     }
 }
 
-pub fn _4d_read_empty_location_1_1_1_50000_to_1_1_1_100000<'a, TStore: CqlStreamReadable>(
+pub fn _4d_read_empty_location_1_1_1_50000_to_1_1_1_100000<'a, TStore: CqlBufferStreamReadable>(
             db_location: &'a str,
             unpack_stream: &'a dyn Fn(&mut Cursor<Vec<u8>>, usize, &mut [TStore::ValueType])
         ) -> impl Fn() + 'a
@@ -256,7 +256,7 @@ pub fn _4d_read_empty_location_1_1_1_50000_to_1_1_1_100000<'a, TStore: CqlStream
     }
 }
 
-pub fn _4d_read_populated_location_1_1_1_50000_to_1_1_1_100000<'a, TStore: CqlStreamReadable + CqlWritable>(
+pub fn _4d_read_populated_location_1_1_1_50000_to_1_1_1_100000<'a, TStore: CqlBufferStreamReadable + CqlBufferWritable>(
             db_location: &'a str,
             value_generator: &'a dyn Fn(u64) -> TStore::ValueType,
             unpack_stream: &'a dyn Fn(&mut Cursor<Vec<u8>>, usize, &mut [TStore::ValueType])
@@ -1,6 +1,6 @@
-use cql_model::{ CqlWritable };
+use cql_model::{ CqlBufferWritable };
 
-pub fn _1d_write_location_1<'a, TStore: CqlWritable>(db_location: &'a str) -> Box<dyn Fn(TStore::ValueType) + 'a> {
+pub fn _1d_write_location_1<'a, TStore: CqlBufferWritable>(db_location: &'a str) -> Box<dyn Fn(TStore::ValueType) + 'a> {
     let axis = [
         1,
     ];
@@ -21,7 +21,7 @@ pub fn _1d_write_location_1<'a, TStore: CqlWritable>(db_location: &'a str) -> Bo
     })
 }
 
-pub fn _1d_write_location_100000<'a, TStore: CqlWritable>(db_location: &'a str) -> Box<dyn Fn(TStore::ValueType) + 'a> {
+pub fn _1d_write_location_100000<'a, TStore: CqlBufferWritable>(db_location: &'a str) -> Box<dyn Fn(TStore::ValueType) + 'a> {
     let axis = [
         100000,
     ];
@@ -42,7 +42,7 @@ pub fn _1d_write_location_100000<'a, TStore: CqlWritable>(db_location: &'a str)
     })
 }
 
-pub fn _4d_write_location_1_1_1_1<'a, TStore: CqlWritable>(db_location: &'a str) -> Box<dyn Fn(TStore::ValueType) + 'a> {
+pub fn _4d_write_location_1_1_1_1<'a, TStore: CqlBufferWritable>(db_location: &'a str) -> Box<dyn Fn(TStore::ValueType) + 'a> {
     let axis = [
         1,
         1,
@@ -71,7 +71,7 @@ pub fn _4d_write_location_1_1_1_1<'a, TStore: CqlWritable>(db_location: &'a str)
     })
 }
 
-pub fn _4d_write_location_1_1_1_100000<'a, TStore: CqlWritable>(db_location: &'a str) -> Box<dyn Fn(TStore::ValueType) + 'a> {
+pub fn _4d_write_location_1_1_1_100000<'a, TStore: CqlBufferWritable>(db_location: &'a str) -> Box<dyn Fn(TStore::ValueType) + 'a> {
     let axis = [
         1,
         1,
@@ -100,7 +100,7 @@ pub fn _4d_write_location_1_1_1_100000<'a, TStore: CqlWritable>(db_location: &'a
     })
 }
 
-pub fn _4d_write_location_1_100000_1_1<'a, TStore: CqlWritable>(db_location: &'a str) -> Box<dyn Fn(TStore::ValueType) + 'a> {
+pub fn _4d_write_location_1_100000_1_1<'a, TStore: CqlBufferWritable>(db_location: &'a str) -> Box<dyn Fn(TStore::ValueType) + 'a> {
     let axis = [
         1,
         100000,
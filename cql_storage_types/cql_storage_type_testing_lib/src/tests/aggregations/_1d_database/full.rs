@@ -0,0 +1,149 @@
+use std::fmt::{ Debug };
+use cql_model::{ CqlBufferWritable, CqlAggregatable };
+use cql_db::aggregation::{ Sum, Min, Max, Mean, Count };
+use super::super::Expected;
+
+const AXIS: [u64; 1] = [
+    3,
+];
+
+const N_VALUES_TO_AGGREGATE: u64 = 3;
+const POINT1: [u64; 1] = [1];
+const POINT2: [u64; 1] = [2];
+const POINT3: [u64; 1] = [3];
+
+pub fn unchecked<TStore: CqlBufferWritable + CqlAggregatable>(
+            db_location: &str,
+            value1: TStore::ValueType,
+            value2: TStore::ValueType,
+            value3: TStore::ValueType,
+            expected: Expected<TStore>
+        )
+        where TStore::Number: Debug, TStore::Sum: Debug + PartialEq {
+    cql_db::create_db_unchecked::<TStore>(
+        db_location,
+        &AXIS
+    ).unwrap();
+
+    cql_db::write_value_unchecked::<TStore>(
+        db_location,
+        &POINT1,
+        value1
+    ).unwrap();
+
+    cql_db::write_value_unchecked::<TStore>(
+        db_location,
+        &POINT2,
+        value2
+    ).unwrap();
+
+    cql_db::write_value_unchecked::<TStore>(
+        db_location,
+        &POINT3,
+        value3
+    ).unwrap();
+
+    let sum = cql_db::aggregate_unchecked::<TStore, Sum>(
+        db_location,
+        &POINT1,
+        N_VALUES_TO_AGGREGATE
+    ).unwrap();
+
+    let min = cql_db::aggregate_unchecked::<TStore, Min>(
+        db_location,
+        &POINT1,
+        N_VALUES_TO_AGGREGATE
+    ).unwrap();
+
+    let max = cql_db::aggregate_unchecked::<TStore, Max>(
+        db_location,
+        &POINT1,
+        N_VALUES_TO_AGGREGATE
+    ).unwrap();
+
+    let mean = cql_db::aggregate_unchecked::<TStore, Mean>(
+        db_location,
+        &POINT1,
+        N_VALUES_TO_AGGREGATE
+    ).unwrap();
+
+    let count = cql_db::aggregate_unchecked::<TStore, Count>(
+        db_location,
+        &POINT1,
+        N_VALUES_TO_AGGREGATE
+    ).unwrap();
+
+    assert_eq!(sum, expected.sum);
+    assert_eq!(min, expected.min);
+    assert_eq!(max, expected.max);
+    assert_eq!(mean, expected.mean);
+    assert_eq!(count, expected.count);
+}
+
+pub fn checked<TStore: CqlBufferWritable + CqlAggregatable>(
+            db_location: &str,
+            value1: TStore::ValueType,
+            value2: TStore::ValueType,
+            value3: TStore::ValueType,
+            expected: Expected<TStore>
+        )
+        where TStore::Number: Debug, TStore::Sum: Debug + PartialEq {
+    cql_db::create_db_unchecked::<TStore>(
+        db_location,
+        &AXIS
+    ).unwrap();
+
+    cql_db::write_value::<TStore>(
+        db_location,
+        &POINT1,
+        value1
+    ).unwrap();
+
+    cql_db::write_value::<TStore>(
+        db_location,
+        &POINT2,
+        value2
+    ).unwrap();
+
+    cql_db::write_value::<TStore>(
+        db_location,
+        &POINT3,
+        value3
+    ).unwrap();
+
+    let sum = cql_db::aggregate::<TStore, Sum>(
+        db_location,
+        &POINT1,
+        N_VALUES_TO_AGGREGATE
+    ).unwrap();
+
+    let min = cql_db::aggregate::<TStore, Min>(
+        db_location,
+        &POINT1,
+        N_VALUES_TO_AGGREGATE
+    ).unwrap();
+
+    let max = cql_db::aggregate::<TStore, Max>(
+        db_location,
+        &POINT1,
+        N_VALUES_TO_AGGREGATE
+    ).unwrap();
+
+    let mean = cql_db::aggregate::<TStore, Mean>(
+        db_location,
+        &POINT1,
+        N_VALUES_TO_AGGREGATE
+    ).unwrap();
+
+    let count = cql_db::aggregate::<TStore, Count>(
+        db_location,
+        &POINT1,
+        N_VALUES_TO_AGGREGATE
+    ).unwrap();
+
+    assert_eq!(sum, expected.sum);
+    assert_eq!(min, expected.min);
+    assert_eq!(max, expected.max);
+    assert_eq!(mean, expected.mean);
+    assert_eq!(count, expected.count);
+}
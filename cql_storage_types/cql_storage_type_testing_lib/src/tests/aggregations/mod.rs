@@ -0,0 +1,13 @@
+use cql_model::CqlAggregatable;
+
+pub mod _1d_database;
+pub mod _4d_database;
+
+/// The expected result of each aggregation of the values read by a test.
+pub struct Expected<TStore: CqlAggregatable> {
+    pub sum: TStore::Sum,
+    pub min: Option<TStore::Number>,
+    pub max: Option<TStore::Number>,
+    pub mean: Option<f64>,
+    pub count: u64,
+}
@@ -0,0 +1,124 @@
+use std::fmt::{ Debug };
+use std::cmp::{ PartialEq };
+use std::io::{ Cursor };
+use cql_model::{ CqlBufferWritable, CqlRangeReadable, CqlBufferStreamReadable };
+
+const AXIS: [u64; 1] = [
+    5,
+];
+
+const N_VALUES_TO_READ: u64 = 5;
+const POINT1: [u64; 1] = [1];
+const POINT2: [u64; 1] = [2];
+const POINT3: [u64; 1] = [4];
+const UNWRITTEN_POINT: [u64; 1] = [3];
+
+pub fn unchecked<TStore: CqlBufferWritable + CqlRangeReadable + CqlBufferStreamReadable>(
+            db_location: &str,
+            value1: TStore::ValueType,
+            value2: TStore::ValueType,
+            value3: TStore::ValueType
+        )
+        where TStore::ValueType: Clone + Debug + PartialEq + Default {
+    cql_db::create_db_unchecked::<TStore>(
+        db_location,
+        &AXIS
+    ).unwrap();
+
+    cql_db::write_value_unchecked::<TStore>(
+        db_location,
+        &POINT1,
+        value1.clone()
+    ).unwrap();
+
+    cql_db::write_value_unchecked::<TStore>(
+        db_location,
+        &POINT2,
+        value2.clone()
+    ).unwrap();
+
+    cql_db::write_value_unchecked::<TStore>(
+        db_location,
+        &POINT3,
+        value3.clone()
+    ).unwrap();
+
+    let mut database = cql_db::open_mapped::<TStore>(db_location).unwrap();
+
+    assert_eq!(database.read_value_unchecked(&POINT1).unwrap(), value1);
+    assert_eq!(database.read_value_unchecked(&POINT2).unwrap(), value2);
+    assert_eq!(database.read_value_unchecked(&POINT3).unwrap(), value3);
+    assert_eq!(database.read_value_unchecked(&UNWRITTEN_POINT).unwrap(), TStore::ValueType::default());
+
+    let mut stream = Cursor::new(Vec::new());
+    cql_db::read_to_stream_unchecked::<TStore>(
+        db_location,
+        &mut stream,
+        &POINT1,
+        N_VALUES_TO_READ
+    ).unwrap();
+
+    let mut mapped_stream = Cursor::new(Vec::new());
+    database.read_to_stream_unchecked(
+        &mut mapped_stream,
+        &POINT1,
+        N_VALUES_TO_READ
+    ).unwrap();
+
+    assert_eq!(mapped_stream.into_inner(), stream.into_inner());
+}
+
+pub fn checked<TStore: CqlBufferWritable + CqlRangeReadable + CqlBufferStreamReadable>(
+            db_location: &str,
+            value1: TStore::ValueType,
+            value2: TStore::ValueType,
+            value3: TStore::ValueType
+        )
+        where TStore::ValueType: Clone + Debug + PartialEq + Default {
+    cql_db::create_db_unchecked::<TStore>(
+        db_location,
+        &AXIS
+    ).unwrap();
+
+    cql_db::write_value::<TStore>(
+        db_location,
+        &POINT1,
+        value1.clone()
+    ).unwrap();
+
+    cql_db::write_value::<TStore>(
+        db_location,
+        &POINT2,
+        value2.clone()
+    ).unwrap();
+
+    cql_db::write_value::<TStore>(
+        db_location,
+        &POINT3,
+        value3.clone()
+    ).unwrap();
+
+    let mut database = cql_db::open_mapped::<TStore>(db_location).unwrap();
+
+    assert_eq!(database.read_value(&POINT1).unwrap(), value1);
+    assert_eq!(database.read_value(&POINT2).unwrap(), value2);
+    assert_eq!(database.read_value(&POINT3).unwrap(), value3);
+    assert_eq!(database.read_value(&UNWRITTEN_POINT).unwrap(), TStore::ValueType::default());
+
+    let mut stream = Cursor::new(Vec::new());
+    cql_db::read_to_stream::<TStore>(
+        db_location,
+        &mut stream,
+        &POINT1,
+        N_VALUES_TO_READ
+    ).unwrap();
+
+    let mut mapped_stream = Cursor::new(Vec::new());
+    database.read_to_stream(
+        &mut mapped_stream,
+        &POINT1,
+        N_VALUES_TO_READ
+    ).unwrap();
+
+    assert_eq!(mapped_stream.into_inner(), stream.into_inner());
+}
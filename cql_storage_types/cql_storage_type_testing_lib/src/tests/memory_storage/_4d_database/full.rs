@@ -0,0 +1,115 @@
+use std::fmt::{ Debug };
+use std::cmp::{ PartialEq };
+use std::io::{ Cursor, Seek, SeekFrom };
+use cql_model::{ CqlBufferWritable, CqlRangeReadable, CqlBufferStreamReadable, CqlBufferStreamWritable };
+use cql_db::storage::MemoryStorage;
+
+const AXIS: [u64; 4] = [
+    3,
+    5,
+    7,
+    9,
+];
+
+const N_VALUES_TO_READ: u64 = 9;
+const POINT1: [u64; 4] = [2, 3, 4, 1];
+const POINT2: [u64; 4] = [2, 3, 4, 5];
+const POINT3: [u64; 4] = [2, 3, 4, 9];
+const UNWRITTEN_POINT: [u64; 4] = [2, 3, 4, 8];
+const COPY_POINT: [u64; 4] = [3, 5, 7, 1];
+
+pub fn unchecked<TStore: CqlBufferWritable + CqlRangeReadable + CqlBufferStreamReadable + CqlBufferStreamWritable>(
+            value1: TStore::ValueType,
+            value2: TStore::ValueType,
+            value3: TStore::ValueType
+        )
+        where TStore::ValueType: Clone + Debug + PartialEq + Default {
+    let storage = MemoryStorage::new();
+    cql_db::create_db_in_unchecked::<TStore, _>(
+        &storage,
+        &AXIS
+    ).unwrap();
+
+    let mut database = cql_db::open_in::<TStore, _>(storage).unwrap();
+
+    database.link_dimensions_unchecked(&POINT1[0..3]).unwrap();
+    database.link_dimensions_unchecked(&COPY_POINT[0..3]).unwrap();
+
+    database.write_value_unchecked(&POINT1, value1.clone()).unwrap();
+    database.write_value_unchecked(&POINT2, value2.clone()).unwrap();
+    database.write_value_unchecked(&POINT3, value3.clone()).unwrap();
+
+    assert_eq!(database.read_value_unchecked(&POINT1).unwrap(), value1);
+    assert_eq!(database.read_value_unchecked(&POINT2).unwrap(), value2);
+    assert_eq!(database.read_value_unchecked(&POINT3).unwrap(), value3);
+    assert_eq!(database.read_value_unchecked(&UNWRITTEN_POINT).unwrap(), TStore::ValueType::default());
+
+    let mut stream = Cursor::new(Vec::new());
+    database.read_to_stream_unchecked(
+        &mut stream,
+        &POINT1,
+        N_VALUES_TO_READ
+    ).unwrap();
+
+    stream.seek(SeekFrom::Start(0)).unwrap();
+    database.write_from_stream_unchecked(
+        &mut stream,
+        &COPY_POINT,
+        N_VALUES_TO_READ
+    ).unwrap();
+
+    let values: Vec<TStore::ValueType> = database.read_range_unchecked(&POINT1, N_VALUES_TO_READ).unwrap().map(|value| value.unwrap()).collect();
+    let copied_values: Vec<TStore::ValueType> = database.read_range_unchecked(&COPY_POINT, N_VALUES_TO_READ).unwrap().map(|value| value.unwrap()).collect();
+
+    assert_eq!(copied_values, values);
+}
+
+pub fn checked<TStore: CqlBufferWritable + CqlRangeReadable + CqlBufferStreamReadable + CqlBufferStreamWritable>(
+            value1: TStore::ValueType,
+            value2: TStore::ValueType,
+            value3: TStore::ValueType
+        )
+        where TStore::ValueType: Clone + Debug + PartialEq + Default {
+    let storage = MemoryStorage::new();
+    cql_db::create_db_in_unchecked::<TStore, _>(
+        &storage,
+        &AXIS
+    ).unwrap();
+
+    let mut database = cql_db::open_in::<TStore, _>(storage).unwrap();
+
+    database.link_dimensions(&POINT1[0..3]).unwrap();
+    database.link_dimensions(&COPY_POINT[0..3]).unwrap();
+
+    database.write_value(&POINT1, value1.clone()).unwrap();
+    database.write_value(&POINT2, value2.clone()).unwrap();
+
+    // transactions encode their values before writing them
+    let mut transaction = database.transaction();
+    transaction.write_value(&POINT3, value3.clone()).unwrap();
+    transaction.commit().unwrap();
+
+    assert_eq!(database.read_value(&POINT1).unwrap(), value1);
+    assert_eq!(database.read_value(&POINT2).unwrap(), value2);
+    assert_eq!(database.read_value(&POINT3).unwrap(), value3);
+    assert_eq!(database.read_value(&UNWRITTEN_POINT).unwrap(), TStore::ValueType::default());
+
+    let mut stream = Cursor::new(Vec::new());
+    database.read_to_stream(
+        &mut stream,
+        &POINT1,
+        N_VALUES_TO_READ
+    ).unwrap();
+
+    stream.seek(SeekFrom::Start(0)).unwrap();
+    database.write_from_stream(
+        &mut stream,
+        &COPY_POINT,
+        N_VALUES_TO_READ
+    ).unwrap();
+
+    let values: Vec<TStore::ValueType> = database.read_range(&POINT1, N_VALUES_TO_READ).unwrap().map(|value| value.unwrap()).collect();
+    let copied_values: Vec<TStore::ValueType> = database.read_range(&COPY_POINT, N_VALUES_TO_READ).unwrap().map(|value| value.unwrap()).collect();
+
+    assert_eq!(copied_values, values);
+}
@@ -0,0 +1,98 @@
+use std::fmt::{ Debug };
+use std::cmp::{ PartialEq };
+use cql_model::{ CqlBufferWritable, CqlRangeReadable };
+
+const AXIS: [u64; 1] = [
+    4,
+];
+
+const N_VALUES_TO_READ: u64 = 4;
+const POINT1: [u64; 1] = [1];
+const POINT2: [u64; 1] = [2];
+const POINT3: [u64; 1] = [3];
+
+pub fn unchecked<TStore: CqlBufferWritable + CqlRangeReadable>(
+            db_location: &str,
+            value1: TStore::ValueType,
+            value2: TStore::ValueType,
+            value3: TStore::ValueType
+        )
+        where TStore::ValueType: Clone + Debug + PartialEq + Default {
+    cql_db::create_db_unchecked::<TStore>(
+        db_location,
+        &AXIS
+    ).unwrap();
+
+    cql_db::write_value_unchecked::<TStore>(
+        db_location,
+        &POINT1,
+        value1.clone()
+    ).unwrap();
+
+    cql_db::write_value_unchecked::<TStore>(
+        db_location,
+        &POINT2,
+        value2.clone()
+    ).unwrap();
+
+    cql_db::write_value_unchecked::<TStore>(
+        db_location,
+        &POINT3,
+        value3.clone()
+    ).unwrap();
+
+    let result: Vec<TStore::ValueType> = cql_db::read_range_unchecked::<TStore>(
+        db_location,
+        &POINT1,
+        N_VALUES_TO_READ
+    ).unwrap().map(|value| value.unwrap()).collect();
+
+    assert_eq!(result.len(), N_VALUES_TO_READ as usize);
+    assert_eq!(result[0], value1);
+    assert_eq!(result[1], value2);
+    assert_eq!(result[2], value3);
+    assert_eq!(result[3], TStore::ValueType::default());
+}
+
+pub fn checked<TStore: CqlBufferWritable + CqlRangeReadable>(
+            db_location: &str,
+            value1: TStore::ValueType,
+            value2: TStore::ValueType,
+            value3: TStore::ValueType
+        )
+        where TStore::ValueType: Clone + Debug + PartialEq + Default {
+    cql_db::create_db_unchecked::<TStore>(
+        db_location,
+        &AXIS
+    ).unwrap();
+
+    cql_db::write_value::<TStore>(
+        db_location,
+        &POINT1,
+        value1.clone()
+    ).unwrap();
+
+    cql_db::write_value::<TStore>(
+        db_location,
+        &POINT2,
+        value2.clone()
+    ).unwrap();
+
+    cql_db::write_value::<TStore>(
+        db_location,
+        &POINT3,
+        value3.clone()
+    ).unwrap();
+
+    let result: Vec<TStore::ValueType> = cql_db::read_range::<TStore>(
+        db_location,
+        &POINT1,
+        N_VALUES_TO_READ
+    ).unwrap().map(|value| value.unwrap()).collect();
+
+    assert_eq!(result.len(), N_VALUES_TO_READ as usize);
+    assert_eq!(result[0], value1);
+    assert_eq!(result[1], value2);
+    assert_eq!(result[2], value3);
+    assert_eq!(result[3], TStore::ValueType::default());
+}
@@ -0,0 +1,121 @@
+use std::fmt::{ Debug };
+use std::cmp::{ PartialEq };
+use cql_model::{ CqlBufferWritable, CqlRangeReadable };
+
+const AXIS: [u64; 4] = [
+    3,
+    5,
+    7,
+    9,
+];
+
+const N_VALUES_TO_READ: u64 = 9;
+const POINT1: [u64; 4] = [2, 3, 4, 1];
+const POINT2: [u64; 4] = [2, 3, 4, 5];
+const POINT3: [u64; 4] = [2, 3, 4, 9];
+
+pub fn unchecked<TStore: CqlBufferWritable + CqlRangeReadable>(
+            db_location: &str,
+            value1: TStore::ValueType,
+            value2: TStore::ValueType,
+            value3: TStore::ValueType
+        )
+        where TStore::ValueType: Clone + Debug + PartialEq + Default {
+    cql_db::create_db_unchecked::<TStore>(
+        db_location,
+        &AXIS
+    ).unwrap();
+
+    cql_db::link_dimensions_unchecked::<TStore>(
+        db_location,
+        &POINT1[0..3],
+    ).unwrap();
+
+    cql_db::write_value_unchecked::<TStore>(
+        db_location,
+        &POINT1,
+        value1.clone()
+    ).unwrap();
+
+    cql_db::write_value_unchecked::<TStore>(
+        db_location,
+        &POINT2,
+        value2.clone()
+    ).unwrap();
+
+    cql_db::write_value_unchecked::<TStore>(
+        db_location,
+        &POINT3,
+        value3.clone()
+    ).unwrap();
+
+    let result: Vec<TStore::ValueType> = cql_db::read_range_unchecked::<TStore>(
+        db_location,
+        &POINT1,
+        N_VALUES_TO_READ
+    ).unwrap().map(|value| value.unwrap()).collect();
+
+    assert_eq!(result.len(), N_VALUES_TO_READ as usize);
+    assert_eq!(result[0], value1);
+    assert_eq!(result[1], TStore::ValueType::default());
+    assert_eq!(result[2], TStore::ValueType::default());
+    assert_eq!(result[3], TStore::ValueType::default());
+    assert_eq!(result[4], value2);
+    assert_eq!(result[5], TStore::ValueType::default());
+    assert_eq!(result[6], TStore::ValueType::default());
+    assert_eq!(result[7], TStore::ValueType::default());
+    assert_eq!(result[8], value3);
+}
+
+pub fn checked<TStore: CqlBufferWritable + CqlRangeReadable>(
+            db_location: &str,
+            value1: TStore::ValueType,
+            value2: TStore::ValueType,
+            value3: TStore::ValueType
+        )
+        where TStore::ValueType: Clone + Debug + PartialEq + Default {
+    cql_db::create_db_unchecked::<TStore>(
+        db_location,
+        &AXIS
+    ).unwrap();
+
+    cql_db::link_dimensions::<TStore>(
+        db_location,
+        &POINT1[0..3],
+    ).unwrap();
+
+    cql_db::write_value::<TStore>(
+        db_location,
+        &POINT1,
+        value1.clone()
+    ).unwrap();
+
+    cql_db::write_value::<TStore>(
+        db_location,
+        &POINT2,
+        value2.clone()
+    ).unwrap();
+
+    cql_db::write_value::<TStore>(
+        db_location,
+        &POINT3,
+        value3.clone()
+    ).unwrap();
+
+    let result: Vec<TStore::ValueType> = cql_db::read_range::<TStore>(
+        db_location,
+        &POINT1,
+        N_VALUES_TO_READ
+    ).unwrap().map(|value| value.unwrap()).collect();
+
+    assert_eq!(result.len(), N_VALUES_TO_READ as usize);
+    assert_eq!(result[0], value1);
+    assert_eq!(result[1], TStore::ValueType::default());
+    assert_eq!(result[2], TStore::ValueType::default());
+    assert_eq!(result[3], TStore::ValueType::default());
+    assert_eq!(result[4], value2);
+    assert_eq!(result[5], TStore::ValueType::default());
+    assert_eq!(result[6], TStore::ValueType::default());
+    assert_eq!(result[7], TStore::ValueType::default());
+    assert_eq!(result[8], value3);
+}
@@ -0,0 +1,2 @@
+pub mod _1d_database;
+pub mod _4d_database;
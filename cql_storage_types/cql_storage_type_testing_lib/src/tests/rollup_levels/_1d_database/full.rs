@@ -0,0 +1,247 @@
+use std::fmt::{ Debug };
+use cql_model::{ CqlBufferWritable, CqlAggregatable };
+use cql_db::aggregation::{ Sum, Min, Max, Mean, Count };
+use super::super::super::aggregations::Expected;
+
+const AXIS: [u64; 1] = [
+    6,
+];
+
+const ROLLUP_LEVELS: [u64; 2] = [3, 6];
+
+const N_VALUES_TO_ROLL_UP: u64 = 3;
+const BUCKET_SIZE: u64 = 3;
+const POINT1: [u64; 1] = [1];
+const POINT2: [u64; 1] = [2];
+const POINT3: [u64; 1] = [3];
+
+// Each aggregation is rolled up twice, first computing the summary of the rollup level and then reading it back
+pub fn unchecked<TStore: CqlBufferWritable + CqlAggregatable>(
+            db_location: &str,
+            value1: TStore::ValueType,
+            value2: TStore::ValueType,
+            value3: TStore::ValueType,
+            expected: Expected<TStore>
+        )
+        where TStore::Number: Debug, TStore::Sum: Debug + PartialEq {
+    cql_db::create_db_with_rollups_unchecked::<TStore>(
+        db_location,
+        &AXIS,
+        &ROLLUP_LEVELS
+    ).unwrap();
+
+    cql_db::write_value_unchecked::<TStore>(
+        db_location,
+        &POINT1,
+        value1
+    ).unwrap();
+
+    cql_db::write_value_unchecked::<TStore>(
+        db_location,
+        &POINT2,
+        value2
+    ).unwrap();
+
+    cql_db::write_value_unchecked::<TStore>(
+        db_location,
+        &POINT3,
+        value3
+    ).unwrap();
+
+    let sum = cql_db::rollup_unchecked::<TStore, Sum>(
+        db_location,
+        &POINT1,
+        N_VALUES_TO_ROLL_UP,
+        BUCKET_SIZE
+    ).unwrap();
+
+    let min = cql_db::rollup_unchecked::<TStore, Min>(
+        db_location,
+        &POINT1,
+        N_VALUES_TO_ROLL_UP,
+        BUCKET_SIZE
+    ).unwrap();
+
+    let max = cql_db::rollup_unchecked::<TStore, Max>(
+        db_location,
+        &POINT1,
+        N_VALUES_TO_ROLL_UP,
+        BUCKET_SIZE
+    ).unwrap();
+
+    let mean = cql_db::rollup_unchecked::<TStore, Mean>(
+        db_location,
+        &POINT1,
+        N_VALUES_TO_ROLL_UP,
+        BUCKET_SIZE
+    ).unwrap();
+
+    let count = cql_db::rollup_unchecked::<TStore, Count>(
+        db_location,
+        &POINT1,
+        N_VALUES_TO_ROLL_UP,
+        BUCKET_SIZE
+    ).unwrap();
+
+    assert_eq!(sum, vec![expected.sum]);
+    assert_eq!(min, vec![expected.min]);
+    assert_eq!(max, vec![expected.max]);
+    assert_eq!(mean, vec![expected.mean]);
+    assert_eq!(count, vec![expected.count]);
+
+    let sum = cql_db::rollup_unchecked::<TStore, Sum>(
+        db_location,
+        &POINT1,
+        N_VALUES_TO_ROLL_UP,
+        BUCKET_SIZE
+    ).unwrap();
+
+    let min = cql_db::rollup_unchecked::<TStore, Min>(
+        db_location,
+        &POINT1,
+        N_VALUES_TO_ROLL_UP,
+        BUCKET_SIZE
+    ).unwrap();
+
+    let max = cql_db::rollup_unchecked::<TStore, Max>(
+        db_location,
+        &POINT1,
+        N_VALUES_TO_ROLL_UP,
+        BUCKET_SIZE
+    ).unwrap();
+
+    let mean = cql_db::rollup_unchecked::<TStore, Mean>(
+        db_location,
+        &POINT1,
+        N_VALUES_TO_ROLL_UP,
+        BUCKET_SIZE
+    ).unwrap();
+
+    let count = cql_db::rollup_unchecked::<TStore, Count>(
+        db_location,
+        &POINT1,
+        N_VALUES_TO_ROLL_UP,
+        BUCKET_SIZE
+    ).unwrap();
+
+    assert_eq!(sum, vec![expected.sum]);
+    assert_eq!(min, vec![expected.min]);
+    assert_eq!(max, vec![expected.max]);
+    assert_eq!(mean, vec![expected.mean]);
+    assert_eq!(count, vec![expected.count]);
+}
+
+pub fn checked<TStore: CqlBufferWritable + CqlAggregatable>(
+            db_location: &str,
+            value1: TStore::ValueType,
+            value2: TStore::ValueType,
+            value3: TStore::ValueType,
+            expected: Expected<TStore>
+        )
+        where TStore::Number: Debug, TStore::Sum: Debug + PartialEq {
+    cql_db::create_db_with_rollups_unchecked::<TStore>(
+        db_location,
+        &AXIS,
+        &ROLLUP_LEVELS
+    ).unwrap();
+
+    cql_db::write_value::<TStore>(
+        db_location,
+        &POINT1,
+        value1
+    ).unwrap();
+
+    cql_db::write_value::<TStore>(
+        db_location,
+        &POINT2,
+        value2
+    ).unwrap();
+
+    cql_db::write_value::<TStore>(
+        db_location,
+        &POINT3,
+        value3
+    ).unwrap();
+
+    let sum = cql_db::rollup::<TStore, Sum>(
+        db_location,
+        &POINT1,
+        N_VALUES_TO_ROLL_UP,
+        BUCKET_SIZE
+    ).unwrap();
+
+    let min = cql_db::rollup::<TStore, Min>(
+        db_location,
+        &POINT1,
+        N_VALUES_TO_ROLL_UP,
+        BUCKET_SIZE
+    ).unwrap();
+
+    let max = cql_db::rollup::<TStore, Max>(
+        db_location,
+        &POINT1,
+        N_VALUES_TO_ROLL_UP,
+        BUCKET_SIZE
+    ).unwrap();
+
+    let mean = cql_db::rollup::<TStore, Mean>(
+        db_location,
+        &POINT1,
+        N_VALUES_TO_ROLL_UP,
+        BUCKET_SIZE
+    ).unwrap();
+
+    let count = cql_db::rollup::<TStore, Count>(
+        db_location,
+        &POINT1,
+        N_VALUES_TO_ROLL_UP,
+        BUCKET_SIZE
+    ).unwrap();
+
+    assert_eq!(sum, vec![expected.sum]);
+    assert_eq!(min, vec![expected.min]);
+    assert_eq!(max, vec![expected.max]);
+    assert_eq!(mean, vec![expected.mean]);
+    assert_eq!(count, vec![expected.count]);
+
+    let sum = cql_db::rollup::<TStore, Sum>(
+        db_location,
+        &POINT1,
+        N_VALUES_TO_ROLL_UP,
+        BUCKET_SIZE
+    ).unwrap();
+
+    let min = cql_db::rollup::<TStore, Min>(
+        db_location,
+        &POINT1,
+        N_VALUES_TO_ROLL_UP,
+        BUCKET_SIZE
+    ).unwrap();
+
+    let max = cql_db::rollup::<TStore, Max>(
+        db_location,
+        &POINT1,
+        N_VALUES_TO_ROLL_UP,
+        BUCKET_SIZE
+    ).unwrap();
+
+    let mean = cql_db::rollup::<TStore, Mean>(
+        db_location,
+        &POINT1,
+        N_VALUES_TO_ROLL_UP,
+        BUCKET_SIZE
+    ).unwrap();
+
+    let count = cql_db::rollup::<TStore, Count>(
+        db_location,
+        &POINT1,
+        N_VALUES_TO_ROLL_UP,
+        BUCKET_SIZE
+    ).unwrap();
+
+    assert_eq!(sum, vec![expected.sum]);
+    assert_eq!(min, vec![expected.min]);
+    assert_eq!(max, vec![expected.max]);
+    assert_eq!(mean, vec![expected.mean]);
+    assert_eq!(count, vec![expected.count]);
+}
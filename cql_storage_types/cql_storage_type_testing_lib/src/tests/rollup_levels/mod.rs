@@ -0,0 +1 @@
+pub mod _1d_database;
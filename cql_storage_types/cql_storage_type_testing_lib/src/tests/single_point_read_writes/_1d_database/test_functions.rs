@@ -1,12 +1,12 @@
 use std::fmt::{ Debug };
 use std::cmp::{ PartialEq };
-use cql_model::{ CqlWritable, CqlReadable };
+use cql_model::{ CqlBufferWritable, CqlRangeReadable };
 
 const AXIS: [u64; 1] = [
     3,
 ];
 
-pub fn unchecked<TStore: CqlWritable + CqlReadable>(db_location: &str, value: TStore::ValueType, point: [u64; 1])
+pub fn unchecked<TStore: CqlBufferWritable + CqlRangeReadable>(db_location: &str, value: TStore::ValueType, point: [u64; 1])
         where TStore::ValueType: Clone + Debug + PartialEq {
     cql_db::create_db_unchecked::<TStore>(
         db_location,
@@ -27,7 +27,7 @@ pub fn unchecked<TStore: CqlWritable + CqlReadable>(db_location: &str, value: TS
     assert_eq!(result1, value);
 }
 
-pub fn checked<TStore: CqlWritable + CqlReadable>(db_location: &str, value: TStore::ValueType, point: [u64; 1])
+pub fn checked<TStore: CqlBufferWritable + CqlRangeReadable>(db_location: &str, value: TStore::ValueType, point: [u64; 1])
         where TStore::ValueType: Clone + Debug + PartialEq {
     cql_db::create_db_unchecked::<TStore>(
         db_location,
@@ -48,7 +48,7 @@ pub fn checked<TStore: CqlWritable + CqlReadable>(db_location: &str, value: TSto
     assert_eq!(result1, value);
 }
 
-pub fn unchecked_write_checked_read<TStore: CqlWritable + CqlReadable>(db_location: &str, value: TStore::ValueType, point: [u64; 1])
+pub fn unchecked_write_checked_read<TStore: CqlBufferWritable + CqlRangeReadable>(db_location: &str, value: TStore::ValueType, point: [u64; 1])
         where TStore::ValueType: Clone + Debug + PartialEq {
     cql_db::create_db_unchecked::<TStore>(
         db_location,
@@ -69,7 +69,7 @@ pub fn unchecked_write_checked_read<TStore: CqlWritable + CqlReadable>(db_locati
     assert_eq!(result1, value);
 }
 
-pub fn checked_write_unchecked_read<TStore: CqlWritable + CqlReadable>(db_location: &str, value: TStore::ValueType, point: [u64; 1])
+pub fn checked_write_unchecked_read<TStore: CqlBufferWritable + CqlRangeReadable>(db_location: &str, value: TStore::ValueType, point: [u64; 1])
         where TStore::ValueType: Clone + Debug + PartialEq {
     cql_db::create_db_unchecked::<TStore>(
         db_location,
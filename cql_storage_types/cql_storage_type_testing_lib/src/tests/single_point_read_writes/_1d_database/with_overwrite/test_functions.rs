@@ -1,12 +1,12 @@
 use std::fmt::{ Debug };
 use std::cmp::{ PartialEq };
-use cql_model::{ CqlWritable, CqlReadable };
+use cql_model::{ CqlBufferWritable, CqlRangeReadable };
 
 const AXIS: [u64; 1] = [
     3,
 ];
 
-pub fn unchecked<TStore: CqlWritable + CqlReadable>(db_location: &str, value1: TStore::ValueType, value2: TStore::ValueType, point: [u64; 1])
+pub fn unchecked<TStore: CqlBufferWritable + CqlRangeReadable>(db_location: &str, value1: TStore::ValueType, value2: TStore::ValueType, point: [u64; 1])
         where TStore::ValueType: Clone + Debug + PartialEq {
     cql_db::create_db_unchecked::<TStore>(
         db_location,
@@ -33,7 +33,7 @@ pub fn unchecked<TStore: CqlWritable + CqlReadable>(db_location: &str, value1: T
     assert_eq!(result1, value2);
 }
 
-pub fn checked<TStore: CqlWritable + CqlReadable>(db_location: &str, value1: TStore::ValueType, value2: TStore::ValueType, point: [u64; 1])
+pub fn checked<TStore: CqlBufferWritable + CqlRangeReadable>(db_location: &str, value1: TStore::ValueType, value2: TStore::ValueType, point: [u64; 1])
         where TStore::ValueType: Clone + Debug + PartialEq {
     cql_db::create_db_unchecked::<TStore>(
         db_location,
@@ -60,7 +60,7 @@ pub fn checked<TStore: CqlWritable + CqlReadable>(db_location: &str, value1: TSt
     assert_eq!(result1, value2);
 }
 
-pub fn unchecked_write_unchecked_overwrite_checked_read<TStore: CqlWritable + CqlReadable>(
+pub fn unchecked_write_unchecked_overwrite_checked_read<TStore: CqlBufferWritable + CqlRangeReadable>(
             db_location: &str,
             value1: TStore::ValueType,
             value2: TStore::ValueType,
@@ -91,7 +91,7 @@ pub fn unchecked_write_unchecked_overwrite_checked_read<TStore: CqlWritable + Cq
     assert_eq!(result1, value2);
 }
 
-pub fn unchecked_write_checked_overwrite_checked_read<TStore: CqlWritable + CqlReadable>(
+pub fn unchecked_write_checked_overwrite_checked_read<TStore: CqlBufferWritable + CqlRangeReadable>(
             db_location: &str,
             value1: TStore::ValueType,
             value2: TStore::ValueType,
@@ -122,7 +122,7 @@ pub fn unchecked_write_checked_overwrite_checked_read<TStore: CqlWritable + CqlR
     assert_eq!(result1, value2);
 }
 
-pub fn checked_write_unchecked_overwrite_checked_read<TStore: CqlWritable + CqlReadable>(
+pub fn checked_write_unchecked_overwrite_checked_read<TStore: CqlBufferWritable + CqlRangeReadable>(
             db_location: &str,
             value1: TStore::ValueType,
             value2: TStore::ValueType,
@@ -153,7 +153,7 @@ pub fn checked_write_unchecked_overwrite_checked_read<TStore: CqlWritable + CqlR
     assert_eq!(result1, value2);
 }
 
-pub fn checked_write_unchecked_overwrite_unchecked_read<TStore: CqlWritable + CqlReadable>(
+pub fn checked_write_unchecked_overwrite_unchecked_read<TStore: CqlBufferWritable + CqlRangeReadable>(
             db_location: &str,
             value1: TStore::ValueType,
             value2: TStore::ValueType,
@@ -184,7 +184,7 @@ pub fn checked_write_unchecked_overwrite_unchecked_read<TStore: CqlWritable + Cq
     assert_eq!(result1, value2);
 }
 
-pub fn unchecked_write_checked_overwrite_unchecked_read<TStore: CqlWritable + CqlReadable>(
+pub fn unchecked_write_checked_overwrite_unchecked_read<TStore: CqlBufferWritable + CqlRangeReadable>(
             db_location: &str,
             value1: TStore::ValueType,
             value2: TStore::ValueType,
@@ -215,7 +215,7 @@ pub fn unchecked_write_checked_overwrite_unchecked_read<TStore: CqlWritable + Cq
     assert_eq!(result1, value2);
 }
 
-pub fn checked_write_checked_overwrite_unchecked_read<TStore: CqlWritable + CqlReadable>(
+pub fn checked_write_checked_overwrite_unchecked_read<TStore: CqlBufferWritable + CqlRangeReadable>(
             db_location: &str,
             value1: TStore::ValueType,
             value2: TStore::ValueType,
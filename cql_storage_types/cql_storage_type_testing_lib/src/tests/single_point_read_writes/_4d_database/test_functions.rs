@@ -1,6 +1,6 @@
 use std::fmt::{ Debug };
 use std::cmp::{ PartialEq };
-use cql_model::{ CqlWritable, CqlReadable };
+use cql_model::{ CqlBufferWritable, CqlRangeReadable };
 
 const AXIS: [u64; 4] = [
     3,
@@ -9,7 +9,7 @@ const AXIS: [u64; 4] = [
     9,
 ];
 
-pub fn unchecked<TStore: CqlWritable + CqlReadable>(db_location: &str, value: TStore::ValueType, point: [u64; 4])
+pub fn unchecked<TStore: CqlBufferWritable + CqlRangeReadable>(db_location: &str, value: TStore::ValueType, point: [u64; 4])
         where TStore::ValueType: Clone + Debug + PartialEq {
     cql_db::create_db_unchecked::<TStore>(
         db_location,
@@ -35,7 +35,7 @@ pub fn unchecked<TStore: CqlWritable + CqlReadable>(db_location: &str, value: TS
     assert_eq!(result1, value);
 }
 
-pub fn checked<TStore: CqlWritable + CqlReadable>(db_location: &str, value: TStore::ValueType, point: [u64; 4])
+pub fn checked<TStore: CqlBufferWritable + CqlRangeReadable>(db_location: &str, value: TStore::ValueType, point: [u64; 4])
         where TStore::ValueType: Clone + Debug + PartialEq {
     cql_db::create_db_unchecked::<TStore>(
         db_location,
@@ -61,7 +61,7 @@ pub fn checked<TStore: CqlWritable + CqlReadable>(db_location: &str, value: TSto
     assert_eq!(result1, value);
 }
 
-pub fn unchecked_write_checked_read<TStore: CqlWritable + CqlReadable>(db_location: &str, value: TStore::ValueType, point: [u64; 4])
+pub fn unchecked_write_checked_read<TStore: CqlBufferWritable + CqlRangeReadable>(db_location: &str, value: TStore::ValueType, point: [u64; 4])
         where TStore::ValueType: Clone + Debug + PartialEq {
     cql_db::create_db_unchecked::<TStore>(
         db_location,
@@ -87,7 +87,7 @@ pub fn unchecked_write_checked_read<TStore: CqlWritable + CqlReadable>(db_locati
     assert_eq!(result1, value);
 }
 
-pub fn checked_write_unchecked_read<TStore: CqlWritable + CqlReadable>(db_location: &str, value: TStore::ValueType, point: [u64; 4])
+pub fn checked_write_unchecked_read<TStore: CqlBufferWritable + CqlRangeReadable>(db_location: &str, value: TStore::ValueType, point: [u64; 4])
         where TStore::ValueType: Clone + Debug + PartialEq {
     cql_db::create_db_unchecked::<TStore>(
         db_location,
@@ -1,6 +1,6 @@
 use std::fmt::{ Debug };
 use std::cmp::{ PartialEq };
-use cql_model::{ CqlWritable, CqlReadable };
+use cql_model::{ CqlBufferWritable, CqlRangeReadable };
 
 const AXIS: [u64; 4] = [
     3,
@@ -9,7 +9,7 @@ const AXIS: [u64; 4] = [
     9,
 ];
 
-pub fn unchecked<TStore: CqlWritable + CqlReadable>(
+pub fn unchecked<TStore: CqlBufferWritable + CqlRangeReadable>(
             db_location: &str,
             value1: TStore::ValueType,
             value2: TStore::ValueType,
@@ -45,7 +45,7 @@ pub fn unchecked<TStore: CqlWritable + CqlReadable>(
     assert_eq!(result1, value2);
 }
 
-pub fn checked<TStore: CqlWritable + CqlReadable>(
+pub fn checked<TStore: CqlBufferWritable + CqlRangeReadable>(
             db_location: &str,
             value1: TStore::ValueType,
             value2: TStore::ValueType,
@@ -81,7 +81,7 @@ pub fn checked<TStore: CqlWritable + CqlReadable>(
     assert_eq!(result1, value2);
 }
 
-pub fn unchecked_write_unchecked_overwrite_checked_read<TStore: CqlWritable + CqlReadable>(
+pub fn unchecked_write_unchecked_overwrite_checked_read<TStore: CqlBufferWritable + CqlRangeReadable>(
             db_location: &str,
             value1: TStore::ValueType,
             value2: TStore::ValueType,
@@ -117,7 +117,7 @@ pub fn unchecked_write_unchecked_overwrite_checked_read<TStore: CqlWritable + Cq
     assert_eq!(result1, value2);
 }
 
-pub fn unchecked_write_checked_overwrite_checked_read<TStore: CqlWritable + CqlReadable>(
+pub fn unchecked_write_checked_overwrite_checked_read<TStore: CqlBufferWritable + CqlRangeReadable>(
             db_location: &str,
             value1: TStore::ValueType,
             value2: TStore::ValueType,
@@ -153,7 +153,7 @@ pub fn unchecked_write_checked_overwrite_checked_read<TStore: CqlWritable + CqlR
     assert_eq!(result1, value2);
 }
 
-pub fn checked_write_unchecked_overwrite_checked_read<TStore: CqlWritable + CqlReadable>(
+pub fn checked_write_unchecked_overwrite_checked_read<TStore: CqlBufferWritable + CqlRangeReadable>(
             db_location: &str,
             value1: TStore::ValueType,
             value2: TStore::ValueType,
@@ -189,7 +189,7 @@ pub fn checked_write_unchecked_overwrite_checked_read<TStore: CqlWritable + CqlR
     assert_eq!(result1, value2);
 }
 
-pub fn checked_write_unchecked_overwrite_unchecked_read<TStore: CqlWritable + CqlReadable>(
+pub fn checked_write_unchecked_overwrite_unchecked_read<TStore: CqlBufferWritable + CqlRangeReadable>(
             db_location: &str,
             value1: TStore::ValueType,
             value2: TStore::ValueType,
@@ -225,7 +225,7 @@ pub fn checked_write_unchecked_overwrite_unchecked_read<TStore: CqlWritable + Cq
     assert_eq!(result1, value2);
 }
 
-pub fn unchecked_write_checked_overwrite_unchecked_read<TStore: CqlWritable + CqlReadable>(
+pub fn unchecked_write_checked_overwrite_unchecked_read<TStore: CqlBufferWritable + CqlRangeReadable>(
             db_location: &str,
             value1: TStore::ValueType,
             value2: TStore::ValueType,
@@ -261,7 +261,7 @@ pub fn unchecked_write_checked_overwrite_unchecked_read<TStore: CqlWritable + Cq
     assert_eq!(result1, value2);
 }
 
-pub fn checked_write_checked_overwrite_unchecked_read<TStore: CqlWritable + CqlReadable>(
+pub fn checked_write_checked_overwrite_unchecked_read<TStore: CqlBufferWritable + CqlRangeReadable>(
             db_location: &str,
             value1: TStore::ValueType,
             value2: TStore::ValueType,
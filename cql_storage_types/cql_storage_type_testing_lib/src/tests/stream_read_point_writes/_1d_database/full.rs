@@ -1,7 +1,7 @@
 use std::fmt::{ Debug };
 use std::cmp::{ PartialEq };
 use std::io::{ Cursor, SeekFrom, Seek };
-use cql_model::{ CqlWritable, CqlStreamReadable };
+use cql_model::{ CqlBufferWritable, CqlBufferStreamReadable };
 
 const AXIS: [u64; 1] = [
     3,
@@ -12,7 +12,7 @@ const POINT1: [u64; 1] = [1];
 const POINT2: [u64; 1] = [2];
 const POINT3: [u64; 1] = [3];
 
-pub fn unchecked<'a, TStore: CqlWritable + CqlStreamReadable>(
+pub fn unchecked<'a, TStore: CqlBufferWritable + CqlBufferStreamReadable>(
             db_location: &str,
             value1: TStore::ValueType,
             value2: TStore::ValueType,
@@ -63,7 +63,7 @@ pub fn unchecked<'a, TStore: CqlWritable + CqlStreamReadable>(
     assert_eq!(result[2], value3);
 }
 
-pub fn checked<'a, TStore: CqlWritable + CqlStreamReadable>(
+pub fn checked<'a, TStore: CqlBufferWritable + CqlBufferStreamReadable>(
             db_location: &str,
             value1: TStore::ValueType,
             value2: TStore::ValueType,
@@ -114,7 +114,7 @@ pub fn checked<'a, TStore: CqlWritable + CqlStreamReadable>(
     assert_eq!(result[2], value3);
 }
 
-pub fn unchecked_write_checked_read<'a, TStore: CqlWritable + CqlStreamReadable>(
+pub fn unchecked_write_checked_read<'a, TStore: CqlBufferWritable + CqlBufferStreamReadable>(
             db_location: &str,
             value1: TStore::ValueType,
             value2: TStore::ValueType,
@@ -165,7 +165,7 @@ pub fn unchecked_write_checked_read<'a, TStore: CqlWritable + CqlStreamReadable>
     assert_eq!(result[2], value3);
 }
 
-pub fn checked_write_unchecked_read<'a, TStore: CqlWritable + CqlStreamReadable>(
+pub fn checked_write_unchecked_read<'a, TStore: CqlBufferWritable + CqlBufferStreamReadable>(
             db_location: &str,
             value1: TStore::ValueType,
             value2: TStore::ValueType,
@@ -1,12 +1,12 @@
 use std::fmt::{ Debug };
 use std::cmp::{ PartialEq };
 use std::io::{ Cursor };
-use cql_model::{ CqlWritable, CqlStreamReadable };
+use cql_model::{ CqlBufferWritable, CqlBufferStreamReadable };
 use crate::tests::stream_read_point_writes::_1d_database::test_functions;
 
 const POINT: [u64; 1] = [3];
 
-pub fn unchecked<'a, TStore: CqlWritable + CqlStreamReadable>(
+pub fn unchecked<'a, TStore: CqlBufferWritable + CqlBufferStreamReadable>(
             db_location: &str,
             value: TStore::ValueType,
             unpack_stream: &'a dyn Fn(&mut Cursor<Vec<u8>>, usize, &mut [TStore::ValueType])
@@ -15,7 +15,7 @@ pub fn unchecked<'a, TStore: CqlWritable + CqlStreamReadable>(
     test_functions::unchecked::<TStore>(db_location, value, POINT, unpack_stream)
 }
 
-pub fn checked<'a, TStore: CqlWritable + CqlStreamReadable>(
+pub fn checked<'a, TStore: CqlBufferWritable + CqlBufferStreamReadable>(
             db_location: &str,
             value: TStore::ValueType,
             unpack_stream: &'a dyn Fn(&mut Cursor<Vec<u8>>, usize, &mut [TStore::ValueType])
@@ -24,7 +24,7 @@ pub fn checked<'a, TStore: CqlWritable + CqlStreamReadable>(
     test_functions::checked::<TStore>(db_location, value, POINT, unpack_stream)
 }
 
-pub fn unchecked_write_checked_read<'a, TStore: CqlWritable + CqlStreamReadable>(
+pub fn unchecked_write_checked_read<'a, TStore: CqlBufferWritable + CqlBufferStreamReadable>(
             db_location: &str,
             value: TStore::ValueType,
             unpack_stream: &'a dyn Fn(&mut Cursor<Vec<u8>>, usize, &mut [TStore::ValueType])
@@ -33,7 +33,7 @@ pub fn unchecked_write_checked_read<'a, TStore: CqlWritable + CqlStreamReadable>
     test_functions::unchecked_write_checked_read::<TStore>(db_location, value, POINT, unpack_stream)
 }
 
-pub fn checked_write_unchecked_read<'a, TStore: CqlWritable + CqlStreamReadable>(
+pub fn checked_write_unchecked_read<'a, TStore: CqlBufferWritable + CqlBufferStreamReadable>(
             db_location: &str,
             value: TStore::ValueType,
             unpack_stream: &'a dyn Fn(&mut Cursor<Vec<u8>>, usize, &mut [TStore::ValueType])
@@ -1,13 +1,13 @@
 use std::fmt::{ Debug };
 use std::cmp::{ PartialEq };
 use std::io::{ Cursor, SeekFrom, Seek };
-use cql_model::{ CqlWritable, CqlStreamReadable };
+use cql_model::{ CqlBufferWritable, CqlBufferStreamReadable };
 
 const AXIS: [u64; 1] = [
     3,
 ];
 
-pub fn unchecked<'a, TStore: CqlWritable + CqlStreamReadable>(
+pub fn unchecked<'a, TStore: CqlBufferWritable + CqlBufferStreamReadable>(
             db_location: &str,
             value: TStore::ValueType,
             point: [u64; 1],
@@ -44,7 +44,7 @@ pub fn unchecked<'a, TStore: CqlWritable + CqlStreamReadable>(
     assert_eq!(result[0], value);
 }
 
-pub fn checked<'a, TStore: CqlWritable + CqlStreamReadable>(
+pub fn checked<'a, TStore: CqlBufferWritable + CqlBufferStreamReadable>(
             db_location: &str,
             value: TStore::ValueType,
             point: [u64; 1],
@@ -81,7 +81,7 @@ pub fn checked<'a, TStore: CqlWritable + CqlStreamReadable>(
     assert_eq!(result[0], value);
 }
 
-pub fn unchecked_write_checked_read<'a, TStore: CqlWritable + CqlStreamReadable>(
+pub fn unchecked_write_checked_read<'a, TStore: CqlBufferWritable + CqlBufferStreamReadable>(
             db_location: &str,
             value: TStore::ValueType,
             point: [u64; 1],
@@ -118,7 +118,7 @@ pub fn unchecked_write_checked_read<'a, TStore: CqlWritable + CqlStreamReadable>
     assert_eq!(result[0], value);
 }
 
-pub fn checked_write_unchecked_read<'a, TStore: CqlWritable + CqlStreamReadable>(
+pub fn checked_write_unchecked_read<'a, TStore: CqlBufferWritable + CqlBufferStreamReadable>(
             db_location: &str,
             value: TStore::ValueType,
             point: [u64; 1],
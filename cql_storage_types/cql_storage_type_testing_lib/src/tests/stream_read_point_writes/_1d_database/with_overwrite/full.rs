@@ -1,7 +1,7 @@
 use std::fmt::{ Debug };
 use std::cmp::{ PartialEq };
 use std::io::{ Cursor, SeekFrom, Seek };
-use cql_model::{ CqlWritable, CqlStreamReadable };
+use cql_model::{ CqlBufferWritable, CqlBufferStreamReadable };
 
 const AXIS: [u64; 1] = [
     3,
@@ -12,7 +12,7 @@ const POINT1: [u64; 1] = [1];
 const POINT2: [u64; 1] = [2];
 const POINT3: [u64; 1] = [3];
 
-pub fn unchecked<'a, TStore: CqlWritable + CqlStreamReadable>(
+pub fn unchecked<'a, TStore: CqlBufferWritable + CqlBufferStreamReadable>(
             db_location: &str,
             value1: TStore::ValueType,
             value2: TStore::ValueType,
@@ -84,7 +84,7 @@ pub fn unchecked<'a, TStore: CqlWritable + CqlStreamReadable>(
     assert_eq!(result[2], value6);
 }
 
-pub fn checked<'a, TStore: CqlWritable + CqlStreamReadable>(
+pub fn checked<'a, TStore: CqlBufferWritable + CqlBufferStreamReadable>(
             db_location: &str,
             value1: TStore::ValueType,
             value2: TStore::ValueType,
@@ -156,7 +156,7 @@ pub fn checked<'a, TStore: CqlWritable + CqlStreamReadable>(
     assert_eq!(result[2], value6);
 }
 
-pub fn unchecked_write_unchecked_overwrite_checked_read<'a, TStore: CqlWritable + CqlStreamReadable>(
+pub fn unchecked_write_unchecked_overwrite_checked_read<'a, TStore: CqlBufferWritable + CqlBufferStreamReadable>(
             db_location: &str,
             value1: TStore::ValueType,
             value2: TStore::ValueType,
@@ -228,7 +228,7 @@ pub fn unchecked_write_unchecked_overwrite_checked_read<'a, TStore: CqlWritable
     assert_eq!(result[2], value6);
 }
 
-pub fn unchecked_write_checked_overwrite_checked_read<'a, TStore: CqlWritable + CqlStreamReadable>(
+pub fn unchecked_write_checked_overwrite_checked_read<'a, TStore: CqlBufferWritable + CqlBufferStreamReadable>(
             db_location: &str,
             value1: TStore::ValueType,
             value2: TStore::ValueType,
@@ -300,7 +300,7 @@ pub fn unchecked_write_checked_overwrite_checked_read<'a, TStore: CqlWritable +
     assert_eq!(result[2], value6);
 }
 
-pub fn checked_write_unchecked_overwrite_checked_read<'a, TStore: CqlWritable + CqlStreamReadable>(
+pub fn checked_write_unchecked_overwrite_checked_read<'a, TStore: CqlBufferWritable + CqlBufferStreamReadable>(
             db_location: &str,
             value1: TStore::ValueType,
             value2: TStore::ValueType,
@@ -372,7 +372,7 @@ pub fn checked_write_unchecked_overwrite_checked_read<'a, TStore: CqlWritable +
     assert_eq!(result[2], value6);
 }
 
-pub fn checked_write_unchecked_overwrite_unchecked_read<'a, TStore: CqlWritable + CqlStreamReadable>(
+pub fn checked_write_unchecked_overwrite_unchecked_read<'a, TStore: CqlBufferWritable + CqlBufferStreamReadable>(
             db_location: &str,
             value1: TStore::ValueType,
             value2: TStore::ValueType,
@@ -444,7 +444,7 @@ pub fn checked_write_unchecked_overwrite_unchecked_read<'a, TStore: CqlWritable
     assert_eq!(result[2], value6);
 }
 
-pub fn unchecked_write_checked_overwrite_unchecked_read<'a, TStore: CqlWritable + CqlStreamReadable>(
+pub fn unchecked_write_checked_overwrite_unchecked_read<'a, TStore: CqlBufferWritable + CqlBufferStreamReadable>(
             db_location: &str,
             value1: TStore::ValueType,
             value2: TStore::ValueType,
@@ -516,7 +516,7 @@ pub fn unchecked_write_checked_overwrite_unchecked_read<'a, TStore: CqlWritable
     assert_eq!(result[2], value6);
 }
 
-pub fn checked_write_checked_overwrite_unchecked_read<'a, TStore: CqlWritable + CqlStreamReadable>(
+pub fn checked_write_checked_overwrite_unchecked_read<'a, TStore: CqlBufferWritable + CqlBufferStreamReadable>(
             db_location: &str,
             value1: TStore::ValueType,
             value2: TStore::ValueType,
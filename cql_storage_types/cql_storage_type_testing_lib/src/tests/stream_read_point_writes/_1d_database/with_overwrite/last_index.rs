@@ -1,12 +1,12 @@
 use std::fmt::{ Debug };
 use std::cmp::{ PartialEq };
 use std::io::{ Cursor };
-use cql_model::{ CqlWritable, CqlStreamReadable };
+use cql_model::{ CqlBufferWritable, CqlBufferStreamReadable };
 use crate::tests::stream_read_point_writes::_1d_database::with_overwrite::test_functions;
 
 const POINT: [u64; 1] = [3];
 
-pub fn unchecked<'a, TStore: CqlWritable + CqlStreamReadable>(
+pub fn unchecked<'a, TStore: CqlBufferWritable + CqlBufferStreamReadable>(
             db_location: &str,
             value1: TStore::ValueType,
             value2: TStore::ValueType,
@@ -16,7 +16,7 @@ pub fn unchecked<'a, TStore: CqlWritable + CqlStreamReadable>(
     test_functions::unchecked::<TStore>(db_location, value1, value2, POINT, unpack_stream)
 }
 
-pub fn checked<'a, TStore: CqlWritable + CqlStreamReadable>(
+pub fn checked<'a, TStore: CqlBufferWritable + CqlBufferStreamReadable>(
             db_location: &str,
             value1: TStore::ValueType,
             value2: TStore::ValueType,
@@ -26,7 +26,7 @@ pub fn checked<'a, TStore: CqlWritable + CqlStreamReadable>(
     test_functions::checked::<TStore>(db_location, value1, value2, POINT, unpack_stream)
 }
 
-pub fn unchecked_write_unchecked_overwrite_checked_read<'a, TStore: CqlWritable + CqlStreamReadable>(
+pub fn unchecked_write_unchecked_overwrite_checked_read<'a, TStore: CqlBufferWritable + CqlBufferStreamReadable>(
             db_location: &str,
             value1: TStore::ValueType,
             value2: TStore::ValueType,
@@ -36,7 +36,7 @@ pub fn unchecked_write_unchecked_overwrite_checked_read<'a, TStore: CqlWritable
     test_functions::unchecked_write_unchecked_overwrite_checked_read::<TStore>(db_location, value1, value2, POINT, unpack_stream)
 }
 
-pub fn unchecked_write_checked_overwrite_checked_read<'a, TStore: CqlWritable + CqlStreamReadable>(
+pub fn unchecked_write_checked_overwrite_checked_read<'a, TStore: CqlBufferWritable + CqlBufferStreamReadable>(
             db_location: &str,
             value1: TStore::ValueType,
             value2: TStore::ValueType,
@@ -46,7 +46,7 @@ pub fn unchecked_write_checked_overwrite_checked_read<'a, TStore: CqlWritable +
     test_functions::unchecked_write_checked_overwrite_checked_read::<TStore>(db_location, value1, value2, POINT, unpack_stream)
 }
 
-pub fn checked_write_unchecked_overwrite_checked_read<'a, TStore: CqlWritable + CqlStreamReadable>(
+pub fn checked_write_unchecked_overwrite_checked_read<'a, TStore: CqlBufferWritable + CqlBufferStreamReadable>(
             db_location: &str,
             value1: TStore::ValueType,
             value2: TStore::ValueType,
@@ -56,7 +56,7 @@ pub fn checked_write_unchecked_overwrite_checked_read<'a, TStore: CqlWritable +
     test_functions::checked_write_unchecked_overwrite_checked_read::<TStore>(db_location, value1, value2, POINT, unpack_stream)
 }
 
-pub fn checked_write_unchecked_overwrite_unchecked_read<'a, TStore: CqlWritable + CqlStreamReadable>(
+pub fn checked_write_unchecked_overwrite_unchecked_read<'a, TStore: CqlBufferWritable + CqlBufferStreamReadable>(
             db_location: &str,
             value1: TStore::ValueType,
             value2: TStore::ValueType,
@@ -66,7 +66,7 @@ pub fn checked_write_unchecked_overwrite_unchecked_read<'a, TStore: CqlWritable
     test_functions::checked_write_unchecked_overwrite_unchecked_read::<TStore>(db_location, value1, value2, POINT, unpack_stream)
 }
 
-pub fn unchecked_write_checked_overwrite_unchecked_read<'a, TStore: CqlWritable + CqlStreamReadable>(
+pub fn unchecked_write_checked_overwrite_unchecked_read<'a, TStore: CqlBufferWritable + CqlBufferStreamReadable>(
             db_location: &str,
             value1: TStore::ValueType,
             value2: TStore::ValueType,
@@ -76,7 +76,7 @@ pub fn unchecked_write_checked_overwrite_unchecked_read<'a, TStore: CqlWritable
     test_functions::unchecked_write_checked_overwrite_unchecked_read::<TStore>(db_location, value1, value2, POINT, unpack_stream)
 }
 
-pub fn checked_write_checked_overwrite_unchecked_read<'a, TStore: CqlWritable + CqlStreamReadable>(
+pub fn checked_write_checked_overwrite_unchecked_read<'a, TStore: CqlBufferWritable + CqlBufferStreamReadable>(
             db_location: &str,
             value1: TStore::ValueType,
             value2: TStore::ValueType,
@@ -0,0 +1,153 @@
+use std::fmt::{ Debug };
+use std::cmp::{ PartialEq };
+use std::io::{ Cursor, SeekFrom, Seek };
+use cql_model::{ CqlBufferWritable, CqlBufferStreamReadable, CqlBufferStreamWritable };
+
+const AXIS: [u64; 1] = [
+    6,
+];
+
+const N_VALUES: usize = 3;
+const SOURCE_POINT: [u64; 1] = [1];
+const DESTINATION_POINT: [u64; 1] = [4];
+const POINT1: [u64; 1] = [1];
+const POINT2: [u64; 1] = [2];
+const POINT3: [u64; 1] = [3];
+
+pub fn unchecked<'a, TStore: CqlBufferWritable + CqlBufferStreamReadable + CqlBufferStreamWritable>(
+            db_location: &str,
+            value1: TStore::ValueType,
+            value2: TStore::ValueType,
+            value3: TStore::ValueType,
+            unpack_stream: &'a dyn Fn(&mut Cursor<Vec<u8>>, usize, &mut [TStore::ValueType])
+        )
+        where TStore::ValueType: Clone + Debug + PartialEq + Default {
+    cql_db::create_db_unchecked::<TStore>(
+        db_location,
+        &AXIS
+    ).unwrap();
+
+    cql_db::write_value_unchecked::<TStore>(
+        db_location,
+        &POINT1,
+        value1.clone()
+    ).unwrap();
+
+    cql_db::write_value_unchecked::<TStore>(
+        db_location,
+        &POINT2,
+        value2.clone()
+    ).unwrap();
+
+    cql_db::write_value_unchecked::<TStore>(
+        db_location,
+        &POINT3,
+        value3.clone()
+    ).unwrap();
+
+    let mut source_stream = Cursor::new(Vec::new());
+
+    cql_db::read_to_stream_unchecked::<TStore>(
+        db_location,
+        &mut source_stream,
+        &SOURCE_POINT,
+        N_VALUES as u64
+    ).unwrap();
+
+    source_stream.seek(SeekFrom::Start(0)).unwrap();
+
+    cql_db::write_from_stream_unchecked::<TStore>(
+        db_location,
+        &mut source_stream,
+        &DESTINATION_POINT,
+        N_VALUES as u64
+    ).unwrap();
+
+    let mut result = Vec::with_capacity(N_VALUES);
+    result.resize_with(N_VALUES, Default::default);
+    let mut stream = Cursor::new(Vec::new());
+
+    cql_db::read_to_stream_unchecked::<TStore>(
+        db_location,
+        &mut stream,
+        &DESTINATION_POINT,
+        N_VALUES as u64
+    ).unwrap();
+
+    stream.seek(SeekFrom::Start(0)).unwrap();
+
+    unpack_stream(&mut stream, N_VALUES, &mut result);
+
+    assert_eq!(result[0], value1);
+    assert_eq!(result[1], value2);
+    assert_eq!(result[2], value3);
+}
+
+pub fn checked<'a, TStore: CqlBufferWritable + CqlBufferStreamReadable + CqlBufferStreamWritable>(
+            db_location: &str,
+            value1: TStore::ValueType,
+            value2: TStore::ValueType,
+            value3: TStore::ValueType,
+            unpack_stream: &'a dyn Fn(&mut Cursor<Vec<u8>>, usize, &mut [TStore::ValueType])
+        )
+        where TStore::ValueType: Clone + Debug + PartialEq + Default {
+    cql_db::create_db_unchecked::<TStore>(
+        db_location,
+        &AXIS
+    ).unwrap();
+
+    cql_db::write_value::<TStore>(
+        db_location,
+        &POINT1,
+        value1.clone()
+    ).unwrap();
+
+    cql_db::write_value::<TStore>(
+        db_location,
+        &POINT2,
+        value2.clone()
+    ).unwrap();
+
+    cql_db::write_value::<TStore>(
+        db_location,
+        &POINT3,
+        value3.clone()
+    ).unwrap();
+
+    let mut source_stream = Cursor::new(Vec::new());
+
+    cql_db::read_to_stream::<TStore>(
+        db_location,
+        &mut source_stream,
+        &SOURCE_POINT,
+        N_VALUES as u64
+    ).unwrap();
+
+    source_stream.seek(SeekFrom::Start(0)).unwrap();
+
+    cql_db::write_from_stream::<TStore>(
+        db_location,
+        &mut source_stream,
+        &DESTINATION_POINT,
+        N_VALUES as u64
+    ).unwrap();
+
+    let mut result = Vec::with_capacity(N_VALUES);
+    result.resize_with(N_VALUES, Default::default);
+    let mut stream = Cursor::new(Vec::new());
+
+    cql_db::read_to_stream::<TStore>(
+        db_location,
+        &mut stream,
+        &DESTINATION_POINT,
+        N_VALUES as u64
+    ).unwrap();
+
+    stream.seek(SeekFrom::Start(0)).unwrap();
+
+    unpack_stream(&mut stream, N_VALUES, &mut result);
+
+    assert_eq!(result[0], value1);
+    assert_eq!(result[1], value2);
+    assert_eq!(result[2], value3);
+}
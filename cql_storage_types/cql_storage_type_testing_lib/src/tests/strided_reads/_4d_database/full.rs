@@ -0,0 +1,174 @@
+use std::fmt::{ Debug };
+use std::cmp::{ PartialEq };
+use std::io::{ Cursor, SeekFrom, Seek };
+use cql_model::{ CqlBufferWritable, CqlBufferStreamReadable };
+use cql_db::Direction;
+
+const AXIS: [u64; 4] = [
+    3,
+    5,
+    7,
+    9,
+];
+
+const N_VALUES_TO_READ: usize = 3;
+const STRIDE: u64 = 4;
+const POINT1: [u64; 4] = [2, 3, 4, 1];
+const POINT2: [u64; 4] = [2, 3, 4, 5];
+const POINT3: [u64; 4] = [2, 3, 4, 9];
+
+pub fn unchecked<'a, TStore: CqlBufferWritable + CqlBufferStreamReadable>(
+            db_location: &str,
+            value1: TStore::ValueType,
+            value2: TStore::ValueType,
+            value3: TStore::ValueType,
+            unpack_stream: &'a dyn Fn(&mut Cursor<Vec<u8>>, usize, &mut [TStore::ValueType])
+        )
+        where TStore::ValueType: Clone + Debug + PartialEq + Default {
+    cql_db::create_db_unchecked::<TStore>(
+        db_location,
+        &AXIS
+    ).unwrap();
+
+    cql_db::link_dimensions_unchecked::<TStore>(
+        db_location,
+        &POINT1[0..3],
+    ).unwrap();
+
+    cql_db::write_value_unchecked::<TStore>(
+        db_location,
+        &POINT1,
+        value1.clone()
+    ).unwrap();
+
+    cql_db::write_value_unchecked::<TStore>(
+        db_location,
+        &POINT2,
+        value2.clone()
+    ).unwrap();
+
+    cql_db::write_value_unchecked::<TStore>(
+        db_location,
+        &POINT3,
+        value3.clone()
+    ).unwrap();
+
+    let mut forward = Vec::with_capacity(N_VALUES_TO_READ);
+    forward.resize_with(N_VALUES_TO_READ, Default::default);
+    let mut forward_stream = Cursor::new(Vec::new());
+
+    cql_db::read_strided_to_stream_unchecked::<TStore>(
+        db_location,
+        &mut forward_stream,
+        &POINT1,
+        N_VALUES_TO_READ as u64,
+        STRIDE,
+        Direction::Forward
+    ).unwrap();
+
+    forward_stream.seek(SeekFrom::Start(0)).unwrap();
+
+    unpack_stream(&mut forward_stream, N_VALUES_TO_READ, &mut forward);
+
+    let mut reversed = Vec::with_capacity(N_VALUES_TO_READ);
+    reversed.resize_with(N_VALUES_TO_READ, Default::default);
+    let mut reversed_stream = Cursor::new(Vec::new());
+
+    cql_db::read_strided_to_stream_unchecked::<TStore>(
+        db_location,
+        &mut reversed_stream,
+        &POINT1,
+        N_VALUES_TO_READ as u64,
+        STRIDE,
+        Direction::Reverse
+    ).unwrap();
+
+    reversed_stream.seek(SeekFrom::Start(0)).unwrap();
+
+    unpack_stream(&mut reversed_stream, N_VALUES_TO_READ, &mut reversed);
+
+    assert_eq!(forward[0], value1);
+    assert_eq!(forward[1], value2);
+    assert_eq!(forward[2], value3);
+    assert_eq!(reversed[0], value3);
+    assert_eq!(reversed[1], value2);
+    assert_eq!(reversed[2], value1);
+}
+
+pub fn checked<'a, TStore: CqlBufferWritable + CqlBufferStreamReadable>(
+            db_location: &str,
+            value1: TStore::ValueType,
+            value2: TStore::ValueType,
+            value3: TStore::ValueType,
+            unpack_stream: &'a dyn Fn(&mut Cursor<Vec<u8>>, usize, &mut [TStore::ValueType])
+        )
+        where TStore::ValueType: Clone + Debug + PartialEq + Default {
+    cql_db::create_db_unchecked::<TStore>(
+        db_location,
+        &AXIS
+    ).unwrap();
+
+    cql_db::link_dimensions::<TStore>(
+        db_location,
+        &POINT1[0..3],
+    ).unwrap();
+
+    cql_db::write_value::<TStore>(
+        db_location,
+        &POINT1,
+        value1.clone()
+    ).unwrap();
+
+    cql_db::write_value::<TStore>(
+        db_location,
+        &POINT2,
+        value2.clone()
+    ).unwrap();
+
+    cql_db::write_value::<TStore>(
+        db_location,
+        &POINT3,
+        value3.clone()
+    ).unwrap();
+
+    let mut forward = Vec::with_capacity(N_VALUES_TO_READ);
+    forward.resize_with(N_VALUES_TO_READ, Default::default);
+    let mut forward_stream = Cursor::new(Vec::new());
+
+    cql_db::read_strided_to_stream::<TStore>(
+        db_location,
+        &mut forward_stream,
+        &POINT1,
+        N_VALUES_TO_READ as u64,
+        STRIDE,
+        Direction::Forward
+    ).unwrap();
+
+    forward_stream.seek(SeekFrom::Start(0)).unwrap();
+
+    unpack_stream(&mut forward_stream, N_VALUES_TO_READ, &mut forward);
+
+    let mut reversed = Vec::with_capacity(N_VALUES_TO_READ);
+    reversed.resize_with(N_VALUES_TO_READ, Default::default);
+    let mut reversed_stream = Cursor::new(Vec::new());
+
+    cql_db::read_strided_to_stream::<TStore>(
+        db_location,
+        &mut reversed_stream,
+        &POINT1,
+        N_VALUES_TO_READ as u64,
+        STRIDE,
+        Direction::Reverse
+    ).unwrap();
+
+    reversed_stream.seek(SeekFrom::Start(0)).unwrap();
+
+    unpack_stream(&mut reversed_stream, N_VALUES_TO_READ, &mut reversed);
+
+    assert_eq!(forward[0], value1);
+    assert_eq!(forward[1], value2);
+    assert_eq!(forward[2], value3);
+    assert_eq!(reversed[0], value3);
+    assert_eq!(reversed[1], value2);
+    assert_eq!(reversed[2], value1);
+}
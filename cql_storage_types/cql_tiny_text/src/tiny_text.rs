@@ -1,14 +1,14 @@
 /*!
-This crate implements various [CqlType](https://docs.rs/cql_model/0.2/cql_model/trait.CqlType.html) derivatives for storing String values of up to (and including) 255 chars in a
-[CQL database](https://docs.rs/cql_db/0.2/cql_db/).
+This crate implements various [CqlType](https://docs.rs/cql_model/0.3/cql_model/trait.CqlType.html) derivatives for storing String values of up to (and including) 255 chars in a
+[CQL database](https://docs.rs/cql_db/0.3/cql_db/).
 
-Will allocate 1020 bytes per value [linked](https://docs.rs/cql_db/0.2/cql_db/fn.link_dimensions.html).
+Will allocate 1020 bytes per value [linked](https://docs.rs/cql_db/0.3/cql_db/fn.link_dimensions.html).
 
 # Benchmarks
 Benchmarks supplied below are fairly rudimentary (and rounded) and are there to give a rough idea of relative costs.
 Full benchmark code can be found in [github](https://github.com/AndrewSisley/CQLDb/tree/master/cql_storage_types/cql_tiny_text) and can be run with
 `rustup run nightly cargo bench`, but please be aware that they will allocate ~102MB of disk space.  The read_to_stream benchmarks also differ slightly from
-other [CqlType](https://docs.rs/cql_model/0.2/cql_model/trait.CqlType.html) derivatives as they stream into a Vector, not an Array.
+other [CqlType](https://docs.rs/cql_model/0.3/cql_model/trait.CqlType.html) derivatives as they stream into a Vector, not an Array.
 
 Operation | Database dimensions | Mean time (ns)
 --- | --- | ---
@@ -80,17 +80,16 @@ assert_eq!(result[2], TinyText::try_from(value3)?);
 # }
 ```
 */
-#![doc(html_root_url = "https://docs.rs/cql_tiny_text/0.2.1")]
+#![doc(html_root_url = "https://docs.rs/cql_tiny_text/0.3.0")]
 
 pub mod errors;
 pub mod interop;
 
-use std::fs::{ File, OpenOptions };
 use std::io;
-use std::io::{ Read, Write, Cursor, SeekFrom, Seek };
+use std::io::{ Read, Write, Cursor };
 use byteorder::{ ReadBytesExt, WriteBytesExt, LittleEndian };
 
-use cql_model::{ CqlType, CqlWritable, CqlReadable, CqlStreamReadable };
+use cql_model::{ CqlType, CqlRangeReadable, CqlBufferStreamReadable, CqlBufferWritable, CqlBufferStreamWritable };
 
 const CONTENT_SIZE: usize = 255 * 4;
 const LENGTH_SIZE: usize = 2;
@@ -104,6 +103,7 @@ pub struct TinyText(String);
 impl CqlType for TinyText {
     type ValueType = Self;
     const VALUE_SIZE: usize = CONTENT_SIZE + LENGTH_SIZE;
+    const TYPE_NAME: &'static str = "cql_tiny_text::TinyText";
 }
 
 impl TinyText {
@@ -112,96 +112,78 @@ impl TinyText {
     }
 }
 
-impl CqlWritable for TinyText {
-    fn write_to_db(db_location: &str, value_location: u64, input_value: Self::ValueType) -> io::Result<()> {
-        let mut file = OpenOptions::new().write(true).open(db_location)?;
-
-        // unwrap should be considered safe by this point, with earlier checks in the cql_db crate (if not deliberately unchecked)
-        file.seek(SeekFrom::Start(value_location * Self::VALUE_SIZE as u64)).unwrap();
+impl CqlRangeReadable for TinyText {
+    fn read_from_buffer(buffer: &[u8]) -> io::Result<Self::ValueType> {
+        let mut size_rdr = Cursor::new(&buffer[0..LENGTH_SIZE]);
+        let size = usize::from(size_rdr.read_u16::<LittleEndian>()?);
 
-        let input_length: u16 = input_value.0.len() as u16;
-        let mut buffer = vec![];
-        buffer.write_u16::<LittleEndian>(input_length)?;
-        buffer.extend(&input_value.0.into_bytes());
+        if size > CONTENT_SIZE {
+            return Err(
+                io::Error::new(io::ErrorKind::InvalidData, errors::ValueTooLargeError)
+            )
+        }
 
-        file.write_all(&buffer)
+        match String::from_utf8(buffer[LENGTH_SIZE..LENGTH_SIZE + size].to_vec()) {
+            Ok(value) => Ok(TinyText(value)),
+            Err(e) => Err(io::Error::new(io::ErrorKind::InvalidData, e)),
+        }
     }
 }
 
-impl CqlReadable for TinyText {
-    fn read_from_db(db_location: &str, value_location: u64) -> io::Result<Self::ValueType> {
-        let mut file = File::open(&db_location)?;
+impl CqlBufferStreamReadable for TinyText {
+    fn read_buffer_to_stream(buffer: &[u8], stream: &mut dyn Write) -> io::Result<()> {
+        for value_buffer in buffer.chunks(Self::VALUE_SIZE) {
+            let mut size_rdr = Cursor::new(&value_buffer[0..LENGTH_SIZE]);
+            let size = usize::from(size_rdr.read_u16::<LittleEndian>()?);
 
-        // unwrap should be considered safe by this point, with earlier checks in the cql_db crate (if not deliberately unchecked)
-        file.seek(SeekFrom::Start(value_location * Self::VALUE_SIZE as u64)).unwrap();
+            if size > CONTENT_SIZE {
+                return Err(
+                    io::Error::new(io::ErrorKind::InvalidData, errors::ValueTooLargeError)
+                )
+            }
 
-        let mut size_buffer = [0; LENGTH_SIZE];
-        file.read_exact(&mut size_buffer)?;
+            // only the used portion of each value is written to the stream, as with read_to_stream
+            stream.write_all(&value_buffer[0..LENGTH_SIZE + size])?;
+        }
 
-        let mut size_rdr = Cursor::new(size_buffer);
-        let size = usize::from(size_rdr.read_u16::<LittleEndian>()?);
+        stream.flush()
+    }
+}
 
-        if size == 0 {
-            return Ok(TinyText::new())
+impl CqlBufferWritable for TinyText {
+    fn write_to_buffer(input_value: Self::ValueType, buffer: &mut [u8]) -> io::Result<()> {
+        let input_bytes = input_value.0.into_bytes();
+        if input_bytes.len() > CONTENT_SIZE {
+            return Err(
+                io::Error::new(io::ErrorKind::InvalidData, errors::ValueTooLargeError)
+            )
         }
 
-        let mut value_buffer = Vec::with_capacity(size);
-        file.take(size as u64).read_to_end(&mut value_buffer)?;
+        (&mut buffer[0..LENGTH_SIZE]).write_u16::<LittleEndian>(input_bytes.len() as u16)?;
+        buffer[LENGTH_SIZE..LENGTH_SIZE + input_bytes.len()].copy_from_slice(&input_bytes);
 
-        Ok(
-            TinyText(
-                // unwrap should be safe here, as we assume we are the only ones writing to the file, however low performance cost plus the fact that someone else `could`
-                // write to the file discourages the use of the unsafe method that skips the checks
-                String::from_utf8(value_buffer).unwrap()
-            )
-        )
+        Ok(())
     }
 }
 
-impl CqlStreamReadable for TinyText {
-    fn read_to_stream(db_location: &str, stream: &mut dyn Write, value_location: u64, n_values: u64) -> io::Result<()> {
-        let mut file = File::open(&db_location)?;
-
-        // unwrap should be considered safe by this point, with earlier checks in the cql_db crate (if not deliberately unchecked)
-        file.seek(SeekFrom::Start(value_location * Self::VALUE_SIZE as u64)).unwrap();
-        let mut value_buffer = [0; CONTENT_SIZE];
-
-        for _i in 0..n_values {
-            // must have value cleared for each value read or previous value will be quietly retained and re-written to the (out) stream
-            let mut size_buffer = [0; LENGTH_SIZE];
-
-            match file.read_exact(&mut size_buffer) {
-                Err(e) => {
-                    // ignore io::ErrorKind::UnexpectedEof and continue
-                    if e.kind() != io::ErrorKind::UnexpectedEof {
-                        return Err(e)
-                    }
-                }
-                _ => { }
-            }
-            let mut size_rdr = Cursor::new(size_buffer);
-            let size = usize::from(size_rdr.read_u16::<LittleEndian>()?);
+impl CqlBufferStreamWritable for TinyText {
+    fn write_stream_to_buffer(stream: &mut dyn Read, buffer: &mut [u8]) -> io::Result<()> {
+        for value_buffer in buffer.chunks_mut(Self::VALUE_SIZE) {
+            stream.read_exact(&mut value_buffer[0..LENGTH_SIZE])?;
 
-            match file.read_exact(&mut value_buffer) {
-                Err(e) => {
-                    // ignore io::ErrorKind::UnexpectedEof and continue
-                    if e.kind() != io::ErrorKind::UnexpectedEof {
-                        return Err(e)
-                    }
-                }
-                _ => { }
-            }
+            let mut size_rdr = Cursor::new(&value_buffer[0..LENGTH_SIZE]);
+            let size = usize::from(size_rdr.read_u16::<LittleEndian>()?);
 
-            let mut write_buffer = Vec::with_capacity(LENGTH_SIZE + size);
-            write_buffer.extend(&size_buffer);
-            for i in 0..size {
-                write_buffer.push(value_buffer[i]);
+            if size > CONTENT_SIZE {
+                return Err(
+                    io::Error::new(io::ErrorKind::InvalidData, errors::ValueTooLargeError)
+                )
             }
 
-            stream.write_all(&mut write_buffer)?;
+            stream.read_exact(&mut value_buffer[LENGTH_SIZE..LENGTH_SIZE + size])?;
         }
 
-        stream.flush()
+        Ok(())
     }
 }
 
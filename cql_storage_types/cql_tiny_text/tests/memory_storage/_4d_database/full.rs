@@ -0,0 +1,21 @@
+use cql_tiny_text::{ TinyText };
+use std::convert::TryFrom;
+use cql_storage_type_testing_lib::tests::memory_storage::_4d_database::full;
+
+#[test]
+fn unchecked() {
+    full::unchecked::<TinyText>(
+        TinyText::try_from("testValue1").unwrap(),
+        TinyText::try_from("testValue22").unwrap(),
+        TinyText::try_from("testValue333").unwrap()
+    );
+}
+
+#[test]
+fn checked() {
+    full::checked::<TinyText>(
+        TinyText::try_from("testValue1").unwrap(),
+        TinyText::try_from("testValue22").unwrap(),
+        TinyText::try_from("testValue333").unwrap()
+    );
+}
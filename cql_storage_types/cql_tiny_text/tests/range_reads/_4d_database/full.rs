@@ -0,0 +1,27 @@
+use serial_test::serial;
+use crate::constants::DATABASE_LOCATION;
+use cql_tiny_text::{ TinyText };
+use std::convert::TryFrom;
+use cql_storage_type_testing_lib::tests::range_reads::_4d_database::full;
+
+#[test]
+#[serial]
+fn unchecked() {
+    full::unchecked::<TinyText>(
+        DATABASE_LOCATION,
+        TinyText::try_from("testValue1").unwrap(),
+        TinyText::try_from("testValue22").unwrap(),
+        TinyText::try_from("testValue333").unwrap()
+    );
+}
+
+#[test]
+#[serial]
+fn checked() {
+    full::checked::<TinyText>(
+        DATABASE_LOCATION,
+        TinyText::try_from("testValue1").unwrap(),
+        TinyText::try_from("testValue22").unwrap(),
+        TinyText::try_from("testValue333").unwrap()
+    );
+}
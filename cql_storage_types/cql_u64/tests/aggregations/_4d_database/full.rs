@@ -0,0 +1,41 @@
+use serial_test::serial;
+use crate::constants::DATABASE_LOCATION;
+use cql_u64::{ U64 };
+use cql_storage_type_testing_lib::tests::aggregations::Expected;
+use cql_storage_type_testing_lib::tests::aggregations::_4d_database::full;
+
+#[test]
+#[serial]
+fn unchecked() {
+    full::unchecked::<U64>(
+        DATABASE_LOCATION,
+        2,
+        3,
+        4,
+        Expected {
+            sum: 9,
+            min: Some(0),
+            max: Some(4),
+            mean: Some(1.0),
+            count: 9,
+        }
+    );
+}
+
+#[test]
+#[serial]
+fn checked() {
+    full::checked::<U64>(
+        DATABASE_LOCATION,
+        2,
+        3,
+        4,
+        Expected {
+            sum: 9,
+            min: Some(0),
+            max: Some(4),
+            mean: Some(1.0),
+            count: 9,
+        }
+    );
+}
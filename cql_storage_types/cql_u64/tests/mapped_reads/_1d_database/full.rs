@@ -0,0 +1,26 @@
+use serial_test::serial;
+use crate::constants::DATABASE_LOCATION;
+use cql_u64::{ U64 };
+use cql_storage_type_testing_lib::tests::mapped_reads::_1d_database::full;
+
+#[test]
+#[serial]
+fn unchecked() {
+    full::unchecked::<U64>(
+        DATABASE_LOCATION,
+        11,
+        22,
+        33
+    );
+}
+
+#[test]
+#[serial]
+fn checked() {
+    full::checked::<U64>(
+        DATABASE_LOCATION,
+        11,
+        22,
+        33
+    );
+}
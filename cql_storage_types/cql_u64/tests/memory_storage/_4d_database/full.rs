@@ -0,0 +1,20 @@
+use cql_u64::{ U64 };
+use cql_storage_type_testing_lib::tests::memory_storage::_4d_database::full;
+
+#[test]
+fn unchecked() {
+    full::unchecked::<U64>(
+        11,
+        22,
+        33
+    );
+}
+
+#[test]
+fn checked() {
+    full::checked::<U64>(
+        11,
+        22,
+        33
+    );
+}
@@ -5,6 +5,13 @@ use std::io::{ Cursor };
 use constants::DATABASE_LOCATION;
 use cql_u64::{ U64, unpack_stream };
 use cql_storage_type_testing_lib::tests;
+pub mod stream_writes;
+pub mod range_reads;
+pub mod strided_reads;
+pub mod aggregations;
+pub mod rollup_levels;
+pub mod memory_storage;
+pub mod mapped_reads;
 
 #[test]
 #[serial]